@@ -7,7 +7,7 @@ use std::path::PathBuf;
 use anyhow::Result;
 use clap::Parser;
 use tracing_subscriber::EnvFilter;
-use viewpoint_mcp::browser::{BrowserConfig, BrowserType, ViewportSize};
+use viewpoint_mcp::browser::{BrowserConfig, BrowserType, ProxyConfig, ViewportSize};
 use viewpoint_mcp::transport::{SseConfig, SseTransport, StdioTransport};
 use viewpoint_mcp::{ImageResponseMode, McpServer, ServerConfig};
 
@@ -45,6 +45,11 @@ struct Args {
     #[arg(long, value_name = "KEY")]
     api_key: Option<String>,
 
+    /// Reject authenticated SSE requests beyond this many per second per client
+    /// IP with 429 Too Many Requests
+    #[arg(long, value_name = "N", requires = "port")]
+    max_requests_per_second: Option<u32>,
+
     /// Enable optional capabilities (comma-separated: vision,pdf)
     #[arg(long, value_name = "CAPS")]
     caps: Option<String>,
@@ -60,17 +65,87 @@ struct Args {
     /// - omit: Save to directory, return confirmation only
     #[arg(long, value_name = "MODE", default_value = "file")]
     image_responses: String,
+
+    /// URL to open when the browser starts, before the first tool call
+    #[arg(long, value_name = "URL")]
+    initial_url: Option<String>,
+
+    /// Abort tool calls that run longer than this many milliseconds
+    #[arg(long, value_name = "MS")]
+    tool_timeout: Option<u64>,
+
+    /// Split `browser_evaluate` results longer than this many characters into chunks
+    #[arg(long, value_name = "CHARS")]
+    max_evaluate_result_length: Option<usize>,
+
+    /// Proxy server for the default context (e.g., "socks5://proxy:1080")
+    #[arg(long, value_name = "URL")]
+    proxy_server: Option<String>,
+
+    /// Username for proxy authentication
+    #[arg(long, value_name = "USER", requires = "proxy_server")]
+    proxy_username: Option<String>,
+
+    /// Password for proxy authentication
+    #[arg(long, value_name = "PASS", requires = "proxy_username")]
+    proxy_password: Option<String>,
+
+    /// Comma-separated hosts that bypass the proxy (e.g., "localhost,*.internal.com")
+    #[arg(long, value_name = "HOSTS", requires = "proxy_server")]
+    proxy_bypass: Option<String>,
+
+    /// Install the browser automatically when none is found at launch
+    #[arg(long, default_value_t = false)]
+    auto_install: bool,
+
+    /// Only log warnings and errors (overrides `RUST_LOG`)
+    #[arg(long, default_value_t = false, conflicts_with = "trace")]
+    quiet: bool,
+
+    /// Log everything, including trace-level output (overrides `RUST_LOG`)
+    #[arg(long, default_value_t = false)]
+    trace: bool,
+}
+
+impl Args {
+    /// Log filter selected by `--quiet`/`--trace`, falling back to `RUST_LOG`
+    fn log_filter(&self) -> EnvFilter {
+        if self.quiet {
+            EnvFilter::new("warn")
+        } else if self.trace {
+            EnvFilter::new("trace")
+        } else {
+            EnvFilter::from_default_env()
+        }
+    }
+
+    /// Proxy configuration from the `--proxy-*` flags
+    fn proxy_config(&self) -> Option<ProxyConfig> {
+        let mut proxy = ProxyConfig::new(self.proxy_server.as_deref()?);
+        if let (Some(username), Some(password)) = (&self.proxy_username, &self.proxy_password) {
+            proxy = proxy.with_auth(username, password);
+        }
+        if let Some(bypass) = &self.proxy_bypass {
+            proxy = proxy.with_bypass(bypass);
+        }
+        Some(proxy)
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize tracing
+    let args = Args::parse();
+
+    // Initialize tracing. Logs go to stderr so they never mix with the
+    // JSON-RPC messages on stdout in stdio mode.
     tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::from_default_env())
+        .with_env_filter(args.log_filter())
+        .with_writer(std::io::stderr)
         .with_target(false)
         .init();
 
-    let args = Args::parse();
+    // Read proxy flags before fields are moved out of `args`
+    let proxy = args.proxy_config();
 
     // Parse viewport size
     let viewport = args.viewport_size.map(|s| {
@@ -106,6 +181,9 @@ async fn main() -> Result<()> {
         cdp_endpoint: args.cdp_endpoint,
         user_data_dir: args.user_data_dir,
         capabilities: capabilities.clone(),
+        executable_path: None,
+        auto_install: args.auto_install,
+        proxy,
     };
 
     // Build server config
@@ -116,6 +194,9 @@ async fn main() -> Result<()> {
             .screenshot_dir
             .unwrap_or_else(|| PathBuf::from(".viewpoint-mcp-screenshots")),
         image_responses,
+        initial_url: args.initial_url,
+        tool_timeout_ms: args.tool_timeout,
+        max_evaluate_result_length: args.max_evaluate_result_length,
         ..Default::default()
     };
 
@@ -124,7 +205,7 @@ async fn main() -> Result<()> {
     // Choose transport based on --port flag
     if let Some(port) = args.port {
         // SSE transport
-        let sse_config = match args.api_key {
+        let mut sse_config = match args.api_key {
             Some(key) => SseConfig::with_api_key(port, key),
             None => {
                 let config = SseConfig::new(port);
@@ -132,6 +213,7 @@ async fn main() -> Result<()> {
                 config
             }
         };
+        sse_config.max_requests_per_second = args.max_requests_per_second;
 
         let transport = SseTransport::new(server, sse_config);
         transport.run().await?;
@@ -2,6 +2,8 @@
 
 use std::path::PathBuf;
 
+use serde::Deserialize;
+
 /// Browser configuration for the MCP server.
 ///
 /// Controls how the browser is launched and configured. By default,
@@ -10,7 +12,7 @@ use std::path::PathBuf;
 /// # Examples
 ///
 /// ```
-/// use viewpoint_mcp::browser::{BrowserConfig, BrowserType, ViewportSize};
+/// use viewpoint_mcp::browser::{BrowserConfig, BrowserType, ProxyConfig, ViewportSize};
 /// use std::path::PathBuf;
 ///
 /// // Default configuration (headed Chromium)
@@ -34,8 +36,14 @@ use std::path::PathBuf;
 ///     user_data_dir: Some(PathBuf::from("/tmp/browser-profile")),
 ///     ..Default::default()
 /// };
+///
+/// // Route the default context through a proxy
+/// let config = BrowserConfig {
+///     proxy: Some(ProxyConfig::new("socks5://proxy.example.com:1080")),
+///     ..Default::default()
+/// };
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct BrowserConfig {
     /// Run browser in headless mode
     pub headless: bool,
@@ -54,6 +62,34 @@ pub struct BrowserConfig {
 
     /// Optional capabilities (vision, pdf)
     pub capabilities: Vec<String>,
+
+    /// Browser executable to launch instead of the one viewpoint-core finds
+    pub executable_path: Option<PathBuf>,
+
+    /// Install the browser automatically if none is found at launch.
+    ///
+    /// Off by default, since installing downloads and runs the Playwright
+    /// installer through `npx`.
+    pub auto_install: bool,
+
+    /// Proxy for the default context (contexts created later set their own)
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Default for BrowserConfig {
+    fn default() -> Self {
+        Self {
+            headless: false,
+            browser_type: BrowserType::default(),
+            viewport: None,
+            cdp_endpoint: None,
+            user_data_dir: None,
+            capabilities: Vec::new(),
+            executable_path: None,
+            auto_install: false,
+            proxy: None,
+        }
+    }
 }
 
 /// Browser type
@@ -81,7 +117,7 @@ pub enum BrowserType {
 /// // Parse from string format "WxH"
 /// let viewport = ViewportSize::parse("1920x1080").unwrap();
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct ViewportSize {
     /// Width in pixels
     pub width: u32,
@@ -180,4 +216,92 @@ impl ProxyConfig {
         self.bypass = Some(bypass.into());
         self
     }
+
+    /// Convert to viewpoint-core's `ProxyConfig` for context creation.
+    ///
+    /// Credentials are only applied when both username and password are set.
+    #[must_use]
+    pub fn to_viewpoint(&self) -> viewpoint_core::ProxyConfig {
+        let mut config = viewpoint_core::ProxyConfig::new(&self.server);
+        if let (Some(username), Some(password)) = (&self.username, &self.password) {
+            config = config.credentials(username, password);
+        }
+        if let Some(bypass) = &self.bypass {
+            config = config.bypass(bypass);
+        }
+        config
+    }
+}
+
+/// Options applied when creating a browser context.
+///
+/// # Examples
+///
+/// ```
+/// use viewpoint_mcp::browser::ContextOptions;
+///
+/// // Context that identifies as a mobile browser
+/// let options = ContextOptions::new()
+///     .with_user_agent("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)");
+/// assert!(options.proxy.is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ContextOptions {
+    /// Proxy to route the context's traffic through
+    pub proxy: Option<viewpoint_core::ProxyConfig>,
+
+    /// User-agent string override
+    pub user_agent: Option<String>,
+
+    /// Locale as a BCP 47 language tag (e.g., "de-DE")
+    pub locale: Option<String>,
+
+    /// Timezone as an IANA timezone ID (e.g., "Europe/Berlin")
+    pub timezone: Option<String>,
+
+    /// Viewport size for the context's pages
+    pub viewport: Option<ViewportSize>,
+}
+
+impl ContextOptions {
+    /// Create options with browser defaults
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the proxy configuration
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: viewpoint_core::ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Set the user-agent override
+    #[must_use]
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Set the locale used for `navigator.language` and `Intl` formatting
+    #[must_use]
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Set the timezone used by the context's pages
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Set the viewport size, overriding the browser-wide default
+    #[must_use]
+    pub const fn with_viewport(mut self, viewport: ViewportSize) -> Self {
+        self.viewport = Some(viewport);
+        self
+    }
 }
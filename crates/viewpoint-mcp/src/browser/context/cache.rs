@@ -0,0 +1,198 @@
+//! Accessibility snapshot caching for the active page
+//!
+//! Cached snapshots are keyed on the page URL and a DOM mutation count kept
+//! by a `MutationObserver` injected into the page and each same-origin frame,
+//! so any change to a document (including ones made by page scripts)
+//! invalidates the cache. The observer state lives under a symbol key on each
+//! document, leaving the page's global names untouched.
+
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use serde_json::Value;
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use super::ContextState;
+use crate::snapshot::AccessibilitySnapshot;
+
+/// A cached accessibility snapshot with metadata
+pub struct CachedSnapshot {
+    /// The cached snapshot
+    pub snapshot: AccessibilitySnapshot,
+
+    /// When the snapshot was captured
+    pub captured_at: Instant,
+
+    /// Page URL and DOM mutation count when captured
+    pub key: SnapshotCacheKey,
+
+    /// Page index when captured
+    pub page_index: usize,
+
+    /// Whether `all_refs` mode was used when capturing
+    pub all_refs: bool,
+}
+
+/// Identifies a page's DOM state for snapshot caching
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotCacheKey {
+    /// URL of the page
+    pub url: String,
+
+    /// Random IDs assigned to the document and its frames' documents when
+    /// the observers were injected, so a reload of the same URL never matches
+    /// an older snapshot
+    pub document_id: String,
+
+    /// Number of DOM mutations observed in the document and its frames
+    pub mutation_count: u64,
+}
+
+impl SnapshotCacheKey {
+    /// Build a key from the mutation observer state returned by the page.
+    ///
+    /// Returns `None` if the value is not `{ id, count }`.
+    #[must_use]
+    pub fn from_observer(url: impl Into<String>, observer: &Value) -> Option<Self> {
+        Some(Self {
+            url: url.into(),
+            document_id: observer["id"].as_str()?.to_string(),
+            mutation_count: observer["count"].as_u64()?,
+        })
+    }
+
+    /// Get the key for the page's current DOM state.
+    ///
+    /// Injects the mutation observer on first use in each document, including
+    /// same-origin frames. Cross-origin frames cannot be observed. Returns
+    /// `None` if the page cannot be inspected, in which case snapshots should
+    /// not be cached.
+    pub async fn capture(page: &Page) -> Option<Self> {
+        let script = js! {
+            (() => {
+                const key = Symbol.for("viewpoint-mcp.mutations");
+                const ids = [];
+                let count = 0;
+                const visit = (win) => {
+                    let doc;
+                    try {
+                        doc = win.document;
+                    } catch (e) {
+                        return;
+                    }
+                    if (!doc) {
+                        return;
+                    }
+                    if (!doc[key]) {
+                        const state = { id: Math.random().toString(36).slice(2), count: 0 };
+                        new MutationObserver((records) => { state.count += records.length; })
+                            .observe(doc, {
+                                subtree: true,
+                                childList: true,
+                                attributes: true,
+                                characterData: true
+                            });
+                        Object.defineProperty(doc, key, { value: state });
+                    }
+                    ids.push(doc[key].id);
+                    count += doc[key].count;
+                    for (let i = 0; i < win.frames.length; i++) {
+                        visit(win.frames[i]);
+                    }
+                };
+                visit(window);
+                return { id: ids.join("."), count };
+            })()
+        };
+
+        let observer: Value = page.evaluate(&script).await.ok()?;
+        let url = page.url().await.ok()?;
+        Self::from_observer(url, &observer)
+    }
+}
+
+/// Default cache TTL in seconds
+const SNAPSHOT_CACHE_TTL_SECS: u64 = 5;
+
+impl ContextState {
+    /// Get the cached snapshot if still valid for `key`
+    ///
+    /// Returns `None` if:
+    /// - No snapshot is cached
+    /// - The cache has expired (>5 seconds old)
+    /// - The URL has changed or the DOM has mutated since capture
+    /// - The active page has changed
+    /// - The cache was invalidated by a page activation event
+    /// - The `all_refs` mode doesn't match (requesting `all_refs` when cached without, or vice versa)
+    pub async fn get_cached_snapshot(
+        &mut self,
+        key: &SnapshotCacheKey,
+        all_refs: bool,
+    ) -> Option<&AccessibilitySnapshot> {
+        // Check if cache was invalidated by activation event
+        {
+            let mut invalidated = self.shared_state.cache_invalidated.write().await;
+            if *invalidated {
+                *invalidated = false;
+                self.cached_snapshot = None;
+                return None;
+            }
+        }
+
+        let cache = self.cached_snapshot.as_ref()?;
+
+        // Check if the page navigated or its DOM mutated
+        if cache.key != *key {
+            self.invalidate_cache();
+            return None;
+        }
+
+        // Check if cache is expired
+        if cache.captured_at.elapsed().as_secs() > SNAPSHOT_CACHE_TTL_SECS {
+            return None;
+        }
+
+        // Check if page changed
+        let current_index = self.shared_state.active_page_index.load(Ordering::SeqCst);
+        if cache.page_index != current_index {
+            return None;
+        }
+
+        // Check if all_refs mode matches
+        // A cached all_refs snapshot can satisfy a non-all_refs request (superset)
+        // But a non-all_refs snapshot cannot satisfy an all_refs request
+        if all_refs && !cache.all_refs {
+            return None;
+        }
+
+        // Re-borrow after dropping the RwLock guard
+        self.cached_snapshot.as_ref().map(|c| &c.snapshot)
+    }
+
+    /// Cache a snapshot for the active page, captured at DOM state `key`
+    pub fn cache_snapshot(
+        &mut self,
+        snapshot: AccessibilitySnapshot,
+        key: SnapshotCacheKey,
+        all_refs: bool,
+    ) {
+        let page_index = self.shared_state.active_page_index.load(Ordering::SeqCst);
+
+        self.cached_snapshot = Some(CachedSnapshot {
+            snapshot,
+            captured_at: Instant::now(),
+            key,
+            page_index,
+            all_refs,
+        });
+    }
+
+    /// Invalidate the cached snapshot
+    ///
+    /// Called when the DOM mutates and after navigation or any action that
+    /// modifies the page
+    pub fn invalidate_cache(&mut self) {
+        self.cached_snapshot = None;
+    }
+}
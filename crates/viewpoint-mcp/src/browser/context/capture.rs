@@ -0,0 +1,122 @@
+//! Network activity tracking and on-demand response capture
+//!
+//! Every page counts its in-flight requests, which is cheap. Capturing a
+//! response reads its whole body, so pages only get a response handler once a
+//! tool asks for HAR output or bodies. From then on every page in the context
+//! is captured, including pages opened later.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use tokio::sync::RwLock;
+use viewpoint_core::Page;
+
+use super::ContextState;
+use crate::browser::network::{
+    SharedNetworkBuffer, SharedRequestActivity, StoredResponse, new_shared_network_buffer,
+};
+
+/// Network response buffers per page, keyed by `target_id`
+pub(super) type NetworkBuffers = Arc<RwLock<HashMap<String, SharedNetworkBuffer>>>;
+
+/// Request activity per page, keyed by `target_id`
+pub(super) type RequestActivities = Arc<RwLock<HashMap<String, SharedRequestActivity>>>;
+
+impl ContextState {
+    /// Start capturing responses on every page in this context.
+    ///
+    /// Returns `true` if capture was off before this call, meaning requests
+    /// made so far were not recorded.
+    pub async fn enable_network_capture(&self) -> bool {
+        if self
+            .shared_state
+            .network_capture
+            .swap(true, Ordering::SeqCst)
+        {
+            return false;
+        }
+
+        if let Ok(pages) = self.ordered_pages().await {
+            for page in &pages {
+                capture_responses(page, &self.network_buffers).await;
+            }
+        }
+        true
+    }
+
+    /// Whether responses are being captured for this context
+    #[must_use]
+    pub fn network_capture_enabled(&self) -> bool {
+        self.shared_state.network_capture.load(Ordering::SeqCst)
+    }
+
+    /// Get the network response buffer for the active page.
+    ///
+    /// Returns `None` if there's no active page or capture is off.
+    pub async fn active_network_buffer(&self) -> Option<SharedNetworkBuffer> {
+        let page = self.active_page().await.ok()??;
+        let buffers = self.network_buffers.read().await;
+        buffers.get(page.target_id()).cloned()
+    }
+
+    /// Get the request activity of the active page.
+    ///
+    /// Returns `None` if there's no active page or it is not tracked.
+    pub async fn active_request_activity(&self) -> Option<SharedRequestActivity> {
+        let page = self.active_page().await.ok()??;
+        let activities = self.request_activities.read().await;
+        activities.get(page.target_id()).cloned()
+    }
+}
+
+/// Count requests starting and finishing on `page`
+pub(super) async fn track_requests(page: &Page, activities: &RequestActivities) {
+    let activity = SharedRequestActivity::default();
+    activities
+        .write()
+        .await
+        .insert(page.target_id().to_string(), activity.clone());
+
+    let started = activity.clone();
+    page.on_request(move |_request| {
+        let activity = started.clone();
+        async move { activity.request_started() }
+    })
+    .await;
+
+    let finished = activity.clone();
+    page.on_request_finished(move |_request| {
+        let activity = finished.clone();
+        async move { activity.request_finished() }
+    })
+    .await;
+
+    page.on_request_failed(move |_request| {
+        let activity = activity.clone();
+        async move { activity.request_finished() }
+    })
+    .await;
+}
+
+/// Register a response handler on `page`, unless it already has a buffer.
+pub(super) async fn capture_responses(page: &Page, buffers: &NetworkBuffers) {
+    let buffer = {
+        let mut buffers = buffers.write().await;
+        if buffers.contains_key(page.target_id()) {
+            return;
+        }
+        let buffer = new_shared_network_buffer();
+        buffers.insert(page.target_id().to_string(), buffer.clone());
+        buffer
+    };
+
+    page.on_response(move |response| {
+        let buffer = buffer.clone();
+        async move {
+            let stored = StoredResponse::from_viewpoint(&response).await;
+            buffer.write().await.push(stored);
+        }
+    })
+    .await;
+}
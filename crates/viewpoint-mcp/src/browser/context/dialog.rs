@@ -0,0 +1,111 @@
+//! Persistent dialog handling for all pages in a context
+//!
+//! Without a handler, dialogs that open before `browser_handle_dialog` is
+//! called are dismissed by the browser. A pre-registered handler answers
+//! every dialog in the context using the response stored here, which later
+//! tool calls can change.
+
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+use tracing::debug;
+use viewpoint_core::error::ContextError;
+use viewpoint_core::{Dialog, HandlerId, Page};
+
+use super::ContextState;
+
+/// How a pre-registered handler answers dialogs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogResponse {
+    /// Whether to accept (true) or dismiss (false) the dialog
+    pub accept: bool,
+
+    /// Text entered into prompt dialogs when accepting
+    pub prompt_text: Option<String>,
+}
+
+/// A dialog handler subscribed on every page of a context
+pub(super) struct DialogHandler {
+    /// Response shared with the page handlers
+    response: Arc<RwLock<DialogResponse>>,
+
+    /// Handler ID for the `on_page` subscription covering new pages (kept alive)
+    _page_handler_id: HandlerId,
+}
+
+impl ContextState {
+    /// Get the response of the pre-registered dialog handler, if any
+    pub async fn dialog_response(&self) -> Option<DialogResponse> {
+        let handler = self.dialog_handler.as_ref()?;
+        Some(handler.response.read().await.clone())
+    }
+
+    /// Install a persistent dialog handler on all current and future pages.
+    ///
+    /// If a handler is already registered, only its response is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the existing pages cannot be listed.
+    pub async fn preregister_dialog_handler(
+        &mut self,
+        response: DialogResponse,
+    ) -> Result<(), ContextError> {
+        if self.set_dialog_response(response.clone()).await {
+            return Ok(());
+        }
+
+        let response = Arc::new(RwLock::new(response));
+        for page in self.ordered_pages().await? {
+            install_dialog_handler(&page, response.clone()).await;
+        }
+
+        let response_for_handler = response.clone();
+        let page_handler_id = self
+            .context
+            .on_page(move |page: Page| {
+                let response = response_for_handler.clone();
+                async move {
+                    install_dialog_handler(&page, response).await;
+                }
+            })
+            .await;
+
+        self.dialog_handler = Some(DialogHandler {
+            response,
+            _page_handler_id: page_handler_id,
+        });
+        Ok(())
+    }
+
+    /// Change how the pre-registered dialog handler answers.
+    ///
+    /// Returns `false` if no handler has been pre-registered.
+    pub async fn set_dialog_response(&self, response: DialogResponse) -> bool {
+        let Some(handler) = &self.dialog_handler else {
+            return false;
+        };
+        *handler.response.write().await = response;
+        true
+    }
+}
+
+/// Answer every dialog on `page` with the current shared response
+async fn install_dialog_handler(page: &Page, response: Arc<RwLock<DialogResponse>>) {
+    page.on_dialog(move |dialog: Dialog| {
+        let response = response.clone();
+        async move {
+            let response = response.read().await.clone();
+            let result = if response.accept {
+                dialog.accept(response.prompt_text.as_deref()).await
+            } else {
+                dialog.dismiss().await
+            };
+
+            if let Err(e) = result {
+                debug!(error = %e, "Failed to answer dialog");
+            }
+        }
+    })
+    .await;
+}
@@ -0,0 +1,44 @@
+//! Extra HTTP headers sent with every request in a context
+
+use std::collections::HashMap;
+
+use viewpoint_core::error::ContextError;
+
+use super::ContextState;
+
+impl ContextState {
+    /// Get the extra HTTP headers currently applied to this context
+    #[must_use]
+    pub const fn extra_headers(&self) -> &HashMap<String, String> {
+        &self.extra_headers
+    }
+
+    /// Merge headers into the context's extra HTTP headers.
+    ///
+    /// Header names are matched case-insensitively, so a new value replaces an
+    /// existing header regardless of its casing. Passing an empty map clears
+    /// all extra headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the headers cannot be applied to the context.
+    pub async fn merge_extra_headers(
+        &mut self,
+        headers: HashMap<String, String>,
+    ) -> Result<(), ContextError> {
+        let mut merged = if headers.is_empty() {
+            HashMap::new()
+        } else {
+            self.extra_headers.clone()
+        };
+
+        for (name, value) in headers {
+            merged.retain(|existing, _| !existing.eq_ignore_ascii_case(&name));
+            merged.insert(name, value);
+        }
+
+        self.context.set_extra_http_headers(merged.clone()).await?;
+        self.extra_headers = merged;
+        Ok(())
+    }
+}
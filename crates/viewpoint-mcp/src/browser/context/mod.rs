@@ -1,8 +1,16 @@
 //! Browser context state management
 
-use std::collections::HashMap;
+mod cache;
+mod capture;
+mod dialog;
+mod headers;
+mod order;
+mod overrides;
+mod pinned;
+
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Instant;
 
 use tokio::sync::RwLock;
@@ -12,7 +20,10 @@ use viewpoint_core::{BrowserContext, HandlerId, Page};
 
 use super::config::ProxyConfig;
 use super::console::{SharedConsoleBuffer, StoredConsoleMessage, new_shared_buffer};
-use crate::snapshot::AccessibilitySnapshot;
+
+pub use cache::{CachedSnapshot, SnapshotCacheKey};
+pub use dialog::DialogResponse;
+pub use overrides::HttpsErrorsOverride;
 
 /// Shared state that can be updated by event handlers.
 ///
@@ -28,12 +39,15 @@ struct SharedPageState {
     cache_invalidated: RwLock<bool>,
     /// Target ID of the last activated page (for index lookup)
     activated_target_id: RwLock<Option<String>>,
+    /// Whether network responses are captured for new pages
+    network_capture: AtomicBool,
 }
 
 /// State for a browser context
 ///
 /// Each context is isolated with its own cookies, storage, and cache.
-/// Pages are tracked by viewpoint-core; we only track console buffers keyed by `target_id`.
+/// Pages are tracked by viewpoint-core; we only track console and network buffers
+/// keyed by `target_id`.
 pub struct ContextState {
     /// Context name (unique identifier)
     pub name: String,
@@ -41,6 +55,21 @@ pub struct ContextState {
     /// Proxy configuration for this context
     pub proxy: Option<ProxyConfig>,
 
+    /// When this context was created
+    pub created_at: Instant,
+
+    /// Extra HTTP headers sent with every request
+    extra_headers: HashMap<String, String>,
+
+    /// Settings restored after per-navigation overrides
+    kept: overrides::KeptSettings,
+
+    /// Target IDs of moved tabs, in tab order (empty until a tab is moved)
+    tab_order: Vec<String>,
+
+    /// Target IDs of pinned tabs
+    pinned_tabs: HashSet<String>,
+
     /// The actual Viewpoint browser context
     context: BrowserContext,
 
@@ -51,6 +80,13 @@ pub struct ContextState {
     /// This allows tracking console for externally-opened pages.
     console_buffers: Arc<RwLock<HashMap<String, SharedConsoleBuffer>>>,
 
+    /// Network response buffers per page, keyed by `target_id`.
+    /// Only populated once capture is enabled.
+    network_buffers: capture::NetworkBuffers,
+
+    /// In-flight request counts per page, keyed by `target_id`.
+    request_activities: capture::RequestActivities,
+
     /// Handler ID for the `on_page` event subscription (kept alive)
     _page_handler_id: HandlerId,
 
@@ -59,29 +95,11 @@ pub struct ContextState {
 
     /// Cached snapshot for the active page
     cached_snapshot: Option<CachedSnapshot>,
-}
-
-/// A cached accessibility snapshot with metadata
-pub struct CachedSnapshot {
-    /// The cached snapshot
-    pub snapshot: AccessibilitySnapshot,
-
-    /// When the snapshot was captured
-    pub captured_at: Instant,
-
-    /// URL when snapshot was captured
-    pub url: String,
 
-    /// Page index when captured
-    pub page_index: usize,
-
-    /// Whether `all_refs` mode was used when capturing
-    pub all_refs: bool,
+    /// Pre-registered dialog handler, if any
+    dialog_handler: Option<dialog::DialogHandler>,
 }
 
-/// Default cache TTL in seconds
-const SNAPSHOT_CACHE_TTL_SECS: u64 = 5;
-
 impl std::fmt::Debug for ContextState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ContextState")
@@ -100,7 +118,8 @@ impl ContextState {
     /// Create a new context state from a Viewpoint context.
     ///
     /// This sets up subscriptions to:
-    /// - `on_page` events for console buffer setup on all new pages
+    /// - `on_page` events for console buffer setup on all new pages, and
+    ///   response capture once it has been enabled
     /// - `on_page_activated` events to track which page is currently active
     pub async fn new(
         name: impl Into<String>,
@@ -112,19 +131,30 @@ impl ContextState {
         let console_buffers: Arc<RwLock<HashMap<String, SharedConsoleBuffer>>> =
             Arc::new(RwLock::new(HashMap::new()));
 
+        // Create shared network buffer storage
+        let network_buffers: capture::NetworkBuffers = Arc::new(RwLock::new(HashMap::new()));
+        let request_activities: capture::RequestActivities = Arc::new(RwLock::new(HashMap::new()));
+
         // Create shared page state for event handlers
         let shared_state = Arc::new(SharedPageState {
             active_page_index: AtomicUsize::new(0),
             current_url: RwLock::new(None),
             cache_invalidated: RwLock::new(false),
             activated_target_id: RwLock::new(None),
+            network_capture: AtomicBool::new(false),
         });
 
-        // Subscribe to on_page events for console buffer setup on all new pages
+        // Subscribe to on_page events for console/network buffer setup on all new pages
         let buffers_for_handler = console_buffers.clone();
+        let network_buffers_for_handler = network_buffers.clone();
+        let activities_for_handler = request_activities.clone();
+        let state_for_page_handler = shared_state.clone();
         let page_handler_id = context
             .on_page(move |page: Page| {
                 let buffers = buffers_for_handler.clone();
+                let network_buffers = network_buffers_for_handler.clone();
+                let activities = activities_for_handler.clone();
+                let state = state_for_page_handler.clone();
                 async move {
                     let target_id = page.target_id().to_string();
                     let buffer = new_shared_buffer();
@@ -140,6 +170,14 @@ impl ContextState {
                     })
                     .await;
 
+                    // Count in-flight requests for network idle waits
+                    capture::track_requests(&page, &activities).await;
+
+                    // Set up response capture for this page, if enabled
+                    if state.network_capture.load(Ordering::SeqCst) {
+                        capture::capture_responses(&page, &network_buffers).await;
+                    }
+
                     // Store the buffer keyed by target_id
                     buffers.write().await.insert(target_id, buffer);
                 }
@@ -179,12 +217,20 @@ impl ContextState {
         Ok(Self {
             name,
             proxy: None,
+            created_at: Instant::now(),
+            extra_headers: HashMap::new(),
+            kept: overrides::KeptSettings::default(),
+            tab_order: Vec::new(),
+            pinned_tabs: HashSet::new(),
             context,
             shared_state,
             console_buffers,
+            network_buffers,
+            request_activities,
             _page_handler_id: page_handler_id,
             _page_activated_handler_id: page_activated_handler_id,
             cached_snapshot: None,
+            dialog_handler: None,
         })
     }
 
@@ -213,7 +259,7 @@ impl ContextState {
     ///
     /// Returns an error if the context is closed.
     pub async fn active_page(&self) -> Result<Option<Page>, ContextError> {
-        let pages = self.context.pages().await?;
+        let pages = self.ordered_pages().await?;
         let index = self.shared_state.active_page_index.load(Ordering::SeqCst);
         Ok(pages.into_iter().nth(index))
     }
@@ -249,8 +295,7 @@ impl ContextState {
     /// Returns an error if closing the page fails.
     pub async fn close_page(&mut self, index: usize) -> Result<(), PageError> {
         let pages = self
-            .context
-            .pages()
+            .ordered_pages()
             .await
             .map_err(|e| PageError::EvaluationFailed(format!("Failed to get pages: {e}")))?;
 
@@ -264,9 +309,13 @@ impl ContextState {
             .nth(index)
             .ok_or_else(|| PageError::EvaluationFailed("Page not found".to_string()))?;
 
-        // Remove the console buffer for this page
+        // Remove the console and network buffers for this page
         let target_id = page.target_id().to_string();
         self.console_buffers.write().await.remove(&target_id);
+        self.network_buffers.write().await.remove(&target_id);
+        self.request_activities.write().await.remove(&target_id);
+        self.tab_order.retain(|id| *id != target_id);
+        self.pinned_tabs.remove(&target_id);
 
         // Close the page
         page.close().await?;
@@ -283,12 +332,46 @@ impl ContextState {
         Ok(())
     }
 
+    /// Close every page in this context, keeping the context itself alive.
+    ///
+    /// Cookies and storage survive, so a later navigation opens a fresh page
+    /// in the same session. Returns the number of pages closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing or closing a page fails.
+    pub async fn close_all_pages(&mut self) -> Result<usize, PageError> {
+        let pages = self
+            .context
+            .pages()
+            .await
+            .map_err(|e| PageError::EvaluationFailed(format!("Failed to get pages: {e}")))?;
+        let count = pages.len();
+
+        for mut page in pages {
+            page.close().await?;
+        }
+
+        self.console_buffers.write().await.clear();
+        self.network_buffers.write().await.clear();
+        self.request_activities.write().await.clear();
+        self.tab_order.clear();
+        self.pinned_tabs.clear();
+        self.shared_state
+            .active_page_index
+            .store(0, Ordering::SeqCst);
+        *self.shared_state.current_url.write().await = None;
+        self.invalidate_cache();
+
+        Ok(count)
+    }
+
     /// Switch to a page by index.
     ///
     /// Returns `true` if the switch was successful, `false` if the index is out of bounds.
     /// Also updates `current_url` to the new page's URL.
     pub async fn switch_page(&mut self, index: usize) -> bool {
-        let Ok(pages) = self.context.pages().await else {
+        let Ok(pages) = self.ordered_pages().await else {
             return false;
         };
 
@@ -320,79 +403,6 @@ impl ContextState {
         self.context.close().await
     }
 
-    /// Get the cached snapshot if still valid
-    ///
-    /// Returns `None` if:
-    /// - No snapshot is cached
-    /// - The cache has expired (>5 seconds old)
-    /// - The URL has changed
-    /// - The active page has changed
-    /// - The cache was invalidated by a page activation event
-    /// - The `all_refs` mode doesn't match (requesting `all_refs` when cached without, or vice versa)
-    pub async fn get_cached_snapshot(&mut self, all_refs: bool) -> Option<&AccessibilitySnapshot> {
-        // Check if cache was invalidated by activation event
-        {
-            let mut invalidated = self.shared_state.cache_invalidated.write().await;
-            if *invalidated {
-                *invalidated = false;
-                self.cached_snapshot = None;
-                return None;
-            }
-        }
-
-        let cache = self.cached_snapshot.as_ref()?;
-
-        // Check if cache is expired
-        if cache.captured_at.elapsed().as_secs() > SNAPSHOT_CACHE_TTL_SECS {
-            return None;
-        }
-
-        // Check if page changed
-        let current_index = self.shared_state.active_page_index.load(Ordering::SeqCst);
-        if cache.page_index != current_index {
-            return None;
-        }
-
-        // Check if URL changed
-        let current_url = self.shared_state.current_url.read().await;
-        if let Some(url) = current_url.as_ref()
-            && cache.url != *url
-        {
-            return None;
-        }
-
-        // Check if all_refs mode matches
-        // A cached all_refs snapshot can satisfy a non-all_refs request (superset)
-        // But a non-all_refs snapshot cannot satisfy an all_refs request
-        if all_refs && !cache.all_refs {
-            return None;
-        }
-
-        // Re-borrow after dropping the RwLock guard
-        self.cached_snapshot.as_ref().map(|c| &c.snapshot)
-    }
-
-    /// Cache a snapshot for the active page
-    pub async fn cache_snapshot(&mut self, snapshot: AccessibilitySnapshot, all_refs: bool) {
-        let current_url = self.shared_state.current_url.read().await.clone();
-        let page_index = self.shared_state.active_page_index.load(Ordering::SeqCst);
-
-        self.cached_snapshot = Some(CachedSnapshot {
-            snapshot,
-            captured_at: Instant::now(),
-            url: current_url.unwrap_or_default(),
-            page_index,
-            all_refs,
-        });
-    }
-
-    /// Invalidate the cached snapshot
-    ///
-    /// Call this after navigation or any action that modifies the page
-    pub fn invalidate_cache(&mut self) {
-        self.cached_snapshot = None;
-    }
-
     /// Get the console buffer for the active page.
     ///
     /// Returns `None` if there's no active page or no buffer for it.
@@ -415,13 +425,13 @@ impl ContextState {
         page.url().await.ok()
     }
 
-    /// Get all pages in this context.
+    /// Get all pages in this context, in tab order.
     ///
     /// # Errors
     ///
     /// Returns an error if the context is closed.
     pub async fn pages(&self) -> Result<Vec<Page>, ContextError> {
-        self.context.pages().await
+        self.ordered_pages().await
     }
 
     /// Get the active page index.
@@ -453,7 +463,7 @@ impl ContextState {
 
         if let Some(target_id) = activated_target_id {
             // Look up the index
-            if let Ok(pages) = self.context.pages().await {
+            if let Ok(pages) = self.ordered_pages().await {
                 for (index, page) in pages.iter().enumerate() {
                     if page.target_id() == target_id {
                         self.shared_state
@@ -0,0 +1,78 @@
+//! Tab ordering within a context
+//!
+//! viewpoint-core lists pages in creation order. Tabs that have been moved
+//! are listed in the order recorded here; pages opened afterwards follow
+//! in creation order.
+
+use std::sync::atomic::Ordering;
+
+use viewpoint_core::Page;
+use viewpoint_core::error::ContextError;
+
+use super::ContextState;
+
+impl ContextState {
+    /// Get all pages in tab order.
+    pub(super) async fn ordered_pages(&self) -> Result<Vec<Page>, ContextError> {
+        let mut pages = self.context.pages().await?;
+        if !self.tab_order.is_empty() {
+            pages.sort_by_key(|page| {
+                self.tab_order
+                    .iter()
+                    .position(|id| id == page.target_id())
+                    .unwrap_or(usize::MAX)
+            });
+        }
+        Ok(pages)
+    }
+
+    /// Move the tab at index `from` to index `to`, shifting the tabs in between.
+    ///
+    /// Pinned tabs stay ahead of unpinned ones, so `to` is clamped to the
+    /// pinned or unpinned part of the tab strip the moved tab belongs to.
+    /// The active tab stays active, although its index may change.
+    /// Returns the index the tab ended up at, or `None` if either index is
+    /// out of range.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context is closed.
+    pub async fn move_page(
+        &mut self,
+        from: usize,
+        to: usize,
+    ) -> Result<Option<usize>, ContextError> {
+        let mut order: Vec<String> = self
+            .ordered_pages()
+            .await?
+            .iter()
+            .map(|page| page.target_id().to_string())
+            .collect();
+
+        if from >= order.len() || to >= order.len() {
+            return Ok(None);
+        }
+
+        let pinned = order.iter().filter(|id| self.is_pinned(id)).count();
+        let to = if self.is_pinned(&order[from]) {
+            to.min(pinned.saturating_sub(1))
+        } else {
+            to.max(pinned)
+        };
+
+        let active_index = self.active_page_index().await;
+        let active_id = order.get(active_index).cloned();
+
+        let moved = order.remove(from);
+        order.insert(to, moved);
+
+        if let Some(new_index) = active_id.and_then(|id| order.iter().position(|t| *t == id)) {
+            self.shared_state
+                .active_page_index
+                .store(new_index, Ordering::SeqCst);
+        }
+
+        self.tab_order = order;
+        Ok(Some(to))
+    }
+}
@@ -0,0 +1,176 @@
+//! Context settings overridden for a single navigation
+//!
+//! `browser_navigate` can answer HTTP Basic Authentication challenges and
+//! ignore certificate errors for one navigation. The context's own settings
+//! are kept here so they can be put back afterwards.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use viewpoint_core::HttpCredentials;
+use viewpoint_core::error::ContextError;
+
+use super::ContextState;
+
+/// Settings that temporary overrides are restored to
+#[derive(Default)]
+pub(super) struct KeptSettings {
+    /// HTTP credentials kept across navigations, as `(username, password)`
+    http_credentials: Option<(String, String)>,
+
+    /// Whether certificate errors are currently ignored
+    ignore_https_errors: AtomicBool,
+
+    /// Value that an [`HttpsErrorsOverride`] dropped without being restored
+    /// should have put back
+    abandoned_https_override: Mutex<Option<bool>>,
+}
+
+/// Certificate errors ignored until [`HttpsErrorsOverride::restore`] is called.
+///
+/// Dropping the override without restoring it (for example when the tool
+/// call is cancelled) leaves the previous setting to be put back by
+/// [`ContextState::restore_abandoned_overrides`].
+#[must_use = "the previous setting is only put back by `restore`"]
+pub struct HttpsErrorsOverride<'a> {
+    context: &'a ContextState,
+    previous: Option<bool>,
+}
+
+impl HttpsErrorsOverride<'_> {
+    /// Put back the setting from before the override.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the setting cannot be applied to the context; it
+    /// is then retried by [`ContextState::restore_abandoned_overrides`].
+    pub async fn restore(mut self) -> Result<(), ContextError> {
+        if let Some(previous) = self.previous {
+            self.context.apply_ignore_https_errors(previous).await?;
+            self.previous = None;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for HttpsErrorsOverride<'_> {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous.take()
+            && let Ok(mut abandoned) = self.context.kept.abandoned_https_override.lock()
+        {
+            // An older abandoned override holds the original setting
+            abandoned.get_or_insert(previous);
+        }
+    }
+}
+
+impl ContextState {
+    /// Apply credentials to the context and keep them for later requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the credentials cannot be applied to the context.
+    pub async fn set_http_credentials(
+        &mut self,
+        username: &str,
+        password: &str,
+    ) -> Result<(), ContextError> {
+        self.context
+            .set_http_credentials(HttpCredentials::new(username, password))
+            .await?;
+        self.kept.http_credentials = Some((username.to_string(), password.to_string()));
+        Ok(())
+    }
+
+    /// Apply credentials to the context until
+    /// [`ContextState::restore_http_credentials`] is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the credentials cannot be applied to the context.
+    pub async fn apply_temporary_http_credentials(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<(), ContextError> {
+        self.context
+            .set_http_credentials(HttpCredentials::new(username, password))
+            .await
+    }
+
+    /// Re-apply the credentials kept by [`ContextState::set_http_credentials`],
+    /// or clear the context's credentials if none were kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context's credentials cannot be updated.
+    pub async fn restore_http_credentials(&self) -> Result<(), ContextError> {
+        match &self.kept.http_credentials {
+            Some((username, password)) => {
+                self.context
+                    .set_http_credentials(HttpCredentials::new(username, password))
+                    .await
+            }
+            None => self.context.clear_http_credentials().await,
+        }
+    }
+
+    /// Whether the context currently ignores certificate errors
+    #[must_use]
+    pub fn ignores_https_errors(&self) -> bool {
+        self.kept.ignore_https_errors.load(Ordering::SeqCst)
+    }
+
+    /// Ignore certificate errors until the returned override is restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the setting cannot be applied to the context.
+    pub async fn ignore_https_errors_temporarily(
+        &self,
+    ) -> Result<HttpsErrorsOverride<'_>, ContextError> {
+        self.restore_abandoned_overrides().await?;
+        let previous = self.ignores_https_errors();
+        self.apply_ignore_https_errors(true).await?;
+        Ok(HttpsErrorsOverride {
+            context: self,
+            previous: Some(previous),
+        })
+    }
+
+    /// Put back the setting of an [`HttpsErrorsOverride`] that was dropped
+    /// without being restored, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the setting cannot be applied to the context; it
+    /// is kept for the next attempt.
+    pub async fn restore_abandoned_overrides(&self) -> Result<(), ContextError> {
+        let abandoned = self
+            .kept
+            .abandoned_https_override
+            .lock()
+            .ok()
+            .and_then(|mut abandoned| abandoned.take());
+        let Some(previous) = abandoned else {
+            return Ok(());
+        };
+
+        let restored = self.apply_ignore_https_errors(previous).await;
+        if restored.is_err()
+            && let Ok(mut abandoned) = self.kept.abandoned_https_override.lock()
+        {
+            abandoned.get_or_insert(previous);
+        }
+        restored
+    }
+
+    /// Apply the certificate error setting and record it
+    async fn apply_ignore_https_errors(&self, ignore: bool) -> Result<(), ContextError> {
+        self.context.set_ignore_https_errors(ignore).await?;
+        self.kept
+            .ignore_https_errors
+            .store(ignore, Ordering::SeqCst);
+        Ok(())
+    }
+}
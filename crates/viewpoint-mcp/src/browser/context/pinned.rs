@@ -0,0 +1,38 @@
+//! Pinned tabs within a context
+//!
+//! Chromium has no pinning concept over CDP, so pinned tabs are tracked here
+//! by target ID. Like a browser tab strip, pinned tabs are kept ahead of
+//! unpinned ones.
+
+use viewpoint_core::Page;
+use viewpoint_core::error::ContextError;
+
+use super::ContextState;
+
+impl ContextState {
+    /// Create a new pinned page in this context.
+    ///
+    /// The page becomes active and is placed after any existing pinned tabs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if page creation fails.
+    pub async fn new_pinned_page(&mut self) -> Result<Page, ContextError> {
+        let page = self.new_page().await?;
+        let from = self.active_page_index().await;
+        let to = self.pinned_tabs.len();
+
+        self.pinned_tabs.insert(page.target_id().to_string());
+        if from != to {
+            self.move_page(from, to).await?;
+        }
+
+        Ok(page)
+    }
+
+    /// Check whether the page with the given target ID is pinned
+    #[must_use]
+    pub fn is_pinned(&self, target_id: &str) -> bool {
+        self.pinned_tabs.contains(target_id)
+    }
+}
@@ -9,6 +9,10 @@ pub enum BrowserError {
     #[error("Failed to launch browser: {0}")]
     LaunchFailed(String),
 
+    /// Browser installation failed
+    #[error("Failed to install browser: {0}")]
+    InstallFailed(String),
+
     /// Connection to CDP endpoint failed
     #[error("Failed to connect to CDP endpoint: {0}")]
     ConnectionFailed(String),
@@ -17,6 +21,10 @@ pub enum BrowserError {
     #[error("Browser not running")]
     NotRunning,
 
+    /// Browser has not been initialized yet
+    #[error("Browser not initialized")]
+    NotInitialized,
+
     /// Context not found
     #[error("Context not found: {0}")]
     ContextNotFound(String),
@@ -25,6 +33,10 @@ pub enum BrowserError {
     #[error("Page not found: {0}")]
     PageNotFound(String),
 
+    /// An operation on an existing context failed
+    #[error("Context operation failed: {0}")]
+    ContextFailed(String),
+
     /// Navigation failed
     #[error("Navigation failed: {0}")]
     NavigationFailed(String),
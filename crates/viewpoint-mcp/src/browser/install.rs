@@ -0,0 +1,242 @@
+//! Browser binary detection and installation
+//!
+//! Locates an installed Chromium or Chrome executable and, when none is
+//! found, installs one with a pinned version of the Playwright browser
+//! installer (`npx playwright@<version> install`), whose download cache is
+//! also searched during detection.
+
+use std::path::{Path, PathBuf};
+
+use tokio::process::Command;
+
+use super::config::BrowserType;
+use super::error::BrowserError;
+
+/// Environment variable overriding the Playwright browser cache directory
+const PLAYWRIGHT_BROWSERS_PATH_ENV: &str = "PLAYWRIGHT_BROWSERS_PATH";
+
+/// Playwright release whose installer is used, pinned so that installs are
+/// reproducible and do not run whatever `npx` resolves as latest
+pub const PLAYWRIGHT_VERSION: &str = "1.56.1";
+
+/// Launch error fragments (lowercase) indicating the browser executable is missing
+const MISSING_BROWSER_PATTERNS: &[&str] = &[
+    "executable not found",
+    "chromium not found",
+    "chrome not found",
+    "could not find chrom",
+    "failed to find chrom",
+    "browser is not installed",
+    "no such file or directory",
+];
+
+/// Playwright cache directory prefixes for Chromium builds, in order of preference
+const PLAYWRIGHT_CACHE_PREFIXES: &[&str] = &["chromium-", "chromium_headless_shell-"];
+
+/// Executable locations inside a Playwright Chromium cache directory
+const PLAYWRIGHT_CACHE_EXECUTABLES: &[&str] = &[
+    "chrome-linux/chrome",
+    "chrome-linux64/chrome",
+    "chrome-mac/Chromium.app/Contents/MacOS/Chromium",
+    "chrome-mac-arm64/Chromium.app/Contents/MacOS/Chromium",
+    "chrome-win/chrome.exe",
+    "chrome-win64/chrome.exe",
+    "chrome-linux/headless_shell",
+    "chrome-headless-shell-linux64/chrome-headless-shell",
+    "chrome-mac/headless_shell",
+    "chrome-headless-shell-mac-arm64/chrome-headless-shell",
+    "chrome-headless-shell-mac-x64/chrome-headless-shell",
+    "chrome-win/headless_shell.exe",
+    "chrome-headless-shell-win64/chrome-headless-shell.exe",
+];
+
+impl BrowserType {
+    /// Executable names searched for on `PATH`, in order of preference
+    #[must_use]
+    pub const fn executable_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Chromium => &[
+                "chromium",
+                "chromium-browser",
+                "google-chrome",
+                "google-chrome-stable",
+                "chrome",
+            ],
+            Self::Chrome => &["google-chrome", "google-chrome-stable", "chrome"],
+        }
+    }
+
+    /// Browser name passed to `playwright install`
+    #[must_use]
+    pub const fn install_name(&self) -> &'static str {
+        match self {
+            Self::Chromium => "chromium",
+            Self::Chrome => "chrome",
+        }
+    }
+
+    /// Well-known install locations outside of `PATH`, including where
+    /// `playwright install chrome` puts Google Chrome
+    fn well_known_paths(&self) -> Vec<PathBuf> {
+        let mut paths = vec![
+            PathBuf::from("/opt/google/chrome/chrome"),
+            PathBuf::from("/Applications/Google Chrome.app/Contents/MacOS/Google Chrome"),
+            PathBuf::from("C:\\Program Files\\Google\\Chrome\\Application\\chrome.exe"),
+        ];
+        if matches!(self, Self::Chromium) {
+            paths.insert(
+                0,
+                PathBuf::from("/Applications/Chromium.app/Contents/MacOS/Chromium"),
+            );
+        }
+        paths
+    }
+}
+
+/// Check whether a launch error message indicates a missing browser executable
+#[must_use]
+pub fn is_missing_browser_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    MISSING_BROWSER_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+/// Find the executable for `browser_type`.
+///
+/// Searches `PATH`, then well-known install locations, then (for Chromium)
+/// the Playwright browser cache.
+#[must_use]
+pub fn find_browser_executable(browser_type: &BrowserType) -> Option<PathBuf> {
+    find_on_path(browser_type.executable_names())
+        .or_else(|| {
+            browser_type
+                .well_known_paths()
+                .into_iter()
+                .find(|path| path.is_file())
+        })
+        .or_else(|| match browser_type {
+            BrowserType::Chromium => {
+                playwright_cache_dir().and_then(|dir| find_in_playwright_cache(&dir))
+            }
+            BrowserType::Chrome => None,
+        })
+}
+
+/// Find a browser to launch after the default launch failed with `message`
+/// because no executable was found.
+///
+/// Installs one only if `auto_install` is set.
+///
+/// # Errors
+///
+/// Returns the original `BrowserError::LaunchFailed` if no browser is found
+/// and auto-install is off, or the install error if installing fails.
+pub(super) async fn locate_missing_browser(
+    browser_type: &BrowserType,
+    auto_install: bool,
+    message: String,
+) -> super::Result<PathBuf> {
+    if let Some(path) = find_browser_executable(browser_type) {
+        tracing::info!(path = %path.display(), "Using browser found on this system");
+        return Ok(path);
+    }
+    if !auto_install {
+        return Err(BrowserError::LaunchFailed(message));
+    }
+
+    tracing::warn!(error = %message, "Browser not found, installing");
+    install_browser(browser_type).await
+}
+
+/// Install `browser_type` with the Playwright installer and return its executable.
+///
+/// # Errors
+///
+/// Returns `BrowserError::InstallFailed` if the installer cannot be run,
+/// exits unsuccessfully, or no executable is found afterwards.
+pub async fn install_browser(browser_type: &BrowserType) -> super::Result<PathBuf> {
+    let name = browser_type.install_name();
+    tracing::info!(browser = name, "Installing browser");
+
+    let package = format!("playwright@{PLAYWRIGHT_VERSION}");
+    let output = Command::new("npx")
+        .args(["--yes", &package, "install", name])
+        .output()
+        .await
+        .map_err(|e| BrowserError::InstallFailed(format!("Failed to run npx: {e}")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(BrowserError::InstallFailed(format!(
+            "`npx {package} install {name}` exited with {}: {}",
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    find_browser_executable(browser_type).ok_or_else(|| {
+        BrowserError::InstallFailed(format!(
+            "Installer finished but no {name} executable was found"
+        ))
+    })
+}
+
+/// Search the directories in `PATH` for the first matching executable
+fn find_on_path(names: &[&str]) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    let dirs: Vec<PathBuf> = std::env::split_paths(&path).collect();
+
+    names.iter().find_map(|name| {
+        dirs.iter()
+            .map(|dir| dir.join(name))
+            .find(|candidate| candidate.is_file())
+    })
+}
+
+/// Directory where Playwright stores downloaded browsers
+fn playwright_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(PLAYWRIGHT_BROWSERS_PATH_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+
+    let home = PathBuf::from(std::env::var_os("HOME")?);
+    if cfg!(target_os = "macos") {
+        Some(home.join("Library/Caches/ms-playwright"))
+    } else {
+        Some(home.join(".cache/ms-playwright"))
+    }
+}
+
+/// Find the newest Chromium executable in a Playwright browser cache.
+///
+/// Playwright installs each revision as `chromium-<revision>/`, and its
+/// headless-only build as `chromium_headless_shell-<revision>/`. Full
+/// Chromium builds are preferred; within each kind the highest revision with
+/// an executable wins.
+#[must_use]
+pub fn find_in_playwright_cache(cache_dir: &Path) -> Option<PathBuf> {
+    let mut revisions: Vec<(usize, u64, PathBuf)> = std::fs::read_dir(cache_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let name = name.to_str()?;
+            PLAYWRIGHT_CACHE_PREFIXES
+                .iter()
+                .enumerate()
+                .find_map(|(preference, prefix)| {
+                    let revision = name.strip_prefix(prefix)?.parse().ok()?;
+                    Some((preference, revision, entry.path()))
+                })
+        })
+        .collect();
+    revisions.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    revisions.into_iter().find_map(|(_, _, dir)| {
+        PLAYWRIGHT_CACHE_EXECUTABLES
+            .iter()
+            .map(|executable| dir.join(executable))
+            .find(|candidate| candidate.is_file())
+    })
+}
@@ -8,6 +8,7 @@
 //! - [`BrowserConfig`]: Configuration for browser launch
 //! - [`ContextState`]: Per-context state (pages, console buffers)
 //! - [`ConsoleBuffer`]: Captured console messages per page
+//! - [`NetworkBuffer`]: Captured network responses per page
 //!
 //! # Multi-Context Architecture
 //!
@@ -23,19 +24,25 @@ mod config;
 pub mod console;
 mod context;
 mod error;
+pub mod install;
+pub mod network;
 mod state;
 
 #[cfg(test)]
 mod tests;
 
-pub use config::{BrowserConfig, BrowserType, ProxyConfig, ViewportSize};
+pub use config::{BrowserConfig, BrowserType, ContextOptions, ProxyConfig, ViewportSize};
 pub use console::{
     ConsoleBuffer, ConsoleLevel, SharedConsoleBuffer, StoredConsoleMessage,
     StoredConsoleMessageType, new_shared_buffer,
 };
-pub use context::ContextState;
+pub use context::{ContextState, DialogResponse, HttpsErrorsOverride, SnapshotCacheKey};
 pub use error::BrowserError;
-pub use state::{BrowserState, ContextInfo};
+pub use network::{
+    NetworkBuffer, RequestActivity, RequestTiming, SharedNetworkBuffer, SharedRequestActivity,
+    StoredResponse, new_shared_network_buffer,
+};
+pub use state::{BrowserState, ContextInfo, ContextSnapshot, StateSnapshot, TabInfo, TabSnapshot};
 
 /// Result type for browser operations
 pub type Result<T> = std::result::Result<T, BrowserError>;
@@ -0,0 +1,257 @@
+//! Network response capture and storage.
+//!
+//! This module provides per-page buffering of HTTP responses so tools can
+//! report request/response headers and bodies after the fact.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::{Engine as _, general_purpose::STANDARD};
+use serde::Serialize;
+use tokio::sync::RwLock;
+use viewpoint_core::Response as VpResponse;
+
+/// Maximum number of responses to store per page.
+const NETWORK_BUFFER_MAX: usize = 500;
+
+/// Maximum body size captured per response, in bytes.
+const NETWORK_BODY_CAPTURE_MAX: usize = 1024 * 1024;
+
+/// A stored HTTP response with its request (serializable, without CDP references).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredResponse {
+    /// Request URL.
+    pub url: String,
+    /// HTTP method of the request.
+    pub method: String,
+    /// Response status code.
+    pub status: u16,
+    /// Response status text.
+    pub status_text: String,
+    /// Request headers as `(name, value)` pairs.
+    pub request_headers: Vec<(String, String)>,
+    /// Response headers as `(name, value)` pairs.
+    pub response_headers: Vec<(String, String)>,
+    /// Request body (e.g., POST data), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_body: Option<String>,
+    /// Response body, if it is no larger than the capture limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Whether `body` is base64-encoded because the response is binary.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub body_base64: bool,
+    /// Size of the response body in bytes (0 if it could not be read).
+    pub body_size: usize,
+    /// Phase timings reported for this request, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<RequestTiming>,
+    /// When the response was captured, in milliseconds since epoch.
+    #[serde(skip)]
+    pub captured_at: f64,
+}
+
+/// Phase timings of a captured request.
+///
+/// `start_time` is in milliseconds since epoch; the other fields are offsets
+/// from it in milliseconds, `-1` when the phase did not happen.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestTiming {
+    /// Request start, in milliseconds since epoch.
+    pub start_time: f64,
+    /// DNS lookup start.
+    pub domain_lookup_start: f64,
+    /// DNS lookup end.
+    pub domain_lookup_end: f64,
+    /// Connection start.
+    pub connect_start: f64,
+    /// TLS handshake start.
+    pub secure_connection_start: f64,
+    /// Connection end (including TLS).
+    pub connect_end: f64,
+    /// Request sent.
+    pub request_start: f64,
+    /// First response byte received.
+    pub response_start: f64,
+    /// Last response byte received.
+    pub response_end: f64,
+}
+
+impl StoredResponse {
+    /// Create a stored response from a viewpoint response, reading its body.
+    pub async fn from_viewpoint(response: &VpResponse) -> Self {
+        let request = response.request();
+        let bytes = response.body().await.unwrap_or_default();
+        let body_size = bytes.len();
+        let (body, body_base64) = if body_size <= NETWORK_BODY_CAPTURE_MAX {
+            match String::from_utf8(bytes) {
+                Ok(text) => (Some(text), false),
+                Err(e) => (Some(STANDARD.encode(e.into_bytes())), true),
+            }
+        } else {
+            (None, false)
+        };
+
+        Self {
+            url: response.url().to_string(),
+            method: request.method().to_string(),
+            status: response.status(),
+            status_text: response.status_text().to_string(),
+            request_headers: header_pairs(request.headers().iter()),
+            response_headers: header_pairs(response.headers().iter()),
+            request_body: request.post_data().map(ToString::to_string),
+            body,
+            body_base64,
+            body_size,
+            timing: request.timing().map(|timing| RequestTiming {
+                start_time: timing.start_time,
+                domain_lookup_start: timing.domain_lookup_start,
+                domain_lookup_end: timing.domain_lookup_end,
+                connect_start: timing.connect_start,
+                secure_connection_start: timing.secure_connection_start,
+                connect_end: timing.connect_end,
+                request_start: timing.request_start,
+                response_start: timing.response_start,
+                response_end: timing.response_end,
+            }),
+            captured_at: epoch_millis(),
+        }
+    }
+
+    /// Get a response header value by case-insensitive name.
+    pub fn response_header(&self, name: &str) -> Option<&str> {
+        find_header(&self.response_headers, name)
+    }
+
+    /// Get a request header value by case-insensitive name.
+    pub fn request_header(&self, name: &str) -> Option<&str> {
+        find_header(&self.request_headers, name)
+    }
+}
+
+/// The current time in milliseconds since epoch.
+#[allow(clippy::cast_precision_loss)]
+fn epoch_millis() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |elapsed| elapsed.as_millis() as f64)
+}
+
+/// Find a header value by case-insensitive name.
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Collect header map entries into sorted `(name, value)` pairs.
+fn header_pairs<'a>(
+    headers: impl Iterator<Item = (&'a String, &'a String)>,
+) -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> = headers
+        .map(|(name, value)| (name.clone(), value.clone()))
+        .collect();
+    pairs.sort();
+    pairs
+}
+
+/// Per-page network response buffer.
+#[derive(Debug, Default)]
+pub struct NetworkBuffer {
+    responses: VecDeque<StoredResponse>,
+}
+
+impl NetworkBuffer {
+    /// Create a new empty buffer.
+    pub fn new() -> Self {
+        Self {
+            responses: VecDeque::new(),
+        }
+    }
+
+    /// Add a response to the buffer, evicting oldest if at capacity.
+    pub fn push(&mut self, response: StoredResponse) {
+        if self.responses.len() >= NETWORK_BUFFER_MAX {
+            self.responses.pop_front();
+        }
+        self.responses.push_back(response);
+    }
+
+    /// Get all responses, oldest first.
+    pub fn responses(&self) -> &VecDeque<StoredResponse> {
+        &self.responses
+    }
+
+    /// Drop responses captured before `epoch_ms`, such as those of a page
+    /// that has since been navigated away from.
+    pub fn retain_since(&mut self, epoch_ms: f64) {
+        self.responses
+            .retain(|response| response.captured_at >= epoch_ms);
+    }
+
+    /// Clear all responses.
+    pub fn clear(&mut self) {
+        self.responses.clear();
+    }
+
+    /// Get the number of stored responses.
+    pub fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    /// Check if buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+}
+
+/// Per-page count of requests in flight and finished.
+///
+/// Requests already running when tracking started are never counted as
+/// started, so finishing them does not drive the in-flight count below zero.
+#[derive(Debug, Default)]
+pub struct RequestActivity {
+    in_flight: AtomicUsize,
+    finished: AtomicUsize,
+}
+
+impl RequestActivity {
+    /// Record a request being sent.
+    pub fn request_started(&self) {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Record a request finishing or failing.
+    pub fn request_finished(&self) {
+        let _ = self
+            .in_flight
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+        self.finished.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Number of requests sent but not yet finished.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Number of requests finished or failed so far.
+    pub fn finished(&self) -> usize {
+        self.finished.load(Ordering::SeqCst)
+    }
+}
+
+/// Request activity shared with the page's event handlers.
+pub type SharedRequestActivity = Arc<RequestActivity>;
+
+/// Shared network buffer that can be passed to async handlers.
+pub type SharedNetworkBuffer = Arc<RwLock<NetworkBuffer>>;
+
+/// Create a new shared network buffer.
+pub fn new_shared_network_buffer() -> SharedNetworkBuffer {
+    Arc::new(RwLock::new(NetworkBuffer::new()))
+}
@@ -0,0 +1,161 @@
+//! Context information queries
+
+use std::time::Instant;
+
+use viewpoint_core::Page;
+
+use super::BrowserState;
+use crate::browser::config::ProxyConfig;
+use crate::browser::context::ContextState;
+use crate::browser::error::BrowserError;
+
+impl BrowserState {
+    /// List all contexts, ordered by creation time (oldest first)
+    #[must_use]
+    pub fn list_contexts(&self) -> Vec<&ContextState> {
+        let mut contexts: Vec<&ContextState> = self.contexts.values().collect();
+        contexts.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        contexts
+    }
+
+    /// Get the active context name
+    #[must_use]
+    pub fn active_context_name(&self) -> &str {
+        &self.active_context
+    }
+
+    /// Get information about all contexts with current URLs fetched dynamically.
+    ///
+    /// Unlike `list_contexts()`, this method queries each page for its current URL,
+    /// ensuring the returned information is always up-to-date. Contexts are
+    /// ordered by creation time, oldest first.
+    pub async fn list_contexts_with_urls(&self) -> Vec<ContextInfo> {
+        let contexts = self.list_contexts();
+        let mut infos = Vec::with_capacity(contexts.len());
+
+        for ctx in contexts {
+            let current_url = ctx.get_current_url().await;
+            let is_active = ctx.name == self.active_context;
+
+            infos.push(ContextInfo {
+                name: ctx.name.clone(),
+                is_active,
+                page_count: ctx.page_count().await.unwrap_or(0),
+                current_url,
+                proxy: ctx.proxy.clone(),
+                created_at: ctx.created_at,
+            });
+        }
+
+        infos
+    }
+
+    /// List the tabs of a context in tab order, with each tab's URL and title
+    ///
+    /// A tab whose URL or title cannot be read reports an empty string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context does not exist or its pages cannot be listed.
+    pub async fn list_tabs(&self, context_name: &str) -> crate::browser::Result<Vec<TabInfo>> {
+        let context = self.get_context(context_name)?;
+        let pages = context
+            .pages()
+            .await
+            .map_err(|e| BrowserError::PageNotFound(format!("{context_name}: {e}")))?;
+        let active_index = context.active_page_index().await;
+
+        let mut tabs = Vec::with_capacity(pages.len());
+        for (index, page) in pages.iter().enumerate() {
+            tabs.push(TabInfo {
+                index,
+                url: page.url().await.unwrap_or_default(),
+                title: page.title().await.unwrap_or_default(),
+                is_active: index == active_index,
+            });
+        }
+        Ok(tabs)
+    }
+
+    /// Get the document title of the active context's active page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no active page or the title cannot be read.
+    pub async fn page_title(&self) -> crate::browser::Result<String> {
+        self.active_page()
+            .await?
+            .title()
+            .await
+            .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to get title: {e}")))
+    }
+
+    /// Get the URL of the active context's active page
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no active page or the URL cannot be read.
+    pub async fn page_url(&self) -> crate::browser::Result<String> {
+        self.active_page()
+            .await?
+            .url()
+            .await
+            .map_err(|e| BrowserError::EvaluationFailed(format!("Failed to get URL: {e}")))
+    }
+
+    async fn active_page(&self) -> crate::browser::Result<Page> {
+        let context = self.active_context()?;
+        context
+            .active_page()
+            .await
+            .map_err(|e| BrowserError::PageNotFound(e.to_string()))?
+            .ok_or_else(|| {
+                BrowserError::PageNotFound(format!("no active page in '{}'", context.name))
+            })
+    }
+}
+
+/// Information about a browser context with dynamically fetched URL.
+///
+/// This struct contains a snapshot of context state with the current URL
+/// fetched from the browser rather than cached.
+#[derive(Debug, Clone)]
+pub struct ContextInfo {
+    /// Context name (unique identifier)
+    pub name: String,
+
+    /// Whether this is the active context
+    pub is_active: bool,
+
+    /// Number of pages in this context
+    pub page_count: usize,
+
+    /// Current URL of the active page (fetched from browser)
+    pub current_url: Option<String>,
+
+    /// Proxy configuration for this context
+    pub proxy: Option<ProxyConfig>,
+
+    /// When this context was created
+    pub created_at: Instant,
+}
+
+/// Information about one tab of a browser context, fetched from the browser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TabInfo {
+    /// Position of the tab in the context's tab order
+    pub index: usize,
+
+    /// URL loaded in the tab
+    pub url: String,
+
+    /// Document title of the tab
+    pub title: String,
+
+    /// Whether this is the context's active tab
+    pub is_active: bool,
+}
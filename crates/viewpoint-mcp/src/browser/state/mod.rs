@@ -2,17 +2,27 @@
 //!
 //! Manages the browser lifecycle and multi-context state across MCP tool calls.
 
+mod info;
+mod recovery;
+mod scripts;
+mod snapshot;
+mod startup;
+
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use viewpoint_core::Browser;
 pub use viewpoint_core::ProxyConfig;
+use viewpoint_core::{Browser, Permission};
 
-use super::config::BrowserConfig;
+use super::config::{BrowserConfig, ContextOptions};
 use super::context::ContextState;
 use super::error::BrowserError;
+use super::install;
 use crate::server::ImageResponseMode;
 
+pub use info::{ContextInfo, TabInfo};
+pub use snapshot::{ContextSnapshot, StateSnapshot, TabSnapshot};
+
 /// Default context name
 pub const DEFAULT_CONTEXT: &str = "default";
 
@@ -57,6 +67,18 @@ pub struct BrowserState {
 
     /// How screenshot images are returned in responses
     image_responses: ImageResponseMode,
+
+    /// Scripts injected into every new page of every context
+    init_scripts: Vec<String>,
+
+    /// URL the default context's first page navigates to on initialization
+    initial_url: Option<String>,
+
+    /// Length at which `browser_evaluate` results are split into chunks
+    max_evaluate_result_length: Option<usize>,
+
+    /// Error from the most recent failed initialization attempt
+    init_error: Option<String>,
 }
 
 impl std::fmt::Debug for BrowserState {
@@ -67,6 +89,7 @@ impl std::fmt::Debug for BrowserState {
             .field("contexts", &self.contexts.keys().collect::<Vec<_>>())
             .field("active_context", &self.active_context)
             .field("browser", &self.browser.is_some())
+            .field("init_error", &self.init_error)
             .finish()
     }
 }
@@ -83,6 +106,10 @@ impl BrowserState {
             browser: None,
             screenshot_dir: PathBuf::from(".viewpoint-mcp-screenshots"),
             image_responses: ImageResponseMode::default(),
+            init_scripts: Vec::new(),
+            initial_url: None,
+            max_evaluate_result_length: None,
+            init_error: None,
         }
     }
 
@@ -101,6 +128,10 @@ impl BrowserState {
             browser: None,
             screenshot_dir,
             image_responses,
+            init_scripts: Vec::new(),
+            initial_url: None,
+            max_evaluate_result_length: None,
+            init_error: None,
         }
     }
 
@@ -116,12 +147,30 @@ impl BrowserState {
         self.image_responses
     }
 
+    /// Set the length at which `browser_evaluate` results are split into chunks
+    #[must_use]
+    pub const fn with_max_evaluate_result_length(mut self, max_length: Option<usize>) -> Self {
+        self.max_evaluate_result_length = max_length;
+        self
+    }
+
+    /// Get the length at which `browser_evaluate` results are split into chunks
+    #[must_use]
+    pub const fn max_evaluate_result_length(&self) -> Option<usize> {
+        self.max_evaluate_result_length
+    }
+
     /// Get the browser configuration
     #[must_use]
     pub const fn config(&self) -> &BrowserConfig {
         &self.config
     }
 
+    /// Set the browser executable used by the next launch
+    pub fn set_executable_path(&mut self, path: PathBuf) {
+        self.config.executable_path = Some(path);
+    }
+
     /// Check if the browser has been initialized
     #[must_use]
     pub const fn is_initialized(&self) -> bool {
@@ -134,6 +183,15 @@ impl BrowserState {
         self.browser.as_ref()
     }
 
+    /// Get the error from the most recent failed initialization attempt.
+    ///
+    /// Returns `None` if the browser has not been initialized yet or the last
+    /// attempt succeeded.
+    #[must_use]
+    pub fn init_error(&self) -> Option<&str> {
+        self.init_error.as_deref()
+    }
+
     /// Initialize the browser (lazy initialization on first tool call)
     ///
     /// # Errors
@@ -144,6 +202,13 @@ impl BrowserState {
             return Ok(());
         }
 
+        let result = self.launch().await;
+        self.init_error = result.as_ref().err().map(ToString::to_string);
+        result
+    }
+
+    /// Launch or connect to the browser and create the default context
+    async fn launch(&mut self) -> super::Result<()> {
         tracing::info!(
             headless = self.config.headless,
             cdp_endpoint = ?self.config.cdp_endpoint,
@@ -165,31 +230,54 @@ impl BrowserState {
                     .map_err(|e| BrowserError::ConnectionFailed(e.to_string()))?
             }
         } else {
-            let mut launcher = Browser::launch()
-                .headless(self.config.headless)
-                // Prevent Chromium from opening its default window on startup.
-                // We create our own context and page, so the default window is unnecessary.
-                .args(["--no-startup-window"]);
-
-            if let Some(ref user_data_dir) = self.config.user_data_dir {
-                launcher = launcher.user_data_dir(user_data_dir);
+            match self.launch_local().await {
+                Err(BrowserError::LaunchFailed(message))
+                    if self.config.executable_path.is_none()
+                        && install::is_missing_browser_error(&message) =>
+                {
+                    let executable = install::locate_missing_browser(
+                        &self.config.browser_type,
+                        self.config.auto_install,
+                        message,
+                    )
+                    .await?;
+                    self.config.executable_path = Some(executable);
+                    self.launch_local().await?
+                }
+                result => result?,
             }
-
-            launcher
-                .launch()
-                .await
-                .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?
         };
 
         self.browser = Some(browser);
 
-        // Create default context (without proxy)
-        self.create_context_internal(DEFAULT_CONTEXT, None).await?;
-
+        self.create_default_context().await?;
         self.initialized = true;
+        self.navigate_to_initial_url().await;
+
         Ok(())
     }
 
+    /// Launch a local browser process
+    async fn launch_local(&self) -> super::Result<Browser> {
+        let mut launcher = Browser::launch()
+            .headless(self.config.headless)
+            // Prevent Chromium from opening its default window on startup.
+            // We create our own context and page, so the default window is unnecessary.
+            .args(["--no-startup-window"]);
+
+        if let Some(ref executable_path) = self.config.executable_path {
+            launcher = launcher.executable_path(executable_path);
+        }
+        if let Some(ref user_data_dir) = self.config.user_data_dir {
+            launcher = launcher.user_data_dir(user_data_dir);
+        }
+
+        launcher
+            .launch()
+            .await
+            .map_err(|e| BrowserError::LaunchFailed(e.to_string()))
+    }
+
     /// Shutdown the browser
     pub async fn shutdown(&mut self) {
         if !self.initialized {
@@ -211,75 +299,16 @@ impl BrowserState {
         self.initialized = false;
     }
 
-    /// Reset browser state after connection loss
-    ///
-    /// Unlike `shutdown()`, this method does NOT attempt to close connections
-    /// since the browser process is dead or unreachable. It simply clears
-    /// internal state to allow re-initialization on the next tool call.
-    pub fn reset_on_connection_loss(&mut self) {
-        tracing::warn!("Resetting browser state after connection loss");
-
-        // Clear contexts without attempting to close them (browser is dead)
-        self.contexts.clear();
-
-        // Drop browser reference without closing (connection is lost)
-        self.browser = None;
-
-        // Reset to uninitialized state so next tool call re-launches browser
-        self.initialized = false;
-
-        // Reset active context to default
-        self.active_context = DEFAULT_CONTEXT.to_string();
-
-        tracing::info!("Browser state reset complete, ready for re-initialization");
-    }
-
-    /// Check if an error message indicates a browser connection loss
-    ///
-    /// Returns `true` if the error message suggests the WebSocket connection
-    /// to the browser has been lost (e.g., browser crashed, killed, or timed out).
-    #[must_use]
-    pub fn is_connection_loss_error(error_msg: &str) -> bool {
-        let patterns = [
-            "WebSocket connection lost",
-            "ConnectionLost",
-            "connection lost",
-            "connection closed",
-            "WebSocket error",
-            "WebSocket closed",
-            "channel closed",
-            "browser disconnected",
-            "CDP connection",
-        ];
-
-        patterns.iter().any(|pattern| error_msg.contains(pattern))
-    }
-
-    /// Handle a potential connection loss based on an error message
-    ///
-    /// If the error indicates a connection loss, resets browser state and returns `true`.
-    /// Otherwise, returns `false` and leaves state unchanged.
-    ///
-    /// This should be called when a tool execution fails to check if recovery is needed.
-    pub fn handle_potential_connection_loss(&mut self, error_msg: &str) -> bool {
-        if Self::is_connection_loss_error(error_msg) {
-            tracing::warn!(
-                error = %error_msg,
-                "Detected browser connection loss, triggering state reset"
-            );
-            self.reset_on_connection_loss();
-            true
-        } else {
-            false
-        }
-    }
-
     /// Get the active context
     ///
     /// # Errors
     ///
-    /// Returns an error if no active context exists.
+    /// Returns `BrowserError::NotInitialized` before [`BrowserState::initialize`]
+    /// succeeds, or an error if no active context exists.
     pub fn active_context(&self) -> super::Result<&ContextState> {
+        if !self.initialized {
+            return Err(BrowserError::NotInitialized);
+        }
         self.contexts
             .get(&self.active_context)
             .ok_or_else(|| BrowserError::ContextNotFound(self.active_context.clone()))
@@ -289,8 +318,12 @@ impl BrowserState {
     ///
     /// # Errors
     ///
-    /// Returns an error if no active context exists.
+    /// Returns `BrowserError::NotInitialized` before [`BrowserState::initialize`]
+    /// succeeds, or an error if no active context exists.
     pub fn active_context_mut(&mut self) -> super::Result<&mut ContextState> {
+        if !self.initialized {
+            return Err(BrowserError::NotInitialized);
+        }
         let name = self.active_context.clone();
         self.contexts
             .get_mut(&name)
@@ -308,32 +341,62 @@ impl BrowserState {
             .ok_or_else(|| BrowserError::ContextNotFound(name.to_string()))
     }
 
-    /// Internal helper to create a context with optional proxy configuration
+    /// Get a mutable reference to a context by name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context doesn't exist.
+    pub fn get_context_mut(&mut self, name: &str) -> super::Result<&mut ContextState> {
+        self.contexts
+            .get_mut(name)
+            .ok_or_else(|| BrowserError::ContextNotFound(name.to_string()))
+    }
+
+    /// Internal helper to create a context with the given options
     async fn create_context_internal(
         &mut self,
         name: &str,
-        proxy: Option<ProxyConfig>,
+        options: ContextOptions,
     ) -> super::Result<()> {
         let browser = self.browser.as_ref().ok_or(BrowserError::NotRunning)?;
 
-        let vp_context = if let Some(proxy_config) = proxy {
-            browser
-                .new_context_builder()
-                .proxy(proxy_config)
-                .build()
-                .await
-                .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?
-        } else {
-            browser
-                .new_context()
-                .await
-                .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?
-        };
+        // Clipboard access is granted up front so browser_clipboard works in every context
+        let mut builder = browser
+            .new_context_builder()
+            .permissions(vec![Permission::ClipboardRead, Permission::ClipboardWrite]);
+        if let Some(proxy_config) = options.proxy {
+            builder = builder.proxy(proxy_config);
+        }
+        if let Some(user_agent) = options.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(locale) = options.locale {
+            builder = builder.locale(locale);
+        }
+        if let Some(timezone) = options.timezone {
+            builder = builder.timezone_id(timezone);
+        }
+        if let Some(viewport) = options.viewport {
+            builder = builder.viewport(viewport.width, viewport.height);
+        }
+        let vp_context = builder
+            .build()
+            .await
+            .map_err(|e| BrowserError::LaunchFailed(e.to_string()))?;
 
         let context_state = ContextState::new(name, vp_context).await.map_err(
             |e: viewpoint_core::error::ContextError| BrowserError::LaunchFailed(e.to_string()),
         )?;
 
+        // Persisted init scripts apply to contexts created after injection too
+        for script in &self.init_scripts {
+            context_state
+                .context()
+                .add_init_script(script)
+                .await
+                .map_err(|e| BrowserError::EvaluationFailed(e.to_string()))?;
+        }
+
         self.contexts.insert(name.to_string(), context_state);
         self.active_context = name.to_string();
 
@@ -346,10 +409,11 @@ impl BrowserState {
     ///
     /// Returns an error if a context with the same name already exists.
     pub async fn create_context(&mut self, name: impl Into<String>) -> super::Result<()> {
-        self.create_context_with_options(name, None).await
+        self.create_context_with_options(name, ContextOptions::default())
+            .await
     }
 
-    /// Create a new named context with optional proxy configuration
+    /// Create a new named context with options such as proxy or user agent
     ///
     /// # Errors
     ///
@@ -357,7 +421,7 @@ impl BrowserState {
     pub async fn create_context_with_options(
         &mut self,
         name: impl Into<String>,
-        proxy: Option<ProxyConfig>,
+        options: ContextOptions,
     ) -> super::Result<()> {
         let name = name.into();
 
@@ -367,9 +431,14 @@ impl BrowserState {
             )));
         }
 
-        tracing::info!(name = %name, proxy = ?proxy.as_ref().map(|p| &p.server), "Creating browser context");
+        tracing::info!(
+            name = %name,
+            proxy = ?options.proxy.as_ref().map(|p| &p.server),
+            user_agent = ?options.user_agent,
+            "Creating browser context"
+        );
 
-        self.create_context_internal(&name, proxy).await
+        self.create_context_internal(&name, options).await
     }
 
     /// Switch to a named context
@@ -410,69 +479,12 @@ impl BrowserState {
         if self.active_context == name {
             self.active_context = DEFAULT_CONTEXT.to_string();
 
-            // Ensure default context exists (without proxy)
+            // Ensure default context exists
             if !self.contexts.contains_key(DEFAULT_CONTEXT) {
-                self.create_context_internal(DEFAULT_CONTEXT, None).await?;
+                self.create_default_context().await?;
             }
         }
 
         Ok(())
     }
-
-    /// List all contexts
-    #[must_use]
-    pub fn list_contexts(&self) -> Vec<&ContextState> {
-        self.contexts.values().collect()
-    }
-
-    /// Get the active context name
-    #[must_use]
-    pub fn active_context_name(&self) -> &str {
-        &self.active_context
-    }
-
-    /// Get information about all contexts with current URLs fetched dynamically.
-    ///
-    /// Unlike `list_contexts()`, this method queries each page for its current URL,
-    /// ensuring the returned information is always up-to-date.
-    pub async fn list_contexts_with_urls(&self) -> Vec<ContextInfo> {
-        let mut infos = Vec::with_capacity(self.contexts.len());
-
-        for ctx in self.contexts.values() {
-            let current_url = ctx.get_current_url().await;
-            let is_active = ctx.name == self.active_context;
-
-            infos.push(ContextInfo {
-                name: ctx.name.clone(),
-                is_active,
-                page_count: ctx.page_count().await.unwrap_or(0),
-                current_url,
-                proxy: ctx.proxy.clone(),
-            });
-        }
-
-        infos
-    }
-}
-
-/// Information about a browser context with dynamically fetched URL.
-///
-/// This struct contains a snapshot of context state with the current URL
-/// fetched from the browser rather than cached.
-#[derive(Debug, Clone)]
-pub struct ContextInfo {
-    /// Context name (unique identifier)
-    pub name: String,
-
-    /// Whether this is the active context
-    pub is_active: bool,
-
-    /// Number of pages in this context
-    pub page_count: usize,
-
-    /// Current URL of the active page (fetched from browser)
-    pub current_url: Option<String>,
-
-    /// Proxy configuration for this context
-    pub proxy: Option<super::config::ProxyConfig>,
 }
@@ -0,0 +1,78 @@
+//! Cross-tab script execution and injection
+
+use serde_json::Value;
+
+use super::BrowserState;
+use crate::browser::error::BrowserError;
+
+impl BrowserState {
+    /// Run a script on every page across all contexts.
+    ///
+    /// Results are keyed as `context_name::tab_index` and ordered by context
+    /// name, then tab index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pages of a context cannot be listed or the
+    /// script fails to evaluate on any page.
+    pub async fn execute_script_on_all_pages(
+        &mut self,
+        script: String,
+    ) -> crate::browser::Result<Vec<(String, Value)>> {
+        let mut names: Vec<&String> = self.contexts.keys().collect();
+        names.sort();
+
+        let mut results = Vec::new();
+        for name in names {
+            let context = &self.contexts[name];
+            let pages = context
+                .pages()
+                .await
+                .map_err(|e| BrowserError::ContextNotFound(format!("{name}: {e}")))?;
+
+            for (index, page) in pages.iter().enumerate() {
+                let value: Value = page
+                    .evaluate(&script)
+                    .await
+                    .map_err(|e| BrowserError::EvaluationFailed(format!("{name}::{index}: {e}")))?;
+                results.push((format!("{name}::{index}"), value));
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Persist a script so it runs on every future page.
+    ///
+    /// The script is registered as an init script on all existing contexts
+    /// and on any context created afterwards, so it runs before page scripts
+    /// on each new document. Already-loaded pages are not affected; use
+    /// [`BrowserState::execute_script_on_all_pages`] for those.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if registering the script on an existing context fails.
+    pub async fn inject_script_on_new_page(
+        &mut self,
+        script: String,
+    ) -> crate::browser::Result<()> {
+        for context in self.contexts.values() {
+            context
+                .context()
+                .add_init_script(&script)
+                .await
+                .map_err(|e| BrowserError::EvaluationFailed(format!("{}: {e}", context.name)))?;
+        }
+
+        tracing::info!(contexts = self.contexts.len(), "Registered init script");
+
+        self.init_scripts.push(script);
+        Ok(())
+    }
+
+    /// Get the scripts injected into every new page
+    #[must_use]
+    pub fn init_scripts(&self) -> &[String] {
+        &self.init_scripts
+    }
+}
@@ -0,0 +1,167 @@
+//! Export and import of browser state as JSON
+//!
+//! A state snapshot records each context's name and the URLs of its tabs, so
+//! a session can be reopened after a server restart. Cookies and storage are
+//! not included; use `browser_context_save_storage` for those.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::BrowserState;
+use crate::browser::error::BrowserError;
+
+/// Serializable snapshot of the browser's contexts and tabs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateSnapshot {
+    /// Name of the active context
+    pub active_context: String,
+
+    /// Contexts, ordered by creation time
+    pub contexts: Vec<ContextSnapshot>,
+}
+
+/// Serializable snapshot of one context
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextSnapshot {
+    /// Context name
+    pub name: String,
+
+    /// Index of the active tab
+    #[serde(default)]
+    pub active_tab: usize,
+
+    /// Tabs in tab order
+    pub tabs: Vec<TabSnapshot>,
+}
+
+/// Serializable snapshot of one tab
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TabSnapshot {
+    /// URL loaded in the tab
+    pub url: String,
+}
+
+impl BrowserState {
+    /// Capture the current contexts and tabs as JSON.
+    ///
+    /// Returns a [`StateSnapshot`] with the active context, and for each
+    /// context its name, active tab index, and tab URLs. An uninitialized
+    /// browser produces a snapshot with no contexts.
+    pub async fn to_snapshot_json(&self) -> Value {
+        let mut contexts = Vec::new();
+
+        for context in self.list_contexts() {
+            let pages = context.pages().await.unwrap_or_default();
+            let mut tabs = Vec::with_capacity(pages.len());
+            for page in &pages {
+                let url = page
+                    .url()
+                    .await
+                    .unwrap_or_else(|_| "about:blank".to_string());
+                tabs.push(TabSnapshot { url });
+            }
+
+            contexts.push(ContextSnapshot {
+                name: context.name.clone(),
+                active_tab: context.active_page_index().await,
+                tabs,
+            });
+        }
+
+        let snapshot = StateSnapshot {
+            active_context: self.active_context.clone(),
+            contexts,
+        };
+        serde_json::to_value(snapshot).unwrap_or_default()
+    }
+
+    /// Restore contexts and tabs from a snapshot produced by
+    /// [`BrowserState::to_snapshot_json`].
+    ///
+    /// Missing contexts are created, and each context gets exactly as many
+    /// tabs as recorded, each navigated to its stored URL in order. A tab that
+    /// fails to load is logged and left open so the rest of the session is
+    /// still restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON is not a valid snapshot, the browser
+    /// cannot be initialized, or a context or tab cannot be created.
+    pub async fn restore_from_snapshot(&mut self, json: &Value) -> crate::browser::Result<()> {
+        let snapshot: StateSnapshot = serde_json::from_value(json.clone())?;
+
+        self.initialize().await?;
+
+        for saved in &snapshot.contexts {
+            if !self.contexts.contains_key(&saved.name) {
+                self.create_context(&saved.name).await?;
+            }
+            self.restore_context_tabs(saved).await?;
+        }
+
+        if self.contexts.contains_key(&snapshot.active_context) {
+            self.active_context = snapshot.active_context;
+        }
+
+        tracing::info!(
+            contexts = snapshot.contexts.len(),
+            "Restored browser state from snapshot"
+        );
+        Ok(())
+    }
+
+    /// Open, close, and navigate tabs so the context matches the snapshot
+    async fn restore_context_tabs(
+        &mut self,
+        saved: &ContextSnapshot,
+    ) -> crate::browser::Result<()> {
+        let name = &saved.name;
+        let context = self
+            .contexts
+            .get_mut(name)
+            .ok_or_else(|| BrowserError::ContextNotFound(name.clone()))?;
+        let context_error = |e: viewpoint_core::error::ContextError| {
+            BrowserError::ContextFailed(format!("{name}: {e}"))
+        };
+
+        // Every context keeps at least one tab
+        let wanted = saved.tabs.len().max(1);
+        let mut count = context.page_count().await.map_err(context_error)?;
+        while count < wanted {
+            context.new_page().await.map_err(context_error)?;
+            count += 1;
+        }
+        while count > wanted {
+            count -= 1;
+            context
+                .close_page(count)
+                .await
+                .map_err(|e| BrowserError::PageNotFound(format!("{name}::{count}: {e}")))?;
+        }
+
+        // Tabs in tab order, so a restored index points at the same tab.
+        // Blank tabs are blanked too, rather than keeping whatever an
+        // existing tab had loaded.
+        let pages = context.pages().await.map_err(context_error)?;
+        for (index, (page, tab)) in pages.iter().zip(&saved.tabs).enumerate() {
+            if tab.url == "about:blank" && page.url().await.is_ok_and(|url| url == "about:blank") {
+                continue;
+            }
+            if let Err(e) = page.goto(&tab.url).goto().await {
+                tracing::warn!(
+                    context = %name,
+                    tab = index,
+                    url = %tab.url,
+                    error = %e,
+                    "Failed to restore tab"
+                );
+            }
+        }
+
+        context.switch_page(saved.active_tab.min(wanted - 1)).await;
+        context.invalidate_cache();
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! Creation and post-initialization setup for the default context
+
+use super::{BrowserState, DEFAULT_CONTEXT};
+use crate::browser::config::ContextOptions;
+
+impl BrowserState {
+    /// Set the URL to open in the default context when the browser initializes
+    #[must_use]
+    pub fn with_initial_url(mut self, url: Option<String>) -> Self {
+        self.initial_url = url;
+        self
+    }
+
+    /// Get the URL opened in the default context on initialization
+    #[must_use]
+    pub fn initial_url(&self) -> Option<&str> {
+        self.initial_url.as_deref()
+    }
+
+    /// Create the default context, routed through the configured proxy if any
+    pub(super) async fn create_default_context(&mut self) -> crate::browser::Result<()> {
+        let proxy = self.config.proxy.clone();
+
+        let mut options = ContextOptions::default();
+        if let Some(proxy) = &proxy {
+            tracing::info!(proxy = %proxy.server, "Using proxy for default context");
+            options = options.with_proxy(proxy.to_viewpoint());
+        }
+
+        self.create_context_internal(DEFAULT_CONTEXT, options)
+            .await?;
+
+        if let Some(context) = self.contexts.get_mut(DEFAULT_CONTEXT) {
+            context.proxy = proxy;
+        }
+        Ok(())
+    }
+
+    /// Navigate the default context's first page to the initial URL, if set.
+    ///
+    /// Failures are logged rather than returned so a bad initial URL doesn't
+    /// prevent the browser from being used.
+    pub(super) async fn navigate_to_initial_url(&mut self) {
+        let Some(url) = self.initial_url.clone() else {
+            return;
+        };
+
+        let Ok(context) = self.active_context_mut() else {
+            return;
+        };
+
+        let page = match context.active_page().await {
+            Ok(Some(page)) => page,
+            Ok(None) => {
+                tracing::warn!(url = %url, "No page available for initial URL");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(url = %url, error = %e, "Failed to get page for initial URL");
+                return;
+            }
+        };
+
+        tracing::info!(url = %url, "Navigating to initial URL");
+
+        if let Err(e) = page.goto(&url).goto().await {
+            tracing::warn!(url = %url, error = %e, "Failed to navigate to initial URL");
+            return;
+        }
+
+        context.set_current_url(Some(url)).await;
+        context.invalidate_cache();
+    }
+}
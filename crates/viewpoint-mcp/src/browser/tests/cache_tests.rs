@@ -0,0 +1,53 @@
+//! Tests for snapshot cache keys
+
+use serde_json::json;
+
+use crate::browser::SnapshotCacheKey;
+
+#[test]
+fn test_cache_key_from_observer() {
+    let key = SnapshotCacheKey::from_observer(
+        "https://example.com/",
+        &json!({ "id": "k3x", "count": 7 }),
+    )
+    .unwrap();
+
+    assert_eq!(key.url, "https://example.com/");
+    assert_eq!(key.document_id, "k3x");
+    assert_eq!(key.mutation_count, 7);
+}
+
+#[test]
+fn test_cache_key_from_invalid_observer() {
+    for observer in [
+        json!(null),
+        json!({ "count": 1 }),
+        json!({ "id": "k3x" }),
+        json!({ "id": "k3x", "count": -1 }),
+    ] {
+        assert!(
+            SnapshotCacheKey::from_observer("about:blank", &observer).is_none(),
+            "Observer {observer} should not produce a key"
+        );
+    }
+}
+
+#[test]
+fn test_cache_key_changes_on_mutation_and_navigation() {
+    let key = |url: &str, id: &str, count: u64| {
+        SnapshotCacheKey::from_observer(url, &json!({ "id": id, "count": count })).unwrap()
+    };
+    let cached = key("https://example.com/a", "k3x", 2);
+
+    // Same document with no further mutations is a hit
+    assert_eq!(cached, key("https://example.com/a", "k3x", 2));
+
+    // DOM mutated since capture
+    assert_ne!(cached, key("https://example.com/a", "k3x", 3));
+
+    // Navigated to another page
+    assert_ne!(cached, key("https://example.com/b", "p9q", 2));
+
+    // Reloaded the same URL: new document, even with a matching count
+    assert_ne!(cached, key("https://example.com/a", "p9q", 2));
+}
@@ -0,0 +1,108 @@
+//! Unit tests for browser detection and installation helpers
+
+use std::fs;
+
+use crate::browser::install::{
+    PLAYWRIGHT_VERSION, find_in_playwright_cache, is_missing_browser_error,
+};
+use crate::browser::{BrowserConfig, BrowserType};
+
+#[test]
+fn test_auto_install_disabled_by_default() {
+    let config = BrowserConfig::default();
+
+    assert!(!config.auto_install);
+    assert!(config.executable_path.is_none());
+}
+
+#[test]
+fn test_playwright_version_is_pinned() {
+    assert!(
+        PLAYWRIGHT_VERSION
+            .split('.')
+            .all(|part| part.parse::<u32>().is_ok())
+    );
+    assert_eq!(PLAYWRIGHT_VERSION.split('.').count(), 3);
+}
+
+#[test]
+fn test_install_names() {
+    assert_eq!(BrowserType::Chromium.install_name(), "chromium");
+    assert_eq!(BrowserType::Chrome.install_name(), "chrome");
+    assert!(
+        BrowserType::Chromium
+            .executable_names()
+            .contains(&"chromium")
+    );
+    assert!(!BrowserType::Chrome.executable_names().contains(&"chromium"));
+}
+
+#[test]
+fn test_is_missing_browser_error() {
+    assert!(is_missing_browser_error("Chromium executable not found"));
+    assert!(is_missing_browser_error(
+        "No such file or directory (os error 2)"
+    ));
+    assert!(!is_missing_browser_error("connection refused"));
+    // Unrelated failures mentioning executables or missing things
+    assert!(!is_missing_browser_error(
+        "executable crashed with signal SIGSEGV"
+    ));
+    assert!(!is_missing_browser_error("Target page not found"));
+}
+
+#[test]
+fn test_find_in_playwright_cache_prefers_newest_revision() {
+    let cache = tempfile::tempdir().unwrap();
+    for revision in ["1000", "1200"] {
+        let dir = cache
+            .path()
+            .join(format!("chromium-{revision}/chrome-linux"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("chrome"), "").unwrap();
+    }
+    // Unrelated browsers and incomplete downloads are ignored
+    fs::create_dir_all(cache.path().join("firefox-1500")).unwrap();
+    fs::create_dir_all(cache.path().join("chromium-1300")).unwrap();
+
+    let found = find_in_playwright_cache(cache.path()).unwrap();
+    assert_eq!(
+        found,
+        cache.path().join("chromium-1200/chrome-linux/chrome")
+    );
+}
+
+#[test]
+fn test_find_in_playwright_cache_headless_shell_and_new_layouts() {
+    let cache = tempfile::tempdir().unwrap();
+    let shell = cache
+        .path()
+        .join("chromium_headless_shell-1300/chrome-headless-shell-linux64");
+    fs::create_dir_all(&shell).unwrap();
+    fs::write(shell.join("chrome-headless-shell"), "").unwrap();
+
+    assert_eq!(
+        find_in_playwright_cache(cache.path()).unwrap(),
+        shell.join("chrome-headless-shell")
+    );
+
+    // A full Chromium build wins over a newer headless shell
+    let full = cache
+        .path()
+        .join("chromium-1200/chrome-mac-arm64/Chromium.app/Contents/MacOS");
+    fs::create_dir_all(&full).unwrap();
+    fs::write(full.join("Chromium"), "").unwrap();
+
+    assert_eq!(
+        find_in_playwright_cache(cache.path()).unwrap(),
+        full.join("Chromium")
+    );
+}
+
+#[test]
+fn test_find_in_playwright_cache_empty() {
+    let cache = tempfile::tempdir().unwrap();
+
+    assert!(find_in_playwright_cache(cache.path()).is_none());
+    assert!(find_in_playwright_cache(&cache.path().join("missing")).is_none());
+}
@@ -3,7 +3,10 @@
 //! Browser integration tests that require Chromium are in `tests/browser_integration.rs`.
 //! Run them with: `cargo test --features integration -p viewpoint-mcp --test browser_integration`
 
-use crate::browser::{BrowserConfig, BrowserState, ProxyConfig, ViewportSize};
+mod cache_tests;
+mod install_tests;
+
+use crate::browser::{BrowserConfig, BrowserError, BrowserState, ProxyConfig, ViewportSize};
 
 #[test]
 fn test_viewport_parse_valid() {
@@ -66,6 +69,33 @@ async fn test_browser_state_new() {
 
     assert!(!state.is_initialized());
     assert_eq!(state.active_context_name(), "default");
+    assert!(state.init_scripts().is_empty());
+}
+
+#[test]
+fn test_browser_state_initial_url() {
+    let state = BrowserState::new(BrowserConfig::default());
+    assert!(state.initial_url().is_none());
+
+    let state = BrowserState::new(BrowserConfig::default())
+        .with_initial_url(Some("https://example.com".to_string()));
+    assert_eq!(state.initial_url(), Some("https://example.com"));
+}
+
+#[tokio::test]
+async fn test_inject_script_before_initialize_is_persisted() {
+    let mut state = BrowserState::new(BrowserConfig::default());
+
+    // No contexts yet, so the script is only stored for future contexts
+    state
+        .inject_script_on_new_page("window.__helper = true;".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        state.init_scripts(),
+        ["window.__helper = true;".to_string()]
+    );
 }
 
 // Connection loss recovery tests
@@ -133,6 +163,35 @@ fn test_reset_on_connection_loss() {
     assert_eq!(state.active_context_name(), "default");
 }
 
+#[test]
+fn test_not_initialized_before_initialize() {
+    let mut state = BrowserState::new(BrowserConfig::default());
+
+    assert!(state.browser().is_none());
+    assert!(matches!(
+        state.active_context(),
+        Err(BrowserError::NotInitialized)
+    ));
+    assert!(matches!(
+        state.active_context_mut(),
+        Err(BrowserError::NotInitialized)
+    ));
+}
+
+#[tokio::test]
+async fn test_page_title_and_url_before_initialize() {
+    let state = BrowserState::new(BrowserConfig::default());
+
+    assert!(matches!(
+        state.page_title().await,
+        Err(BrowserError::NotInitialized)
+    ));
+    assert!(matches!(
+        state.page_url().await,
+        Err(BrowserError::NotInitialized)
+    ));
+}
+
 #[test]
 fn test_handle_potential_connection_loss_triggers_reset() {
     let config = BrowserConfig::default();
@@ -153,3 +212,46 @@ fn test_handle_potential_connection_loss_ignores_other_errors() {
     let triggered = state.handle_potential_connection_loss("Element not found: #button");
     assert!(!triggered);
 }
+
+// State snapshot tests
+
+#[tokio::test]
+async fn test_snapshot_json_before_initialize() {
+    let state = BrowserState::new(BrowserConfig::default());
+
+    let json = state.to_snapshot_json().await;
+
+    assert_eq!(
+        json,
+        serde_json::json!({ "activeContext": "default", "contexts": [] })
+    );
+}
+
+#[tokio::test]
+async fn test_restore_from_invalid_snapshot() {
+    let mut state = BrowserState::new(BrowserConfig::default());
+
+    let result = state
+        .restore_from_snapshot(&serde_json::json!({ "contexts": "not a list" }))
+        .await;
+
+    assert!(matches!(result, Err(crate::browser::BrowserError::Json(_))));
+    assert!(!state.is_initialized());
+}
+
+#[test]
+fn test_browser_config_has_no_proxy_by_default() {
+    assert!(BrowserConfig::default().proxy.is_none());
+}
+
+#[test]
+fn test_browser_config_with_proxy() {
+    let config = BrowserConfig {
+        proxy: Some(ProxyConfig::new("http://proxy:8080").with_bypass("localhost")),
+        ..Default::default()
+    };
+
+    let proxy = config.proxy.unwrap();
+    assert_eq!(proxy.server, "http://proxy:8080");
+    assert_eq!(proxy.bypass.as_deref(), Some("localhost"));
+}
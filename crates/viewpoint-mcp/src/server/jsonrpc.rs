@@ -0,0 +1,117 @@
+//! JSON-RPC 2.0 message types
+//!
+//! Requests, responses, and notifications exchanged with MCP clients over
+//! every transport.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::error::ServerError;
+
+/// JSON-RPC request
+#[derive(Debug, Deserialize)]
+pub struct JsonRpcRequest {
+    /// JSON-RPC version (always "2.0")
+    pub jsonrpc: String,
+
+    /// Request ID (null for notifications)
+    #[serde(default)]
+    pub id: Option<Value>,
+
+    /// Method name
+    pub method: String,
+
+    /// Method parameters
+    #[serde(default)]
+    pub params: Value,
+}
+
+impl JsonRpcRequest {
+    /// Whether this request is a notification (no `id`, or `id: null`)
+    #[must_use]
+    pub fn is_notification(&self) -> bool {
+        self.id.as_ref().is_none_or(Value::is_null)
+    }
+}
+
+/// Parameters of a `notifications/message` logging notification
+#[derive(Debug, Deserialize)]
+pub struct LoggingMessageParams {
+    /// Severity level (e.g., "debug", "info", "warning", "error")
+    #[serde(default)]
+    pub level: String,
+
+    /// Optional name of the logger that emitted the message
+    #[serde(default)]
+    pub logger: Option<String>,
+
+    /// Message payload
+    #[serde(default)]
+    pub data: Value,
+}
+
+/// JSON-RPC response
+#[derive(Debug, Serialize)]
+pub struct JsonRpcResponse {
+    /// JSON-RPC version
+    pub jsonrpc: &'static str,
+
+    /// Request ID
+    pub id: Value,
+
+    /// Result (mutually exclusive with error)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+
+    /// Error (mutually exclusive with result)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+}
+
+/// JSON-RPC error
+#[derive(Debug, Serialize)]
+pub struct JsonRpcError {
+    /// Error code
+    pub code: i32,
+
+    /// Error message
+    pub message: String,
+
+    /// Additional error data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl JsonRpcResponse {
+    /// Create a success response
+    #[must_use]
+    pub fn success(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Create an error response
+    #[must_use]
+    pub fn error(id: Value, code: i32, message: String) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(JsonRpcError {
+                code,
+                message,
+                data: None,
+            }),
+        }
+    }
+
+    /// Create an error response from a `ServerError`
+    #[must_use]
+    pub fn from_error(id: Value, err: &ServerError) -> Self {
+        Self::error(id, err.error_code(), err.to_string())
+    }
+}
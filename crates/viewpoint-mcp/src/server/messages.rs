@@ -0,0 +1,156 @@
+//! MCP message types
+//!
+//! Payloads of the `initialize`, `tools/list`, and `tools/call` methods, and
+//! the content items tools return.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// MCP Server capabilities
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerCapabilities {
+    /// Tool capabilities
+    pub tools: ToolCapabilities,
+}
+
+/// Tool capabilities
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolCapabilities {
+    /// Whether tools support list changes
+    pub list_changed: bool,
+}
+
+/// MCP initialization result
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InitializeResult {
+    /// Protocol version
+    pub protocol_version: String,
+
+    /// Server capabilities
+    pub capabilities: ServerCapabilities,
+
+    /// Server info
+    pub server_info: ServerInfo,
+}
+
+/// Server information
+#[derive(Debug, Serialize)]
+pub struct ServerInfo {
+    /// Server name
+    pub name: String,
+
+    /// Server version
+    pub version: String,
+}
+
+/// Tool definition for listing
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolDefinition {
+    /// Tool name
+    pub name: String,
+
+    /// Tool description
+    pub description: String,
+
+    /// Primary tool category (e.g., `navigation`), if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Input JSON schema
+    pub input_schema: Value,
+}
+
+/// Tool call parameters
+#[derive(Debug, Deserialize)]
+pub struct ToolCallParams {
+    /// Tool name
+    pub name: String,
+
+    /// Tool arguments
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+/// Content item for tool responses.
+///
+/// MCP responses can contain multiple content items of different types.
+/// This enum supports text and image content per the MCP specification.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ContentItem {
+    /// Text content
+    #[serde(rename = "text")]
+    Text {
+        /// The text content
+        text: String,
+    },
+    /// Image content (base64 encoded)
+    #[serde(rename = "image")]
+    Image {
+        /// Base64-encoded image data
+        data: String,
+        /// MIME type (e.g., "image/png", "image/jpeg")
+        #[serde(rename = "mimeType")]
+        mime_type: String,
+    },
+}
+
+impl ContentItem {
+    /// Create a text content item
+    #[must_use]
+    pub fn text(s: impl Into<String>) -> Self {
+        Self::Text { text: s.into() }
+    }
+
+    /// Create an image content item
+    #[must_use]
+    pub fn image(data: String, mime_type: impl Into<String>) -> Self {
+        Self::Image {
+            data,
+            mime_type: mime_type.into(),
+        }
+    }
+}
+
+/// Output from a successful tool execution.
+///
+/// Contains one or more content items that make up the tool's response.
+/// Most tools return a single text item, but screenshot tools may include
+/// an image item as well.
+#[derive(Debug, Clone)]
+pub struct ToolOutput {
+    /// Content items in the response
+    pub content: Vec<ContentItem>,
+}
+
+impl ToolOutput {
+    /// Create a simple text-only output
+    #[must_use]
+    pub fn text(s: impl Into<String>) -> Self {
+        Self {
+            content: vec![ContentItem::text(s)],
+        }
+    }
+
+    /// Create output with multiple content items
+    #[must_use]
+    pub fn new(content: Vec<ContentItem>) -> Self {
+        Self { content }
+    }
+}
+
+/// Tool call result
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolCallResult {
+    /// Result content
+    pub content: Vec<ContentItem>,
+
+    /// Whether the tool execution errored
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub is_error: bool,
+}
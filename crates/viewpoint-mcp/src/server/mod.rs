@@ -4,9 +4,13 @@
 //! - JSON-RPC message types
 //! - MCP initialization handshake
 //! - Tool listing and invocation
+//! - Structured logging of tool calls
 
 mod error;
+mod jsonrpc;
+mod messages;
 pub mod protocol;
+mod tool_call;
 mod types;
 
 #[cfg(test)]
@@ -12,243 +12,20 @@
 
 use std::sync::Arc;
 
-use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tokio::sync::RwLock;
 
 use super::error::ServerError;
+use super::tool_call::execute_tool;
 use super::types::ServerConfig;
 use crate::browser::BrowserState;
-use crate::tools::{Capability, ToolRegistry, register_all_tools};
+use crate::tools::{Capability, Tool, ToolRegistry, register_all_tools};
 
-/// JSON-RPC request
-#[derive(Debug, Deserialize)]
-pub struct JsonRpcRequest {
-    /// JSON-RPC version (always "2.0")
-    pub jsonrpc: String,
-
-    /// Request ID (null for notifications)
-    #[serde(default)]
-    pub id: Option<Value>,
-
-    /// Method name
-    pub method: String,
-
-    /// Method parameters
-    #[serde(default)]
-    pub params: Value,
-}
-
-/// JSON-RPC response
-#[derive(Debug, Serialize)]
-pub struct JsonRpcResponse {
-    /// JSON-RPC version
-    pub jsonrpc: &'static str,
-
-    /// Request ID
-    pub id: Value,
-
-    /// Result (mutually exclusive with error)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<Value>,
-
-    /// Error (mutually exclusive with result)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<JsonRpcError>,
-}
-
-/// JSON-RPC error
-#[derive(Debug, Serialize)]
-pub struct JsonRpcError {
-    /// Error code
-    pub code: i32,
-
-    /// Error message
-    pub message: String,
-
-    /// Additional error data
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<Value>,
-}
-
-impl JsonRpcResponse {
-    /// Create a success response
-    #[must_use]
-    pub fn success(id: Value, result: Value) -> Self {
-        Self {
-            jsonrpc: "2.0",
-            id,
-            result: Some(result),
-            error: None,
-        }
-    }
-
-    /// Create an error response
-    #[must_use]
-    pub fn error(id: Value, code: i32, message: String) -> Self {
-        Self {
-            jsonrpc: "2.0",
-            id,
-            result: None,
-            error: Some(JsonRpcError {
-                code,
-                message,
-                data: None,
-            }),
-        }
-    }
-
-    /// Create an error response from a `ServerError`
-    #[must_use]
-    pub fn from_error(id: Value, err: &ServerError) -> Self {
-        Self::error(id, err.error_code(), err.to_string())
-    }
-}
-
-/// MCP Server capabilities
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ServerCapabilities {
-    /// Tool capabilities
-    pub tools: ToolCapabilities,
-}
-
-/// Tool capabilities
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ToolCapabilities {
-    /// Whether tools support list changes
-    pub list_changed: bool,
-}
-
-/// MCP initialization result
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct InitializeResult {
-    /// Protocol version
-    pub protocol_version: String,
-
-    /// Server capabilities
-    pub capabilities: ServerCapabilities,
-
-    /// Server info
-    pub server_info: ServerInfo,
-}
-
-/// Server information
-#[derive(Debug, Serialize)]
-pub struct ServerInfo {
-    /// Server name
-    pub name: String,
-
-    /// Server version
-    pub version: String,
-}
-
-/// Tool definition for listing
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ToolDefinition {
-    /// Tool name
-    pub name: String,
-
-    /// Tool description
-    pub description: String,
-
-    /// Input JSON schema
-    pub input_schema: Value,
-}
-
-/// Tool call parameters
-#[derive(Debug, Deserialize)]
-pub struct ToolCallParams {
-    /// Tool name
-    pub name: String,
-
-    /// Tool arguments
-    #[serde(default)]
-    pub arguments: Value,
-}
-
-/// Content item for tool responses.
-///
-/// MCP responses can contain multiple content items of different types.
-/// This enum supports text and image content per the MCP specification.
-#[derive(Debug, Clone, Serialize)]
-#[serde(tag = "type", rename_all = "camelCase")]
-pub enum ContentItem {
-    /// Text content
-    #[serde(rename = "text")]
-    Text {
-        /// The text content
-        text: String,
-    },
-    /// Image content (base64 encoded)
-    #[serde(rename = "image")]
-    Image {
-        /// Base64-encoded image data
-        data: String,
-        /// MIME type (e.g., "image/png", "image/jpeg")
-        #[serde(rename = "mimeType")]
-        mime_type: String,
-    },
-}
-
-impl ContentItem {
-    /// Create a text content item
-    #[must_use]
-    pub fn text(s: impl Into<String>) -> Self {
-        Self::Text { text: s.into() }
-    }
-
-    /// Create an image content item
-    #[must_use]
-    pub fn image(data: String, mime_type: impl Into<String>) -> Self {
-        Self::Image {
-            data,
-            mime_type: mime_type.into(),
-        }
-    }
-}
-
-/// Output from a successful tool execution.
-///
-/// Contains one or more content items that make up the tool's response.
-/// Most tools return a single text item, but screenshot tools may include
-/// an image item as well.
-#[derive(Debug, Clone)]
-pub struct ToolOutput {
-    /// Content items in the response
-    pub content: Vec<ContentItem>,
-}
-
-impl ToolOutput {
-    /// Create a simple text-only output
-    #[must_use]
-    pub fn text(s: impl Into<String>) -> Self {
-        Self {
-            content: vec![ContentItem::text(s)],
-        }
-    }
-
-    /// Create output with multiple content items
-    #[must_use]
-    pub fn new(content: Vec<ContentItem>) -> Self {
-        Self { content }
-    }
-}
-
-/// Tool call result
-#[derive(Debug, Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ToolCallResult {
-    /// Result content
-    pub content: Vec<ContentItem>,
-
-    /// Whether the tool execution errored
-    #[serde(skip_serializing_if = "std::ops::Not::not")]
-    pub is_error: bool,
-}
+pub use super::jsonrpc::{JsonRpcError, JsonRpcRequest, JsonRpcResponse, LoggingMessageParams};
+pub use super::messages::{
+    ContentItem, InitializeResult, ServerCapabilities, ServerInfo, ToolCallParams, ToolCallResult,
+    ToolCapabilities, ToolDefinition, ToolOutput,
+};
 
 /// MCP Server for browser automation.
 ///
@@ -306,7 +83,9 @@ impl McpServer {
             browser_config,
             config.screenshot_dir.clone(),
             config.image_responses,
-        );
+        )
+        .with_initial_url(config.initial_url.clone())
+        .with_max_evaluate_result_length(config.max_evaluate_result_length);
 
         Self {
             config,
@@ -316,6 +95,16 @@ impl McpServer {
         }
     }
 
+    /// Register an additional tool after construction.
+    ///
+    /// Lets embedding applications expose their own tools alongside the
+    /// built-in browser tools. A tool with the same name as an existing one
+    /// replaces it. Tools requiring a capability are only listed and callable
+    /// when that capability is enabled in [`ServerConfig::capabilities`].
+    pub fn register_tool(&mut self, tool: Arc<dyn Tool>) {
+        self.tools.register(tool);
+    }
+
     /// Handle a JSON-RPC request
     ///
     /// # Errors
@@ -331,6 +120,47 @@ impl McpServer {
         }
     }
 
+    /// Handle a JSON-RPC notification.
+    ///
+    /// Notifications are fire-and-forget: they never produce a response, so
+    /// failures are logged rather than returned. Unknown notifications are
+    /// ignored.
+    pub fn handle_notification(&mut self, method: &str, params: &Value) {
+        match method {
+            "initialized" | "notifications/initialized" => {
+                tracing::debug!("Client completed initialization");
+                self.initialized = true;
+            }
+            "notifications/message" => Self::handle_logging_message(params),
+            _ => tracing::debug!(method = %method, "Ignoring unknown notification"),
+        }
+    }
+
+    /// Forward a client logging notification to the server log
+    fn handle_logging_message(params: &Value) {
+        let message: LoggingMessageParams = match serde_json::from_value(params.clone()) {
+            Ok(message) => message,
+            Err(e) => {
+                tracing::warn!(error = %e, "Invalid notifications/message params");
+                return;
+            }
+        };
+
+        let logger = message.logger.as_deref().unwrap_or("client");
+        let data = &message.data;
+        match message.level.as_str() {
+            "debug" => tracing::debug!(logger = %logger, data = %data, "Client log"),
+            "notice" | "info" => tracing::info!(logger = %logger, data = %data, "Client log"),
+            "warning" => tracing::warn!(logger = %logger, data = %data, "Client log"),
+            "error" | "critical" | "alert" | "emergency" => {
+                tracing::error!(logger = %logger, data = %data, "Client log");
+            }
+            level => {
+                tracing::info!(logger = %logger, level = %level, data = %data, "Client log");
+            }
+        }
+    }
+
     async fn handle_initialize(&mut self, _params: &Value) -> super::Result<Value> {
         self.initialized = true;
 
@@ -353,11 +183,12 @@ impl McpServer {
     async fn handle_tools_list(&self) -> super::Result<Value> {
         let tools: Vec<ToolDefinition> = self
             .tools
-            .list()
+            .list_sorted()
             .iter()
             .map(|tool| ToolDefinition {
                 name: tool.name().to_string(),
                 description: tool.description().to_string(),
+                category: tool.categories().first().map(ToString::to_string),
                 input_schema: tool.input_schema(),
             })
             .collect();
@@ -375,7 +206,14 @@ impl McpServer {
             .ok_or_else(|| ServerError::MethodNotFound(call_params.name.clone()))?;
 
         let mut browser = self.browser.write().await;
-        let result = tool.execute(&call_params.arguments, &mut browser).await;
+
+        let result = execute_tool(
+            tool.as_ref(),
+            &call_params.arguments,
+            &mut browser,
+            self.config.tool_timeout_ms,
+        )
+        .await;
 
         let call_result = match result {
             Ok(output) => ToolCallResult {
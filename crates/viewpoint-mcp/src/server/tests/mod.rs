@@ -1,3 +1,5 @@
 //! Unit tests for MCP server protocol
 
 mod protocol_tests;
+mod register_tool_tests;
+mod tool_call_tests;
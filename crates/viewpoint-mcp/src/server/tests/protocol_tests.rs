@@ -60,6 +60,47 @@ async fn test_initialized_notification() {
     assert_eq!(result, Value::Null);
 }
 
+#[test]
+fn test_is_notification() {
+    let request = create_request("tools/list", json!({}));
+    assert!(!request.is_notification());
+
+    let request: JsonRpcRequest =
+        serde_json::from_value(json!({ "jsonrpc": "2.0", "method": "initialized" })).unwrap();
+    assert!(request.is_notification());
+
+    let request: JsonRpcRequest = serde_json::from_value(
+        json!({ "jsonrpc": "2.0", "id": null, "method": "notifications/message" }),
+    )
+    .unwrap();
+    assert!(request.is_notification());
+}
+
+#[test]
+fn test_handle_notification_initialized() {
+    let mut server = create_test_server();
+    assert!(!server.is_initialized());
+
+    server.handle_notification("notifications/initialized", &json!({}));
+
+    assert!(server.is_initialized());
+}
+
+#[test]
+fn test_handle_notification_logging_message() {
+    let mut server = create_test_server();
+
+    // Valid, malformed, and unknown notifications are all handled without panicking
+    server.handle_notification(
+        "notifications/message",
+        &json!({ "level": "warning", "logger": "client", "data": "Something happened" }),
+    );
+    server.handle_notification("notifications/message", &json!({ "level": 42 }));
+    server.handle_notification("notifications/unknown", &json!({}));
+
+    assert!(!server.is_initialized());
+}
+
 #[tokio::test]
 async fn test_tools_list_returns_core_tools() {
     let mut server = create_test_server();
@@ -74,14 +115,12 @@ async fn test_tools_list_returns_core_tools() {
     assert!(result.get("tools").is_some());
     let tools = result["tools"].as_array().unwrap();
 
-    // Without any capabilities enabled, we should have 27 core tools
-    // (31 total - 3 vision tools - 1 pdf tool = 27 core tools)
-    // Actually: 31 total tools, 3 require Vision, 1 requires Pdf
-    // So without capabilities: 31 - 3 - 1 = 27 core tools
+    // Without any capabilities enabled, we should have 34 core tools
+    // (38 total - 3 vision tools - 1 pdf tool = 34 core tools)
     assert_eq!(
         tools.len(),
-        27,
-        "Expected 27 core tools without optional capabilities"
+        34,
+        "Expected 34 core tools without optional capabilities"
     );
 
     // Verify some expected tool names are present
@@ -116,6 +155,29 @@ async fn test_tools_list_returns_core_tools() {
     );
 }
 
+#[tokio::test]
+async fn test_tools_list_includes_category() {
+    let mut server = create_test_server();
+
+    let init_request = create_request("initialize", json!({}));
+    server.handle_request(&init_request).await.unwrap();
+
+    let request = create_request("tools/list", json!({}));
+    let result = server.handle_request(&request).await.unwrap();
+    let tools = result["tools"].as_array().unwrap();
+
+    let navigate = tools
+        .iter()
+        .find(|t| t["name"] == "browser_navigate")
+        .unwrap();
+    assert_eq!(navigate["category"], "navigation");
+
+    assert!(
+        tools.iter().all(|t| t["category"].is_string()),
+        "Every built-in tool should have a category"
+    );
+}
+
 #[tokio::test]
 async fn test_tools_call_unknown_tool() {
     let mut server = create_test_server();
@@ -139,6 +201,33 @@ async fn test_tools_call_unknown_tool() {
     assert_eq!(err.error_code(), -32601); // Method not found
 }
 
+#[tokio::test]
+async fn test_tools_call_timeout() {
+    let mut server = McpServer::new(ServerConfig {
+        tool_timeout_ms: Some(50),
+        ..Default::default()
+    });
+
+    let init_request = create_request("initialize", json!({}));
+    server.handle_request(&init_request).await.unwrap();
+
+    // A plain time wait does not need a browser, so only the timeout can fail it
+    let request = create_request(
+        "tools/call",
+        json!({
+            "name": "browser_wait_for",
+            "arguments": { "time": 1 }
+        }),
+    );
+
+    let result = server.handle_request(&request).await.unwrap();
+    assert_eq!(result["isError"], true);
+    assert_eq!(
+        result["content"][0]["text"],
+        "Tool 'browser_wait_for' timed out after 50ms"
+    );
+}
+
 #[tokio::test]
 async fn test_unknown_method() {
     let mut server = create_test_server();
@@ -204,8 +293,8 @@ async fn test_tools_registered_with_vision_capability() {
 
     let tools = result["tools"].as_array().unwrap();
 
-    // With vision enabled: 27 core + 3 vision = 30 tools
-    assert_eq!(tools.len(), 30, "Expected 30 tools with vision capability");
+    // With vision enabled: 34 core + 3 vision = 37 tools
+    assert_eq!(tools.len(), 37, "Expected 37 tools with vision capability");
 
     let tool_names: Vec<&str> = tools.iter().filter_map(|t| t["name"].as_str()).collect();
 
@@ -233,8 +322,8 @@ async fn test_tools_registered_with_all_capabilities() {
 
     let tools = result["tools"].as_array().unwrap();
 
-    // With all capabilities: all 31 tools
-    assert_eq!(tools.len(), 31, "Expected 31 tools with all capabilities");
+    // With all capabilities: all 38 tools
+    assert_eq!(tools.len(), 38, "Expected 38 tools with all capabilities");
 
     let tool_names: Vec<&str> = tools.iter().filter_map(|t| t["name"].as_str()).collect();
 
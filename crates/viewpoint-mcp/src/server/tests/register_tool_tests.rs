@@ -0,0 +1,111 @@
+//! Tests for registering custom tools at runtime
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+use crate::browser::BrowserState;
+use crate::server::ServerConfig;
+use crate::server::protocol::{JsonRpcRequest, McpServer};
+use crate::tools::{Capability, Tool, ToolOutput, ToolResult};
+
+/// App-specific tool that echoes its input without touching the browser
+struct EchoTool {
+    capability: Option<Capability>,
+}
+
+#[async_trait]
+impl Tool for EchoTool {
+    fn name(&self) -> &'static str {
+        "app_echo"
+    }
+
+    fn description(&self) -> &'static str {
+        "Echo the given message"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": { "message": { "type": "string" } }
+        })
+    }
+
+    fn required_capability(&self) -> Option<Capability> {
+        self.capability
+    }
+
+    fn requires_browser(&self) -> bool {
+        false
+    }
+
+    async fn execute(&self, args: &Value, _browser: &mut BrowserState) -> ToolResult {
+        Ok(ToolOutput::text(format!("echo: {}", args["message"])))
+    }
+}
+
+fn create_request(method: &str, params: Value) -> JsonRpcRequest {
+    JsonRpcRequest {
+        jsonrpc: "2.0".to_string(),
+        id: Some(json!(1)),
+        method: method.to_string(),
+        params,
+    }
+}
+
+async fn listed_tool_names(server: &mut McpServer) -> Vec<String> {
+    let request = create_request("tools/list", json!({}));
+    let result = server.handle_request(&request).await.unwrap();
+    result["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_registered_tool_is_listed_and_callable() {
+    let mut server = McpServer::new(ServerConfig::default());
+    server.register_tool(Arc::new(EchoTool { capability: None }));
+
+    let names = listed_tool_names(&mut server).await;
+    assert!(names.contains(&"app_echo".to_string()));
+    assert!(names.contains(&"browser_navigate".to_string()));
+
+    let request = create_request(
+        "tools/call",
+        json!({ "name": "app_echo", "arguments": { "message": "hi" } }),
+    );
+    let result = server.handle_request(&request).await.unwrap();
+
+    assert!(result.get("isError").is_none());
+    assert_eq!(result["content"][0]["text"], "echo: \"hi\"");
+}
+
+#[tokio::test]
+async fn test_registered_tool_respects_capabilities() {
+    let mut server = McpServer::new(ServerConfig::default());
+    server.register_tool(Arc::new(EchoTool {
+        capability: Some(Capability::Vision),
+    }));
+    assert!(
+        !listed_tool_names(&mut server)
+            .await
+            .contains(&"app_echo".to_string())
+    );
+
+    let mut server = McpServer::new(ServerConfig {
+        capabilities: vec!["vision".to_string()],
+        ..Default::default()
+    });
+    server.register_tool(Arc::new(EchoTool {
+        capability: Some(Capability::Vision),
+    }));
+    assert!(
+        listed_tool_names(&mut server)
+            .await
+            .contains(&"app_echo".to_string())
+    );
+}
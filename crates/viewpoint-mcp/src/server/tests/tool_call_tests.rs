@@ -0,0 +1,157 @@
+//! Tests for structured tool call logging
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use serde_json::json;
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::browser::{BrowserConfig, BrowserState};
+use crate::server::tool_call::execute_tool;
+use crate::tools::{BrowserTabsTool, BrowserWaitForTool, ToolError};
+
+/// In-memory log sink shared between the subscriber and the test
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl LogBuffer {
+    fn contents(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBuffer {
+    type Writer = Self;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+fn capture_logs() -> (LogBuffer, tracing::subscriber::DefaultGuard) {
+    let buffer = LogBuffer::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(buffer.clone())
+        .with_ansi(false)
+        .finish();
+    let guard = tracing::subscriber::set_default(subscriber);
+    (buffer, guard)
+}
+
+#[test]
+fn test_error_categories() {
+    assert_eq!(
+        ToolError::InvalidParams(String::new()).category(),
+        "invalid_params"
+    );
+    assert_eq!(
+        ToolError::ElementNotFound(String::new()).category(),
+        "element_not_found"
+    );
+    assert_eq!(
+        ToolError::Timeout {
+            tool_name: "browser_wait_for".to_string(),
+            timeout_ms: 10,
+        }
+        .category(),
+        "timeout"
+    );
+}
+
+#[tokio::test]
+async fn test_successful_call_logs_fields() {
+    let (logs, _guard) = capture_logs();
+    let mut browser = BrowserState::new(BrowserConfig::default());
+
+    let result = execute_tool(
+        &BrowserWaitForTool::new(),
+        &json!({ "time": 0 }),
+        &mut browser,
+        None,
+    )
+    .await;
+    assert!(result.is_ok());
+
+    let output = logs.contents();
+    assert!(output.contains("tool_call{"), "Missing span: {output}");
+    assert!(
+        output.contains("tool_name=\"browser_wait_for\""),
+        "{output}"
+    );
+    assert!(output.contains("duration_ms="), "{output}");
+    assert!(output.contains("is_error=false"), "{output}");
+    assert!(!output.contains("error_category="), "{output}");
+}
+
+#[tokio::test]
+async fn test_failed_call_logs_error_category() {
+    let (logs, _guard) = capture_logs();
+    let mut browser = BrowserState::new(BrowserConfig::default());
+
+    // No condition given, so the tool rejects its parameters
+    let result = execute_tool(&BrowserWaitForTool::new(), &json!({}), &mut browser, None).await;
+    assert!(matches!(result, Err(ToolError::InvalidParams(_))));
+
+    let output = logs.contents();
+    assert!(output.contains("is_error=true"), "{output}");
+    assert!(
+        output.contains("error_category=\"invalid_params\""),
+        "{output}"
+    );
+}
+
+#[tokio::test]
+async fn test_timed_out_call_logs_timeout_category() {
+    let (logs, _guard) = capture_logs();
+    let mut browser = BrowserState::new(BrowserConfig::default());
+
+    let result = execute_tool(
+        &BrowserWaitForTool::new(),
+        &json!({ "time": 1 }),
+        &mut browser,
+        Some(20),
+    )
+    .await;
+    assert!(matches!(result, Err(ToolError::Timeout { .. })));
+
+    let output = logs.contents();
+    assert!(output.contains("error_category=\"timeout\""), "{output}");
+}
+
+#[tokio::test]
+async fn test_browser_start_failure_is_logged_as_tool_call() {
+    let (logs, _guard) = capture_logs();
+    let mut browser = BrowserState::new(BrowserConfig {
+        cdp_endpoint: Some("http://127.0.0.1:1".to_string()),
+        ..Default::default()
+    });
+
+    // The tab list needs a browser, so the call starts one and fails to connect
+    let result = execute_tool(
+        &BrowserTabsTool::new(),
+        &json!({ "action": "list" }),
+        &mut browser,
+        Some(10_000),
+    )
+    .await;
+    assert!(matches!(result, Err(ToolError::BrowserNotAvailable(_))));
+    assert!(!browser.is_initialized());
+
+    let output = logs.contents();
+    assert!(output.contains("tool_name=\"browser_tabs\""), "{output}");
+    assert!(
+        output.contains("error_category=\"browser_not_available\""),
+        "{output}"
+    );
+}
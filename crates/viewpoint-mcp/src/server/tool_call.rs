@@ -0,0 +1,75 @@
+//! Tool call execution with structured logging
+//!
+//! Every tool call runs inside a `tool_call` span that records the tool name,
+//! duration, and result status so log lines can be parsed for SLA monitoring.
+
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use tracing::Span;
+
+use crate::browser::BrowserState;
+use crate::tools::{Tool, ToolError, ToolResult};
+
+/// Execute a tool, enforcing the optional time limit and logging the outcome.
+///
+/// Tools that need the browser start it first if it is not running yet; the
+/// launch counts toward the time limit. Records `duration_ms` and `is_error` on the span, plus `error_category`
+/// when the call fails.
+#[tracing::instrument(
+    name = "tool_call",
+    level = "info",
+    skip_all,
+    fields(
+        tool_name = tool.name(),
+        duration_ms = tracing::field::Empty,
+        is_error = tracing::field::Empty,
+        error_category = tracing::field::Empty,
+    )
+)]
+pub async fn execute_tool(
+    tool: &dyn Tool,
+    args: &Value,
+    browser: &mut BrowserState,
+    timeout_ms: Option<u64>,
+) -> ToolResult {
+    let started = Instant::now();
+
+    let execution = async {
+        if tool.requires_browser() && !browser.is_initialized() {
+            browser
+                .initialize()
+                .await
+                .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+        }
+        tool.execute(args, browser).await
+    };
+    let result = match timeout_ms {
+        Some(timeout_ms) => tokio::time::timeout(Duration::from_millis(timeout_ms), execution)
+            .await
+            .unwrap_or_else(|_elapsed| {
+                Err(ToolError::Timeout {
+                    tool_name: tool.name().to_string(),
+                    timeout_ms,
+                })
+            }),
+        None => execution.await,
+    };
+
+    let span = Span::current();
+    span.record(
+        "duration_ms",
+        u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    );
+    span.record("is_error", result.is_err());
+
+    match &result {
+        Ok(_) => tracing::info!("Tool call completed"),
+        Err(e) => {
+            span.record("error_category", e.category());
+            tracing::info!(error = %e, "Tool call failed");
+        }
+    }
+
+    result
+}
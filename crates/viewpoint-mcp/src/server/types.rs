@@ -89,6 +89,16 @@ pub struct ServerConfig {
 
     /// How screenshot images are included in responses
     pub image_responses: ImageResponseMode,
+
+    /// URL to open in the default context when the browser first initializes
+    pub initial_url: Option<String>,
+
+    /// Maximum time a single tool call may run, in milliseconds (no limit if `None`)
+    pub tool_timeout_ms: Option<u64>,
+
+    /// Maximum length in characters of each `browser_evaluate` content item; longer
+    /// results are split into chunks (no limit if `None`)
+    pub max_evaluate_result_length: Option<usize>,
 }
 
 impl Default for ServerConfig {
@@ -100,6 +110,9 @@ impl Default for ServerConfig {
             capabilities: Vec::new(),
             screenshot_dir: PathBuf::from(".viewpoint-mcp-screenshots"),
             image_responses: ImageResponseMode::default(),
+            initial_url: None,
+            tool_timeout_ms: None,
+            max_evaluate_result_length: None,
         }
     }
 }
@@ -0,0 +1,116 @@
+//! DOM-only ARIA attributes for [`SnapshotElement::attributes`]
+//!
+//! viewpoint-core's aria snapshot carries computed states (expanded, checked,
+//! pressed, ...) but not attributes such as `aria-haspopup` or `aria-current`.
+//! Those are read from the DOM for each element with a ref.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use super::element::SnapshotElement;
+
+/// Add `aria-haspopup`, `aria-current`, `aria-invalid`, and `aria-required`
+/// to every element with a ref that carries them.
+///
+/// A native `required` attribute is reported as `aria-required="true"`.
+/// Pages without any of these attributes (in the document, open shadow roots,
+/// or same-origin frames) are skipped after a single check. Lookups are best
+/// effort: elements that cannot be resolved keep their existing attributes.
+pub(super) async fn add_dom_attributes(page: &Page, root: &mut SnapshotElement) {
+    let present: Value = page
+        .evaluate(&js! {
+            (() => {
+                const selector = "[aria-haspopup],[aria-current],[aria-invalid],[aria-required],[required]";
+                const search = (scope) => {
+                    if (scope.querySelector(selector)) {
+                        return true;
+                    }
+                    for (const element of scope.querySelectorAll("*")) {
+                        if (element.shadowRoot && search(element.shadowRoot)) {
+                            return true;
+                        }
+                        if (element.tagName === "IFRAME" || element.tagName === "FRAME") {
+                            let inner = null;
+                            try {
+                                inner = element.contentDocument;
+                            } catch (e) {
+                                inner = null;
+                            }
+                            if (inner && search(inner)) {
+                                return true;
+                            }
+                        }
+                    }
+                    return false;
+                };
+                return search(document);
+            })()
+        })
+        .await
+        .unwrap_or(Value::Bool(false));
+    if present != Value::Bool(true) {
+        return;
+    }
+
+    let script = js! {
+        (() => {
+            const found = {};
+            for (const name of ["aria-haspopup", "aria-current", "aria-invalid", "aria-required"]) {
+                const value = element.getAttribute(name);
+                if (value !== null) {
+                    found[name] = value;
+                }
+            }
+            if (!("aria-required" in found) && element.required === true) {
+                found["aria-required"] = "true";
+            }
+            return found;
+        })()
+    };
+    let mut refs = Vec::new();
+    collect_refs(root, &mut refs);
+
+    let mut found_by_ref = HashMap::new();
+    for ref_str in refs {
+        let found: Value = page
+            .locator_from_ref(&ref_str)
+            .evaluate(&script)
+            .await
+            .unwrap_or(Value::Null);
+        if let Ok(found) = serde_json::from_value::<HashMap<String, String>>(found)
+            && !found.is_empty()
+        {
+            found_by_ref.insert(ref_str, found);
+        }
+    }
+    apply(root, &mut found_by_ref);
+}
+
+/// Collect every ref string in the tree, in document order
+fn collect_refs(element: &SnapshotElement, refs: &mut Vec<String>) {
+    if let Some(ref_str) = element.ref_string() {
+        refs.push(ref_str);
+    }
+    for child in &element.children {
+        collect_refs(child, refs);
+    }
+}
+
+/// Merge the attributes found for each ref into its element
+fn apply(
+    element: &mut SnapshotElement,
+    found_by_ref: &mut HashMap<String, HashMap<String, String>>,
+) {
+    if let Some(found) = element
+        .ref_string()
+        .and_then(|ref_str| found_by_ref.remove(&ref_str))
+    {
+        element.attributes.extend(found);
+    }
+    for child in &mut element.children {
+        apply(child, found_by_ref);
+    }
+}
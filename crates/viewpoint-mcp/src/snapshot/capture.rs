@@ -8,9 +8,10 @@ use viewpoint_core::page::locator::aria::AriaSnapshot as VpAriaSnapshot;
 use super::classification::is_interactive_container;
 use super::element::{CheckedState, SnapshotElement};
 use super::error::{SnapshotError, SnapshotResult};
-use super::format::SnapshotFormatter;
+use super::format::{OutputFormat, SnapshotFormatter};
+use super::ref_generator::RefGenerator;
 use super::reference::ElementRef;
-use super::stale::StaleRefDetector;
+use super::stale::{RefValidationResult, StaleRefDetector, StaleRefError};
 
 /// Threshold for switching to compact mode
 const COMPACT_MODE_THRESHOLD: usize = 100;
@@ -23,6 +24,19 @@ pub struct SnapshotOptions {
 
     /// Context name for multi-context mode
     pub context: Option<String>,
+
+    /// Replace native refs with deterministic ones derived from role, name,
+    /// and tree position (see [`RefGenerator`]). Deterministic refs keep
+    /// output stable across captures but cannot be used with interaction tools.
+    pub deterministic: bool,
+
+    /// CSS selector scoping the snapshot to the subtree rooted at the first
+    /// matching element
+    pub selector: Option<String>,
+
+    /// Mark the element holding keyboard focus (`document.activeElement`)
+    /// with `[focused]` in the formatted output
+    pub focus_element: bool,
 }
 
 /// The main accessibility snapshot for a page
@@ -56,16 +70,27 @@ impl AccessibilitySnapshot {
     /// For pages with empty or minimal accessibility trees (e.g., blank pages,
     /// pages still loading), returns a minimal document node.
     ///
+    /// When `options.selector` is set, only the subtree rooted at the first
+    /// matching element is captured. When `options.focus_element` is set, the
+    /// focused element is marked with `[focused]`.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the accessibility tree cannot be captured
+    /// Returns an error if the accessibility tree cannot be captured, or
+    /// `SnapshotError::SelectorNotFound` if the selector matches nothing
     pub async fn capture(page: &Page, options: SnapshotOptions) -> SnapshotResult<Self> {
         // Capture aria snapshot with refs, including iframe content
         // Handle empty accessibility trees gracefully
-        let aria_snapshot = match page.aria_snapshot_with_frames().await {
+        let captured = match &options.selector {
+            Some(selector) => Self::capture_scoped(page, selector).await,
+            None => page
+                .aria_snapshot_with_frames()
+                .await
+                .map_err(|e| SnapshotError::CaptureError(e.to_string())),
+        };
+        let aria_snapshot = match captured {
             Ok(snapshot) => snapshot,
-            Err(e) => {
-                let error_msg = e.to_string();
+            Err(SnapshotError::CaptureError(error_msg)) => {
                 // Check if this is the null/empty accessibility tree error
                 if error_msg.contains("invalid type: null")
                     || error_msg.contains("expected struct AriaSnapshot")
@@ -75,18 +100,51 @@ impl AccessibilitySnapshot {
                 }
                 return Err(SnapshotError::CaptureError(error_msg));
             }
+            Err(e) => return Err(e),
         };
 
+        let mut root = Self::convert_aria_snapshot(&aria_snapshot, options.context.as_deref());
+        super::attributes::add_dom_attributes(page, &mut root).await;
+        // Resolve focus before deterministic refs replace the native ones
+        if options.focus_element {
+            super::focus::mark_focused(page, &mut root).await;
+        }
+        if options.deterministic {
+            RefGenerator::role_and_name().apply(&mut root);
+        }
+
+        Ok(Self::from_root(root, options.context, options.all_refs))
+    }
+
+    /// Capture the aria snapshot of the first element matching `selector`
+    async fn capture_scoped(page: &Page, selector: &str) -> SnapshotResult<VpAriaSnapshot> {
+        let locator = page.locator(selector);
+        if locator.count().await? == 0 {
+            return Err(SnapshotError::SelectorNotFound(selector.to_string()));
+        }
+
+        locator
+            .first()
+            .aria_snapshot()
+            .await
+            .map_err(|e| SnapshotError::CaptureError(e.to_string()))
+    }
+
+    /// Build a snapshot around an already converted element tree
+    pub(super) fn from_root(
+        root: SnapshotElement,
+        context: Option<String>,
+        all_refs: bool,
+    ) -> Self {
         let mut ref_map = HashMap::new();
-        let root =
-            Self::convert_aria_snapshot(&aria_snapshot, &mut ref_map, options.context.as_deref());
+        Self::collect_refs(&root, &mut ref_map);
 
         // Determine if we need compact mode
         let interactive_count = root.count_refs();
-        let compact_mode = !options.all_refs && interactive_count > COMPACT_MODE_THRESHOLD;
+        let compact_mode = !all_refs && interactive_count > COMPACT_MODE_THRESHOLD;
 
         let formatter = SnapshotFormatter::new()
-            .with_all_refs(options.all_refs)
+            .with_all_refs(all_refs)
             .with_compact_mode(compact_mode);
 
         let mut snapshot = Self {
@@ -95,20 +153,30 @@ impl AccessibilitySnapshot {
             compact_mode,
             formatter,
             stale_detector: StaleRefDetector::new(),
-            context: options.context,
+            context,
         };
 
         // Update stale detector with this snapshot
         snapshot.stale_detector.update(&snapshot.root);
 
-        Ok(snapshot)
+        snapshot
+    }
+
+    /// Index every element ref in the tree by its raw ref string
+    fn collect_refs(element: &SnapshotElement, ref_map: &mut HashMap<String, ElementRef>) {
+        if let Some(element_ref) = &element.element_ref {
+            ref_map.insert(element_ref.ref_string().to_string(), element_ref.clone());
+        }
+        for child in &element.children {
+            Self::collect_refs(child, ref_map);
+        }
     }
 
     /// Create an empty snapshot for pages with no accessibility tree
     ///
     /// This returns a minimal document node, which is the expected output
     /// for blank pages or pages with no accessible content.
-    fn empty_snapshot(context: Option<String>) -> Self {
+    pub(super) fn empty_snapshot(context: Option<String>) -> Self {
         let root = SnapshotElement::new("document");
         let formatter = SnapshotFormatter::new();
 
@@ -126,11 +194,7 @@ impl AccessibilitySnapshot {
     ///
     /// Uses viewpoint-core's native `node_ref` field which provides refs in the
     /// correct format (`e{backendNodeId}`) for use with `locator_from_ref()`.
-    fn convert_aria_snapshot(
-        aria: &VpAriaSnapshot,
-        ref_map: &mut HashMap<String, ElementRef>,
-        context: Option<&str>,
-    ) -> SnapshotElement {
+    fn convert_aria_snapshot(aria: &VpAriaSnapshot, context: Option<&str>) -> SnapshotElement {
         let role = aria.role.clone().unwrap_or_else(|| "none".to_string());
 
         let mut element = SnapshotElement::new(&role);
@@ -153,6 +217,8 @@ impl AccessibilitySnapshot {
             });
         }
 
+        element.attributes = Self::aria_attributes(&element);
+
         // Use viewpoint-core's native ref if available
         // The node_ref field provides refs in the format `e{backendNodeId}`
         // which is exactly what `locator_from_ref()` expects
@@ -161,14 +227,13 @@ impl AccessibilitySnapshot {
                 Some(ctx) => ElementRef::with_context(ref_string, ctx),
                 None => ElementRef::new(ref_string),
             };
-            ref_map.insert(ref_string.clone(), element_ref.clone());
             element.element_ref = Some(element_ref);
         }
 
         // Process children recursively
         let is_container = is_interactive_container(&role);
         for child in &aria.children {
-            let child_element = Self::convert_aria_snapshot(child, ref_map, context);
+            let child_element = Self::convert_aria_snapshot(child, context);
             element.children.push(child_element);
         }
 
@@ -178,18 +243,180 @@ impl AccessibilitySnapshot {
         element
     }
 
+    /// Derive raw ARIA attributes from an element's converted accessibility states
+    fn aria_attributes(element: &SnapshotElement) -> HashMap<String, String> {
+        let mut attributes = HashMap::new();
+
+        if element.disabled {
+            attributes.insert("aria-disabled".to_string(), "true".to_string());
+        }
+        if let Some(expanded) = element.expanded {
+            attributes.insert("aria-expanded".to_string(), expanded.to_string());
+        }
+        if let Some(selected) = element.selected {
+            attributes.insert("aria-selected".to_string(), selected.to_string());
+        }
+        if let Some(checked) = element.checked {
+            let value = match checked {
+                CheckedState::True => "true",
+                CheckedState::False => "false",
+                CheckedState::Mixed => "mixed",
+            };
+            attributes.insert("aria-checked".to_string(), value.to_string());
+        }
+        if let Some(pressed) = element.pressed {
+            attributes.insert("aria-pressed".to_string(), pressed.to_string());
+        }
+        if let Some(level) = element.level {
+            attributes.insert("aria-level".to_string(), level.to_string());
+        }
+        if let Some(value) = element.value {
+            attributes.insert("aria-valuenow".to_string(), value.to_string());
+        }
+        if let Some(description) = &element.description {
+            attributes.insert("aria-description".to_string(), description.clone());
+        }
+
+        attributes
+    }
+
+    /// Merge another snapshot (e.g., from an iframe) into a copy of this one.
+    ///
+    /// `other`'s root is appended as a child of this snapshot's root, wrapped
+    /// in a `frame` element. To keep refs unique, every ref from `other` is
+    /// nested inside a new frame whose index is one greater than the highest
+    /// outermost frame index already present in this snapshot (see
+    /// [`ElementRef::with_frame_index`]).
+    #[must_use]
+    pub fn merge(&self, other: &Self) -> Self {
+        let frame_index = self
+            .ref_map
+            .values()
+            .filter_map(ElementRef::frame_index)
+            .max()
+            .map_or(0, |max| max + 1);
+
+        let mut frame_root = other.root.clone();
+        Self::reindex_frame(&mut frame_root, frame_index);
+
+        let mut wrapper = SnapshotElement::new("frame").with_child(frame_root);
+        wrapper.is_frame = true;
+
+        let mut root = self.root.clone();
+        root.children.push(wrapper);
+
+        Self::from_root(root, self.context.clone(), self.formatter.all_refs)
+    }
+
+    /// Merge several snapshots into one, in order.
+    ///
+    /// The first snapshot is the base; each following snapshot is merged
+    /// into it as a separate frame. Returns an empty document snapshot if
+    /// `snapshots` is empty.
+    #[must_use]
+    pub fn merge_all(snapshots: Vec<Self>) -> Self {
+        let mut snapshots = snapshots.into_iter();
+        let Some(first) = snapshots.next() else {
+            return Self::empty_snapshot(None);
+        };
+        snapshots.fold(first, |merged, snapshot| merged.merge(&snapshot))
+    }
+
+    /// Nest every ref in an element tree inside frame `frame_index`
+    fn reindex_frame(element: &mut SnapshotElement, frame_index: usize) {
+        if let Some(element_ref) = &element.element_ref {
+            element.element_ref = Some(element_ref.with_frame_index(frame_index));
+        }
+        for child in &mut element.children {
+            Self::reindex_frame(child, frame_index);
+        }
+    }
+
     /// Format the snapshot as text for LLM consumption
     #[must_use]
     pub fn format(&self) -> String {
         self.formatter.format(&self.root)
     }
 
+    /// Format the snapshot with a custom maximum length for accessible names
+    ///
+    /// Names longer than `max_text_length` are truncated with an ellipsis.
+    #[must_use]
+    pub fn format_with_max_text_length(&self, max_text_length: usize) -> String {
+        self.formatter
+            .clone()
+            .with_max_text_length(max_text_length)
+            .format(&self.root)
+    }
+
+    /// Format the snapshot in `output_format`, optionally overriding the
+    /// maximum length of accessible names
+    #[must_use]
+    pub fn format_as(&self, output_format: OutputFormat, max_text_length: Option<usize>) -> String {
+        self.formatter_for(output_format, max_text_length)
+            .format(&self.root)
+    }
+
+    /// Format the snapshot without elements whose role is in `skip_roles`
+    /// (see [`SnapshotElement::without_roles`]) and, given a `needle`, only
+    /// the elements whose accessible name contains it, ignoring case (see
+    /// [`SnapshotElement::filter_by_name`]).
+    ///
+    /// Name matches are listed under the root element. Compact mode is still
+    /// decided by the full snapshot, not the filtered one.
+    #[must_use]
+    pub fn format_filtered(
+        &self,
+        needle: Option<&str>,
+        skip_roles: &[String],
+        max_text_length: Option<usize>,
+        output_format: OutputFormat,
+    ) -> String {
+        let mut elements = self.root.without_roles(skip_roles);
+        if let Some(needle) = needle {
+            elements = elements
+                .iter()
+                .flat_map(|element| element.filter_by_name(needle))
+                .collect();
+        }
+
+        // Without a name filter the root survives unless its own role is skipped
+        let keeps_root =
+            needle.is_none() && elements.len() == 1 && elements[0].role == self.root.role;
+        let root = if keeps_root {
+            elements.swap_remove(0)
+        } else {
+            let mut root = SnapshotElement::new(self.root.role.clone());
+            root.children = elements;
+            root
+        };
+
+        self.formatter_for(output_format, max_text_length)
+            .format(&root)
+    }
+
+    /// This snapshot's formatter with the given output format and name length
+    fn formatter_for(
+        &self,
+        output_format: OutputFormat,
+        max_text_length: Option<usize>,
+    ) -> SnapshotFormatter {
+        let formatter = self.formatter.clone().with_format(output_format);
+        match max_text_length {
+            Some(n) => formatter.with_max_text_length(n),
+            None => formatter,
+        }
+    }
+
     /// Look up an element by its reference
     pub fn lookup(&self, ref_str: &str) -> SnapshotResult<&ElementRef> {
         let element_ref = ElementRef::parse(ref_str).map_err(SnapshotError::InvalidRefFormat)?;
 
         // Validate against stale detector
-        if let Err(stale_err) = self.stale_detector.validate_ref(&element_ref) {
+        if let Err(stale_err) = self
+            .stale_detector
+            .validate_ref(&element_ref, self.context.as_deref())
+        {
             return Err(SnapshotError::StaleRef(stale_err.to_string()));
         }
 
@@ -199,6 +426,37 @@ impl AccessibilitySnapshot {
             .ok_or_else(|| SnapshotError::RefNotFound(ref_str.to_string()))
     }
 
+    /// Validate several references at once.
+    ///
+    /// Unlike [`AccessibilitySnapshot::lookup`], which stops at the first
+    /// problem, this reports a result for every ref, in input order.
+    #[must_use]
+    pub fn validate_refs(&self, refs: &[&str]) -> Vec<RefValidationResult> {
+        refs.iter().map(|ref_str| self.validate(ref_str)).collect()
+    }
+
+    /// Validate a single reference for [`AccessibilitySnapshot::validate_refs`]
+    fn validate(&self, ref_str: &str) -> RefValidationResult {
+        let element_ref =
+            ElementRef::parse(ref_str).map_err(|reason| StaleRefError::InvalidFormat {
+                ref_string: ref_str.to_string(),
+                reason,
+            })?;
+
+        self.stale_detector
+            .validate_ref(&element_ref, self.context.as_deref())?;
+
+        self.ref_map
+            .get(element_ref.ref_string())
+            .cloned()
+            .ok_or_else(|| StaleRefError::ElementRemoved {
+                ref_string: element_ref.to_ref_string(),
+                original_description: None,
+                path: None,
+                similar_elements: Vec::new(),
+            })
+    }
+
     /// Get the root element
     #[must_use]
     pub fn root(&self) -> &SnapshotElement {
@@ -229,36 +487,3 @@ impl AccessibilitySnapshot {
         self.context.as_deref()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_empty_snapshot_has_document_root() {
-        let snapshot = AccessibilitySnapshot::empty_snapshot(None);
-
-        assert_eq!(snapshot.root().role, "document");
-        assert!(snapshot.root().children.is_empty());
-        assert_eq!(snapshot.ref_count(), 0);
-        assert_eq!(snapshot.element_count(), 1);
-        assert!(!snapshot.is_compact());
-    }
-
-    #[test]
-    fn test_empty_snapshot_with_context() {
-        let snapshot = AccessibilitySnapshot::empty_snapshot(Some("test-context".to_string()));
-
-        assert_eq!(snapshot.context(), Some("test-context"));
-        assert_eq!(snapshot.root().role, "document");
-    }
-
-    #[test]
-    fn test_empty_snapshot_format() {
-        let snapshot = AccessibilitySnapshot::empty_snapshot(None);
-        let output = snapshot.format();
-
-        // Should contain "document" since that's the root role
-        assert!(output.contains("document"));
-    }
-}
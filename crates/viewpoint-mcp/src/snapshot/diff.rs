@@ -0,0 +1,187 @@
+//! Comparison of two accessibility snapshots
+//!
+//! Provides [`SnapshotDiff`], which reports the elements added, removed, and
+//! changed between a snapshot taken before an action and one taken after it.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::element::{CheckedState, SnapshotElement};
+use super::format::SIGNIFICANT_ATTRIBUTES;
+
+/// A change to an element present in both snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElementChange {
+    /// Location of the element in the tree
+    pub path: String,
+
+    /// Role, name, and ref of the element after the change
+    pub element: String,
+
+    /// Changed properties, e.g. `aria-expanded: "false" -> "true"`
+    pub changes: Vec<String>,
+}
+
+/// Differences between two accessibility snapshots.
+///
+/// Elements are matched by their tree path (see [`SnapshotElement::paths`])
+/// rather than by ref, so the comparison does not depend on refs staying
+/// stable between captures. An element whose path changes, for example
+/// because a sibling with the same role was inserted before it, is reported
+/// as removed and added.
+///
+/// # Examples
+///
+/// ```
+/// use viewpoint_mcp::snapshot::{SnapshotDiff, SnapshotElement};
+///
+/// let menu = |expanded: bool| {
+///     let mut button = SnapshotElement::new("button").with_name("Menu");
+///     button.expanded = Some(expanded);
+///     SnapshotElement::new("document").with_child(button)
+/// };
+///
+/// let diff = SnapshotDiff::compute(&menu(false), &menu(true));
+/// assert_eq!(diff.changed.len(), 1);
+/// assert_eq!(diff.changed[0].changes, vec!["expanded: false -> true"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    /// Elements only in the later snapshot, in document order
+    pub added: Vec<String>,
+
+    /// Elements only in the earlier snapshot, in document order
+    pub removed: Vec<String>,
+
+    /// Elements in both snapshots whose properties differ
+    pub changed: Vec<ElementChange>,
+}
+
+impl SnapshotDiff {
+    /// Compare the snapshot tree `before` with `after`
+    #[must_use]
+    pub fn compute(before: &SnapshotElement, after: &SnapshotElement) -> Self {
+        let before = before.paths();
+        let after = after.paths();
+        let before_index: HashMap<&str, &SnapshotElement> =
+            before.iter().map(|(path, e)| (path.as_str(), *e)).collect();
+        let after_index: HashMap<&str, &SnapshotElement> =
+            after.iter().map(|(path, e)| (path.as_str(), *e)).collect();
+
+        let mut diff = Self::default();
+        for (path, element) in &after {
+            let Some(previous) = before_index.get(path.as_str()) else {
+                diff.added.push(describe(element));
+                continue;
+            };
+            let changes = property_changes(previous, element);
+            if !changes.is_empty() {
+                diff.changed.push(ElementChange {
+                    path: path.clone(),
+                    element: describe(element),
+                    changes,
+                });
+            }
+        }
+        diff.removed = before
+            .iter()
+            .filter(|(path, _)| !after_index.contains_key(path.as_str()))
+            .map(|(_, element)| describe(element))
+            .collect();
+
+        diff
+    }
+
+    /// Whether the snapshots were identical
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Format the diff as text, one line per added, removed, or changed element
+    #[must_use]
+    pub fn format(&self) -> String {
+        if self.is_empty() {
+            return "No accessibility tree changes".to_string();
+        }
+
+        let mut output = format!(
+            "Accessibility tree changes ({} added, {} removed, {} changed):\n",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        );
+        for element in &self.added {
+            let _ = writeln!(output, "+ {element}");
+        }
+        for element in &self.removed {
+            let _ = writeln!(output, "- {element}");
+        }
+        for change in &self.changed {
+            let _ = writeln!(
+                output,
+                "~ {} ({}): {}",
+                change.element,
+                change.path,
+                change.changes.join(", ")
+            );
+        }
+        output.trim_end().to_string()
+    }
+}
+
+/// Short description of an element: role, quoted name, and ref
+fn describe(element: &SnapshotElement) -> String {
+    let mut description = element.role.clone();
+    if let Some(name) = &element.name {
+        let _ = write!(description, " \"{name}\"");
+    }
+    if let Some(ref_str) = element.ref_string() {
+        let _ = write!(description, " [ref={ref_str}]");
+    }
+    description
+}
+
+/// Comparable properties of an element, `None` where the property is unset
+fn properties(element: &SnapshotElement) -> Vec<(&'static str, Option<String>)> {
+    let quoted = |s: &str| format!("\"{s}\"");
+    let mut properties = vec![
+        ("name", element.name.as_deref().map(quoted)),
+        ("description", element.description.as_deref().map(quoted)),
+        ("disabled", Some(element.disabled.to_string())),
+        ("focused", Some(element.focused.to_string())),
+        ("expanded", element.expanded.map(|v| v.to_string())),
+        ("selected", element.selected.map(|v| v.to_string())),
+        (
+            "checked",
+            element.checked.map(|state| {
+                match state {
+                    CheckedState::True => "true",
+                    CheckedState::False => "false",
+                    CheckedState::Mixed => "mixed",
+                }
+                .to_string()
+            }),
+        ),
+        ("pressed", element.pressed.map(|v| v.to_string())),
+        ("level", element.level.map(|v| v.to_string())),
+        ("value", element.value.map(|v| v.to_string())),
+    ];
+    for name in SIGNIFICANT_ATTRIBUTES {
+        properties.push((*name, element.attribute(name).map(quoted)));
+    }
+    properties
+}
+
+/// Describe each property that differs between two versions of an element
+fn property_changes(before: &SnapshotElement, after: &SnapshotElement) -> Vec<String> {
+    properties(before)
+        .into_iter()
+        .zip(properties(after))
+        .filter(|((_, old), (_, new))| old != new)
+        .map(|((name, old), (_, new))| {
+            let show = |value: Option<String>| value.unwrap_or_else(|| "none".to_string());
+            format!("{name}: {} -> {}", show(old), show(new))
+        })
+        .collect()
+}
@@ -2,6 +2,8 @@
 //!
 //! Provides the [`SnapshotElement`] type representing nodes in the accessibility tree.
 
+use std::collections::HashMap;
+
 use super::reference::ElementRef;
 
 /// An element in the accessibility snapshot with reference information.
@@ -71,6 +73,12 @@ pub struct SnapshotElement {
     /// Whether this element is an interactive container
     pub is_interactive_container: bool,
 
+    /// Whether this element holds keyboard focus (`document.activeElement`)
+    pub focused: bool,
+
+    /// Raw ARIA attributes (e.g., `aria-expanded` -> `"true"`)
+    pub attributes: HashMap<String, String>,
+
     /// Child elements
     pub children: Vec<Self>,
 }
@@ -104,6 +112,8 @@ impl SnapshotElement {
             value: None,
             is_frame: false,
             is_interactive_container: false,
+            focused: false,
+            attributes: HashMap::new(),
             children: Vec::new(),
         }
     }
@@ -122,6 +132,19 @@ impl SnapshotElement {
         self
     }
 
+    /// Set an ARIA attribute
+    #[must_use]
+    pub fn with_attribute(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Get an ARIA attribute value by name (e.g., `aria-expanded`)
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(String::as_str)
+    }
+
     /// Add a child element
     #[must_use]
     pub fn with_child(mut self, child: Self) -> Self {
@@ -175,4 +198,126 @@ impl SnapshotElement {
 
         (self_refs + child_refs, 1 + child_elements)
     }
+
+    /// Elements in this subtree whose accessible name contains `needle`,
+    /// ignoring case.
+    ///
+    /// Non-matching elements are dropped and their matching descendants take
+    /// their place, so a match keeps only the matches below it as children.
+    #[must_use]
+    pub fn filter_by_name(&self, needle: &str) -> Vec<Self> {
+        self.filter_by_lowercase_name(&needle.to_lowercase())
+    }
+
+    /// [`SnapshotElement::filter_by_name`] with an already lowercased needle
+    fn filter_by_lowercase_name(&self, needle: &str) -> Vec<Self> {
+        let children: Vec<Self> = self
+            .children
+            .iter()
+            .flat_map(|child| child.filter_by_lowercase_name(needle))
+            .collect();
+
+        let matches = self
+            .name
+            .as_ref()
+            .is_some_and(|name| name.to_lowercase().contains(needle));
+        if !matches {
+            return children;
+        }
+
+        vec![self.with_children(children)]
+    }
+
+    /// Remove elements whose role is in `roles`, ignoring case.
+    ///
+    /// A removed element's children take its place, so descendants with
+    /// other roles stay in the tree while descendants sharing a skipped
+    /// role are removed too.
+    #[must_use]
+    pub fn without_roles(&self, roles: &[String]) -> Vec<Self> {
+        let children: Vec<Self> = self
+            .children
+            .iter()
+            .flat_map(|child| child.without_roles(roles))
+            .collect();
+
+        if roles
+            .iter()
+            .any(|role| role.eq_ignore_ascii_case(&self.role))
+        {
+            return children;
+        }
+
+        vec![self.with_children(children)]
+    }
+
+    /// Copy this element with different children, without cloning the old ones
+    fn with_children(&self, children: Vec<Self>) -> Self {
+        Self {
+            role: self.role.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            element_ref: self.element_ref.clone(),
+            disabled: self.disabled,
+            expanded: self.expanded,
+            selected: self.selected,
+            checked: self.checked,
+            pressed: self.pressed,
+            level: self.level,
+            value: self.value,
+            is_frame: self.is_frame,
+            is_interactive_container: self.is_interactive_container,
+            focused: self.focused,
+            attributes: self.attributes.clone(),
+            children,
+        }
+    }
+
+    /// Every element in the tree with its location, in document order,
+    /// treating this element as the root.
+    ///
+    /// A location is the chain of roles from the root, e.g.
+    /// `document > main > section[1] > button[2]`; siblings sharing a role are
+    /// numbered from 1.
+    #[must_use]
+    pub fn paths(&self) -> Vec<(String, &Self)> {
+        fn visit<'a>(
+            element: &'a SnapshotElement,
+            path: String,
+            out: &mut Vec<(String, &'a SnapshotElement)>,
+        ) {
+            let segments = element.child_segments();
+            out.push((path.clone(), element));
+            for (child, segment) in element.children.iter().zip(segments) {
+                visit(child, format!("{path} > {segment}"), out);
+            }
+        }
+
+        let mut elements = Vec::new();
+        visit(self, self.role.clone(), &mut elements);
+        elements
+    }
+
+    /// Path segment of each child: its role, numbered when siblings share it
+    fn child_segments(&self) -> Vec<String> {
+        let mut totals: HashMap<&str, usize> = HashMap::new();
+        for child in &self.children {
+            *totals.entry(child.role.as_str()).or_default() += 1;
+        }
+
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        self.children
+            .iter()
+            .map(|child| {
+                let role = child.role.as_str();
+                if totals[role] > 1 {
+                    let index = seen.entry(role).or_default();
+                    *index += 1;
+                    format!("{role}[{index}]")
+                } else {
+                    role.to_string()
+                }
+            })
+            .collect()
+    }
 }
@@ -24,6 +24,10 @@ pub enum SnapshotError {
     #[error("Stale reference: {0}")]
     StaleRef(String),
 
+    /// No element matches the selector used to scope the snapshot
+    #[error("No element matches selector '{0}'")]
+    SelectorNotFound(String),
+
     /// Page not available
     #[error("Page not available for snapshot")]
     PageNotAvailable,
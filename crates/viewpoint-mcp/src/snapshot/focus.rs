@@ -0,0 +1,113 @@
+//! Focused element detection for [`SnapshotOptions::focus_element`]
+//!
+//! [`SnapshotOptions::focus_element`]: super::SnapshotOptions::focus_element
+
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use super::element::SnapshotElement;
+
+/// Mark the element holding keyboard focus with [`SnapshotElement::focused`].
+///
+/// The focused element is looked up once, following `activeElement` into open
+/// shadow roots and same-origin frames, and the ref resolving to the same
+/// backend node is marked. Focus inside a cross-origin frame leaves the tree
+/// unmarked. Detection is best effort: lookup failures leave it unmarked too.
+pub(super) async fn mark_focused(page: &Page, root: &mut SnapshotElement) {
+    let Some(focused) = focused_backend_node_id(page).await else {
+        return;
+    };
+
+    let mut refs = Vec::new();
+    collect_refs(root, &mut refs);
+
+    for ref_str in refs {
+        if ref_backend_node_id(page, &ref_str).await == Some(focused) {
+            mark(root, &ref_str);
+            return;
+        }
+    }
+}
+
+/// Backend node id of the innermost focused element, if any
+async fn focused_backend_node_id(page: &Page) -> Option<i64> {
+    let found = send(
+        page,
+        "Runtime.evaluate",
+        json!({
+            "expression": js! {
+                (() => {
+                    let element = document.activeElement;
+                    while (element) {
+                        if (element.shadowRoot && element.shadowRoot.activeElement) {
+                            element = element.shadowRoot.activeElement;
+                            continue;
+                        }
+                        if (element.tagName !== "IFRAME" && element.tagName !== "FRAME") {
+                            break;
+                        }
+                        let inner = null;
+                        try {
+                            inner = element.contentDocument;
+                        } catch (e) {
+                            inner = null;
+                        }
+                        // Focus in a cross-origin frame cannot be followed
+                        if (!inner || !inner.activeElement) {
+                            return null;
+                        }
+                        element = inner.activeElement;
+                    }
+                    const body = element && element.ownerDocument.body;
+                    return element === body ? null : element;
+                })()
+            }
+        }),
+    )
+    .await?;
+    let object_id = found["result"]["objectId"].as_str()?;
+    describe_backend_node_id(page, object_id).await
+}
+
+/// Backend node id of the element `ref_str` resolves to
+async fn ref_backend_node_id(page: &Page, ref_str: &str) -> Option<i64> {
+    let handle = page.element_from_ref(ref_str).await.ok()?;
+    describe_backend_node_id(page, handle.object_id()).await
+}
+
+/// Backend node id of the remote object `object_id`
+async fn describe_backend_node_id(page: &Page, object_id: &str) -> Option<i64> {
+    let described = send(page, "DOM.describeNode", json!({ "objectId": object_id })).await?;
+    described["node"]["backendNodeId"].as_i64()
+}
+
+/// Send a CDP command to the page's session, discarding errors
+async fn send(page: &Page, method: &str, params: Value) -> Option<Value> {
+    page.connection()
+        .send_command::<_, Value>(method, Some(params), Some(page.session_id()))
+        .await
+        .ok()
+}
+
+/// Collect every ref string in the tree, in document order
+fn collect_refs(element: &SnapshotElement, refs: &mut Vec<String>) {
+    if let Some(ref_str) = element.ref_string() {
+        refs.push(ref_str);
+    }
+    for child in &element.children {
+        collect_refs(child, refs);
+    }
+}
+
+/// Mark the element with the given ref as focused
+fn mark(element: &mut SnapshotElement, ref_str: &str) -> bool {
+    if element.ref_string().as_deref() == Some(ref_str) {
+        element.focused = true;
+        return true;
+    }
+    element
+        .children
+        .iter_mut()
+        .any(|child| mark(child, ref_str))
+}
@@ -1,14 +1,24 @@
 //! Snapshot formatting for LLM consumption
 //!
-//! Converts accessibility snapshots into indented text format optimized for LLM understanding.
+//! Converts accessibility snapshots into indented text format optimized for LLM understanding,
+//! or into a Markdown list for clients that render their tool output.
 
 use std::fmt::Write;
 use std::sync::LazyLock;
 
 use super::element::SnapshotElement;
 
-/// Maximum text content length before truncation
-const MAX_TEXT_LENGTH: usize = 100;
+/// Default maximum text content length before truncation
+const DEFAULT_MAX_TEXT_LENGTH: usize = 100;
+
+/// ARIA attributes rendered inline because they describe widget state an LLM acts on
+pub(super) const SIGNIFICANT_ATTRIBUTES: &[&str] = &[
+    "aria-expanded",
+    "aria-haspopup",
+    "aria-current",
+    "aria-invalid",
+    "aria-required",
+];
 
 /// Default indentation string
 const INDENT: &str = "  ";
@@ -26,6 +36,30 @@ static INDENT_CACHE: LazyLock<Vec<String>> = LazyLock::new(|| {
         .collect()
 });
 
+/// Output syntax produced by [`SnapshotFormatter`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Indented plain-text list (default)
+    #[default]
+    Text,
+    /// Markdown list with bold roles and refs in inline code spans
+    Markdown,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "markdown" => Ok(Self::Markdown),
+            other => Err(format!(
+                "Unknown output format: '{other}'. Valid values: text, markdown"
+            )),
+        }
+    }
+}
+
 /// Formatter for accessibility snapshots.
 ///
 /// Converts snapshot element trees into indented text representation
@@ -48,7 +82,7 @@ static INDENT_CACHE: LazyLock<Vec<String>> = LazyLock::new(|| {
 /// assert!(output.contains("button"));
 /// assert!(output.contains("Click me"));
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug, Clone)]
 pub struct SnapshotFormatter {
     /// Whether to show all refs (including Tier 2)
     pub all_refs: bool,
@@ -58,6 +92,18 @@ pub struct SnapshotFormatter {
 
     /// Whether we're in compact mode (>100 interactive elements)
     pub compact_mode: bool,
+
+    /// Maximum length of accessible names before they are truncated
+    pub max_text_length: usize,
+
+    /// Output syntax
+    pub output_format: OutputFormat,
+}
+
+impl Default for SnapshotFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SnapshotFormatter {
@@ -68,6 +114,8 @@ impl SnapshotFormatter {
             all_refs: false,
             max_depth: -1,
             compact_mode: false,
+            max_text_length: DEFAULT_MAX_TEXT_LENGTH,
+            output_format: OutputFormat::Text,
         }
     }
 
@@ -85,6 +133,24 @@ impl SnapshotFormatter {
         self
     }
 
+    /// Set the maximum length of accessible names before truncation
+    #[must_use]
+    pub fn with_max_text_length(mut self, max_text_length: usize) -> Self {
+        self.max_text_length = max_text_length;
+        self
+    }
+
+    /// Set the output syntax.
+    ///
+    /// In [`OutputFormat::Markdown`], roles are rendered in bold and elements
+    /// with refs are rendered as inline code spans, so the ref can be copied
+    /// verbatim from rendered output.
+    #[must_use]
+    pub const fn with_format(mut self, format: OutputFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
     /// Format a snapshot element tree as indented text
     #[must_use]
     pub fn format(&self, root: &SnapshotElement) -> String {
@@ -130,11 +196,35 @@ impl SnapshotFormatter {
         // Format the element line
         output.push_str(&indent);
         output.push_str("- ");
-        output.push_str(&element.role);
+        match (self.output_format, element.ref_string()) {
+            (OutputFormat::Text, _) => {
+                output.push_str(&element.role);
+                self.format_details(output, element);
+            }
+            (OutputFormat::Markdown, None) => {
+                let _ = write!(output, "**{}**", element.role);
+                self.format_details(output, element);
+            }
+            (OutputFormat::Markdown, Some(_)) => {
+                let mut line = element.role.clone();
+                self.format_details(&mut line, element);
+                push_code_span(output, &line);
+            }
+        }
 
+        output.push('\n');
+
+        // Format children
+        for child in &element.children {
+            self.format_element(output, child, depth + 1);
+        }
+    }
+
+    /// Format everything after the role: name, markers, states, attributes, and ref
+    fn format_details(&self, output: &mut String, element: &SnapshotElement) {
         // Add accessible name if present
         if let Some(name) = &element.name {
-            let truncated = truncate_text(name, MAX_TEXT_LENGTH);
+            let truncated = truncate_text(name, self.max_text_length);
             let _ = write!(output, " \"{truncated}\"");
         }
 
@@ -143,20 +233,21 @@ impl SnapshotFormatter {
             output.push_str(" [frame-boundary]");
         }
 
+        // Add focus marker
+        if element.focused {
+            output.push_str(" [focused]");
+        }
+
         // Add state indicators
         Self::format_state(output, element);
 
+        // Add significant ARIA attributes
+        Self::format_attributes(output, element);
+
         // Add ref if present
         if let Some(ref_str) = element.ref_string() {
             let _ = write!(output, " [ref={ref_str}]");
         }
-
-        output.push('\n');
-
-        // Format children
-        for child in &element.children {
-            self.format_element(output, child, depth + 1);
-        }
     }
 
     /// Format element state indicators
@@ -201,6 +292,30 @@ impl SnapshotFormatter {
             let _ = write!(output, " (value: {value})");
         }
     }
+
+    /// Format significant ARIA attributes as `name="value"` pairs
+    fn format_attributes(output: &mut String, element: &SnapshotElement) {
+        for name in SIGNIFICANT_ATTRIBUTES {
+            if let Some(value) = element.attribute(name) {
+                let _ = write!(output, " {name}=\"{value}\"");
+            }
+        }
+    }
+}
+
+/// Append `text` as a Markdown inline code span.
+///
+/// The fence is one backtick longer than the longest run of backticks in
+/// `text`, so names containing backticks cannot end the span early.
+fn push_code_span(output: &mut String, text: &str) {
+    let longest_run = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest_run + 1);
+    let pad = if longest_run > 0 { " " } else { "" };
+    let _ = write!(output, "{fence}{pad}{text}{pad}{fence}");
 }
 
 /// Truncate text to a maximum length with ellipsis
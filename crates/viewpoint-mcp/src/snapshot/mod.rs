@@ -3,21 +3,28 @@
 //! This module provides accessibility tree capture and element reference management,
 //! enabling LLMs to interact with web pages using structured data rather than vision.
 
+mod attributes;
 mod capture;
 mod classification;
+mod diff;
 mod element;
 mod error;
+mod focus;
 mod format;
+mod ref_generator;
 mod reference;
 mod stale;
+mod stats;
 
 #[cfg(test)]
 mod tests;
 
 pub use capture::{AccessibilitySnapshot, SnapshotOptions};
 pub use classification::{ElementTier, classify_role};
+pub use diff::{ElementChange, SnapshotDiff};
 pub use element::SnapshotElement;
 pub use error::{SnapshotError, SnapshotResult};
-pub use format::SnapshotFormatter;
+pub use format::{OutputFormat, SnapshotFormatter};
+pub use ref_generator::RefGenerator;
 pub use reference::ElementRef;
-pub use stale::{StaleRefDetector, StaleRefError};
+pub use stale::{RefValidationResult, StaleRefDetector, StaleRefError};
@@ -0,0 +1,136 @@
+//! Deterministic element reference generation
+//!
+//! viewpoint-core assigns element counters in capture order, so the same page
+//! can produce different refs across runs. [`RefGenerator`] rewrites refs from
+//! element properties and tree position instead, which keeps formatted output
+//! stable across re-captures as long as the DOM structure does not change.
+//!
+//! Deterministic refs are intended for comparing snapshot output (e.g., in
+//! tests). They are not known to viewpoint-core, so interaction tools cannot
+//! resolve them.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Write;
+
+use super::element::SnapshotElement;
+
+/// Upper bound (exclusive) for generated element ids, keeping refs short
+const ELEMENT_ID_SPACE: u64 = 1_000_000_000;
+
+/// FNV-1a 64-bit offset basis
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Seed function deriving an element's identity from its properties
+type SeedFn = Box<dyn Fn(&SnapshotElement) -> String + Send + Sync>;
+
+/// Generates deterministic element refs for a snapshot tree.
+///
+/// Each ref keeps the context, page, and frame segments of the element's
+/// native ref and replaces the element counter with a hash of the element's
+/// seed and its child-index path from the root.
+///
+/// # Examples
+///
+/// ```
+/// use viewpoint_mcp::snapshot::{ElementRef, RefGenerator, SnapshotElement};
+///
+/// let build = |counter: &str| {
+///     let mut button = SnapshotElement::new("button").with_name("Submit");
+///     button.element_ref = Some(ElementRef::new(format!("c0p0f0e{counter}")));
+///     SnapshotElement::new("document").with_child(button)
+/// };
+///
+/// let (mut first, mut second) = (build("3"), build("17"));
+/// RefGenerator::role_and_name().apply(&mut first);
+/// RefGenerator::role_and_name().apply(&mut second);
+///
+/// assert_eq!(first.children[0].ref_string(), second.children[0].ref_string());
+/// ```
+pub struct RefGenerator {
+    /// Derives the identity of an element
+    seed_fn: SeedFn,
+
+    /// Refs already handed out, used to resolve hash collisions
+    used: HashSet<String>,
+}
+
+impl RefGenerator {
+    /// Create a generator deriving refs from `seed_fn` and the element's
+    /// position in the tree
+    #[must_use]
+    pub fn deterministic(
+        seed_fn: impl Fn(&SnapshotElement) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            seed_fn: Box::new(seed_fn),
+            used: HashSet::new(),
+        }
+    }
+
+    /// Create a deterministic generator seeded by role and accessible name
+    #[must_use]
+    pub fn role_and_name() -> Self {
+        Self::deterministic(|element| {
+            format!("{}|{}", element.role, element.name.as_deref().unwrap_or(""))
+        })
+    }
+
+    /// Rewrite every ref in the tree rooted at `root` deterministically
+    pub fn apply(&mut self, root: &mut SnapshotElement) {
+        let mut path = Vec::new();
+        self.apply_at(root, &mut path);
+    }
+
+    /// Rewrite the ref of `element` at `path`, then recurse into its children
+    fn apply_at(&mut self, element: &mut SnapshotElement, path: &mut Vec<usize>) {
+        if let Some(element_ref) = &element.element_ref {
+            let mut key = (self.seed_fn)(element);
+            for index in path.iter() {
+                let _ = write!(key, "/{index}");
+            }
+
+            // Rehash with a salt until the ref is unique within this tree
+            let mut salt = 0u32;
+            let generated = loop {
+                let id = fnv1a(key.as_bytes(), salt) % ELEMENT_ID_SPACE;
+                let candidate = element_ref.with_element_id(id);
+                if self.used.insert(candidate.ref_string().to_string()) {
+                    break candidate;
+                }
+                salt += 1;
+            };
+            element.element_ref = Some(generated);
+        }
+
+        for (index, child) in element.children.iter_mut().enumerate() {
+            path.push(index);
+            self.apply_at(child, path);
+            path.pop();
+        }
+    }
+}
+
+impl fmt::Debug for RefGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefGenerator")
+            .field("used", &self.used.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Stable FNV-1a hash of `bytes` followed by `salt`
+///
+/// Unlike `std`'s default hasher, the output is guaranteed not to change
+/// between Rust releases.
+fn fnv1a(bytes: &[u8], salt: u32) -> u64 {
+    bytes
+        .iter()
+        .chain(&salt.to_le_bytes())
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+        })
+}
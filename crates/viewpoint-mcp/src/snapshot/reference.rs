@@ -119,6 +119,75 @@ impl ElementRef {
         ))
     }
 
+    /// Byte range of the outermost frame index digits within the ref string
+    fn frame_digits(&self) -> Option<(usize, usize)> {
+        let start = self.ref_string.find('f')? + 1;
+        let end = start + self.ref_string[start..].find(['f', 'e'])?;
+        (end > start).then_some((start, end))
+    }
+
+    /// Get the outermost frame index (the first `f{frame}` segment), if the
+    /// ref has one
+    ///
+    /// ```
+    /// use viewpoint_mcp::snapshot::ElementRef;
+    ///
+    /// assert_eq!(ElementRef::new("c0p0f2e7").frame_index(), Some(2));
+    /// ```
+    #[must_use]
+    pub fn frame_index(&self) -> Option<usize> {
+        let (start, end) = self.frame_digits()?;
+        self.ref_string[start..end].parse().ok()
+    }
+
+    /// Return a copy of this reference nested inside frame `frame`
+    ///
+    /// A new outermost `f{frame}` segment is prepended, so refs from
+    /// different frames of a merged snapshot stay distinct. Refs without a
+    /// frame segment are returned unchanged.
+    ///
+    /// ```
+    /// use viewpoint_mcp::snapshot::ElementRef;
+    ///
+    /// let element_ref = ElementRef::new("c0p0f1e7").with_frame_index(3);
+    /// assert_eq!(element_ref.ref_string(), "c0p0f3f1e7");
+    /// assert_eq!(element_ref.frame_index(), Some(3));
+    /// ```
+    #[must_use]
+    pub fn with_frame_index(&self, frame: usize) -> Self {
+        let mut element_ref = self.clone();
+        if let Some((start, _)) = self.frame_digits() {
+            element_ref
+                .ref_string
+                .insert_str(start - 1, &format!("f{frame}"));
+        }
+        element_ref
+    }
+
+    /// Return a copy of this reference with its element counter (the
+    /// `e{counter}` segment) replaced
+    ///
+    /// Refs without an element segment are returned unchanged.
+    ///
+    /// ```
+    /// use viewpoint_mcp::snapshot::ElementRef;
+    ///
+    /// let element_ref = ElementRef::new("c0p0f1e7").with_element_id(42);
+    /// assert_eq!(element_ref.ref_string(), "c0p0f1e42");
+    /// ```
+    #[must_use]
+    pub fn with_element_id(&self, id: u64) -> Self {
+        let mut element_ref = self.clone();
+        if self.frame_digits().is_some()
+            && let Some(e) = self.ref_string.rfind('e')
+        {
+            element_ref
+                .ref_string
+                .replace_range(e + 1.., &id.to_string());
+        }
+        element_ref
+    }
+
     /// Get the MCP context name if set (for display purposes)
     #[must_use]
     pub fn context(&self) -> Option<&str> {
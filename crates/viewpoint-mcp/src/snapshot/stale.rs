@@ -10,13 +10,17 @@ use super::reference::ElementRef;
 
 /// Error type for stale reference detection
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum StaleRefError {
     /// Element no longer exists
     ElementRemoved {
         /// The ref that was not found
         ref_string: String,
-        /// The original element description
-        original_description: String,
+        /// The original element description, if the element was seen in an
+        /// earlier snapshot
+        original_description: Option<String>,
+        /// Where the element was in the previous snapshot's tree, if known
+        path: Option<String>,
         /// Similar elements that might be what the user meant
         similar_elements: Vec<SimilarElement>,
     },
@@ -29,6 +33,8 @@ pub enum StaleRefError {
         was: String,
         /// What the element is now
         now: String,
+        /// Where the element is in the current snapshot's tree
+        path: String,
     },
 
     /// Element has minor changes (action can proceed with warning)
@@ -37,21 +43,41 @@ pub enum StaleRefError {
         ref_string: String,
         /// Description of the change
         change_description: String,
+        /// Where the element is in the current snapshot's tree
+        path: String,
+    },
+
+    /// The ref string could not be parsed, so it never referred to an element
+    InvalidFormat {
+        /// The ref as submitted
+        ref_string: String,
+        /// Why parsing failed (already names the ref and the expected format)
+        reason: String,
     },
 }
 
+/// Outcome of validating one ref: the resolved ref, or why it cannot be used
+pub type RefValidationResult = Result<ElementRef, StaleRefError>;
+
 impl std::fmt::Display for StaleRefError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::ElementRemoved {
                 ref_string,
                 original_description,
+                path,
                 similar_elements,
             } => {
-                writeln!(
-                    f,
-                    "Element '{original_description}' (ref: {ref_string}) no longer exists."
-                )?;
+                match original_description {
+                    Some(description) => writeln!(
+                        f,
+                        "Element '{description}' (ref: {ref_string}) no longer exists."
+                    )?,
+                    None => writeln!(f, "Element with ref {ref_string} no longer exists.")?,
+                }
+                if let Some(path) = path {
+                    writeln!(f, "Last seen at: {path}")?;
+                }
                 if !similar_elements.is_empty() {
                     writeln!(f, "Similar elements on page:")?;
                     for elem in similar_elements.iter().take(3) {
@@ -64,8 +90,9 @@ impl std::fmt::Display for StaleRefError {
                 ref_string: _,
                 was,
                 now,
+                path,
             } => {
-                writeln!(f, "Element changed since snapshot.")?;
+                writeln!(f, "Element at {path} changed since snapshot.")?;
                 writeln!(f, "Was: {was}")?;
                 writeln!(f, "Now: {now}")?;
                 write!(f, "Take a new snapshot to get current element state.")
@@ -73,12 +100,15 @@ impl std::fmt::Display for StaleRefError {
             Self::MinorChange {
                 ref_string: _,
                 change_description,
+                path,
             } => {
                 write!(
                     f,
-                    "Note: Element may have changed ({change_description}). Using current state."
+                    "Note: Element at {path} may have changed ({change_description}). \
+                     Using current state."
                 )
             }
+            Self::InvalidFormat { reason, .. } => f.write_str(reason),
         }
     }
 }
@@ -94,11 +124,14 @@ pub struct SimilarElement {
     pub similarity: f64,
 }
 
+/// Key identifying a ref within a context: `(context name, ref string)`
+pub type ScopedRef = (Option<String>, String);
+
 /// Stored snapshot info for comparison
 #[derive(Debug, Clone)]
 pub struct SnapshotInfo {
-    /// Map from ref string (e.g., "c0p0f0e1") to element info
-    pub elements: HashMap<String, StoredElementInfo>,
+    /// Map from `(context, ref string)` (e.g., `(Some("main"), "c0p0f0e1")`) to element info
+    pub elements: HashMap<ScopedRef, StoredElementInfo>,
 }
 
 /// Stored element info for staleness comparison
@@ -110,6 +143,8 @@ pub struct StoredElementInfo {
     pub name: Option<String>,
     /// Description for error messages
     pub description: String,
+    /// Location in the snapshot tree (see [`SnapshotElement::paths`])
+    pub path: String,
 }
 
 /// Detector for stale references.
@@ -149,21 +184,53 @@ impl StaleRefDetector {
         self.current = Some(Self::extract_info(snapshot));
     }
 
+    /// Forget all tracked snapshots.
+    ///
+    /// Unlike a fresh detector, which accepts any ref until the first
+    /// snapshot arrives, a cleared detector rejects every ref as never seen
+    /// ([`StaleRefError::ElementRemoved`] described only by its ref string)
+    /// until [`StaleRefDetector::update`] is called again.
+    pub fn clear(&mut self) {
+        self.previous = None;
+        self.current = Some(SnapshotInfo {
+            elements: HashMap::new(),
+        });
+    }
+
+    /// Number of refs tracked in the current snapshot
+    #[must_use]
+    pub fn ref_count(&self) -> usize {
+        self.current
+            .as_ref()
+            .map_or(0, |current| current.elements.len())
+    }
+
     /// Check if a ref is valid and not stale
     ///
+    /// Refs are scoped to `context`, falling back to the ref's own context
+    /// name, so identical ref strings from different contexts never match.
+    ///
     /// Returns `Ok(())` if the ref is valid, or an appropriate error
-    pub fn validate_ref(&self, element_ref: &ElementRef) -> Result<(), StaleRefError> {
+    pub fn validate_ref(
+        &self,
+        element_ref: &ElementRef,
+        context: Option<&str>,
+    ) -> Result<(), StaleRefError> {
         let Some(current) = &self.current else {
             return Ok(()); // No snapshot to validate against
         };
 
         let ref_str = element_ref.ref_string();
+        let key: ScopedRef = (
+            context.or_else(|| element_ref.context()).map(String::from),
+            ref_str.to_string(),
+        );
 
         // Check if element exists in current snapshot
-        if let Some(current_info) = current.elements.get(ref_str) {
+        if let Some(current_info) = current.elements.get(&key) {
             // Element exists - check if it changed from previous
             if let Some(previous) = &self.previous
-                && let Some(previous_info) = previous.elements.get(ref_str)
+                && let Some(previous_info) = previous.elements.get(&key)
             {
                 // Compare for significant changes
                 if current_info.role != previous_info.role {
@@ -171,6 +238,7 @@ impl StaleRefDetector {
                         ref_string: element_ref.to_ref_string(),
                         was: previous_info.description.clone(),
                         now: current_info.description.clone(),
+                        path: current_info.path.clone(),
                     });
                 }
 
@@ -182,6 +250,7 @@ impl StaleRefDetector {
                             "name changed from {:?} to {:?}",
                             previous_info.name, current_info.name
                         ),
+                        path: current_info.path.clone(),
                     });
                 }
             }
@@ -191,19 +260,13 @@ impl StaleRefDetector {
             // Element not found - find similar elements
             let similar = Self::find_similar_elements(ref_str);
 
-            // Get original description from previous snapshot if available
-            let original_description = self
-                .previous
-                .as_ref()
-                .and_then(|p| p.elements.get(ref_str))
-                .map_or_else(
-                    || format!("element {}", element_ref.to_ref_string()),
-                    |info| info.description.clone(),
-                );
+            // Get original description and location from previous snapshot if available
+            let previous_info = self.previous.as_ref().and_then(|p| p.elements.get(&key));
 
             Err(StaleRefError::ElementRemoved {
                 ref_string: element_ref.to_ref_string(),
-                original_description,
+                original_description: previous_info.map(|info| info.description.clone()),
+                path: previous_info.map(|info| info.path.clone()),
                 similar_elements: similar,
             })
         }
@@ -218,30 +281,28 @@ impl StaleRefDetector {
     /// Extract element info from a snapshot tree
     fn extract_info(root: &SnapshotElement) -> SnapshotInfo {
         let mut elements = HashMap::new();
-        Self::collect_elements(&mut elements, root);
-        SnapshotInfo { elements }
-    }
-
-    /// Recursively collect elements with refs
-    fn collect_elements(map: &mut HashMap<String, StoredElementInfo>, element: &SnapshotElement) {
-        if let Some(element_ref) = &element.element_ref {
+        for (path, element) in root.paths() {
+            let Some(element_ref) = &element.element_ref else {
+                continue;
+            };
             let description = format!("{} {}", element.role, element.name.as_deref().unwrap_or(""))
                 .trim()
                 .to_string();
 
-            // Store using the raw ref string (e.g., "c0p0f0e1")
-            map.insert(
-                element_ref.ref_string().to_string(),
+            // Store keyed by context and raw ref string (e.g., "c0p0f0e1")
+            elements.insert(
+                (
+                    element_ref.context().map(String::from),
+                    element_ref.ref_string().to_string(),
+                ),
                 StoredElementInfo {
                     role: element.role.clone(),
                     name: element.name.clone(),
                     description,
+                    path,
                 },
             );
         }
-
-        for child in &element.children {
-            Self::collect_elements(map, child);
-        }
+        SnapshotInfo { elements }
     }
 }
@@ -0,0 +1,94 @@
+//! Element statistics for accessibility snapshots
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use super::capture::AccessibilitySnapshot;
+use super::classification::{ElementTier, classify_role};
+use super::element::SnapshotElement;
+
+impl AccessibilitySnapshot {
+    /// Count the elements in the snapshot tree by role.
+    #[must_use]
+    pub fn count_by_role(&self) -> HashMap<String, usize> {
+        fn visit(element: &SnapshotElement, counts: &mut HashMap<String, usize>) {
+            *counts.entry(element.role.clone()).or_default() += 1;
+            for child in &element.children {
+                visit(child, counts);
+            }
+        }
+
+        let mut counts = HashMap::new();
+        visit(self.root(), &mut counts);
+        counts
+    }
+
+    /// Count the elements whose role is always or contextually interactive.
+    #[must_use]
+    pub fn interactive_element_count(&self) -> usize {
+        self.count_by_role()
+            .iter()
+            .filter(|(role, _)| classify_role(role) != ElementTier::NonInteractive)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// One-line summary of element counts, most frequent roles first.
+    ///
+    /// Roles with equal counts are ordered alphabetically so the summary is
+    /// stable across captures.
+    #[must_use]
+    pub fn element_counts_summary(&self) -> String {
+        let mut counts: Vec<(String, usize)> = self.count_by_role().into_iter().collect();
+        counts.sort_by(|(a_role, a), (b_role, b)| b.cmp(a).then_with(|| a_role.cmp(b_role)));
+
+        let mut summary = format!(
+            "Element counts: {} interactive of {} total",
+            self.interactive_element_count(),
+            self.element_count()
+        );
+        for (index, (role, count)) in counts.iter().enumerate() {
+            let separator = if index == 0 { " (" } else { ", " };
+            let _ = write!(summary, "{separator}{role}: {count}");
+        }
+        if !counts.is_empty() {
+            summary.push(')');
+        }
+        summary
+    }
+
+    /// Compact statistics block, e.g. `[Stats: 2 buttons, 1 link, 4 other]`.
+    ///
+    /// Interactive roles are counted individually, most frequent first (ties
+    /// alphabetical); all remaining elements are grouped as "other".
+    #[must_use]
+    pub fn role_stats(&self) -> String {
+        let (mut interactive, other): (Vec<(String, usize)>, Vec<(String, usize)>) = self
+            .count_by_role()
+            .into_iter()
+            .partition(|(role, _)| classify_role(role) != ElementTier::NonInteractive);
+        interactive.sort_by(|(a_role, a), (b_role, b)| b.cmp(a).then_with(|| a_role.cmp(b_role)));
+
+        let mut parts: Vec<String> = interactive
+            .iter()
+            .map(|(role, count)| format!("{count} {}", pluralize(role, *count)))
+            .collect();
+        let other: usize = other.iter().map(|(_, count)| count).sum();
+        if other > 0 {
+            parts.push(format!("{other} other"));
+        }
+
+        format!("[Stats: {}]", parts.join(", "))
+    }
+}
+
+/// Pluralize a role name for display (`textbox` becomes `textboxes`)
+fn pluralize(role: &str, count: usize) -> String {
+    if count == 1 {
+        role.to_string()
+    } else if role.ends_with('x') || role.ends_with('s') {
+        format!("{role}es")
+    } else {
+        format!("{role}s")
+    }
+}
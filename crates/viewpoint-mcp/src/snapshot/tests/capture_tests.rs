@@ -0,0 +1,143 @@
+//! Unit tests for accessibility snapshot capture, merging, and ref validation
+
+use crate::snapshot::capture::AccessibilitySnapshot;
+use crate::snapshot::element::SnapshotElement;
+use crate::snapshot::reference::ElementRef;
+use crate::snapshot::stale::StaleRefError;
+
+#[test]
+fn test_empty_snapshot_has_document_root() {
+    let snapshot = AccessibilitySnapshot::empty_snapshot(None);
+
+    assert_eq!(snapshot.root().role, "document");
+    assert!(snapshot.root().children.is_empty());
+    assert_eq!(snapshot.ref_count(), 0);
+    assert_eq!(snapshot.element_count(), 1);
+    assert!(!snapshot.is_compact());
+}
+
+#[test]
+fn test_empty_snapshot_with_context() {
+    let snapshot = AccessibilitySnapshot::empty_snapshot(Some("test-context".to_string()));
+
+    assert_eq!(snapshot.context(), Some("test-context"));
+    assert_eq!(snapshot.root().role, "document");
+}
+
+fn snapshot_with_button(ref_str: &str, name: &str) -> AccessibilitySnapshot {
+    let root = SnapshotElement::new("document").with_child(
+        SnapshotElement::new("button")
+            .with_name(name)
+            .with_ref(ElementRef::new(ref_str)),
+    );
+    AccessibilitySnapshot::from_root(root, None, false)
+}
+
+#[test]
+fn test_merge_wraps_other_in_frame() {
+    let main = snapshot_with_button("c0p0f0e1", "Main");
+    let frame = snapshot_with_button("c0p0f0e1", "Inner");
+
+    let merged = main.merge(&frame);
+
+    assert_eq!(merged.root().children.len(), 2);
+    let wrapper = &merged.root().children[1];
+    assert_eq!(wrapper.role, "frame");
+    assert!(wrapper.is_frame);
+    assert_eq!(wrapper.children[0].role, "document");
+    assert!(merged.format().contains("[frame-boundary]"));
+}
+
+#[test]
+fn test_merge_resolves_ref_collisions() {
+    let main = snapshot_with_button("c0p0f0e1", "Main");
+    let frame = snapshot_with_button("c0p0f0e1", "Inner");
+
+    let merged = main.merge(&frame);
+
+    assert_eq!(merged.ref_count(), 2);
+    assert!(merged.lookup("c0p0f0e1").is_ok());
+    assert!(merged.lookup("c0p0f1f0e1").is_ok());
+}
+
+#[test]
+fn test_merge_keeps_refs_of_merged_frames_distinct() {
+    let inner = snapshot_with_button("c0p0f0e1", "Inner")
+        .merge(&snapshot_with_button("c0p0f0e1", "Nested"));
+    let main = snapshot_with_button("c0p0f0e1", "Main");
+
+    let merged = main.merge(&inner);
+
+    assert_eq!(merged.ref_count(), 3);
+    assert!(merged.lookup("c0p0f0e1").is_ok());
+    assert!(merged.lookup("c0p0f1f0e1").is_ok());
+    assert!(merged.lookup("c0p0f1f1f0e1").is_ok());
+}
+
+#[test]
+fn test_merge_all_assigns_increasing_frame_indices() {
+    let merged = AccessibilitySnapshot::merge_all(vec![
+        snapshot_with_button("c0p0f0e1", "Main"),
+        snapshot_with_button("c0p0f0e1", "First"),
+        snapshot_with_button("c0p0f0e1", "Second"),
+    ]);
+
+    assert_eq!(merged.ref_count(), 3);
+    assert!(merged.lookup("c0p0f1f0e1").is_ok());
+    assert!(merged.lookup("c0p0f2f0e1").is_ok());
+    assert_eq!(merged.root().children.len(), 3);
+}
+
+#[test]
+fn test_merge_all_empty() {
+    let merged = AccessibilitySnapshot::merge_all(Vec::new());
+
+    assert_eq!(merged.root().role, "document");
+    assert_eq!(merged.ref_count(), 0);
+}
+
+#[test]
+fn test_empty_snapshot_format() {
+    let snapshot = AccessibilitySnapshot::empty_snapshot(None);
+    let output = snapshot.format();
+
+    // Should contain "document" since that's the root role
+    assert!(output.contains("document"));
+}
+
+#[test]
+fn test_validate_refs_reports_every_ref_in_order() {
+    let snapshot = snapshot_with_button("c0p0f0e1", "Save");
+
+    let results = snapshot.validate_refs(&["c0p0f0e1", "c0p0f0e9", "bogus"]);
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().ref_string(), "c0p0f0e1");
+    assert!(matches!(
+        results[1],
+        Err(StaleRefError::ElementRemoved { .. })
+    ));
+    assert!(matches!(
+        &results[2],
+        Err(StaleRefError::InvalidFormat { ref_string, .. }) if ref_string == "bogus"
+    ));
+}
+
+#[test]
+fn test_validate_refs_empty_snapshot_rejects_unknown_ref() {
+    let snapshot = AccessibilitySnapshot::empty_snapshot(None);
+
+    let results = snapshot.validate_refs(&["c0p0f0e1"]);
+
+    assert!(matches!(
+        results[0],
+        Err(StaleRefError::ElementRemoved { .. })
+    ));
+}
+
+#[test]
+fn test_validate_refs_empty_input() {
+    let snapshot = snapshot_with_button("c0p0f0e1", "Save");
+
+    assert!(snapshot.validate_refs(&[]).is_empty());
+}
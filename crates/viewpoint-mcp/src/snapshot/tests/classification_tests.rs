@@ -0,0 +1,88 @@
+//! Unit tests for role classification and ref eligibility
+
+use crate::snapshot::classification::{ElementTier, classify_role, should_receive_ref};
+
+#[test]
+fn test_classify_tier1_roles() {
+    assert_eq!(classify_role("button"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("link"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("textbox"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("checkbox"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("radio"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("combobox"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("slider"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("menuitem"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("tab"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("switch"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("searchbox"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("spinbutton"), ElementTier::AlwaysInteractive);
+}
+
+#[test]
+fn test_classify_tier2_roles() {
+    assert_eq!(
+        classify_role("listitem"),
+        ElementTier::ContextuallyInteractive
+    );
+    assert_eq!(
+        classify_role("option"),
+        ElementTier::ContextuallyInteractive
+    );
+    assert_eq!(
+        classify_role("treeitem"),
+        ElementTier::ContextuallyInteractive
+    );
+    assert_eq!(classify_role("row"), ElementTier::ContextuallyInteractive);
+    assert_eq!(classify_role("cell"), ElementTier::ContextuallyInteractive);
+}
+
+#[test]
+fn test_classify_tier3_roles() {
+    assert_eq!(classify_role("heading"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("paragraph"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("text"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("separator"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("img"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("main"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("navigation"), ElementTier::NonInteractive);
+}
+
+#[test]
+fn test_classify_case_insensitive() {
+    assert_eq!(classify_role("BUTTON"), ElementTier::AlwaysInteractive);
+    assert_eq!(classify_role("Button"), ElementTier::AlwaysInteractive);
+    assert_eq!(
+        classify_role("LISTITEM"),
+        ElementTier::ContextuallyInteractive
+    );
+}
+
+#[test]
+fn test_classify_unknown_role() {
+    assert_eq!(classify_role("unknown"), ElementTier::NonInteractive);
+    assert_eq!(classify_role("custom-element"), ElementTier::NonInteractive);
+}
+
+#[test]
+fn test_should_receive_ref_tier1() {
+    // Tier 1 elements always receive refs
+    assert!(should_receive_ref("button", false, false));
+    assert!(should_receive_ref("link", false, false));
+    assert!(should_receive_ref("textbox", false, false));
+}
+
+#[test]
+fn test_should_receive_ref_tier2_in_container() {
+    // Tier 2 elements only receive refs in interactive containers
+    assert!(!should_receive_ref("listitem", false, false));
+    assert!(should_receive_ref("listitem", true, false));
+    assert!(should_receive_ref("option", true, false));
+}
+
+#[test]
+fn test_should_receive_ref_with_tabindex() {
+    // Elements with tabindex always receive refs
+    assert!(should_receive_ref("heading", false, true));
+    assert!(should_receive_ref("paragraph", false, true));
+    assert!(should_receive_ref("div", false, true));
+}
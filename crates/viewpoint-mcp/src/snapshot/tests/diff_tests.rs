@@ -0,0 +1,91 @@
+//! Unit tests for snapshot diffs
+
+use crate::snapshot::diff::SnapshotDiff;
+use crate::snapshot::element::{CheckedState, SnapshotElement};
+use crate::snapshot::reference::ElementRef;
+
+/// Page with a disclosure button, a checkbox, and an optional status message
+fn page(expanded: bool, checked: bool, status: Option<&str>) -> SnapshotElement {
+    let toggle = SnapshotElement::new("button")
+        .with_name("Details")
+        .with_ref(ElementRef::new("c0p0f0e1"))
+        .with_attribute("aria-expanded", expanded.to_string());
+    let mut checkbox = SnapshotElement::new("checkbox")
+        .with_name("Subscribe")
+        .with_ref(ElementRef::new("c0p0f0e2"));
+    checkbox.checked = Some(if checked {
+        CheckedState::True
+    } else {
+        CheckedState::False
+    });
+
+    let mut root = SnapshotElement::new("document")
+        .with_child(toggle)
+        .with_child(checkbox);
+    if let Some(status) = status {
+        root = root.with_child(SnapshotElement::new("status").with_name(status));
+    }
+    root
+}
+
+#[test]
+fn test_identical_snapshots_have_no_diff() {
+    let diff = SnapshotDiff::compute(&page(false, false, None), &page(false, false, None));
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.format(), "No accessibility tree changes");
+}
+
+#[test]
+fn test_attribute_and_state_changes() {
+    let diff = SnapshotDiff::compute(&page(false, false, None), &page(true, true, None));
+
+    assert!(diff.added.is_empty());
+    assert!(diff.removed.is_empty());
+    assert_eq!(diff.changed.len(), 2);
+
+    assert_eq!(diff.changed[0].element, "button \"Details\" [ref=c0p0f0e1]");
+    assert_eq!(diff.changed[0].path, "document > button");
+    assert_eq!(
+        diff.changed[0].changes,
+        vec!["aria-expanded: \"false\" -> \"true\""]
+    );
+    assert_eq!(diff.changed[1].changes, vec!["checked: false -> true"]);
+}
+
+#[test]
+fn test_added_and_removed_elements() {
+    let before = page(false, false, Some("Saving"));
+    let after = page(false, false, None);
+
+    let diff = SnapshotDiff::compute(&before, &after);
+    assert_eq!(diff.removed, vec!["status \"Saving\""]);
+    assert!(diff.added.is_empty());
+
+    let diff = SnapshotDiff::compute(&after, &before);
+    assert_eq!(diff.added, vec!["status \"Saving\""]);
+    assert!(diff.removed.is_empty());
+}
+
+#[test]
+fn test_elements_matched_by_path_not_ref() {
+    let mut after = page(false, false, None);
+    after.children[0].element_ref = Some(ElementRef::new("c0p0f0e9"));
+
+    let diff = SnapshotDiff::compute(&page(false, false, None), &after);
+    assert!(diff.is_empty(), "Got: {diff:?}");
+}
+
+#[test]
+fn test_format_lists_each_change() {
+    let before = page(false, false, Some("Idle"));
+    let after = page(true, false, Some("Open"));
+
+    let output = SnapshotDiff::compute(&before, &after).format();
+    assert_eq!(
+        output,
+        "Accessibility tree changes (0 added, 0 removed, 2 changed):\n\
+         ~ button \"Details\" [ref=c0p0f0e1] (document > button): aria-expanded: \"false\" -> \"true\"\n\
+         ~ status \"Open\" (document > status): name: \"Idle\" -> \"Open\""
+    );
+}
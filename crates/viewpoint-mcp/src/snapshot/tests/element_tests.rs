@@ -0,0 +1,135 @@
+//! Unit tests for snapshot element tree paths and filtering
+
+use crate::snapshot::element::SnapshotElement;
+
+fn tree() -> SnapshotElement {
+    SnapshotElement::new("document").with_child(
+        SnapshotElement::new("main")
+            .with_child(SnapshotElement::new("heading"))
+            .with_child(SnapshotElement::new("section"))
+            .with_child(
+                SnapshotElement::new("section")
+                    .with_child(SnapshotElement::new("button"))
+                    .with_child(SnapshotElement::new("link"))
+                    .with_child(SnapshotElement::new("button")),
+            ),
+    )
+}
+
+/// Path of every element in `root`'s tree, in document order
+fn paths(root: &SnapshotElement) -> Vec<String> {
+    root.paths().into_iter().map(|(path, _)| path).collect()
+}
+
+#[test]
+fn test_path_of_lone_element_is_its_role() {
+    assert_eq!(paths(&SnapshotElement::new("button")), vec!["button"]);
+}
+
+#[test]
+fn test_paths() {
+    assert_eq!(
+        paths(&tree()),
+        vec![
+            "document",
+            "document > main",
+            "document > main > heading",
+            "document > main > section[1]",
+            "document > main > section[2]",
+            "document > main > section[2] > button[1]",
+            "document > main > section[2] > link",
+            "document > main > section[2] > button[2]",
+        ]
+    );
+}
+
+#[test]
+fn test_paths_are_relative_to_the_element() {
+    let tree = tree();
+    let section = tree.paths()[4].1;
+
+    // A subtree is numbered from its own root
+    assert_eq!(
+        paths(section),
+        vec![
+            "section",
+            "section > button[1]",
+            "section > link",
+            "section > button[2]"
+        ]
+    );
+}
+
+#[test]
+fn test_filter_by_name() {
+    let root = SnapshotElement::new("document").with_child(
+        SnapshotElement::new("form")
+            .with_name("Search form")
+            .with_child(SnapshotElement::new("textbox").with_name("Query"))
+            .with_child(SnapshotElement::new("button").with_name("Start SEARCH")),
+    );
+
+    let matches = root.filter_by_name("search");
+
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].name.as_deref(), Some("Search form"));
+    assert_eq!(matches[0].children.len(), 1);
+    assert_eq!(matches[0].children[0].name.as_deref(), Some("Start SEARCH"));
+}
+
+#[test]
+fn test_filter_by_name_lifts_matches_past_non_matching_ancestors() {
+    let root = SnapshotElement::new("document").with_child(
+        SnapshotElement::new("list")
+            .with_child(SnapshotElement::new("link").with_name("Docs"))
+            .with_child(SnapshotElement::new("link").with_name("Blog")),
+    );
+
+    let matches = root.filter_by_name("docs");
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].role, "link");
+
+    assert!(root.filter_by_name("missing").is_empty());
+}
+
+fn roles(names: &[&str]) -> Vec<String> {
+    names.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_without_roles_reduces_count_and_keeps_buttons() {
+    let root = SnapshotElement::new("document").with_child(
+        SnapshotElement::new("paragraph")
+            .with_child(SnapshotElement::new("text").with_name("Intro"))
+            .with_child(
+                SnapshotElement::new("text")
+                    .with_child(SnapshotElement::new("button").with_name("Buy"))
+                    .with_child(SnapshotElement::new("text").with_name("Nested")),
+            ),
+    );
+
+    let pruned = root.without_roles(&roles(&["text"]));
+
+    assert_eq!(pruned.len(), 1);
+    assert_eq!(pruned[0].counts().1, 3);
+    assert!(pruned[0].counts().1 < root.counts().1);
+
+    // The button under a skipped element takes its place
+    let paragraph = &pruned[0].children[0];
+    assert_eq!(paragraph.children.len(), 1);
+    assert_eq!(paragraph.children[0].role, "button");
+    assert_eq!(paragraph.children[0].name.as_deref(), Some("Buy"));
+}
+
+#[test]
+fn test_without_roles_ignores_case_and_empty_list() {
+    let root = SnapshotElement::new("document")
+        .with_child(SnapshotElement::new("img"))
+        .with_child(SnapshotElement::new("link"));
+
+    let pruned = root.without_roles(&roles(&["IMG"]));
+    assert_eq!(pruned[0].children.len(), 1);
+    assert_eq!(pruned[0].children[0].role, "link");
+
+    assert_eq!(root.without_roles(&[])[0].counts(), root.counts());
+}
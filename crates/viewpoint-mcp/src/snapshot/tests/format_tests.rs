@@ -1,6 +1,7 @@
-//! Unit tests for snapshot formatting (truncate_text function)
+//! Unit tests for snapshot formatting (truncate_text function and name truncation)
 
 use crate::snapshot::format::truncate_text;
+use crate::snapshot::{ElementRef, OutputFormat, SnapshotElement, SnapshotFormatter};
 
 #[test]
 fn test_truncate_text_short_string() {
@@ -80,3 +81,87 @@ fn test_truncate_text_wikipedia_languages() {
     assert!(result.ends_with("..."));
     assert!(result.len() <= 50 + 10); // Allow some slack for char boundary
 }
+
+#[test]
+fn test_formatter_default_max_text_length() {
+    let name = "a".repeat(150);
+    let root = SnapshotElement::new("heading").with_name(&name);
+
+    let output = SnapshotFormatter::new().format(&root);
+    assert!(output.contains(&format!("\"{}...\"", "a".repeat(97))));
+    assert!(!output.contains(&"a".repeat(98)));
+
+    assert_eq!(SnapshotFormatter::default().max_text_length, 100);
+}
+
+#[test]
+fn test_formatter_with_max_text_length() {
+    let name = "a".repeat(150);
+    let root = SnapshotElement::new("heading").with_name(&name);
+
+    let short = SnapshotFormatter::new()
+        .with_max_text_length(50)
+        .format(&root);
+    assert!(short.contains(&format!("\"{}...\"", "a".repeat(47))));
+    assert!(!short.contains(&"a".repeat(48)));
+
+    let long = SnapshotFormatter::new()
+        .with_max_text_length(500)
+        .format(&root);
+    assert!(long.contains(&format!("\"{name}\"")));
+}
+
+#[test]
+fn test_formatter_marks_focused_element() {
+    let mut input = SnapshotElement::new("textbox").with_name("Email");
+    input.focused = true;
+    let root = SnapshotElement::new("form")
+        .with_child(input)
+        .with_child(SnapshotElement::new("textbox").with_name("Password"));
+
+    let output = SnapshotFormatter::new().format(&root);
+    assert!(output.contains("textbox \"Email\" [focused]"));
+    assert!(!output.contains("\"Password\" [focused]"));
+    assert_eq!(output.matches("[focused]").count(), 1);
+}
+
+#[test]
+fn test_output_format_from_str() {
+    assert_eq!("text".parse(), Ok(OutputFormat::Text));
+    assert_eq!("Markdown".parse(), Ok(OutputFormat::Markdown));
+    assert!("html".parse::<OutputFormat>().is_err());
+    assert_eq!(SnapshotFormatter::new().output_format, OutputFormat::Text);
+}
+
+#[test]
+fn test_formatter_markdown() {
+    let root = SnapshotElement::new("form")
+        .with_child(SnapshotElement::new("heading").with_name("Sign in"))
+        .with_child(
+            SnapshotElement::new("button")
+                .with_name("Submit")
+                .with_ref(ElementRef::new("c0p0f0e4")),
+        );
+
+    let output = SnapshotFormatter::new()
+        .with_format(OutputFormat::Markdown)
+        .format(&root);
+    assert_eq!(
+        output,
+        "- **form**\n  \
+         - **heading** \"Sign in\"\n  \
+         - `button \"Submit\" [ref=c0p0f0e4]`\n"
+    );
+}
+
+#[test]
+fn test_formatter_markdown_escapes_backticks_in_code_spans() {
+    let root = SnapshotElement::new("link")
+        .with_name("Run `cargo test`")
+        .with_ref(ElementRef::new("c0p0f0e1"));
+
+    let output = SnapshotFormatter::new()
+        .with_format(OutputFormat::Markdown)
+        .format(&root);
+    assert_eq!(output, "- `` link \"Run `cargo test`\" [ref=c0p0f0e1] ``\n");
+}
@@ -0,0 +1,85 @@
+//! Unit tests for deterministic ref generation
+
+use crate::snapshot::element::SnapshotElement;
+use crate::snapshot::ref_generator::RefGenerator;
+use crate::snapshot::reference::ElementRef;
+
+/// Element with a native ref
+fn with_ref(role: &str, name: &str, ref_str: &str) -> SnapshotElement {
+    let mut element = SnapshotElement::new(role).with_name(name);
+    element.element_ref = Some(ElementRef::new(ref_str));
+    element
+}
+
+/// Page with two buttons whose native refs use the given counters
+fn page(first: u32, second: u32) -> SnapshotElement {
+    SnapshotElement::new("document")
+        .with_child(with_ref("button", "Save", &format!("c0p0f0e{first}")))
+        .with_child(with_ref("button", "Cancel", &format!("c0p0f1e{second}")))
+}
+
+/// Collect ref strings in document order
+fn refs(element: &SnapshotElement) -> Vec<String> {
+    let mut refs: Vec<String> = element.ref_string().into_iter().collect();
+    for child in &element.children {
+        refs.extend(self::refs(child));
+    }
+    refs
+}
+
+#[test]
+fn test_deterministic_refs_stable_across_captures() {
+    let mut first = page(1, 2);
+    let mut second = page(40, 41);
+
+    RefGenerator::role_and_name().apply(&mut first);
+    RefGenerator::role_and_name().apply(&mut second);
+
+    assert_eq!(refs(&first), refs(&second));
+}
+
+#[test]
+fn test_deterministic_refs_keep_scope_prefix() {
+    let mut root = page(1, 2);
+    RefGenerator::role_and_name().apply(&mut root);
+
+    let refs = refs(&root);
+    assert!(refs[0].starts_with("c0p0f0e"));
+    assert!(refs[1].starts_with("c0p0f1e"));
+    assert!(ElementRef::parse(&refs[0]).is_ok());
+}
+
+#[test]
+fn test_deterministic_refs_depend_on_position() {
+    let mut root = SnapshotElement::new("document")
+        .with_child(with_ref("button", "Same", "c0p0f0e1"))
+        .with_child(with_ref("button", "Same", "c0p0f0e2"));
+    RefGenerator::role_and_name().apply(&mut root);
+
+    let refs = refs(&root);
+    assert_ne!(refs[0], refs[1]);
+}
+
+#[test]
+fn test_deterministic_refs_unique_with_constant_seed() {
+    let mut root = SnapshotElement::new("document")
+        .with_child(
+            with_ref("button", "A", "c0p0f0e1").with_child(with_ref("link", "B", "c0p0f0e2")),
+        )
+        .with_child(with_ref("button", "C", "c0p0f0e3"));
+    // Position still distinguishes elements when the seed ignores properties
+    RefGenerator::deterministic(|_| String::new()).apply(&mut root);
+
+    let mut refs = refs(&root);
+    refs.sort();
+    refs.dedup();
+    assert_eq!(refs.len(), 3);
+}
+
+#[test]
+fn test_element_ref_with_element_id() {
+    let element_ref = ElementRef::with_context("c1p2f3e4", "ctx").with_element_id(99);
+
+    assert_eq!(element_ref.ref_string(), "c1p2f3e99");
+    assert_eq!(element_ref.context(), Some("ctx"));
+}
@@ -0,0 +1,133 @@
+//! Unit tests for element reference parsing and display
+
+use crate::snapshot::reference::ElementRef;
+
+#[test]
+fn test_element_ref_format() {
+    // ElementRef stores the full ref string as provided by viewpoint-core
+    let element_ref = ElementRef::new("c0p0f0e1");
+    assert_eq!(element_ref.to_ref_string(), "c0p0f0e1");
+    assert_eq!(element_ref.ref_string(), "c0p0f0e1");
+}
+
+#[test]
+fn test_element_ref_with_context() {
+    // In new format, context_name is for MCP display purposes only
+    // The ref string itself contains the context index (c{n})
+    let element_ref = ElementRef::with_context("c0p0f0e1", "clean");
+    // to_ref_string returns just the ref (context is embedded)
+    assert_eq!(element_ref.to_ref_string(), "c0p0f0e1");
+    assert_eq!(element_ref.ref_string(), "c0p0f0e1");
+    // context() returns the MCP context name for display
+    assert_eq!(element_ref.context(), Some("clean"));
+}
+
+#[test]
+fn test_element_ref_parse_simple() {
+    let parsed = ElementRef::parse("c0p0f0e1").unwrap();
+    assert_eq!(parsed.ref_string(), "c0p0f0e1");
+    assert!(parsed.context().is_none());
+}
+
+#[test]
+fn test_element_ref_parse_invalid() {
+    // Invalid: must be in c{ctx}p{page}f{frame}e{counter} format
+    assert!(ElementRef::parse("invalid").is_err());
+    assert!(ElementRef::parse("e12345").is_err()); // Old format no longer supported
+    assert!(ElementRef::parse("").is_err());
+    assert!(ElementRef::parse("ctx:e12345").is_err()); // Old format with context no longer supported
+    assert!(ElementRef::parse("eabc123").is_err());
+    assert!(ElementRef::parse("c0p0e1").is_err()); // Missing f component
+}
+
+#[test]
+fn test_parse_new_format_basic() {
+    let element_ref = ElementRef::parse("c0p0f0e1").unwrap();
+    assert_eq!(element_ref.ref_string(), "c0p0f0e1");
+    assert_eq!(element_ref.context(), None);
+    assert_eq!(element_ref.to_ref_string(), "c0p0f0e1");
+}
+
+#[test]
+fn test_parse_new_format_multi_context() {
+    let element_ref = ElementRef::parse("c1p0f0e5").unwrap();
+    assert_eq!(element_ref.ref_string(), "c1p0f0e5");
+}
+
+#[test]
+fn test_parse_new_format_multi_page() {
+    let element_ref = ElementRef::parse("c0p2f0e3").unwrap();
+    assert_eq!(element_ref.ref_string(), "c0p2f0e3");
+}
+
+#[test]
+fn test_parse_new_format_iframe() {
+    let element_ref = ElementRef::parse("c0p0f1e2").unwrap();
+    assert_eq!(element_ref.ref_string(), "c0p0f1e2");
+}
+
+#[test]
+fn test_parse_new_format_large_numbers() {
+    let element_ref = ElementRef::parse("c10p20f3e456").unwrap();
+    assert_eq!(element_ref.ref_string(), "c10p20f3e456");
+}
+
+// Invalid format tests
+#[test]
+fn test_parse_invalid_no_prefix() {
+    let result = ElementRef::parse("12345");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_invalid_legacy_format() {
+    // Old e{id} format is no longer supported
+    let result = ElementRef::parse("e12345");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_invalid_empty() {
+    let result = ElementRef::parse("");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_invalid_missing_parts() {
+    // Missing parts of format
+    let result = ElementRef::parse("c0p0e1"); // Missing f
+    assert!(result.is_err());
+}
+
+// Constructor and display tests
+#[test]
+fn test_new_and_display() {
+    let element_ref = ElementRef::new("c0p0f0e42");
+    assert_eq!(format!("{element_ref}"), "c0p0f0e42");
+}
+
+#[test]
+fn test_with_context_name() {
+    // Note: context_name is for MCP display, not part of the ref
+    let element_ref = ElementRef::with_context("c0p0f0e42", "main");
+    assert_eq!(element_ref.to_ref_string(), "c0p0f0e42");
+    assert_eq!(element_ref.context(), Some("main"));
+}
+
+#[test]
+fn test_with_frame_index_prepends_segment() {
+    let element_ref = ElementRef::new("c0p0f2e7").with_frame_index(1);
+    assert_eq!(element_ref.ref_string(), "c0p0f1f2e7");
+    assert_eq!(element_ref.frame_index(), Some(1));
+
+    // Nesting again keeps the inner segments
+    let nested = element_ref.with_frame_index(4);
+    assert_eq!(nested.ref_string(), "c0p0f4f1f2e7");
+    assert!(ElementRef::parse(nested.ref_string()).is_ok());
+}
+
+#[test]
+fn test_with_element_id_on_nested_ref() {
+    let element_ref = ElementRef::new("c0p0f1f2e7").with_element_id(42);
+    assert_eq!(element_ref.ref_string(), "c0p0f1f2e42");
+}
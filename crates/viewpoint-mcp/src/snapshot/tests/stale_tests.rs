@@ -0,0 +1,134 @@
+//! Unit tests for stale ref detector state management
+
+use crate::snapshot::element::SnapshotElement;
+use crate::snapshot::reference::ElementRef;
+use crate::snapshot::stale::{StaleRefDetector, StaleRefError};
+
+/// Page with two buttons
+fn page() -> SnapshotElement {
+    SnapshotElement::new("document")
+        .with_child(
+            SnapshotElement::new("button")
+                .with_name("Save")
+                .with_ref(ElementRef::new("c0p0f0e1")),
+        )
+        .with_child(
+            SnapshotElement::new("button")
+                .with_name("Cancel")
+                .with_ref(ElementRef::new("c0p0f0e2")),
+        )
+}
+
+#[test]
+fn test_ref_count() {
+    let mut detector = StaleRefDetector::new();
+    assert_eq!(detector.ref_count(), 0);
+
+    detector.update(&page());
+    assert_eq!(detector.ref_count(), 2);
+
+    // Only the current snapshot is counted
+    detector.update(&SnapshotElement::new("document"));
+    assert_eq!(detector.ref_count(), 0);
+}
+
+#[test]
+fn test_clear_empties_tracked_refs() {
+    let mut detector = StaleRefDetector::new();
+    detector.update(&page());
+    detector.update(&page());
+
+    detector.clear();
+
+    assert_eq!(detector.ref_count(), 0);
+}
+
+#[test]
+fn test_clear_then_validate_reports_never_seen() {
+    let mut detector = StaleRefDetector::new();
+    detector.update(&page());
+
+    detector.clear();
+
+    let error = detector
+        .validate_ref(&ElementRef::new("c0p0f0e1"), None)
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("Element with ref c0p0f0e1 no longer exists."),
+        "Got: {error}"
+    );
+    let StaleRefError::ElementRemoved {
+        ref_string,
+        original_description,
+        path,
+        similar_elements,
+    } = &error
+    else {
+        panic!("Expected ElementRemoved, got {error:?}");
+    };
+
+    // The previous snapshot is gone too, so there is no original description
+    assert_eq!(ref_string, "c0p0f0e1");
+    assert!(original_description.is_none());
+    assert!(path.is_none());
+    assert!(similar_elements.is_empty());
+}
+
+#[test]
+fn test_update_after_clear_tracks_again() {
+    let mut detector = StaleRefDetector::new();
+    detector.clear();
+
+    detector.update(&page());
+
+    assert_eq!(detector.ref_count(), 2);
+    assert!(
+        detector
+            .validate_ref(&ElementRef::new("c0p0f0e2"), None)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_removed_element_reports_last_path() {
+    let mut detector = StaleRefDetector::new();
+    detector.update(&page());
+    detector.update(&SnapshotElement::new("document"));
+
+    let error = detector
+        .validate_ref(&ElementRef::new("c0p0f0e2"), None)
+        .unwrap_err();
+    let StaleRefError::ElementRemoved { path, .. } = &error else {
+        panic!("Expected ElementRemoved, got {error:?}");
+    };
+
+    assert_eq!(path.as_deref(), Some("document > button[2]"));
+    assert!(
+        error
+            .to_string()
+            .contains("Last seen at: document > button[2]")
+    );
+}
+
+#[test]
+fn test_changed_element_reports_path() {
+    let mut detector = StaleRefDetector::new();
+    detector.update(&page());
+    detector.update(
+        &SnapshotElement::new("document").with_child(
+            SnapshotElement::new("main").with_child(
+                SnapshotElement::new("link")
+                    .with_name("Save")
+                    .with_ref(ElementRef::new("c0p0f0e1")),
+            ),
+        ),
+    );
+
+    let result = detector.validate_ref(&ElementRef::new("c0p0f0e1"), None);
+    let Err(StaleRefError::ElementChanged { path, .. }) = result else {
+        panic!("Expected ElementChanged, got {result:?}");
+    };
+    assert_eq!(path, "document > main > link");
+}
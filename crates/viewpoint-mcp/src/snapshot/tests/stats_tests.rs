@@ -0,0 +1,66 @@
+//! Unit tests for snapshot element statistics
+
+use crate::snapshot::capture::AccessibilitySnapshot;
+use crate::snapshot::element::SnapshotElement;
+
+/// Page with a heading, two buttons, a link, and a list with one item
+fn page() -> AccessibilitySnapshot {
+    let root = SnapshotElement::new("document")
+        .with_child(SnapshotElement::new("heading").with_name("Title"))
+        .with_child(SnapshotElement::new("button").with_name("Save"))
+        .with_child(SnapshotElement::new("button").with_name("Cancel"))
+        .with_child(SnapshotElement::new("link").with_name("Help"))
+        .with_child(SnapshotElement::new("list").with_child(SnapshotElement::new("listitem")));
+    AccessibilitySnapshot::from_root(root, None, false)
+}
+
+#[test]
+fn test_count_by_role() {
+    let counts = page().count_by_role();
+
+    assert_eq!(counts["button"], 2);
+    assert_eq!(counts["link"], 1);
+    assert_eq!(counts["heading"], 1);
+    assert_eq!(counts["document"], 1);
+    assert!(!counts.contains_key("textbox"));
+    assert_eq!(counts.values().sum::<usize>(), page().element_count());
+}
+
+#[test]
+fn test_interactive_element_count_includes_both_tiers() {
+    // Two buttons and a link (always) plus a list item (contextually)
+    assert_eq!(page().interactive_element_count(), 4);
+}
+
+#[test]
+fn test_element_counts_summary_orders_by_count_then_role() {
+    assert_eq!(
+        page().element_counts_summary(),
+        "Element counts: 4 interactive of 7 total \
+         (button: 2, document: 1, heading: 1, link: 1, list: 1, listitem: 1)"
+    );
+}
+
+#[test]
+fn test_role_stats_groups_non_interactive_as_other() {
+    // document, heading, and list are not interactive
+    assert_eq!(
+        page().role_stats(),
+        "[Stats: 2 buttons, 1 link, 1 listitem, 3 other]"
+    );
+}
+
+#[test]
+fn test_role_stats_pluralizes_box_roles() {
+    let root = SnapshotElement::new("document")
+        .with_child(SnapshotElement::new("textbox"))
+        .with_child(SnapshotElement::new("textbox"))
+        .with_child(SnapshotElement::new("checkbox"))
+        .with_child(SnapshotElement::new("checkbox"));
+    let snapshot = AccessibilitySnapshot::from_root(root, None, false);
+
+    assert_eq!(
+        snapshot.role_stats(),
+        "[Stats: 2 checkboxes, 2 textboxes, 1 other]"
+    );
+}
@@ -0,0 +1,135 @@
+//! Browser accessibility tree diff tool for checking what an action changed
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{ContentItem, Tool, ToolError, ToolLookup, ToolResult};
+use crate::browser::BrowserState;
+use crate::snapshot::{AccessibilitySnapshot, SnapshotDiff, SnapshotElement, SnapshotOptions};
+
+/// Browser accessibility tree diff tool - runs another tool and reports how
+/// the accessibility tree changed
+pub struct BrowserAccessibilityTreeDiffTool {
+    /// Registry the wrapped tool is looked up in
+    tools: ToolLookup,
+}
+
+/// Input parameters for `browser_accessibility_tree_diff`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserAccessibilityTreeDiffInput {
+    /// Name of the tool to run, e.g. `browser_click`
+    pub tool: String,
+
+    /// Arguments for the wrapped tool
+    #[serde(default = "empty_args")]
+    pub args: Value,
+}
+
+fn empty_args() -> Value {
+    json!({})
+}
+
+impl BrowserAccessibilityTreeDiffTool {
+    /// Create a diff tool that can wrap any tool available in the registry
+    /// behind `tools`.
+    ///
+    /// The wrapped tool is looked up on each call, so tools registered and
+    /// capabilities enabled after this tool was created can be wrapped too.
+    #[must_use]
+    pub const fn new(tools: ToolLookup) -> Self {
+        Self { tools }
+    }
+
+    /// Capture the active page's accessibility tree
+    async fn capture(browser: &BrowserState) -> Result<SnapshotElement, ToolError> {
+        let context = browser
+            .active_context()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        let snapshot = AccessibilitySnapshot::capture(&page, SnapshotOptions::default())
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+        Ok(snapshot.root().clone())
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserAccessibilityTreeDiffTool {
+    fn name(&self) -> &'static str {
+        "browser_accessibility_tree_diff"
+    }
+
+    fn description(&self) -> &'static str {
+        "Run another browser tool and report how the accessibility tree changed. Captures a \
+         snapshot before and after the wrapped tool call and returns the tool's output followed \
+         by the elements that were added, removed, or changed (e.g. aria-expanded flipping \
+         after clicking a toggle)."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["inspection"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["tool"],
+            "properties": {
+                "tool": {
+                    "type": "string",
+                    "description": "Name of the tool to run, e.g. 'browser_click'"
+                },
+                "args": {
+                    "type": "object",
+                    "default": {},
+                    "description": "Arguments to pass to the wrapped tool"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserAccessibilityTreeDiffInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if input.tool == self.name() {
+            return Err(ToolError::InvalidParams(format!(
+                "{} cannot wrap itself",
+                self.name()
+            )));
+        }
+        let tool = self.tools.get(&input.tool).ok_or_else(|| {
+            ToolError::InvalidParams(format!("Unknown or unavailable tool '{}'", input.tool))
+        })?;
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let before = Self::capture(browser).await?;
+        let mut output = tool.execute(&input.args, browser).await?;
+
+        // The wrapped tool may have closed the page; its output is still worth returning
+        let diff = match Self::capture(browser).await {
+            Ok(after) => SnapshotDiff::compute(&before, &after).format(),
+            Err(e) => format!(
+                "Could not capture the accessibility tree after {}: {e}",
+                input.tool
+            ),
+        };
+        output.content.push(ContentItem::text(diff));
+
+        Ok(output)
+    }
+}
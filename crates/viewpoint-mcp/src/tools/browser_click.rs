@@ -3,7 +3,8 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
-use viewpoint_core::MouseButton;
+use viewpoint_core::{MouseButton, Page};
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -34,6 +35,14 @@ pub struct BrowserClickInput {
     /// Modifier keys to hold during click
     #[serde(default)]
     pub modifiers: Vec<ModifierKey>,
+
+    /// Only check that the element could be clicked, without clicking it
+    #[serde(default)]
+    pub trial: bool,
+
+    /// Skip actionability checks and dispatch the click directly
+    #[serde(default)]
+    pub force: bool,
 }
 
 /// Mouse button for click
@@ -92,6 +101,60 @@ fn modifiers_to_bitmask(modifiers: &[ModifierKey]) -> i32 {
     modifiers.iter().fold(0, |acc, m| acc | m.to_cdp_modifier())
 }
 
+/// Reason a trial click would not reach the element, or `None` if it would.
+///
+/// Expects the element to already be scrolled into view. The click point is
+/// the element's center, matching where a real click lands. Hit testing
+/// descends into open shadow roots, so elements inside web components are
+/// not mistaken for being covered by their host.
+async fn trial_click_blocker(page: &Page, element_ref: &str) -> Result<Option<String>, String> {
+    let script = js! {
+        (() => {
+            const style = window.getComputedStyle(element);
+            const rect = element.getBoundingClientRect();
+            if (style.visibility === "hidden" || style.display === "none"
+                || rect.width === 0 || rect.height === 0) {
+                return "is not visible";
+            }
+            if (element.matches(":disabled") || element.getAttribute("aria-disabled") === "true") {
+                return "is disabled";
+            }
+            const x = rect.left + rect.width / 2;
+            const y = rect.top + rect.height / 2;
+            let hit = element.ownerDocument.elementFromPoint(x, y);
+            if (!hit) {
+                return "is outside the viewport";
+            }
+            while (hit.shadowRoot) {
+                const inner = hit.shadowRoot.elementFromPoint(x, y);
+                if (!inner || inner === hit) {
+                    break;
+                }
+                hit = inner;
+            }
+            let within = false;
+            for (let node = hit; node; node = node.parentNode || node.host) {
+                if (node === element) {
+                    within = true;
+                    break;
+                }
+            }
+            if (!within) {
+                const id = hit.id ? "#" + hit.id : "";
+                return "is obscured by <" + hit.tagName.toLowerCase() + id + ">";
+            }
+            return null;
+        })()
+    };
+
+    let blocker: Value = page
+        .locator_from_ref(element_ref)
+        .evaluate(&script)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(blocker.as_str().map(String::from))
+}
+
 impl BrowserClickTool {
     /// Create a new browser click tool
     #[must_use]
@@ -116,7 +179,13 @@ impl Tool for BrowserClickTool {
     #[allow(clippy::unnecessary_literal_bound)]
     fn description(&self) -> &'static str {
         "Click an element on the page using its ref from browser_snapshot. \
-         Supports left/right/middle click, double-click, and modifier keys."
+         Supports left/right/middle click, double-click, and modifier keys. \
+         Use trial: true to check that the element is clickable without clicking it. \
+         force: true skips actionability checks; use it only as a last resort."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -150,6 +219,16 @@ impl Tool for BrowserClickTool {
                         "enum": ["Alt", "Control", "ControlOrMeta", "Meta", "Shift"]
                     },
                     "description": "Modifier keys to hold during click"
+                },
+                "trial": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Dry run: scroll the element into view and check it is visible, enabled, and not covered by another element, without clicking"
+                },
+                "force": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Skip actionability checks (visible, attached, stable, enabled, not obscured) and dispatch the click directly. Last resort: use only when the element is confirmed visible to the user and a normal click fails"
                 }
             }
         })
@@ -160,6 +239,13 @@ impl Tool for BrowserClickTool {
         let input: BrowserClickInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
+        if input.trial && input.force {
+            return Err(ToolError::InvalidParams(
+                "trial and force cannot be combined: a trial click runs the checks that force skips"
+                    .to_string(),
+            ));
+        }
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -190,6 +276,38 @@ impl Tool for BrowserClickTool {
 
         // Use native ref resolution API from viewpoint
         let locator = page.locator_from_ref(&input.element_ref);
+        let element_desc = input.element.as_deref().unwrap_or("element");
+
+        if input.trial {
+            locator.scroll_into_view_if_needed().await.map_err(|e| {
+                ToolError::ExecutionFailed(format!(
+                    "Failed to scroll element '{element_desc}' into view: {e}"
+                ))
+            })?;
+
+            let blocker = trial_click_blocker(&page, &input.element_ref)
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to check element '{element_desc}' [ref={}]: {e}",
+                        input.element_ref
+                    ))
+                })?;
+            if let Some(blocker) = blocker {
+                return Err(ToolError::ExecutionFailed(format!(
+                    "Element '{element_desc}' [ref={}] {blocker}, so a click would fail",
+                    input.element_ref
+                )));
+            }
+
+            // Scrolling may trigger lazy loading
+            context.invalidate_cache();
+
+            return Ok(ToolOutput::text(format!(
+                "Trial click succeeded: {element_desc} [ref={}] is clickable (not clicked)",
+                input.element_ref
+            )));
+        }
 
         // Build the click operation with button and modifier support
         let modifiers_bitmask = modifiers_to_bitmask(&input.modifiers);
@@ -202,6 +320,9 @@ impl Tool for BrowserClickTool {
             if modifiers_bitmask != 0 {
                 builder = builder.modifiers(modifiers_bitmask);
             }
+            if input.force {
+                builder = builder.force(true);
+            }
             builder.await
         } else {
             // Single click with button and modifiers
@@ -212,6 +333,9 @@ impl Tool for BrowserClickTool {
             if modifiers_bitmask != 0 {
                 builder = builder.modifiers(modifiers_bitmask);
             }
+            if input.force {
+                builder = builder.force(true);
+            }
             builder.await
         };
 
@@ -220,17 +344,19 @@ impl Tool for BrowserClickTool {
                 // Invalidate cache after successful click (DOM may have changed)
                 context.invalidate_cache();
 
-                let element_desc = input.element.as_deref().unwrap_or("element");
+                let forced = if input.force {
+                    " (forced, actionability checks skipped)"
+                } else {
+                    ""
+                };
                 Ok(ToolOutput::text(format!(
-                    "Clicked {} [ref={}]",
+                    "Clicked {} [ref={}]{forced}",
                     element_desc, input.element_ref
                 )))
             }
             Err(e) => Err(ToolError::ExecutionFailed(format!(
                 "Failed to click element '{}' [ref={}]: {}. The element may have changed since the snapshot.",
-                input.element.as_deref().unwrap_or("element"),
-                input.element_ref,
-                e
+                element_desc, input.element_ref, e
             ))),
         }
     }
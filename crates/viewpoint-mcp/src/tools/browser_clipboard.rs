@@ -0,0 +1,151 @@
+//! Browser clipboard tool for reading and writing clipboard text
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use viewpoint_js::js;
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+/// Browser clipboard tool - reads or writes the clipboard via the page
+pub struct BrowserClipboardTool;
+
+/// Input parameters for `browser_clipboard`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserClipboardInput {
+    /// Action to perform: "read" or "write"
+    pub action: ClipboardAction,
+
+    /// Text to write (required for write)
+    pub text: Option<String>,
+}
+
+/// Clipboard actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipboardAction {
+    /// Read text from the clipboard
+    Read,
+    /// Write text to the clipboard
+    Write,
+}
+
+impl BrowserClipboardTool {
+    /// Create a new browser clipboard tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserClipboardTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserClipboardTool {
+    fn name(&self) -> &'static str {
+        "browser_clipboard"
+    }
+
+    fn description(&self) -> &'static str {
+        "Read or write clipboard text using the page's Clipboard API. Use action 'write' \
+         with text to copy, or 'read' to get the current clipboard text. The page must be \
+         a secure context (https or localhost)."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["state"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["action"],
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["read", "write"],
+                    "description": "Whether to read from or write to the clipboard"
+                },
+                "text": {
+                    "type": "string",
+                    "description": "Text to write to the clipboard (required for write)"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserClipboardInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if input.action == ClipboardAction::Write && input.text.is_none() {
+            return Err(ToolError::InvalidParams(
+                "text is required for write action".to_string(),
+            ));
+        }
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active page
+        let context = browser
+            .active_context()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        let available: bool = page
+            .evaluate(&js! { !!(navigator.clipboard && window.isSecureContext) })
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to check clipboard support: {e}"))
+            })?;
+        if !available {
+            return Err(ToolError::ExecutionFailed(
+                "Clipboard API is unavailable on this page (requires a secure context such as \
+                 https or localhost)"
+                    .to_string(),
+            ));
+        }
+
+        if input.action == ClipboardAction::Read {
+            let text: String = page
+                .evaluate(&js! { navigator.clipboard.readText() })
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Failed to read clipboard: {e}"))
+                })?;
+
+            if text.is_empty() {
+                return Ok(ToolOutput::text("(clipboard is empty)"));
+            }
+            return Ok(ToolOutput::text(text));
+        }
+
+        let text = input.text.unwrap_or_default();
+        page.evaluate::<Value>(&js! {
+            navigator.clipboard.writeText(#{text}).then(() => null)
+        })
+        .await
+        .map_err(|e| ToolError::ExecutionFailed(format!("Failed to write clipboard: {e}")))?;
+
+        Ok(ToolOutput::text(format!(
+            "Wrote {} characters to the clipboard",
+            text.chars().count()
+        )))
+    }
+}
@@ -14,7 +14,14 @@ pub struct BrowserCloseTool;
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowserCloseInput {
-    // No required parameters - closes the current active page
+    /// Close every page in this context instead of only the active page.
+    /// The context itself, with its cookies and storage, stays open.
+    pub context: Option<String>,
+
+    /// Close every tab in the active context, then open a blank tab so the
+    /// context stays usable
+    #[serde(default)]
+    pub all_tabs: bool,
 }
 
 impl BrowserCloseTool {
@@ -39,21 +46,48 @@ impl Tool for BrowserCloseTool {
 
     fn description(&self) -> &'static str {
         "Close the current page. If there are multiple pages open, this closes only the \
-         active page. The browser context remains open with any remaining pages."
+         active page; the last page of a context cannot be closed this way. Set allTabs: true \
+         to close every tab in the active context and start over with a single blank tab. \
+         Pass a context name to close all of that context's pages while keeping its cookies \
+         and storage; use browser_navigate afterwards to open a fresh page in it."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["management"]
+    }
+
+    fn requires_browser(&self) -> bool {
+        false
     }
 
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
-            "properties": {}
+            "properties": {
+                "context": {
+                    "type": "string",
+                    "description": "Name of a context whose pages should all be closed. The context stays open with its cookies and storage."
+                },
+                "allTabs": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Close every tab in the active context, then open a new blank tab so the context remains usable. Cannot be combined with context."
+                }
+            }
         })
     }
 
     async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
-        // Parse input (validates the JSON structure even though there are no params)
-        let _input: BrowserCloseInput = serde_json::from_value(args.clone())
+        // Parse input
+        let input: BrowserCloseInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
+        if input.all_tabs && input.context.is_some() {
+            return Err(ToolError::InvalidParams(
+                "allTabs cannot be combined with context".to_string(),
+            ));
+        }
+
         // Ensure browser is initialized
         if !browser.is_initialized() {
             return Err(ToolError::BrowserNotAvailable(
@@ -61,11 +95,40 @@ impl Tool for BrowserCloseTool {
             ));
         }
 
+        if let Some(name) = input.context {
+            let context = browser.get_context_mut(&name).map_err(|e| {
+                ToolError::ExecutionFailed(format!("Context '{name}' not found: {e}"))
+            })?;
+
+            let closed = context.close_all_pages().await.map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to close pages in '{name}': {e}"))
+            })?;
+
+            return Ok(ToolOutput::text(format!(
+                "Closed {closed} page(s) in context '{name}'; the context remains open \
+                 with its cookies and storage"
+            )));
+        }
+
         // Get active context and page info before closing
         let context = browser
             .active_context_mut()
             .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
 
+        if input.all_tabs {
+            let closed = context
+                .close_all_pages()
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Failed to close tabs: {e}")))?;
+            context.new_page().await.map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to open a blank tab: {e}"))
+            })?;
+
+            return Ok(ToolOutput::text(format!(
+                "Closed {closed} tab(s) and opened a new blank tab"
+            )));
+        }
+
         let page_count = context
             .page_count()
             .await
@@ -80,6 +143,11 @@ impl Tool for BrowserCloseTool {
                 "No pages to close".to_string(),
             ));
         }
+        if page_count == 1 {
+            return Err(ToolError::ExecutionFailed(
+                "Cannot close last tab".to_string(),
+            ));
+        }
 
         // Close the active page
         context
@@ -94,14 +162,9 @@ impl Tool for BrowserCloseTool {
         let url_info = current_url.map(|u| format!(" ({u})")).unwrap_or_default();
 
         let remaining = page_count - 1;
-        let remaining_info = if remaining > 0 {
-            format!(", {remaining} page(s) remaining")
-        } else {
-            ", no pages remaining".to_string()
-        };
 
         Ok(ToolOutput::text(format!(
-            "Closed page{url_info}{remaining_info}"
+            "Closed page{url_info}, {remaining} page(s) remaining"
         )))
     }
 }
@@ -3,9 +3,11 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
-use crate::browser::{BrowserState, ConsoleLevel as BrowserConsoleLevel};
+use crate::browser::{BrowserState, ConsoleLevel as BrowserConsoleLevel, StoredConsoleMessage};
 
 /// Browser console messages tool - retrieves console log messages
 pub struct BrowserConsoleMessagesTool;
@@ -38,6 +40,16 @@ impl ConsoleLevel {
     }
 }
 
+/// Output format for console messages
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ConsoleOutputFormat {
+    /// One human-readable line per message
+    Text,
+    /// JSON array of structured message objects
+    Json,
+}
+
 /// Input parameters for `browser_console_messages`
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -45,6 +57,71 @@ pub struct BrowserConsoleMessagesInput {
     /// Minimum log level to include
     #[serde(default)]
     pub level: ConsoleLevel,
+
+    /// Output format; when omitted, messages are listed as the original JSON array
+    pub format: Option<ConsoleOutputFormat>,
+}
+
+/// Structured form of a console message.
+///
+/// `time_origin` is the page's navigation start in milliseconds since epoch;
+/// `timestamp_ms` is relative to it, matching the `DevTools` convention.
+#[must_use]
+pub fn message_to_json(message: &StoredConsoleMessage, time_origin: f64) -> Value {
+    json!({
+        "timestamp_ms": (message.timestamp - time_origin).round(),
+        "level": message.message_type.to_string(),
+        "text": message.text,
+        "source_url": message.url,
+        "line_number": message.line_number,
+    })
+}
+
+/// Human-readable form of a console message, e.g. `[+12ms] [error] Boom (app.js:42)`
+#[must_use]
+pub fn message_to_text(message: &StoredConsoleMessage, time_origin: f64) -> String {
+    use std::fmt::Write;
+
+    let mut line = format!(
+        "[+{}ms] [{}] {}",
+        (message.timestamp - time_origin).round(),
+        message.message_type,
+        message.text
+    );
+    match (&message.url, message.line_number) {
+        (Some(url), Some(line_number)) if !url.is_empty() => {
+            let _ = write!(line, " ({url}:{line_number})");
+        }
+        (Some(url), None) if !url.is_empty() => {
+            let _ = write!(line, " ({url})");
+        }
+        _ => {}
+    }
+    line
+}
+
+/// The page's navigation start in milliseconds since epoch.
+///
+/// Falls back to `performance.timing.navigationStart` where `timeOrigin` is
+/// missing; returns `None` if neither is available or the page cannot run
+/// scripts.
+async fn navigation_start(page: &Page) -> Option<f64> {
+    let origin: Value = page
+        .evaluate(&js! {
+            (() => {
+                if (typeof performance === "undefined") {
+                    return null;
+                }
+                if (typeof performance.timeOrigin === "number" && performance.timeOrigin > 0) {
+                    return performance.timeOrigin;
+                }
+                const timing = performance.timing;
+                return timing && timing.navigationStart > 0 ? timing.navigationStart : null;
+            })()
+        })
+        .await
+        .ok()?;
+    origin.as_f64()
 }
 
 impl BrowserConsoleMessagesTool {
@@ -73,6 +150,10 @@ impl Tool for BrowserConsoleMessagesTool {
          includes log), 'debug' (all messages)."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["inspection"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -82,6 +163,11 @@ impl Tool for BrowserConsoleMessagesTool {
                     "enum": ["error", "warning", "info", "debug"],
                     "default": "info",
                     "description": "Minimum log level to include. Each level includes more severe levels."
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["text", "json"],
+                    "description": "Output format: 'text' (one line per message) or 'json' (array of { timestamp_ms, level, text, source_url, line_number }). Timestamps are milliseconds since navigation start. When omitted, messages are listed as a JSON array of { type, text, timestamp, url, lineNumber }."
                 }
             }
         })
@@ -108,11 +194,39 @@ impl Tool for BrowserConsoleMessagesTool {
             ToolError::BrowserNotAvailable("No active page for console messages".to_string())
         })?;
 
+        // Timestamps are reported relative to the page's navigation start
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+        let time_origin = if input.format.is_some() {
+            navigation_start(&page).await
+        } else {
+            None
+        };
+
         // Read messages from buffer
         let buffer = console_buffer.read().await;
         let browser_level = input.level.to_browser_level();
         let messages = buffer.get_messages(browser_level);
 
+        // Without a navigation start, times are relative to the oldest message
+        let time_origin = time_origin
+            .or_else(|| messages.first().map(|m| m.timestamp))
+            .unwrap_or_default();
+
+        if input.format == Some(ConsoleOutputFormat::Json) {
+            let messages_json: Vec<Value> = messages
+                .iter()
+                .map(|m| message_to_json(m, time_origin))
+                .collect();
+            let output = serde_json::to_string_pretty(&messages_json).map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to serialize messages: {e}"))
+            })?;
+            return Ok(ToolOutput::text(output));
+        }
+
         // Format the output
         let level_str = match input.level {
             ConsoleLevel::Error => "error",
@@ -127,24 +241,31 @@ impl Tool for BrowserConsoleMessagesTool {
             )));
         }
 
-        // Format messages as JSON array for structured output
-        let messages_json: Vec<_> = messages
-            .iter()
-            .map(|m| {
-                json!({
-                    "type": m.message_type.to_string(),
-                    "text": m.text,
-                    "timestamp": m.timestamp,
-                    "url": m.url,
-                    "lineNumber": m.line_number,
+        let body = if input.format == Some(ConsoleOutputFormat::Text) {
+            let lines: Vec<String> = messages
+                .iter()
+                .map(|m| message_to_text(m, time_origin))
+                .collect();
+            lines.join("\n")
+        } else {
+            // Format messages as JSON array for structured output
+            let messages_json: Vec<_> = messages
+                .iter()
+                .map(|m| {
+                    json!({
+                        "type": m.message_type.to_string(),
+                        "text": m.text,
+                        "timestamp": m.timestamp,
+                        "url": m.url,
+                        "lineNumber": m.line_number,
+                    })
                 })
-            })
-            .collect();
+                .collect();
+            serde_json::to_string_pretty(&messages_json).unwrap_or_else(|_| "[]".to_string())
+        };
 
         Ok(ToolOutput::text(format!(
-            "Console messages (level >= {}):\n\n{}",
-            level_str,
-            serde_json::to_string_pretty(&messages_json).unwrap_or_else(|_| "[]".to_string())
+            "Console messages (level >= {level_str}):\n\n{body}"
         )))
     }
 }
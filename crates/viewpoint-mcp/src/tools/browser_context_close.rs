@@ -43,6 +43,10 @@ impl Tool for BrowserContextCloseTool {
          If the closed context was active, switches to the default context."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -6,7 +6,7 @@ use serde_json::{Value, json};
 use viewpoint_core::ProxyConfig;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
-use crate::browser::BrowserState;
+use crate::browser::{BrowserState, ContextOptions, ViewportSize};
 
 /// Browser context create tool - creates a new isolated browser context
 pub struct BrowserContextCreateTool;
@@ -23,6 +23,24 @@ pub struct BrowserContextCreateInput {
 
     /// Optional path to JSON file with cookies/localStorage
     pub storage_state: Option<String>,
+
+    /// Optional inline storage state object (alternative to `storage_state`).
+    ///
+    /// Rejected until storage state can be restored, since restoring it would
+    /// otherwise mean writing its cookies to disk
+    pub storage_state_json: Option<Value>,
+
+    /// Optional user-agent string override
+    pub user_agent: Option<String>,
+
+    /// Optional locale as a BCP 47 tag (e.g., "de-DE")
+    pub locale: Option<String>,
+
+    /// Optional IANA timezone ID (e.g., "Europe/Berlin")
+    pub timezone: Option<String>,
+
+    /// Optional viewport size for this context's pages
+    pub viewport: Option<ViewportSize>,
 }
 
 /// Proxy configuration input
@@ -75,6 +93,10 @@ impl Tool for BrowserContextCreateTool {
          The new context becomes the active context."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -106,6 +128,36 @@ impl Tool for BrowserContextCreateTool {
                 "storageState": {
                     "type": "string",
                     "description": "Path to JSON file with cookies/localStorage to restore"
+                },
+                "storageStateJson": {
+                    "type": "object",
+                    "description": "Storage state to restore, passed inline in the same format as a \
+                                   browser_context_save_storage file ({ cookies, origins }). \
+                                   Not supported yet: restoring storage state is not implemented, \
+                                   so passing it is an error"
+                },
+                "userAgent": {
+                    "type": "string",
+                    "description": "Optional user-agent string reported by pages in this context"
+                },
+                "locale": {
+                    "type": "string",
+                    "description": "Optional BCP 47 locale (e.g., 'de-DE') used for navigator.language, \
+                                   Accept-Language and Intl formatting"
+                },
+                "timezone": {
+                    "type": "string",
+                    "description": "Optional IANA timezone ID (e.g., 'Europe/Berlin') used for dates in this context"
+                },
+                "viewport": {
+                    "type": "object",
+                    "required": ["width", "height"],
+                    "properties": {
+                        "width": { "type": "integer", "minimum": 1 },
+                        "height": { "type": "integer", "minimum": 1 }
+                    },
+                    "description": "Optional viewport size in CSS pixels for pages in this context \
+                                   (e.g., a phone-sized viewport), overriding the browser default"
                 }
             }
         })
@@ -125,6 +177,35 @@ impl Tool for BrowserContextCreateTool {
             ));
         }
 
+        if let Some(ref viewport) = input.viewport
+            && (viewport.width == 0 || viewport.height == 0)
+        {
+            return Err(ToolError::InvalidParams(
+                "Viewport width and height must be positive".to_string(),
+            ));
+        }
+
+        match (&input.storage_state, &input.storage_state_json) {
+            (Some(_), Some(_)) => {
+                return Err(ToolError::InvalidParams(
+                    "storageState and storageStateJson cannot both be provided".to_string(),
+                ));
+            }
+            (None, Some(value)) if !value.is_object() => {
+                return Err(ToolError::InvalidParams(
+                    "storageStateJson must be an object with cookies and origins".to_string(),
+                ));
+            }
+            (None, Some(_)) => {
+                return Err(ToolError::InvalidParams(
+                    "storageStateJson is not supported yet: storage state cannot be restored \
+                     into a new context"
+                        .to_string(),
+                ));
+            }
+            _ => {}
+        }
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -132,11 +213,26 @@ impl Tool for BrowserContextCreateTool {
             .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
 
         // Convert proxy input to ProxyConfig if provided
-        let proxy_config = input.proxy.as_ref().map(ProxyInput::to_proxy_config);
+        let mut options = ContextOptions::new();
+        if let Some(ref proxy) = input.proxy {
+            options = options.with_proxy(proxy.to_proxy_config());
+        }
+        if let Some(ref user_agent) = input.user_agent {
+            options = options.with_user_agent(user_agent);
+        }
+        if let Some(ref locale) = input.locale {
+            options = options.with_locale(locale);
+        }
+        if let Some(ref timezone) = input.timezone {
+            options = options.with_timezone(timezone);
+        }
+        if let Some(ref viewport) = input.viewport {
+            options = options.with_viewport(viewport.clone());
+        }
 
-        // Create the new context with optional proxy configuration
+        // Create the new context with the requested options
         browser
-            .create_context_with_options(&input.name, proxy_config)
+            .create_context_with_options(&input.name, options)
             .await
             .map_err(|e| ToolError::ExecutionFailed(format!("Failed to create context: {e}")))?;
 
@@ -146,6 +242,26 @@ impl Tool for BrowserContextCreateTool {
             let _ = write!(result, " with proxy '{}'", proxy.server);
         }
 
+        if let Some(ref user_agent) = input.user_agent {
+            let _ = write!(result, " with user agent '{user_agent}'");
+        }
+
+        if let Some(ref locale) = input.locale {
+            let _ = write!(result, " with locale '{locale}'");
+        }
+
+        if let Some(ref timezone) = input.timezone {
+            let _ = write!(result, " with timezone '{timezone}'");
+        }
+
+        if let Some(ref viewport) = input.viewport {
+            let _ = write!(
+                result,
+                " with viewport {}x{}",
+                viewport.width, viewport.height
+            );
+        }
+
         // Storage state loading not yet implemented in viewpoint-core
         if input.storage_state.is_some() {
             result.push_str(" (storage state loading not yet implemented)");
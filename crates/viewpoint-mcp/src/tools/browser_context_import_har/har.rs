@@ -0,0 +1,134 @@
+//! HAR (HTTP Archive) parsing for response replay
+//!
+//! Only the fields needed to fulfill a request are read; everything else in
+//! the archive is ignored.
+
+use base64::engine::{Engine as _, general_purpose::STANDARD};
+use serde::Deserialize;
+
+/// Top-level HAR document
+#[derive(Debug, Deserialize)]
+struct HarDocument {
+    log: HarLog,
+}
+
+/// The `log` object holding the recorded entries
+#[derive(Debug, Deserialize)]
+struct HarLog {
+    #[serde(default)]
+    entries: Vec<HarEntry>,
+}
+
+/// A single request/response pair
+#[derive(Debug, Deserialize)]
+struct HarEntry {
+    request: HarRequest,
+    response: HarResponse,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarRequest {
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarResponse {
+    status: u16,
+    #[serde(default)]
+    headers: Vec<HarHeader>,
+    #[serde(default)]
+    content: HarContent,
+}
+
+#[derive(Debug, Deserialize)]
+struct HarHeader {
+    name: String,
+    value: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarContent {
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    encoding: Option<String>,
+}
+
+/// A recorded response to replay for one URL
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HarRoute {
+    /// Request URL the response was recorded for
+    pub url: String,
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers as `(name, value)` pairs
+    pub headers: Vec<(String, String)>,
+    /// Decoded response body
+    pub body: Vec<u8>,
+}
+
+/// Headers that no longer describe the replayed body and must be dropped
+const SKIPPED_HEADERS: &[&str] = &["content-encoding", "content-length", "transfer-encoding"];
+
+/// Parse a HAR document into one route per distinct URL.
+///
+/// When a URL was recorded several times, the first entry wins. Entries
+/// whose URL does not contain `url_filter` are skipped, as are requests that
+/// never received a response (status 0).
+///
+/// # Errors
+///
+/// Returns an error message if the document is not valid HAR JSON or a
+/// base64-encoded body cannot be decoded.
+pub fn parse_har(contents: &str, url_filter: Option<&str>) -> Result<Vec<HarRoute>, String> {
+    let document: HarDocument =
+        serde_json::from_str(contents).map_err(|e| format!("Invalid HAR file: {e}"))?;
+
+    let mut routes: Vec<HarRoute> = Vec::new();
+    for entry in document.log.entries {
+        let url = entry.request.url;
+        if entry.response.status == 0
+            || url_filter.is_some_and(|filter| !url.contains(filter))
+            || routes.iter().any(|route| route.url == url)
+        {
+            continue;
+        }
+
+        let body = decode_body(&entry.response.content)
+            .map_err(|e| format!("Invalid body for '{url}': {e}"))?;
+        let headers = entry
+            .response
+            .headers
+            .into_iter()
+            .filter(|h| {
+                !SKIPPED_HEADERS
+                    .iter()
+                    .any(|skipped| h.name.eq_ignore_ascii_case(skipped))
+            })
+            .map(|h| (h.name, h.value))
+            .collect();
+
+        routes.push(HarRoute {
+            url,
+            status: entry.response.status,
+            headers,
+            body,
+        });
+    }
+
+    Ok(routes)
+}
+
+/// Decode a response body, honoring `encoding: "base64"`
+fn decode_body(content: &HarContent) -> Result<Vec<u8>, String> {
+    let Some(text) = &content.text else {
+        return Ok(Vec::new());
+    };
+
+    match content.encoding.as_deref() {
+        Some("base64") => STANDARD.decode(text).map_err(|e| e.to_string()),
+        _ => Ok(text.clone().into_bytes()),
+    }
+}
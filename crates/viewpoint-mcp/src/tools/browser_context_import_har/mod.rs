@@ -0,0 +1,144 @@
+//! Browser context import HAR tool for replaying recorded network traffic
+
+mod har;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use viewpoint_core::BrowserContext;
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+pub use har::{HarRoute, parse_har};
+
+/// Browser context import HAR tool - replays a HAR file as mocked responses
+pub struct BrowserContextImportHarTool;
+
+/// Input parameters for `browser_context_import_har`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserContextImportHarInput {
+    /// Path to the HAR file to replay
+    pub path: String,
+
+    /// Only replay entries whose URL contains this substring
+    #[serde(alias = "url_filter")]
+    pub url_filter: Option<String>,
+}
+
+/// Fulfill every request for `route.url` in the context with the recorded response
+async fn register_route(context: &BrowserContext, route: HarRoute) -> Result<(), ToolError> {
+    let url = route.url.clone();
+    let recorded = Arc::new(route);
+
+    context
+        .route(url.as_str(), move |intercepted| {
+            let recorded = recorded.clone();
+            async move {
+                let mut response = intercepted.fulfill().status(recorded.status);
+                for (name, value) in &recorded.headers {
+                    response = response.header(name, value);
+                }
+                response.body(recorded.body.clone()).send().await
+            }
+        })
+        .await
+        .map_err(|e| ToolError::ExecutionFailed(format!("Failed to register route '{url}': {e}")))
+}
+
+impl BrowserContextImportHarTool {
+    /// Create a new browser context import HAR tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserContextImportHarTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserContextImportHarTool {
+    fn name(&self) -> &'static str {
+        "browser_context_import_har"
+    }
+
+    fn description(&self) -> &'static str {
+        "Replay a HAR file in the active browser context: every URL recorded in the HAR \
+         is answered with its recorded response instead of hitting the network. \
+         Returns the number of routes registered."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["path"],
+            "properties": {
+                "path": {
+                    "type": "string",
+                    "description": "Path to the HAR file to replay"
+                },
+                "urlFilter": {
+                    "type": "string",
+                    "description": "Only replay entries whose URL contains this substring"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserContextImportHarInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if input.path.trim().is_empty() {
+            return Err(ToolError::InvalidParams("Path cannot be empty".to_string()));
+        }
+
+        let contents = tokio::fs::read_to_string(&input.path).await.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to read HAR file '{}': {e}", input.path))
+        })?;
+        let routes =
+            parse_har(&contents, input.url_filter.as_deref()).map_err(ToolError::InvalidParams)?;
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let count = routes.len();
+        for route in routes {
+            register_route(context.context(), route).await?;
+        }
+
+        // Responses may differ from what the cached snapshot was built from
+        context.invalidate_cache();
+
+        let output = serde_json::to_string(&json!({
+            "routes": count,
+            "context": context.name,
+            "path": input.path,
+            "message": format!(
+                "Registered {count} route(s) from '{}' in context '{}'",
+                input.path, context.name
+            )
+        }))
+        .unwrap_or_else(|_| format!("Registered {count} route(s) from '{}'", input.path));
+        Ok(ToolOutput::text(output))
+    }
+}
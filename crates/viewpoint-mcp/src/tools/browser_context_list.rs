@@ -14,8 +14,9 @@ pub struct BrowserContextListTool;
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowserContextListInput {
-    // No required inputs - this is intentionally empty
-    // but we keep the struct for consistency with other tools
+    /// Include each context's tabs with their URLs and titles
+    #[serde(default)]
+    pub verbose: bool,
 }
 
 impl BrowserContextListTool {
@@ -39,20 +40,31 @@ impl Tool for BrowserContextListTool {
     }
 
     fn description(&self) -> &'static str {
-        "List all browser contexts with their details including name, active status, \
-         page count, current URL, and proxy configuration."
+        "List all browser contexts, oldest first, with their details including name, \
+         active status, page count, current URL, proxy configuration, and age. Set \
+         verbose: true to also list every tab's index, URL, title, and whether it is active."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
     }
 
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
-            "properties": {}
+            "properties": {
+                "verbose": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Add a tabs array to each context with every tab's index, URL, title, and whether it is the active tab"
+                }
+            }
         })
     }
 
     async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
-        // Parse input (even though it's empty, validate it's an object)
-        let _input: BrowserContextListInput = serde_json::from_value(args.clone())
+        // Parse input
+        let input: BrowserContextListInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
         // Ensure browser is initialized
@@ -72,7 +84,7 @@ impl Tool for BrowserContextListTool {
         }
 
         // Build context info list
-        let context_infos: Vec<Value> = contexts
+        let mut context_infos: Vec<Value> = contexts
             .iter()
             .map(|ctx| {
                 json!({
@@ -83,11 +95,34 @@ impl Tool for BrowserContextListTool {
                     "proxy": ctx.proxy.as_ref().map(|p| json!({
                         "server": p.server,
                         "hasAuth": p.username.is_some()
-                    }))
+                    })),
+                    "createdAt": format!("{}s ago", ctx.created_at.elapsed().as_secs())
                 })
             })
             .collect();
 
+        if input.verbose {
+            for (info, ctx) in context_infos.iter_mut().zip(&contexts) {
+                let tabs = browser.list_tabs(&ctx.name).await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to list tabs of context '{}': {e}",
+                        ctx.name
+                    ))
+                })?;
+                info["tabs"] = tabs
+                    .into_iter()
+                    .map(|tab| {
+                        json!({
+                            "tabIndex": tab.index,
+                            "url": tab.url,
+                            "title": tab.title,
+                            "isActiveTab": tab.is_active
+                        })
+                    })
+                    .collect();
+            }
+        }
+
         // Return as formatted JSON
         let result = json!({
             "contexts": context_infos,
@@ -19,6 +19,64 @@ pub struct BrowserContextSaveStorageInput {
 
     /// File path to save the storage state JSON to
     pub path: String,
+
+    /// Only export cookies whose domain matches one of these (subdomains included)
+    pub domains: Option<Vec<String>>,
+
+    /// Whether to export cookies
+    #[serde(default = "default_true")]
+    pub include_cookies: bool,
+
+    /// Whether to export localStorage origins
+    #[serde(default = "default_true")]
+    pub include_local_storage: bool,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+/// Whether a cookie domain falls under `domain`.
+///
+/// Leading dots are ignored, so `.example.com` and `sub.example.com` both
+/// match `example.com`, but `notexample.com` does not.
+fn cookie_domain_matches(cookie_domain: &str, domain: &str) -> bool {
+    let cookie_domain = cookie_domain.trim_start_matches('.').to_ascii_lowercase();
+    let domain = domain.trim_start_matches('.').to_ascii_lowercase();
+    cookie_domain == domain || cookie_domain.ends_with(&format!(".{domain}"))
+}
+
+/// Apply the export options to a serialized storage state.
+///
+/// Excluded sections are written as empty arrays so the file stays loadable,
+/// and an `exported` object records which parts were kept.
+#[must_use]
+pub fn filter_storage_state(mut state: Value, input: &BrowserContextSaveStorageInput) -> Value {
+    if let Some(cookies) = state.get_mut("cookies").and_then(Value::as_array_mut) {
+        if !input.include_cookies {
+            cookies.clear();
+        } else if let Some(ref domains) = input.domains {
+            cookies.retain(|cookie| {
+                let cookie_domain = cookie["domain"].as_str().unwrap_or_default();
+                domains
+                    .iter()
+                    .any(|domain| cookie_domain_matches(cookie_domain, domain))
+            });
+        }
+    }
+
+    if !input.include_local_storage
+        && let Some(origins) = state.get_mut("origins").and_then(Value::as_array_mut)
+    {
+        origins.clear();
+    }
+
+    state["exported"] = json!({
+        "cookies": input.include_cookies,
+        "localStorage": input.include_local_storage,
+        "domains": input.domains,
+    });
+    state
 }
 
 impl BrowserContextSaveStorageTool {
@@ -43,7 +101,13 @@ impl Tool for BrowserContextSaveStorageTool {
 
     fn description(&self) -> &'static str {
         "Save the storage state (cookies and localStorage) of a browser context to a JSON file. \
-         This can be used to persist authentication state for later use."
+         This can be used to persist authentication state for later use. Use domains to export \
+         only cookies for specific sites, and includeCookies/includeLocalStorage to choose \
+         which parts are saved."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
     }
 
     fn input_schema(&self) -> Value {
@@ -58,6 +122,21 @@ impl Tool for BrowserContextSaveStorageTool {
                 "path": {
                     "type": "string",
                     "description": "File path to save the storage state JSON to"
+                },
+                "domains": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Only export cookies for these domains (e.g., ['example.com'], which also matches subdomains). Defaults to all cookies."
+                },
+                "includeCookies": {
+                    "type": "boolean",
+                    "default": true,
+                    "description": "Whether to export cookies"
+                },
+                "includeLocalStorage": {
+                    "type": "boolean",
+                    "default": true,
+                    "description": "Whether to export localStorage for each origin"
                 }
             }
         })
@@ -105,8 +184,18 @@ impl Tool for BrowserContextSaveStorageTool {
             ToolError::ExecutionFailed(format!("Failed to collect storage state: {e}"))
         })?;
 
+        // Keep only the requested parts before saving
+        let storage_state = serde_json::to_value(&storage_state).map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to serialize storage state: {e}"))
+        })?;
+        let storage_state = filter_storage_state(storage_state, &input);
+        let cookie_count = storage_state["cookies"].as_array().map_or(0, Vec::len);
+        let contents = serde_json::to_string_pretty(&storage_state).map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to serialize storage state: {e}"))
+        })?;
+
         // Save to the specified path
-        storage_state.save(&input.path).await.map_err(|e| {
+        tokio::fs::write(&input.path, contents).await.map_err(|e| {
             ToolError::ExecutionFailed(format!(
                 "Failed to save storage state to '{}': {e}",
                 input.path
@@ -117,6 +206,7 @@ impl Tool for BrowserContextSaveStorageTool {
             "saved": true,
             "context": context_name,
             "path": input.path,
+            "cookies": cookie_count,
             "message": format!("Storage state for context '{}' saved to '{}'", context_name, input.path)
         })).unwrap_or_else(|_| format!("Storage state saved to '{}'", input.path));
         Ok(ToolOutput::text(output))
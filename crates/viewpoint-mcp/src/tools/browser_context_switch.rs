@@ -14,8 +14,19 @@ pub struct BrowserContextSwitchTool;
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowserContextSwitchInput {
-    /// Name of the context to switch to
-    pub name: String,
+    /// Name of the context to switch to (takes precedence over `index`)
+    pub name: Option<String>,
+
+    /// Position of the context in creation order, as listed by `browser_context_list`
+    pub index: Option<usize>,
+}
+
+/// Context to switch to, resolved from the input
+enum SwitchTarget {
+    /// Context name
+    Name(String),
+    /// Index in creation order
+    Index(usize),
 }
 
 impl BrowserContextSwitchTool {
@@ -39,18 +50,27 @@ impl Tool for BrowserContextSwitchTool {
     }
 
     fn description(&self) -> &'static str {
-        "Switch to an existing browser context by name. \
+        "Switch to an existing browser context by name, or by index in creation order \
+         (as listed by browser_context_list). \
          The context must have been previously created with browser_context_create."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
-            "required": ["name"],
             "properties": {
                 "name": {
                     "type": "string",
-                    "description": "Name of the browser context to switch to"
+                    "description": "Name of the browser context to switch to. Takes precedence over index."
+                },
+                "index": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Index of the context to switch to, in creation order (0 is the default context)"
                 }
             }
         })
@@ -62,29 +82,58 @@ impl Tool for BrowserContextSwitchTool {
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
         // Validate context name is not empty
-        if input.name.trim().is_empty() {
+        if input
+            .name
+            .as_ref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
             return Err(ToolError::InvalidParams(
                 "Context name cannot be empty".to_string(),
             ));
         }
 
+        let target = match (input.name, input.index) {
+            (Some(name), _) => SwitchTarget::Name(name),
+            (None, Some(index)) => SwitchTarget::Index(index),
+            (None, None) => {
+                return Err(ToolError::InvalidParams(
+                    "Either name or index is required".to_string(),
+                ));
+            }
+        };
+
         // Ensure browser is initialized
         browser
             .initialize()
             .await
             .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
 
+        let name = match target {
+            SwitchTarget::Name(name) => name,
+            SwitchTarget::Index(index) => {
+                let contexts = browser.list_contexts();
+                contexts
+                    .get(index)
+                    .map(|context| context.name.clone())
+                    .ok_or_else(|| {
+                        ToolError::InvalidParams(format!(
+                            "Context index {index} out of range ({} contexts)",
+                            contexts.len()
+                        ))
+                    })?
+            }
+        };
+
         // Get previous context name for the message
         let previous_context = browser.active_context_name().to_string();
 
         // Switch to the specified context
         browser
-            .switch_context(&input.name)
+            .switch_context(&name)
             .map_err(|e| ToolError::ExecutionFailed(format!("Failed to switch context: {e}")))?;
 
         Ok(ToolOutput::text(format!(
-            "Switched from context '{}' to '{}'",
-            previous_context, input.name
+            "Switched from context '{previous_context}' to '{name}'"
         )))
     }
 }
@@ -3,6 +3,7 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -26,6 +27,90 @@ pub struct BrowserDragInput {
 
     /// Target element description
     pub end_element: String,
+
+    /// Dispatch HTML5 drag-and-drop events instead of simulating mouse movement
+    #[serde(default)]
+    pub use_drag_drop: bool,
+}
+
+/// Script firing `dragstart` on `element`.
+///
+/// Evaluates to the drag data as `{ effectAllowed, items: [[type, data], ...] }`,
+/// or `null` if the page cancelled the drag. The data is handed to the target
+/// and source scripts below, which may run in other frames, so no state is
+/// left behind in the page between steps.
+fn drag_start_script() -> String {
+    js! {
+        (() => {
+            const dataTransfer = new DataTransfer();
+            const event = new DragEvent("dragstart", {
+                bubbles: true,
+                cancelable: true,
+                composed: true,
+                dataTransfer
+            });
+            if (!element.dispatchEvent(event)) {
+                return null;
+            }
+            return {
+                effectAllowed: dataTransfer.effectAllowed,
+                items: Array.from(dataTransfer.types).map(type => [type, dataTransfer.getData(type)])
+            };
+        })()
+    }
+}
+
+/// Script firing `dragenter`, `dragover`, and (if accepted) `drop` on `element`
+/// with the data returned by [`drag_start_script`], passed as JSON.
+///
+/// Evaluates to the drop effect, or `"none"` if the target did not accept the
+/// drop by cancelling `dragover`.
+fn drop_script(drag_data: &str) -> String {
+    js! {
+        (() => {
+            const data = JSON.parse(#{drag_data});
+            const dataTransfer = new DataTransfer();
+            for (const [type, value] of data.items) {
+                dataTransfer.setData(type, value);
+            }
+            dataTransfer.effectAllowed = data.effectAllowed;
+            const fire = (type) => {
+                const event = new DragEvent(type, {
+                    bubbles: true,
+                    cancelable: true,
+                    composed: true,
+                    dataTransfer
+                });
+                element.dispatchEvent(event);
+                return event;
+            };
+
+            fire("dragenter");
+            if (!fire("dragover").defaultPrevented) {
+                fire("dragleave");
+                return "none";
+            }
+            fire("drop");
+            return dataTransfer.dropEffect === "none" ? "move" : dataTransfer.dropEffect;
+        })()
+    }
+}
+
+/// Script firing `dragend` on `element` with the given drop effect
+fn drag_end_script(drop_effect: &str) -> String {
+    js! {
+        (() => {
+            const dataTransfer = new DataTransfer();
+            dataTransfer.dropEffect = #{drop_effect};
+            element.dispatchEvent(new DragEvent("dragend", {
+                bubbles: true,
+                cancelable: false,
+                composed: true,
+                dataTransfer
+            }));
+            return null;
+        })()
+    }
 }
 
 impl BrowserDragTool {
@@ -49,7 +134,12 @@ impl Tool for BrowserDragTool {
     }
 
     fn description(&self) -> &'static str {
-        "Perform a drag and drop operation from one element to another."
+        "Perform a drag and drop operation from one element to another. Set useDragDrop \
+         for apps built on the HTML5 drag-and-drop API instead of mouse events."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -72,6 +162,12 @@ impl Tool for BrowserDragTool {
                 "endElement": {
                     "type": "string",
                     "description": "Human-readable description of the target element"
+                },
+                "useDragDrop": {
+                    "type": "boolean",
+                    "description": "Dispatch native HTML5 dragstart, dragover, and drop events \
+                                   instead of simulating mouse movement",
+                    "default": false
                 }
             }
         })
@@ -117,20 +213,50 @@ impl Tool for BrowserDragTool {
         let source = page.locator_from_ref(&input.start_ref);
         let target = page.locator_from_ref(&input.end_ref);
 
-        // Perform drag and drop
-        source.drag_to(&target).await.map_err(|e| {
+        let drag_error = |e: &dyn std::fmt::Display| {
             ToolError::ExecutionFailed(format!(
-                "Failed to drag '{}' to '{}': {}",
-                input.start_element, input.end_element, e
+                "Failed to drag '{}' to '{}': {e}",
+                input.start_element, input.end_element
             ))
-        })?;
+        };
+
+        // Perform drag and drop
+        let mut result = format!(
+            "Dragged {} [ref={}] to {} [ref={}]",
+            input.start_element, input.start_ref, input.end_element, input.end_ref
+        );
+        if input.use_drag_drop {
+            // Each step runs in its element's own frame, so the source and
+            // target may live in different frames
+            let drag_data: Value = source
+                .evaluate(&drag_start_script())
+                .await
+                .map_err(|e| drag_error(&e))?;
+
+            result.push_str(" using HTML5 drag and drop");
+            if drag_data.is_null() {
+                result.push_str(" (source cancelled the drag)");
+            } else {
+                let drop_effect: String = target
+                    .evaluate(&drop_script(&drag_data.to_string()))
+                    .await
+                    .map_err(|e| drag_error(&e))?;
+                let _: Value = source
+                    .evaluate(&drag_end_script(&drop_effect))
+                    .await
+                    .map_err(|e| drag_error(&e))?;
+
+                if drop_effect == "none" {
+                    result.push_str(" (target did not accept the drop)");
+                }
+            }
+        } else {
+            source.drag_to(&target).await.map_err(|e| drag_error(&e))?;
+        }
 
         // Invalidate cache after interaction
         context.invalidate_cache();
 
-        Ok(ToolOutput::text(format!(
-            "Dragged {} [ref={}] to {} [ref={}]",
-            input.start_element, input.start_ref, input.end_element, input.end_ref
-        )))
+        Ok(ToolOutput::text(result))
     }
 }
@@ -0,0 +1,104 @@
+//! Evaluation in every frame of the page
+//!
+//! Frame documents are found in the page's DOM tree, depth-first from the
+//! main document, and the function runs through CDP in the main world of each
+//! one, so every frame uses its own globals and the frames' `eval` is never
+//! used. Cross-origin frames and functions that throw are reported as
+//! `{ url, error }` entries, so one failing frame does not abort the whole
+//! call.
+
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+
+use super::cdp::{evaluate_in_document, send};
+use crate::tools::ToolError;
+
+/// A frame found in the DOM tree
+enum FrameDocument {
+    /// A document that can be evaluated in
+    Reachable { backend_node_id: i64, url: String },
+    /// A frame element whose document is in another process
+    CrossOrigin { url: String },
+}
+
+/// Evaluate a function or expression in the main frame and every nested frame.
+///
+/// Returns an array of `{ url, value }` or `{ url, error }` entries in
+/// document order. Functions are called with no arguments; promises are
+/// awaited.
+pub async fn evaluate_in_all_frames(page: &Page, function: &str) -> Result<Value, ToolError> {
+    let tree = send(
+        page,
+        "DOM.getDocument",
+        json!({ "depth": -1, "pierce": true }),
+    )
+    .await?;
+
+    let mut frames = Vec::new();
+    collect_frames(&tree["root"], &mut frames);
+
+    let mut results = Vec::with_capacity(frames.len());
+    for frame in frames {
+        let entry = match frame {
+            FrameDocument::Reachable {
+                backend_node_id,
+                url,
+            } => match evaluate_in_document(page, backend_node_id, function).await {
+                Ok(value) => json!({ "url": url, "value": value }),
+                Err(ToolError::ExecutionFailed(message)) => {
+                    let message = message
+                        .strip_prefix("JavaScript evaluation failed: ")
+                        .unwrap_or(&message);
+                    json!({ "url": url, "error": message })
+                }
+                Err(e) => json!({ "url": url, "error": e.to_string() }),
+            },
+            FrameDocument::CrossOrigin { url } => {
+                json!({ "url": url, "error": "cross-origin frame cannot be evaluated in" })
+            }
+        };
+        results.push(entry);
+    }
+    Ok(Value::Array(results))
+}
+
+/// Collect the documents under `node` in document order, including those of
+/// frames nested in shadow roots
+fn collect_frames(node: &Value, frames: &mut Vec<FrameDocument>) {
+    if node["nodeName"] == "#document"
+        && let Some(backend_node_id) = node["backendNodeId"].as_i64()
+    {
+        frames.push(FrameDocument::Reachable {
+            backend_node_id,
+            url: node["documentURL"]
+                .as_str()
+                .unwrap_or("about:blank")
+                .to_string(),
+        });
+    }
+
+    let name = node["nodeName"].as_str().unwrap_or_default();
+    if name.eq_ignore_ascii_case("iframe") || name.eq_ignore_ascii_case("frame") {
+        match node.get("contentDocument") {
+            Some(document) => collect_frames(document, frames),
+            None => frames.push(FrameDocument::CrossOrigin {
+                url: frame_src(node).unwrap_or("about:blank").to_string(),
+            }),
+        }
+    }
+
+    for key in ["shadowRoots", "children"] {
+        for child in node[key].as_array().into_iter().flatten() {
+            collect_frames(child, frames);
+        }
+    }
+}
+
+/// The `src` attribute of a frame element, from CDP's flat attribute list
+fn frame_src(node: &Value) -> Option<&str> {
+    let attributes = node["attributes"].as_array()?;
+    attributes
+        .chunks(2)
+        .find(|pair| pair[0] == "src")
+        .and_then(|pair| pair.get(1)?.as_str())
+}
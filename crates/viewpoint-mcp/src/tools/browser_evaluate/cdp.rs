@@ -0,0 +1,83 @@
+//! CDP helpers shared by the evaluation modes
+//!
+//! Scripts are run through the DevTools protocol in a chosen execution
+//! context, so they use that context's globals without relying on the page's
+//! own `eval`.
+
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use crate::tools::ToolError;
+
+/// Send a CDP command to the page's session
+pub(super) async fn send(page: &Page, method: &str, params: Value) -> Result<Value, ToolError> {
+    page.connection()
+        .send_command::<_, Value>(method, Some(params), Some(page.session_id()))
+        .await
+        .map_err(|e| ToolError::ExecutionFailed(format!("{method} failed: {e}")))
+}
+
+/// Message of the exception a `Runtime` command reported, if any
+pub(super) fn exception_message(response: &Value) -> Option<String> {
+    let details = response.get("exceptionDetails")?;
+    Some(
+        details["exception"]["description"]
+            .as_str()
+            .or_else(|| details["text"].as_str())
+            .unwrap_or("Unknown error")
+            .to_string(),
+    )
+}
+
+/// Wrap a function or expression so that functions are called with no
+/// arguments and other values are returned as they are
+pub(super) fn call_expression(function: &str) -> String {
+    js! {
+        (() => {
+            const value = (@{function});
+            return typeof value === "function" ? value() : value;
+        })()
+    }
+}
+
+/// Evaluate a function or expression in the main world of the frame that
+/// owns the document with `document_backend_node_id`.
+///
+/// Promises are awaited and the result is returned by value.
+pub(super) async fn evaluate_in_document(
+    page: &Page,
+    document_backend_node_id: i64,
+    function: &str,
+) -> Result<Value, ToolError> {
+    // Without an explicit context, the node resolves in its own frame's main world
+    let document = send(
+        page,
+        "DOM.resolveNode",
+        json!({ "backendNodeId": document_backend_node_id }),
+    )
+    .await?;
+    let object_id = document["object"]["objectId"]
+        .as_str()
+        .ok_or_else(|| ToolError::ExecutionFailed("Frame document is not available".to_string()))?;
+
+    let declaration = format!("function() {{ return {}; }}", call_expression(function));
+    let response = send(
+        page,
+        "Runtime.callFunctionOn",
+        json!({
+            "objectId": object_id,
+            "functionDeclaration": declaration,
+            "returnByValue": true,
+            "awaitPromise": true
+        }),
+    )
+    .await?;
+
+    if let Some(message) = exception_message(&response) {
+        return Err(ToolError::ExecutionFailed(format!(
+            "JavaScript evaluation failed: {message}"
+        )));
+    }
+    Ok(response["result"]["value"].clone())
+}
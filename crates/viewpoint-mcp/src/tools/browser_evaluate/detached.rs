@@ -0,0 +1,26 @@
+//! Fire-and-forget evaluation
+//!
+//! The user's function is wrapped in a synchronous function that starts it
+//! and drops the returned promise, so evaluation completes as soon as the
+//! script has been dispatched. Rejections are logged to the page console
+//! instead of surfacing as unhandled errors.
+
+use viewpoint_js::js;
+
+/// Result reported when a promise is deliberately not awaited
+pub const NOT_AWAITED: &str = "<async, not awaited>";
+
+/// Wrap a function or expression so evaluating it does not wait for its promise.
+///
+/// The wrapper forwards its arguments, so it can be used anywhere the
+/// original function could, including element evaluation.
+#[must_use]
+pub fn detach(function: &str) -> String {
+    js! {
+        (...args) => {
+            const value = (@{function});
+            const result = typeof value === "function" ? value(...args) : value;
+            Promise.resolve(result).catch((e) => console.error(e));
+        }
+    }
+}
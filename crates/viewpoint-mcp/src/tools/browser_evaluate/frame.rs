@@ -0,0 +1,82 @@
+//! Evaluation inside an iframe's JavaScript context
+//!
+//! The frame element is located in the main document, and the function runs
+//! through CDP in the main world of the frame's own document, so globals it
+//! reads or writes are those of the frame and the frame's `eval` is never
+//! used. Out-of-process (cross-origin) frames are not reachable this way.
+
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use super::cdp::{evaluate_in_document, exception_message, send};
+use crate::tools::ToolError;
+
+/// Evaluate a function or expression in the frame matched by `selector`.
+///
+/// Functions are called with no arguments; promises are awaited.
+pub async fn evaluate_in_frame(
+    page: &Page,
+    selector: &str,
+    function: &str,
+) -> Result<Value, ToolError> {
+    let found = send(
+        page,
+        "Runtime.evaluate",
+        json!({ "expression": js! { document.querySelector(#{selector}) } }),
+    )
+    .await?;
+    if let Some(message) = exception_message(&found) {
+        return Err(ToolError::ExecutionFailed(format!(
+            "JavaScript evaluation failed: {message}"
+        )));
+    }
+    let Some(object_id) = found["result"]["objectId"].as_str() else {
+        return Err(ToolError::ElementNotFound(format!(
+            "No frame matches selector '{selector}'"
+        )));
+    };
+
+    let described = send(page, "DOM.describeNode", json!({ "objectId": object_id })).await?;
+    let node = &described["node"];
+    let tag = node["nodeName"].as_str().unwrap_or_default().to_lowercase();
+    if tag != "iframe" && tag != "frame" {
+        return Err(ToolError::InvalidParams(format!(
+            "Selector '{selector}' matches a <{tag}> element, not an <iframe>"
+        )));
+    }
+
+    match node["contentDocument"]["backendNodeId"].as_i64() {
+        Some(document) => evaluate_in_document(page, document, function).await,
+        None => Err(unreachable_frame(page, object_id, selector).await),
+    }
+}
+
+/// Explain why the frame element with `object_id` has no reachable document
+async fn unreachable_frame(page: &Page, object_id: &str, selector: &str) -> ToolError {
+    let checked = send(
+        page,
+        "Runtime.callFunctionOn",
+        json!({
+            "objectId": object_id,
+            "functionDeclaration": js! {
+                (function () {
+                    try {
+                        return Boolean(this.contentWindow && this.contentWindow.document);
+                    } catch (e) {
+                        return "crossOrigin";
+                    }
+                })
+            },
+            "returnByValue": true
+        }),
+    )
+    .await;
+
+    match checked {
+        Ok(response) if response["result"]["value"] == "crossOrigin" => ToolError::ExecutionFailed(
+            format!("Frame '{selector}' is cross-origin and cannot be evaluated in"),
+        ),
+        _ => ToolError::ExecutionFailed(format!("Frame '{selector}' has not loaded a document")),
+    }
+}
@@ -0,0 +1,54 @@
+//! Evaluation in an isolated JavaScript world
+//!
+//! An isolated world shares the page's DOM but has its own globals, so the
+//! script can neither read nor pollute the page's JavaScript state. A fresh
+//! world is created for each evaluation.
+
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+
+use super::cdp::{call_expression, exception_message, send};
+use crate::tools::ToolError;
+
+/// Name of the isolated world created for `browser_evaluate`
+const ISOLATED_WORLD_NAME: &str = "viewpoint-mcp-isolated";
+
+/// Evaluate a function or expression in a new isolated world of the main frame.
+///
+/// Functions are called with no arguments; promises are awaited.
+pub async fn evaluate_in_isolated_world(page: &Page, function: &str) -> Result<Value, ToolError> {
+    let frame_tree = send(page, "Page.getFrameTree", json!({})).await?;
+    let frame_id = frame_tree["frameTree"]["frame"]["id"]
+        .as_str()
+        .ok_or_else(|| ToolError::ExecutionFailed("Page has no main frame".to_string()))?;
+
+    let world = send(
+        page,
+        "Page.createIsolatedWorld",
+        json!({ "frameId": frame_id, "worldName": ISOLATED_WORLD_NAME }),
+    )
+    .await?;
+    let context_id = world["executionContextId"]
+        .as_i64()
+        .ok_or_else(|| ToolError::ExecutionFailed("Failed to create isolated world".to_string()))?;
+
+    let response = send(
+        page,
+        "Runtime.evaluate",
+        json!({
+            "expression": call_expression(function),
+            "contextId": context_id,
+            "returnByValue": true,
+            "awaitPromise": true
+        }),
+    )
+    .await?;
+
+    if let Some(message) = exception_message(&response) {
+        return Err(ToolError::ExecutionFailed(format!(
+            "JavaScript evaluation failed: {message}"
+        )));
+    }
+
+    Ok(response["result"]["value"].clone())
+}
@@ -0,0 +1,49 @@
+//! jQuery injection for evaluations written with jQuery selectors
+//!
+//! The bundled copy is compiled into the binary, so injecting it needs no
+//! network access and works on pages whose CSP blocks external scripts. It
+//! should be the unmodified release file from [`JQUERY_URL`], which a test
+//! checks against the integrity hash published with the release.
+
+use serde_json::Value;
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use crate::tools::ToolError;
+
+/// Minified jQuery, MIT licensed (see the header comment)
+pub const JQUERY_SOURCE: &str = include_str!("jquery-3.6.1.min.js");
+
+/// Upstream release the bundled copy should match byte for byte
+pub const JQUERY_URL: &str = "https://code.jquery.com/jquery-3.7.1.min.js";
+
+/// Subresource integrity hash published for [`JQUERY_URL`]
+#[cfg(test)]
+pub const JQUERY_INTEGRITY: &str = "sha256-/JqT3SQfawRcv/BIHPThkBvs0OEvtFFmqPF/lYI/Cxo=";
+
+/// Load the bundled jQuery into the page's main world unless the page
+/// already has `window.jQuery`.
+///
+/// Returns whether jQuery was injected. A page's own jQuery is left in place,
+/// so repeated calls on the same document inject at most once.
+pub async fn ensure_jquery(page: &Page) -> Result<bool, ToolError> {
+    let loaded: Value = page
+        .evaluate(&js! { typeof window.jQuery === "function" })
+        .await
+        .map_err(|e| ToolError::ExecutionFailed(format!("Failed to check for jQuery: {e}")))?;
+    if loaded == Value::Bool(true) {
+        return Ok(false);
+    }
+
+    page.evaluate::<Value>(&js! {
+        (() => {
+            @{JQUERY_SOURCE}
+            return null;
+        })()
+    })
+    .await
+    .map_err(|e| ToolError::ExecutionFailed(format!("Failed to inject jQuery: {e}")))?;
+
+    tracing::debug!(source = JQUERY_URL, "Injected bundled jQuery into the page");
+    Ok(true)
+}
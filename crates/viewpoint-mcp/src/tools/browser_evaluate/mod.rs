@@ -0,0 +1,342 @@
+//! Browser evaluate tool for executing JavaScript in page context
+
+mod all_frames;
+mod cdp;
+mod detached;
+mod frame;
+mod isolated;
+mod jquery;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use viewpoint_js::js;
+
+use super::{ContentItem, Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+
+#[cfg(test)]
+pub(crate) use jquery::{JQUERY_INTEGRITY, JQUERY_SOURCE, JQUERY_URL};
+
+/// Browser evaluate tool - executes JavaScript in page context
+pub struct BrowserEvaluateTool;
+
+/// Input parameters for `browser_evaluate`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserEvaluateInput {
+    /// JavaScript function or expression to execute
+    /// For element evaluation: `(element) => { /* code */ }`
+    /// For page evaluation: `() => { /* code */ }`
+    pub function: String,
+
+    /// Element reference from snapshot (optional)
+    /// When provided, the element will be passed as the first argument to the function
+    #[serde(rename = "ref")]
+    pub element_ref: Option<String>,
+
+    /// Human-readable element description (required if ref is provided)
+    pub element: Option<String>,
+
+    /// Maximum length of the returned result, in characters; longer results
+    /// are truncated instead of split by the server limit
+    pub max_result_length: Option<usize>,
+
+    /// Run in an isolated world that shares the DOM but not the page's globals
+    #[serde(default)]
+    pub isolated_world: bool,
+
+    /// CSS selector of an `<iframe>` to evaluate in instead of the main frame
+    pub frame: Option<String>,
+
+    /// Evaluate in the main frame and every iframe, returning one result per frame
+    #[serde(default)]
+    pub all_frames: bool,
+
+    /// Wait for a returned promise to settle; when false, return immediately
+    /// after dispatching the script
+    #[serde(default = "default_await_promise")]
+    pub await_promise: bool,
+
+    /// Load the bundled jQuery into the page first, if it is not already loaded
+    #[serde(default, rename = "injectJQuery")]
+    pub inject_jquery: bool,
+}
+
+const fn default_await_promise() -> bool {
+    true
+}
+
+/// Truncate a stringified result to at most `max_length` characters.
+///
+/// Truncated results end with a `...[truncated, N chars total]` suffix.
+#[must_use]
+pub fn truncate_result(result: String, max_length: usize) -> String {
+    let total = result.chars().count();
+    if total <= max_length {
+        return result;
+    }
+
+    let truncated: String = result.chars().take(max_length).collect();
+    format!("{truncated}...[truncated, {total} chars total]")
+}
+
+/// Split a stringified result into chunks of at most `chunk_length` characters.
+///
+/// Returns a single chunk when the result fits (or `chunk_length` is zero).
+#[must_use]
+pub fn chunk_result(result: &str, chunk_length: usize) -> Vec<String> {
+    if chunk_length == 0 || result.chars().count() <= chunk_length {
+        return vec![result.to_string()];
+    }
+
+    let chars: Vec<char> = result.chars().collect();
+    chars
+        .chunks(chunk_length)
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+impl BrowserEvaluateTool {
+    /// Create a new browser evaluate tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserEvaluateTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserEvaluateTool {
+    fn name(&self) -> &'static str {
+        "browser_evaluate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Execute JavaScript in the page context. When an element ref is provided, \
+         the function receives that element as its first argument. Returns the \
+         serialized result of the expression. Set isolatedWorld: true to run in a \
+         sandbox that shares the DOM but not the page's global variables, or pass a \
+         frame selector to run inside a same-origin iframe, or set allFrames: true to run in \
+         every frame and get one result per frame URL. Set awaitPromise: false to \
+         start long-running async work without waiting for it to finish. Set injectJQuery: true \
+         to use jQuery's `$` in the function; note that this adds jQuery and `$` to the page's \
+         global scope, replacing any existing `$`, and the page keeps them afterwards."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["state"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["function"],
+            "properties": {
+                "function": {
+                    "type": "string",
+                    "description": "JavaScript function or expression to execute. Use `() => { /* code */ }` for page-level code or `(element) => { /* code */ }` when element ref is provided."
+                },
+                "ref": {
+                    "type": "string",
+                    "description": "Element reference from browser_snapshot. When provided, the element will be passed to the function."
+                },
+                "element": {
+                    "type": "string",
+                    "description": "Human-readable description of the element. Required if ref is provided."
+                },
+                "maxResultLength": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Truncate the result to this many characters to avoid flooding the response. When omitted, results longer than the server limit are returned in full, split into several content items."
+                },
+                "isolatedWorld": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Run in an isolated JavaScript world that shares the DOM but not the page's global variables. Cannot be combined with ref."
+                },
+                "frame": {
+                    "type": "string",
+                    "description": "CSS selector of a same-origin <iframe> whose JavaScript context the function runs in. Cannot be combined with ref or isolatedWorld."
+                },
+                "allFrames": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Run the function in the main frame and every iframe, returning an array of { url, value } entries. Frames that throw or are cross-origin give { url, error } instead of failing the call. Cannot be combined with ref, frame, or isolatedWorld."
+                },
+                "awaitPromise": {
+                    "type": "boolean",
+                    "default": true,
+                    "description": "Wait for a returned promise to settle. When false, the call returns as soon as the script is dispatched and the result is always \"<async, not awaited>\"."
+                },
+                "injectJQuery": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Load a bundled copy of jQuery into the page before running the function, unless the page already has jQuery, so the function can use `$`. Modifies the page's global scope. Cannot be combined with frame, allFrames, or isolatedWorld."
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserEvaluateInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        // Validate that element is provided if ref is provided
+        if input.element_ref.is_some() && input.element.is_none() {
+            return Err(ToolError::InvalidParams(
+                "element description is required when ref is provided".to_string(),
+            ));
+        }
+
+        if input.isolated_world && input.element_ref.is_some() {
+            return Err(ToolError::InvalidParams(
+                "isolatedWorld cannot be combined with ref".to_string(),
+            ));
+        }
+
+        if input.frame.is_some() && (input.element_ref.is_some() || input.isolated_world) {
+            return Err(ToolError::InvalidParams(
+                "frame cannot be combined with ref or isolatedWorld".to_string(),
+            ));
+        }
+
+        if input.all_frames
+            && (input.element_ref.is_some() || input.frame.is_some() || input.isolated_world)
+        {
+            return Err(ToolError::InvalidParams(
+                "allFrames cannot be combined with ref, frame, or isolatedWorld".to_string(),
+            ));
+        }
+
+        if input.inject_jquery
+            && (input.frame.is_some() || input.all_frames || input.isolated_world)
+        {
+            return Err(ToolError::InvalidParams(
+                "injectJQuery cannot be combined with frame, allFrames, or isolatedWorld"
+                    .to_string(),
+            ));
+        }
+
+        let chunk_length = browser.max_evaluate_result_length();
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active page
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        if input.inject_jquery {
+            jquery::ensure_jquery(&page).await?;
+        }
+
+        let function = if input.await_promise {
+            input.function.clone()
+        } else {
+            detached::detach(&input.function)
+        };
+
+        // Execute JavaScript based on whether an element ref is provided
+        let result = if let Some(ref element_ref_str) = input.element_ref {
+            // Capture current snapshot for validation
+            let options = SnapshotOptions::default();
+            let snapshot = AccessibilitySnapshot::capture(&page, options)
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+
+            // Validate the ref exists in the snapshot
+            snapshot.lookup(element_ref_str).map_err(|e| {
+                ToolError::ElementNotFound(format!("Element ref '{element_ref_str}': {e}"))
+            })?;
+
+            // Use native ref resolution API from viewpoint
+            let locator = page.locator_from_ref(element_ref_str);
+
+            // Evaluate with element - the viewpoint API expects expressions using `element`
+            // User provides a function like `(el) => el.textContent`, we need to convert it
+            // to an expression: `((el) => el.textContent)(element)`
+            // Using js! macro with @{} raw interpolation for the user's function
+            let user_fn = &function;
+            let expression = js! { (@{user_fn})(element) };
+
+            locator.evaluate(&expression).await.map_err(|e| {
+                ToolError::ExecutionFailed(format!("JavaScript evaluation failed: {e}"))
+            })?
+        } else if input.all_frames {
+            all_frames::evaluate_in_all_frames(&page, &function).await?
+        } else if let Some(ref selector) = input.frame {
+            frame::evaluate_in_frame(&page, selector, &function).await?
+        } else if input.isolated_world {
+            isolated::evaluate_in_isolated_world(&page, &function).await?
+        } else {
+            // Evaluate without element - page-level evaluation
+            page.evaluate(&function).await.map_err(|e| {
+                ToolError::ExecutionFailed(format!("JavaScript evaluation failed: {e}"))
+            })?
+        };
+
+        // Invalidate cache after potential DOM modifications
+        context.invalidate_cache();
+
+        // Format the result
+        let result_str = match result {
+            _ if !input.await_promise => detached::NOT_AWAITED.to_string(),
+            Value::Null => "null".to_string(),
+            Value::String(s) => s,
+            other => serde_json::to_string_pretty(&other).unwrap_or_else(|_| format!("{other:?}")),
+        };
+        let label = if let Some(element_desc) = input.element {
+            format!(
+                "Evaluated on {} [ref={}]",
+                element_desc,
+                input.element_ref.unwrap_or_default()
+            )
+        } else if input.all_frames {
+            "Evaluation results by frame".to_string()
+        } else if let Some(selector) = input.frame {
+            format!("Evaluation result in frame '{selector}'")
+        } else {
+            "Evaluation result".to_string()
+        };
+
+        // An explicit per-call limit truncates; the server limit splits the
+        // result into several content items so nothing is lost
+        if let Some(max_length) = input.max_result_length {
+            let result_str = truncate_result(result_str, max_length);
+            return Ok(ToolOutput::text(format!("{label}: {result_str}")));
+        }
+
+        let chunks = chunk_result(&result_str, chunk_length.unwrap_or(0));
+        if chunks.len() == 1 {
+            return Ok(ToolOutput::text(format!("{label}: {result_str}")));
+        }
+
+        let total = chunks.len();
+        let mut content = vec![ContentItem::text(format!(
+            "{label}: {} chars, split into {total} chunks",
+            result_str.chars().count()
+        ))];
+        content.extend(chunks.into_iter().enumerate().map(|(i, chunk)| {
+            ContentItem::text(format!("Result (chunk {}/{total}):\n{chunk}", i + 1))
+        }));
+        Ok(ToolOutput::new(content))
+    }
+}
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
 use std::path::Path;
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -17,8 +18,92 @@ pub struct BrowserFileUploadTool;
 pub struct BrowserFileUploadInput {
     /// Absolute paths to the files to upload
     /// If empty or omitted, the file chooser dialog is cancelled
-    #[serde(default)]
+    #[serde(default, alias = "files")]
     pub paths: Vec<String>,
+
+    /// Whether to validate files against the input's `accept` attribute
+    #[serde(default = "default_accept_check")]
+    pub accept_check: bool,
+}
+
+const fn default_accept_check() -> bool {
+    true
+}
+
+/// Known MIME types by lowercase file extension, used for `accept` validation
+const MIME_TYPES: &[(&str, &str)] = &[
+    ("txt", "text/plain"),
+    ("csv", "text/csv"),
+    ("html", "text/html"),
+    ("htm", "text/html"),
+    ("css", "text/css"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("xml", "application/xml"),
+    ("pdf", "application/pdf"),
+    ("zip", "application/zip"),
+    ("doc", "application/msword"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ),
+    ("xls", "application/vnd.ms-excel"),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    ),
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml"),
+    ("bmp", "image/bmp"),
+    ("ico", "image/x-icon"),
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("ogg", "audio/ogg"),
+    ("mp4", "video/mp4"),
+    ("webm", "video/webm"),
+    ("mov", "video/quicktime"),
+];
+
+/// Guess a file's MIME type from its extension
+fn mime_type_for(path: &str) -> Option<&'static str> {
+    let ext = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    MIME_TYPES
+        .iter()
+        .find(|(known, _)| *known == ext)
+        .map(|(_, mime)| *mime)
+}
+
+/// Check whether a file satisfies an `<input accept="">` specification.
+///
+/// The specification is a comma-separated list of extensions (`.pdf`),
+/// MIME types (`image/png`), or MIME wildcards (`image/*`). An empty
+/// specification accepts every file.
+pub fn file_matches_accept(accept: &str, path: &str) -> bool {
+    let tokens: Vec<String> = accept
+        .split(',')
+        .map(|t| t.trim().to_ascii_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if tokens.is_empty() {
+        return true;
+    }
+
+    let lower_path = path.to_ascii_lowercase();
+    let mime = mime_type_for(path);
+
+    tokens.iter().any(|token| {
+        if token.starts_with('.') {
+            lower_path.ends_with(token.as_str())
+        } else if let Some(prefix) = token.strip_suffix("/*") {
+            mime.is_some_and(|m| m.split('/').next() == Some(prefix))
+        } else {
+            mime == Some(token.as_str())
+        }
+    })
 }
 
 impl BrowserFileUploadTool {
@@ -47,6 +132,10 @@ impl Tool for BrowserFileUploadTool {
          chooser dialog is cancelled."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -54,7 +143,12 @@ impl Tool for BrowserFileUploadTool {
                 "paths": {
                     "type": "array",
                     "items": { "type": "string" },
-                    "description": "Absolute paths to the files to upload. If omitted or empty, the file chooser is cancelled."
+                    "description": "Absolute paths to the files to upload. If omitted or empty, the file chooser is cancelled. Also accepted as 'files'."
+                },
+                "acceptCheck": {
+                    "type": "boolean",
+                    "default": true,
+                    "description": "Reject files whose type doesn't match the file input's accept attribute"
                 }
             }
         })
@@ -108,6 +202,24 @@ impl Tool for BrowserFileUploadTool {
             }
         }
 
+        // Validate file types against the input's accept attribute
+        if input.accept_check
+            && let Some(accept) = Self::file_input_accept(&page).await
+        {
+            let rejected: Vec<&str> = input
+                .paths
+                .iter()
+                .filter(|path| !file_matches_accept(&accept, path))
+                .map(String::as_str)
+                .collect();
+            if !rejected.is_empty() {
+                return Err(ToolError::InvalidParams(format!(
+                    "File(s) not accepted by input (accept=\"{accept}\"): {}",
+                    rejected.join(", ")
+                )));
+            }
+        }
+
         // Convert paths to &str slice for the API
         let path_refs: Vec<&str> = input.paths.iter().map(String::as_str).collect();
 
@@ -134,6 +246,22 @@ impl Tool for BrowserFileUploadTool {
 }
 
 impl BrowserFileUploadTool {
+    /// Get the `accept` attribute of the file input that will receive the files.
+    ///
+    /// Returns `None` if there is no file input or it has no `accept` attribute.
+    async fn file_input_accept(page: &viewpoint_core::Page) -> Option<String> {
+        let js_code = js! {
+            (() => {
+                const input = document.querySelector("input[type=file]")
+                    || document.querySelector("input[accept]");
+                return input ? input.getAttribute("accept") : null;
+            })()
+        };
+
+        let result: Value = page.evaluate(&js_code).await.ok()?;
+        result.as_str().map(String::from)
+    }
+
     /// Try multiple strategies to find and set files on a file input element.
     ///
     /// File inputs are often hidden for styling purposes, so we need to try
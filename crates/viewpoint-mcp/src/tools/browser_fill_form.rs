@@ -3,6 +3,8 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -17,6 +19,18 @@ pub struct BrowserFillFormTool;
 pub struct BrowserFillFormInput {
     /// Fields to fill in
     pub fields: Vec<FormField>,
+
+    /// Reload the page to undo already filled fields if a later field fails
+    #[serde(default)]
+    pub rollback_on_failure: bool,
+
+    /// Replace existing textbox values (default); when false, append to them
+    #[serde(default = "default_clear_first")]
+    pub clear_first: bool,
+}
+
+const fn default_clear_first() -> bool {
+    true
 }
 
 /// A single form field to fill
@@ -80,7 +94,13 @@ impl Tool for BrowserFillFormTool {
 
     fn description(&self) -> &'static str {
         "Fill multiple form fields at once. Supports textbox, checkbox, radio, combobox (dropdown), \
-         and slider field types. Each field requires a ref from browser_snapshot."
+         and slider field types. Each field requires a ref from browser_snapshot. Set \
+         rollbackOnFailure to reload the page if a field fails partway through (best-effort; \
+         client-side state that survives a reload is not restored)."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -114,6 +134,19 @@ impl Tool for BrowserFillFormTool {
                             }
                         }
                     }
+                },
+                "rollbackOnFailure": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "If a field fails after others were filled, reload the page to discard the partial fill \
+                                   before returning the error. Best-effort: pages that persist state across reloads \
+                                   (e.g., in localStorage) may not be fully restored."
+                },
+                "clearFirst": {
+                    "type": "boolean",
+                    "default": true,
+                    "description": "Replace existing textbox values. Set to false to append to pre-populated \
+                                   values instead."
                 }
             }
         })
@@ -153,87 +186,176 @@ impl Tool for BrowserFillFormTool {
             .await
             .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
 
+        // Remember the pre-fill URL so a rollback can reload it
+        let url = if input.rollback_on_failure {
+            page.url().await.ok()
+        } else {
+            None
+        };
+
+        // Report every bad ref up front instead of failing on the first
+        validate_fields(&snapshot, &input.fields)?;
+
         let mut filled_fields = Vec::new();
+        let result = fill_fields(&page, &input, &mut filled_fields).await;
 
-        // Fill each field
-        for field in &input.fields {
-            // Validate the ref exists in the snapshot
-            snapshot.lookup(&field.element_ref).map_err(|e| {
-                ToolError::ElementNotFound(format!(
-                    "Element ref '{}' for field '{}': {}",
-                    field.element_ref, field.name, e
-                ))
-            })?;
-
-            // Use native ref resolution API from viewpoint 0.2.9
-            let locator = page.locator_from_ref(&field.element_ref);
-
-            // Fill based on field type
-            match field.field_type {
-                FieldType::Textbox => {
-                    locator.fill(&field.value).await.map_err(|e| {
-                        ToolError::ExecutionFailed(format!(
-                            "Failed to fill textbox '{}': {}",
-                            field.name, e
-                        ))
-                    })?;
-                }
-                FieldType::Checkbox => {
-                    let should_check = field.value.eq_ignore_ascii_case("true");
-                    if should_check {
-                        locator.check().await
-                    } else {
-                        locator.uncheck().await
-                    }
-                    .map_err(|e| {
-                        ToolError::ExecutionFailed(format!(
-                            "Failed to set checkbox '{}': {}",
-                            field.name, e
-                        ))
-                    })?;
-                }
-                FieldType::Radio => {
-                    locator.check().await.map_err(|e| {
-                        ToolError::ExecutionFailed(format!(
-                            "Failed to select radio '{}': {}",
-                            field.name, e
-                        ))
-                    })?;
-                }
-                FieldType::Combobox => {
-                    // Use the new builder API from viewpoint 0.2.10
-                    locator
-                        .select_option()
-                        .value(&field.value)
+        // Invalidate cache after form interaction
+        context.invalidate_cache();
+
+        if let Err(error) = result {
+            if input.rollback_on_failure && !filled_fields.is_empty() {
+                return Err(rollback(&page, url.as_deref(), error, filled_fields.len()).await);
+            }
+            return Err(error);
+        }
+
+        Ok(ToolOutput::text(format!(
+            "Filled {} field(s): {}",
+            filled_fields.len(),
+            filled_fields.join(", ")
+        )))
+    }
+}
+
+/// Validate all field refs against the snapshot, listing every invalid one
+fn validate_fields(
+    snapshot: &AccessibilitySnapshot,
+    fields: &[FormField],
+) -> Result<(), ToolError> {
+    let refs: Vec<&str> = fields.iter().map(|f| f.element_ref.as_str()).collect();
+
+    let errors: Vec<String> = fields
+        .iter()
+        .zip(snapshot.validate_refs(&refs))
+        .filter_map(|(field, result)| {
+            result.err().map(|e| {
+                format!(
+                    "  - Element ref '{}' for field '{}': {e}",
+                    field.element_ref, field.name
+                )
+            })
+        })
+        .collect();
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    Err(ToolError::ElementNotFound(format!(
+        "{} of {} field ref(s) are invalid; no fields were filled:\n{}",
+        errors.len(),
+        fields.len(),
+        errors.join("\n")
+    )))
+}
+
+/// Fill each field in order, recording the names of fields filled so far
+async fn fill_fields(
+    page: &Page,
+    input: &BrowserFillFormInput,
+    filled_fields: &mut Vec<String>,
+) -> Result<(), ToolError> {
+    for field in &input.fields {
+        // Use native ref resolution API from viewpoint 0.2.9
+        let locator = page.locator_from_ref(&field.element_ref);
+
+        // Fill based on field type
+        match field.field_type {
+            FieldType::Textbox => {
+                let value = if input.clear_first {
+                    field.value.clone()
+                } else {
+                    // Contenteditable elements have no value; their text is the content
+                    let current: Value = locator
+                        .evaluate(&js! {
+                            element.isContentEditable
+                                ? element.textContent ?? ""
+                                : element.value ?? ""
+                        })
                         .await
                         .map_err(|e| {
                             ToolError::ExecutionFailed(format!(
-                                "Failed to select option in '{}': {}",
+                                "Failed to read textbox '{}': {}",
                                 field.name, e
                             ))
                         })?;
+                    format!("{}{}", current.as_str().unwrap_or_default(), field.value)
+                };
+                locator.fill(&value).await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to fill textbox '{}': {}",
+                        field.name, e
+                    ))
+                })?;
+            }
+            FieldType::Checkbox => {
+                let should_check = field.value.eq_ignore_ascii_case("true");
+                if should_check {
+                    locator.check().await
+                } else {
+                    locator.uncheck().await
                 }
-                FieldType::Slider => {
-                    // For sliders, we fill the value which works for range inputs
-                    locator.fill(&field.value).await.map_err(|e| {
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to set checkbox '{}': {}",
+                        field.name, e
+                    ))
+                })?;
+            }
+            FieldType::Radio => {
+                locator.check().await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to select radio '{}': {}",
+                        field.name, e
+                    ))
+                })?;
+            }
+            FieldType::Combobox => {
+                // Use the new builder API from viewpoint 0.2.10
+                locator
+                    .select_option()
+                    .value(&field.value)
+                    .await
+                    .map_err(|e| {
                         ToolError::ExecutionFailed(format!(
-                            "Failed to set slider '{}': {}",
+                            "Failed to select option in '{}': {}",
                             field.name, e
                         ))
                     })?;
-                }
             }
-
-            filled_fields.push(field.name.clone());
+            FieldType::Slider => {
+                // For sliders, we fill the value which works for range inputs
+                locator.fill(&field.value).await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to set slider '{}': {}",
+                        field.name, e
+                    ))
+                })?;
+            }
         }
 
-        // Invalidate cache after form interaction
-        context.invalidate_cache();
+        filled_fields.push(field.name.clone());
+    }
 
-        Ok(ToolOutput::text(format!(
-            "Filled {} field(s): {}",
-            filled_fields.len(),
-            filled_fields.join(", ")
-        )))
+    Ok(())
+}
+
+/// Best-effort rollback: reload the page to discard partially filled fields.
+///
+/// Returns the original fill error annotated with the rollback outcome.
+async fn rollback(page: &Page, url: Option<&str>, error: ToolError, filled: usize) -> ToolError {
+    let outcome = match url {
+        Some(url) => match page.goto(url).goto().await {
+            Ok(_) => format!("rolled back {filled} filled field(s) by reloading the page"),
+            Err(e) => format!("rollback failed: {e}"),
+        },
+        None => "rollback failed: page URL unavailable".to_string(),
+    };
+    tracing::warn!(%outcome, "browser_fill_form rollback");
+
+    match error {
+        ToolError::ExecutionFailed(msg) => ToolError::ExecutionFailed(format!("{msg} ({outcome})")),
+        ToolError::ElementNotFound(msg) => ToolError::ElementNotFound(format!("{msg} ({outcome})")),
+        other => other,
     }
 }
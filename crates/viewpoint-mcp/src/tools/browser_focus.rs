@@ -0,0 +1,174 @@
+//! Browser focus tool for setting keyboard focus without clicking
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+
+/// Browser focus tool - focuses an element, optionally moving focus one step on
+pub struct BrowserFocusTool;
+
+/// Input parameters for `browser_focus`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserFocusInput {
+    /// Element reference from snapshot
+    #[serde(rename = "ref")]
+    pub element_ref: String,
+
+    /// Human-readable element description for verification
+    pub element: String,
+
+    /// Move focus one step from the element after focusing it
+    pub direction: Option<FocusDirection>,
+}
+
+/// Direction to move focus in tab order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FocusDirection {
+    /// Next element in tab order (Tab)
+    Forward,
+    /// Previous element in tab order (Shift+Tab)
+    Backward,
+}
+
+impl FocusDirection {
+    /// Key combination that moves focus in this direction
+    #[must_use]
+    pub const fn key(self) -> &'static str {
+        match self {
+            Self::Forward => "Tab",
+            Self::Backward => "Shift+Tab",
+        }
+    }
+
+    /// Lowercase name used in the input and output
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Forward => "forward",
+            Self::Backward => "backward",
+        }
+    }
+}
+
+impl BrowserFocusTool {
+    /// Create a new browser focus tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserFocusTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserFocusTool {
+    fn name(&self) -> &'static str {
+        "browser_focus"
+    }
+
+    fn description(&self) -> &'static str {
+        "Give keyboard focus to an element without clicking it. Useful for widgets that \
+         react to focus, such as menus opened on focus. Optionally moves focus one step \
+         forward (Tab) or backward (Shift+Tab) from the element afterwards."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["ref", "element"],
+            "properties": {
+                "ref": {
+                    "type": "string",
+                    "description": "Element reference from browser_snapshot"
+                },
+                "element": {
+                    "type": "string",
+                    "description": "Human-readable description of the element"
+                },
+                "direction": {
+                    "type": "string",
+                    "enum": ["forward", "backward"],
+                    "description": "After focusing the element, move focus one step in tab order: \
+                                   'forward' presses Tab, 'backward' presses Shift+Tab"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        use std::fmt::Write;
+
+        // Parse input
+        let input: BrowserFocusInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active page (need mutable context for cache invalidation)
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        // Capture current snapshot for validation
+        let options = SnapshotOptions::default();
+        let snapshot = AccessibilitySnapshot::capture(&page, options)
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+
+        // Validate the ref exists in the snapshot
+        snapshot.lookup(&input.element_ref).map_err(|e| {
+            ToolError::ElementNotFound(format!("Element ref '{}': {}", input.element_ref, e))
+        })?;
+
+        page.locator_from_ref(&input.element_ref)
+            .focus()
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!(
+                    "Failed to focus element '{}': {e}",
+                    input.element
+                ))
+            })?;
+
+        let mut result = format!("Focused {} [ref={}]", input.element, input.element_ref);
+
+        if let Some(direction) = input.direction {
+            page.keyboard().press(direction.key()).await.map_err(|e| {
+                ToolError::ExecutionFailed(format!(
+                    "Failed to move focus {}: {e}",
+                    direction.as_str()
+                ))
+            })?;
+            let _ = write!(result, ", then moved focus {}", direction.as_str());
+        }
+
+        // Invalidate cache after focus change (focus handlers may alter the DOM)
+        context.invalidate_cache();
+
+        Ok(ToolOutput::text(result))
+    }
+}
@@ -5,7 +5,7 @@ use serde::Deserialize;
 use serde_json::{Value, json};
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
-use crate::browser::BrowserState;
+use crate::browser::{BrowserState, DialogResponse};
 
 /// Browser handle dialog tool - accepts or dismisses browser dialogs
 pub struct BrowserHandleDialogTool;
@@ -19,6 +19,11 @@ pub struct BrowserHandleDialogInput {
 
     /// Text to enter for prompt dialogs
     pub prompt_text: Option<String>,
+
+    /// Install a persistent handler that answers every dialog in the active
+    /// context, including ones that open before the next tool call
+    #[serde(default)]
+    pub preregister: bool,
 }
 
 impl BrowserHandleDialogTool {
@@ -44,7 +49,13 @@ impl Tool for BrowserHandleDialogTool {
     fn description(&self) -> &'static str {
         "Handle a browser dialog (alert, confirm, prompt, or beforeunload). \
          Use accept: true to accept/confirm the dialog, or accept: false to dismiss/cancel. \
-         For prompt dialogs, use promptText to provide the input value."
+         For prompt dialogs, use promptText to provide the input value. \
+         Use preregister: true before triggering dialogs (e.g. on page load) to answer every \
+         dialog in the current context automatically."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["state"]
     }
 
     fn input_schema(&self) -> Value {
@@ -59,6 +70,11 @@ impl Tool for BrowserHandleDialogTool {
                 "promptText": {
                     "type": "string",
                     "description": "Text to enter in the prompt dialog (only used for prompt dialogs)"
+                },
+                "preregister": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Install a persistent handler on the current context that answers all dialogs on current and future pages, including ones that appear before the next tool call"
                 }
             }
         })
@@ -96,8 +112,35 @@ impl Tool for BrowserHandleDialogTool {
         // 2. The handler would accept/dismiss based on these settings
         // 3. Return after the dialog is handled or timeout
 
+        let response = DialogResponse {
+            accept: input.accept,
+            prompt_text: input.prompt_text.clone(),
+        };
+
+        if input.preregister {
+            context
+                .preregister_dialog_handler(response)
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Failed to register dialog handler: {e}"))
+                })?;
+
+            let action = match (input.accept, &input.prompt_text) {
+                (true, Some(prompt_text)) => format!("accept with text '{prompt_text}'"),
+                (true, None) => "accept".to_string(),
+                (false, _) => "dismiss".to_string(),
+            };
+            return Ok(ToolOutput::text(format!(
+                "Dialog handler registered: will {action} all dialogs in context '{}'",
+                context.name
+            )));
+        }
+
+        // A pre-registered handler answers according to the latest call
+        let updated = context.set_dialog_response(response).await;
+
         // For now, we provide feedback about what action would be taken
-        let result = if input.accept {
+        let mut result = if input.accept {
             if let Some(ref prompt_text) = input.prompt_text {
                 format!(
                     "Dialog handler configured: will accept next dialog with text '{prompt_text}'"
@@ -108,6 +151,9 @@ impl Tool for BrowserHandleDialogTool {
         } else {
             "Dialog handler configured: will dismiss next dialog".to_string()
         };
+        if updated {
+            result.push_str(" (pre-registered handler updated)");
+        }
 
         // Invalidate cache as dialog state may have changed
         context.invalidate_cache();
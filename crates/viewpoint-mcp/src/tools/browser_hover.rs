@@ -1,12 +1,18 @@
 //! Browser hover tool for hovering over elements
 
+use std::time::Duration;
+
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
-use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+use crate::snapshot::{AccessibilitySnapshot, SnapshotElement, SnapshotOptions};
+
+/// How long to keep hovering before looking for a tooltip, if not given
+const DEFAULT_TOOLTIP_WAIT_MS: u64 = 500;
 
 /// Browser hover tool - hovers over an element
 pub struct BrowserHoverTool;
@@ -21,6 +27,81 @@ pub struct BrowserHoverInput {
 
     /// Human-readable element description for verification
     pub element: String,
+
+    /// Offset from the element's top-left corner to hover at (default: center)
+    pub position: Option<HoverPosition>,
+
+    /// How long to keep hovering before returning, in milliseconds
+    pub duration_ms: Option<u64>,
+
+    /// Report the text of any tooltip that appears while hovering
+    #[serde(default)]
+    pub capture_tooltip: bool,
+}
+
+/// Texts of the `tooltip` elements in a snapshot tree, in document order.
+///
+/// A tooltip without an accessible name is described by its descendants' names.
+#[must_use]
+pub fn tooltip_texts(root: &SnapshotElement) -> Vec<String> {
+    fn text(element: &SnapshotElement) -> String {
+        element.name.clone().unwrap_or_else(|| {
+            element
+                .children
+                .iter()
+                .map(text)
+                .filter(|t| !t.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+
+    fn collect(element: &SnapshotElement, texts: &mut Vec<String>) {
+        if element.role == "tooltip" {
+            texts.push(text(element));
+        } else {
+            for child in &element.children {
+                collect(child, texts);
+            }
+        }
+    }
+
+    let mut texts = Vec::new();
+    collect(root, &mut texts);
+    texts
+}
+
+/// Hover point relative to the element's top-left corner, in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HoverPosition {
+    /// Horizontal offset from the element's left edge
+    #[serde(alias = "x_offset")]
+    pub x_offset: f64,
+
+    /// Vertical offset from the element's top edge
+    #[serde(alias = "y_offset")]
+    pub y_offset: f64,
+}
+
+impl HoverPosition {
+    /// Check that the offset lies within an element of the given size
+    ///
+    /// # Errors
+    ///
+    /// Returns `ToolError::InvalidParams` if either offset is negative, not
+    /// finite, or beyond the element's width or height.
+    pub fn validate(&self, width: f64, height: f64) -> Result<(), ToolError> {
+        let within = |offset: f64, size: f64| offset.is_finite() && (0.0..=size).contains(&offset);
+        if within(self.x_offset, width) && within(self.y_offset, height) {
+            Ok(())
+        } else {
+            Err(ToolError::InvalidParams(format!(
+                "Position ({}, {}) is outside the element's {width}x{height} bounding box",
+                self.x_offset, self.y_offset
+            )))
+        }
+    }
 }
 
 impl BrowserHoverTool {
@@ -45,7 +126,13 @@ impl Tool for BrowserHoverTool {
 
     fn description(&self) -> &'static str {
         "Hover the mouse over an element on the page. Useful for triggering hover states, \
-         tooltips, or dropdown menus."
+         tooltips, or dropdown menus. Hovers at the element's center unless a position \
+         relative to its top-left corner is given. Set captureTooltip: true to return \
+         the text of a tooltip that appears while hovering."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -60,12 +147,44 @@ impl Tool for BrowserHoverTool {
                 "element": {
                     "type": "string",
                     "description": "Human-readable description of the element"
+                },
+                "position": {
+                    "type": "object",
+                    "description": "Point to hover at, relative to the element's top-left corner in CSS pixels. \
+                                   Useful for canvas widgets or maps. Defaults to the element's center.",
+                    "required": ["xOffset", "yOffset"],
+                    "properties": {
+                        "xOffset": {
+                            "type": "number",
+                            "minimum": 0,
+                            "description": "Horizontal offset from the element's left edge"
+                        },
+                        "yOffset": {
+                            "type": "number",
+                            "minimum": 0,
+                            "description": "Vertical offset from the element's top edge"
+                        }
+                    }
+                },
+                "durationMs": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "How long to keep hovering before returning, in milliseconds. \
+                                   Defaults to 0, or 500 with captureTooltip."
+                },
+                "captureTooltip": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "After hovering, return the text of newly appeared tooltip elements \
+                                   (falling back to the element's title attribute), or an empty string if none appeared"
                 }
             }
         })
     }
 
     async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        use std::fmt::Write;
+
         // Parse input
         let input: BrowserHoverInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
@@ -101,20 +220,85 @@ impl Tool for BrowserHoverTool {
         // Use native ref resolution API from viewpoint 0.2.9
         let locator = page.locator_from_ref(&input.element_ref);
 
-        // Perform the hover
-        locator.hover().await.map_err(|e| {
+        let hover_error = |e: &dyn std::fmt::Display| {
             ToolError::ExecutionFailed(format!(
-                "Failed to hover over element '{}': {}",
-                input.element, e
+                "Failed to hover over element '{}': {e}",
+                input.element
             ))
-        })?;
+        };
+
+        // Perform the hover
+        let mut result = format!(
+            "Hovering over {} [ref={}]",
+            input.element, input.element_ref
+        );
+        if let Some(position) = input.position {
+            locator
+                .scroll_into_view_if_needed()
+                .await
+                .map_err(|e| hover_error(&e))?;
+
+            let bbox = locator
+                .bounding_box()
+                .await
+                .map_err(|e| hover_error(&e))?
+                .ok_or_else(|| {
+                    ToolError::ElementNotFound(format!(
+                        "Element '{}' has no bounding box (may be hidden)",
+                        input.element
+                    ))
+                })?;
+            position.validate(bbox.width, bbox.height)?;
+
+            page.mouse()
+                .move_(bbox.x + position.x_offset, bbox.y + position.y_offset)
+                .send()
+                .await
+                .map_err(|e| hover_error(&e))?;
+
+            let _ = write!(
+                result,
+                " at offset ({}, {})",
+                position.x_offset, position.y_offset
+            );
+        } else {
+            locator.hover().await.map_err(|e| hover_error(&e))?;
+        }
+
+        let wait_ms = input.duration_ms.unwrap_or(if input.capture_tooltip {
+            DEFAULT_TOOLTIP_WAIT_MS
+        } else {
+            0
+        });
+        if wait_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(wait_ms)).await;
+        }
 
         // Invalidate cache after hover (DOM may have changed via hover effects)
         context.invalidate_cache();
 
-        Ok(ToolOutput::text(format!(
-            "Hovering over {} [ref={}]",
-            input.element, input.element_ref
-        )))
+        if input.capture_tooltip {
+            let before = tooltip_texts(snapshot.root());
+            let after = AccessibilitySnapshot::capture(&page, SnapshotOptions::default())
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+            let mut tooltips: Vec<String> = tooltip_texts(after.root())
+                .into_iter()
+                .filter(|text| !before.contains(text))
+                .collect();
+
+            // Native `title` tooltips are drawn by the browser, outside the page
+            if tooltips.is_empty() {
+                let title: Value = locator
+                    .evaluate(&js! { element.getAttribute("title") })
+                    .await
+                    .unwrap_or(Value::Null);
+                tooltips.extend(title.as_str().map(String::from));
+            }
+
+            let _ = write!(result, "\nTooltip: {}", Value::String(tooltips.join("\n")));
+        }
+
+        Ok(ToolOutput::text(result))
     }
 }
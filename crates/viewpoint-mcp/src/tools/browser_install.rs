@@ -6,6 +6,9 @@ use serde_json::{Value, json};
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
+use crate::browser::install::{
+    PLAYWRIGHT_VERSION, find_browser_executable, install_browser, is_missing_browser_error,
+};
 
 /// Browser install tool - checks for and installs the browser
 pub struct BrowserInstallTool;
@@ -43,6 +46,14 @@ impl Tool for BrowserInstallTool {
          executable required for automation."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["management"]
+    }
+
+    fn requires_browser(&self) -> bool {
+        false
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -55,8 +66,7 @@ impl Tool for BrowserInstallTool {
         let _input: BrowserInstallInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
-        // Check if browser is already working by attempting to initialize
-        // If it works, the browser is already installed
+        // If the browser is already running, it is installed
         if browser.is_initialized() {
             return Ok(ToolOutput::text(
                 "Browser is already installed and running. No installation needed.",
@@ -64,41 +74,50 @@ impl Tool for BrowserInstallTool {
         }
 
         // Try to initialize - this will launch the browser
-        match browser.initialize().await {
-            Ok(()) => Ok(ToolOutput::text(
-                "Browser is already installed and successfully initialized.",
-            )),
-            Err(e) => {
-                // Browser launch failed - attempt installation
-                let error_msg = e.to_string();
-
-                // Check if the error indicates the browser is not installed
-                if error_msg.contains("not found")
-                    || error_msg.contains("not installed")
-                    || error_msg.contains("executable")
-                    || error_msg.contains("No such file")
-                {
-                    // Provide manual installation instructions
-                    Ok(ToolOutput::text(
-                        "Browser installation required.\n\
-                        To install the browser manually, run:\n\
-                        \n\
-                        npx playwright install chromium\n\
-                        \n\
-                        Or install Playwright browsers with:\n\
-                        \n\
-                        npx playwright install\n\
-                        \n\
-                        After installation, browser tools should work correctly.",
-                    ))
-                } else {
-                    // Some other error - might be a connection issue or config problem
-                    Err(ToolError::ExecutionFailed(format!(
-                        "Failed to initialize browser: {error_msg}. \
-                         If the browser is not installed, the error message should indicate that."
-                    )))
-                }
+        let error_msg = match browser.initialize().await {
+            Ok(()) => {
+                return Ok(ToolOutput::text(
+                    "Browser is already installed and successfully initialized.",
+                ));
             }
+            Err(e) => e.to_string(),
+        };
+
+        // Some other error - might be a connection issue or config problem
+        if !is_missing_browser_error(&error_msg) {
+            return Err(ToolError::ExecutionFailed(format!(
+                "Failed to initialize browser: {error_msg}. \
+                 If the browser is not installed, the error message should indicate that."
+            )));
         }
+
+        let browser_type = browser.config().browser_type.clone();
+        let name = browser_type.install_name();
+        let mut progress = vec![format!("Browser launch failed: {error_msg}")];
+
+        let executable = if let Some(path) = find_browser_executable(&browser_type) {
+            progress.push(format!("Found {name} at {}", path.display()));
+            path
+        } else {
+            progress.push(format!(
+                "{name} not found; installing with `npx playwright@{PLAYWRIGHT_VERSION} install {name}`"
+            ));
+            let path = install_browser(&browser_type)
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("{}\n{e}", progress.join("\n"))))?;
+            progress.push(format!("Installed {name} at {}", path.display()));
+            path
+        };
+
+        browser.set_executable_path(executable);
+        browser.initialize().await.map_err(|e| {
+            ToolError::ExecutionFailed(format!(
+                "{}\nFailed to launch installed browser: {e}",
+                progress.join("\n")
+            ))
+        })?;
+        progress.push("Browser installed and successfully initialized.".to_string());
+
+        Ok(ToolOutput::text(progress.join("\n")))
     }
 }
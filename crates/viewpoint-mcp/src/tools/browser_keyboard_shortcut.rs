@@ -0,0 +1,261 @@
+//! Browser keyboard shortcut tool for pressing human-friendly key combinations
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+/// Browser keyboard shortcut tool - presses a shortcut like `Ctrl+Z`
+pub struct BrowserKeyboardShortcutTool;
+
+/// Input parameters for `browser_keyboard_shortcut`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserKeyboardShortcutInput {
+    /// Shortcut to press (e.g., "Ctrl+Z", "Alt+F4", "Cmd+Shift+4")
+    pub shortcut: String,
+}
+
+/// Modifier key in a shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ShortcutModifier {
+    Control,
+    Alt,
+    Shift,
+    Meta,
+}
+
+impl ShortcutModifier {
+    /// Parse a platform-agnostic modifier alias (case-insensitive)
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => Some(Self::Control),
+            "alt" | "option" | "opt" => Some(Self::Alt),
+            "shift" => Some(Self::Shift),
+            "cmd" | "command" | "meta" | "super" | "win" => Some(Self::Meta),
+            _ => None,
+        }
+    }
+
+    /// Key name understood by viewpoint-core's keyboard
+    const fn key_name(self) -> &'static str {
+        match self {
+            Self::Control => "Control",
+            Self::Alt => "Alt",
+            Self::Shift => "Shift",
+            Self::Meta => "Meta",
+        }
+    }
+}
+
+/// A parsed keyboard shortcut
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyShortcut {
+    /// Modifiers held during the key press, in canonical order
+    pub modifiers: Vec<ShortcutModifier>,
+
+    /// The main key (viewpoint-core key name or single character)
+    pub key: String,
+}
+
+/// Named keys accepted in shortcuts, as `(alias, key name)` pairs
+const NAMED_KEYS: &[(&str, &str)] = &[
+    ("esc", "Escape"),
+    ("escape", "Escape"),
+    ("enter", "Enter"),
+    ("return", "Enter"),
+    ("tab", "Tab"),
+    ("space", "Space"),
+    ("backspace", "Backspace"),
+    ("del", "Delete"),
+    ("delete", "Delete"),
+    ("ins", "Insert"),
+    ("insert", "Insert"),
+    ("home", "Home"),
+    ("end", "End"),
+    ("pgup", "PageUp"),
+    ("pageup", "PageUp"),
+    ("pgdn", "PageDown"),
+    ("pagedown", "PageDown"),
+    ("up", "ArrowUp"),
+    ("arrowup", "ArrowUp"),
+    ("down", "ArrowDown"),
+    ("arrowdown", "ArrowDown"),
+    ("left", "ArrowLeft"),
+    ("arrowleft", "ArrowLeft"),
+    ("right", "ArrowRight"),
+    ("arrowright", "ArrowRight"),
+];
+
+impl KeyShortcut {
+    /// Parse a human-friendly shortcut such as `Ctrl+Shift+I` or `F5`.
+    ///
+    /// Modifiers are case-insensitive and `Cmd`/`Command`/`Win` map to `Meta`.
+    /// Single letters are normalized to lowercase so the shortcut does not
+    /// implicitly add Shift.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the shortcut is empty, has no main key,
+    /// has more than one main key, or uses an unknown key name.
+    pub fn parse(shortcut: &str) -> Result<Self, String> {
+        let shortcut = shortcut.trim();
+        if shortcut.is_empty() {
+            return Err("Shortcut cannot be empty".to_string());
+        }
+
+        // A trailing "++" (or a lone "+") means the plus key itself
+        let (prefix, plus_key) = if shortcut == "+" {
+            ("", true)
+        } else if let Some(prefix) = shortcut.strip_suffix("++") {
+            (prefix, true)
+        } else {
+            (shortcut, false)
+        };
+
+        let mut modifiers = Vec::new();
+        let mut key = plus_key.then(|| "+".to_string());
+
+        for part in prefix.split('+').map(str::trim).filter(|p| !p.is_empty()) {
+            if let Some(modifier) = ShortcutModifier::parse(part) {
+                if !modifiers.contains(&modifier) {
+                    modifiers.push(modifier);
+                }
+                continue;
+            }
+
+            if key.is_some() {
+                return Err(format!(
+                    "Shortcut '{shortcut}' has more than one non-modifier key"
+                ));
+            }
+            key = Some(Self::normalize_key(part)?);
+        }
+
+        let key = key.ok_or_else(|| format!("Shortcut '{shortcut}' has no main key"))?;
+        modifiers.sort();
+
+        Ok(Self { modifiers, key })
+    }
+
+    /// Normalize a main key to its viewpoint-core key name
+    fn normalize_key(key: &str) -> Result<String, String> {
+        let mut chars = key.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Ok(c.to_lowercase().collect());
+        }
+
+        let lower = key.to_ascii_lowercase();
+
+        if let Some((_, name)) = NAMED_KEYS.iter().find(|(alias, _)| *alias == lower) {
+            return Ok((*name).to_string());
+        }
+
+        // Function keys F1-F24
+        if let Some(number) = lower.strip_prefix('f')
+            && let Ok(n) = number.parse::<u8>()
+            && (1..=24).contains(&n)
+        {
+            return Ok(format!("F{n}"));
+        }
+
+        Err(format!("Unknown key '{key}'"))
+    }
+
+    /// Key combination string for viewpoint-core's `keyboard().press()`
+    #[must_use]
+    pub fn to_key_combination(&self) -> String {
+        self.modifiers
+            .iter()
+            .map(|m| m.key_name())
+            .chain(std::iter::once(self.key.as_str()))
+            .collect::<Vec<_>>()
+            .join("+")
+    }
+}
+
+impl BrowserKeyboardShortcutTool {
+    /// Create a new browser keyboard shortcut tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserKeyboardShortcutTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserKeyboardShortcutTool {
+    fn name(&self) -> &'static str {
+        "browser_keyboard_shortcut"
+    }
+
+    fn description(&self) -> &'static str {
+        "Press a keyboard shortcut written the way humans write it, e.g. 'Ctrl+Z', 'Alt+F4', \
+         'Cmd+Shift+4', 'F5'. Modifiers are case-insensitive; 'Cmd' and 'Win' map to Meta."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["shortcut"],
+            "properties": {
+                "shortcut": {
+                    "type": "string",
+                    "description": "Shortcut using '+' between keys: modifiers (Ctrl, Alt, Shift, Cmd/Meta) followed by one key, such as 'Ctrl+Z', 'Ctrl+Shift+I', 'F5', or 'Esc'"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserKeyboardShortcutInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        let shortcut = KeyShortcut::parse(&input.shortcut).map_err(ToolError::InvalidParams)?;
+        let combination = shortcut.to_key_combination();
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active page
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        page.keyboard().press(&combination).await.map_err(|e| {
+            ToolError::ExecutionFailed(format!(
+                "Failed to press shortcut '{}': {e}",
+                input.shortcut
+            ))
+        })?;
+
+        // Invalidate cache after keyboard interaction
+        context.invalidate_cache();
+
+        Ok(ToolOutput::text(format!(
+            "Pressed shortcut '{}' ({combination})",
+            input.shortcut
+        )))
+    }
+}
@@ -82,6 +82,10 @@ impl Tool for BrowserMouseClickXyTool {
          element positions from screenshots. Coordinates are in CSS pixels relative to viewport."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -61,6 +61,10 @@ impl Tool for BrowserMouseDragXyTool {
          Performs mouse down at start, moves to end, then releases."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -55,6 +55,10 @@ impl Tool for BrowserMouseMoveXyTool {
          For vision-enabled LLMs. Useful for triggering hover states or positioning before click."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -0,0 +1,356 @@
+//! Browser navigate tool for navigating to URLs
+
+mod retry;
+mod security;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use url::Url;
+
+use super::navigation_wait::NavigationWaitCondition;
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+pub use retry::{DEFAULT_RETRY_DELAY_MS, DEFAULT_RETRY_ON, MAX_RETRIES, is_transient_error};
+pub use security::SecurityInfo;
+
+/// Browser navigate tool - navigates to a URL
+pub struct BrowserNavigateTool;
+
+/// Input parameters for `browser_navigate`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserNavigateInput {
+    /// The URL to navigate to
+    pub url: String,
+
+    /// Load state to wait for before returning (default: load)
+    #[serde(default)]
+    pub wait_until: NavigationWaitCondition,
+
+    /// Navigation timeout in milliseconds
+    pub timeout_ms: Option<u64>,
+
+    /// HTTP Basic Authentication credentials for this navigation
+    pub basic_auth: Option<BasicAuth>,
+
+    /// Accept invalid TLS certificates (e.g., self-signed) for this navigation
+    #[serde(default, rename = "ignoreHTTPSErrors")]
+    pub ignore_https_errors: bool,
+
+    /// Include the connection protocol and certificate details in the result
+    #[serde(default)]
+    pub include_security_info: bool,
+
+    /// How many times to retry after a transient failure (at most 3)
+    pub retries: Option<u8>,
+
+    /// HTTP statuses that trigger a retry (default: 503 and 504)
+    pub retry_on: Option<Vec<u16>>,
+
+    /// Delay between attempts in milliseconds (default: 1000)
+    pub retry_delay_ms: Option<u64>,
+}
+
+/// HTTP Basic Authentication credentials
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicAuth {
+    /// User name
+    pub username: String,
+
+    /// Password
+    pub password: String,
+
+    /// Keep the credentials on the context after the navigation instead of
+    /// restoring the credentials kept by an earlier navigation
+    #[serde(default)]
+    pub persist: bool,
+}
+
+impl std::fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never log the password
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("persist", &self.persist)
+            .finish()
+    }
+}
+
+/// Result of a successful navigation, returned as JSON
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NavigationResult {
+    /// Always `"navigated"`
+    pub status: &'static str,
+    /// Final URL after any redirects
+    pub url: String,
+    /// Page title (empty if the page has none)
+    pub title: String,
+    /// Requested URL, if the browser ended up somewhere else
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirected_from: Option<String>,
+    /// Connection security details, if requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityInfo>,
+    /// Number of attempts, if the navigation had to be retried
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u8>,
+}
+
+impl NavigationResult {
+    /// Build a result, recording `requested_url` as the redirect source when
+    /// it differs from `final_url`.
+    ///
+    /// Both URLs are compared after parsing, so normalization (an added root
+    /// path, a default port, host case) is not a redirect, while a different
+    /// path such as `/docs` vs `/docs/` is.
+    #[must_use]
+    pub fn new(requested_url: &str, final_url: String, title: String) -> Self {
+        let is_redirect = match (Url::parse(requested_url), Url::parse(&final_url)) {
+            (Ok(requested), Ok(landed)) => requested != landed,
+            _ => requested_url != final_url,
+        };
+        Self {
+            status: "navigated",
+            redirected_from: is_redirect.then(|| requested_url.to_string()),
+            url: final_url,
+            title,
+            security: None,
+            attempts: None,
+        }
+    }
+}
+
+impl BrowserNavigateTool {
+    /// Create a new browser navigate tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserNavigateTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserNavigateTool {
+    fn name(&self) -> &'static str {
+        "browser_navigate"
+    }
+
+    fn description(&self) -> &'static str {
+        "Navigate to a URL in the browser. By default waits for the load event before returning; \
+         use waitUntil to wait for DOMContentLoaded or network idle instead. Returns JSON with \
+         the final URL (after redirects), page title, and redirectedFrom when redirected. \
+         Pass basicAuth for sites behind HTTP Basic Authentication and ignoreHTTPSErrors for \
+         sites with self-signed certificates. Set includeSecurityInfo: true to also report the \
+         protocol and TLS certificate validity and expiry. Set retries (max 3) to retry \
+         connection failures, timeouts, and retryOn statuses (default 503, 504) after \
+         retryDelayMs."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["navigation"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["url"],
+            "properties": {
+                "url": {
+                    "type": "string",
+                    "description": "The URL to navigate to"
+                },
+                "waitUntil": NavigationWaitCondition::schema(
+                    "When to consider navigation finished",
+                    Some(NavigationWaitCondition::Load)
+                ),
+                "timeoutMs": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Navigation timeout in milliseconds"
+                },
+                "basicAuth": {
+                    "type": "object",
+                    "required": ["username", "password"],
+                    "properties": {
+                        "username": { "type": "string" },
+                        "password": { "type": "string" },
+                        "persist": {
+                            "type": "boolean",
+                            "default": false,
+                            "description": "Keep the credentials on the context for later requests instead of restoring the previously kept credentials after this navigation"
+                        }
+                    },
+                    "description": "HTTP Basic Authentication credentials, answered when the server challenges the request. Avoids embedding credentials in the URL."
+                },
+                "ignoreHTTPSErrors": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Accept invalid TLS certificates (e.g., self-signed staging servers) during this navigation only"
+                },
+                "includeSecurityInfo": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Add security: { protocol, certValid, certExpiry } to the result. certValid is true for \
+                                   HTTPS pages whose certificate the browser accepted and has not expired, and false \
+                                   for other protocols or expired certificates. certValid is omitted when it cannot \
+                                   be told, such as with ignoreHTTPSErrors and an unexpired certificate"
+                },
+                "retries": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": MAX_RETRIES,
+                    "default": 0,
+                    "description": "Retry this many times after a transient failure (connection error, timeout, or a retryOn status)"
+                },
+                "retryOn": {
+                    "type": "array",
+                    "items": { "type": "integer" },
+                    "default": DEFAULT_RETRY_ON,
+                    "description": "HTTP statuses of the loaded page that trigger a retry"
+                },
+                "retryDelayMs": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "default": DEFAULT_RETRY_DELAY_MS,
+                    "description": "Milliseconds to wait before each retry"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserNavigateInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if input.retries.is_some_and(|retries| retries > MAX_RETRIES) {
+            return Err(ToolError::InvalidParams(format!(
+                "retries must be at most {MAX_RETRIES}"
+            )));
+        }
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active context
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Auto-create a new page if context has no pages
+        let page_count = context
+            .page_count()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get page count: {e}")))?;
+        if page_count == 0 {
+            context
+                .new_page()
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Failed to create page: {e}")))?;
+        }
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        // Put back settings left behind by an earlier navigation that was cancelled
+        context.restore_abandoned_overrides().await.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to restore HTTPS error checks: {e}"))
+        })?;
+
+        if let Some(auth) = input.basic_auth.as_ref().filter(|auth| auth.persist) {
+            context
+                .set_http_credentials(&auth.username, &auth.password)
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Failed to set HTTP credentials: {e}"))
+                })?;
+        }
+        let temporary_auth = input.basic_auth.as_ref().filter(|auth| !auth.persist);
+        let overridden = &*context;
+
+        let https_override = if input.ignore_https_errors {
+            tracing::warn!(url = %input.url, "Ignoring HTTPS certificate errors for navigation");
+            Some(
+                overridden
+                    .ignore_https_errors_temporarily()
+                    .await
+                    .map_err(|e| {
+                        ToolError::ExecutionFailed(format!("Failed to ignore HTTPS errors: {e}"))
+                    })?,
+            )
+        } else {
+            None
+        };
+
+        // Navigate to URL, retrying transient failures if requested
+        let navigated = async {
+            if let Some(auth) = temporary_auth {
+                overridden
+                    .apply_temporary_http_credentials(&auth.username, &auth.password)
+                    .await
+                    .map_err(|e| {
+                        ToolError::ExecutionFailed(format!("Failed to set HTTP credentials: {e}"))
+                    })?;
+            }
+            retry::goto_with_retries(&page, &input)
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Navigation failed: {e}")))
+        }
+        .await;
+
+        // Put back the context's own settings whether or not the navigation succeeded
+        let credentials_restored = match temporary_auth {
+            Some(_) => overridden.restore_http_credentials().await,
+            None => Ok(()),
+        };
+        let https_restored = match https_override {
+            Some(https_override) => https_override.restore().await,
+            None => Ok(()),
+        };
+        credentials_restored.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to restore HTTP credentials: {e}"))
+        })?;
+        https_restored.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to restore HTTPS error checks: {e}"))
+        })?;
+
+        let navigated = navigated?;
+
+        // Read the final URL and title after redirects, from the page that navigated
+        let final_url = page.url().await.unwrap_or_else(|_| input.url.clone());
+        let title = page.title().await.unwrap_or_default();
+
+        // Update context's current URL
+        context.set_current_url(Some(final_url.clone())).await;
+
+        // Invalidate cache after navigation
+        context.invalidate_cache();
+
+        let mut result = NavigationResult::new(&input.url, final_url, title);
+        result.attempts = (navigated.attempts > 1).then_some(navigated.attempts);
+        if input.include_security_info {
+            result.security = Some(SecurityInfo::new(
+                &result.url,
+                navigated.cert_valid_to,
+                !input.ignore_https_errors,
+            ));
+        }
+        Ok(ToolOutput::text(serde_json::to_string_pretty(&result)?))
+    }
+}
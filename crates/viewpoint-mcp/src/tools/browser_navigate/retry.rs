@@ -0,0 +1,124 @@
+//! Retrying navigations that fail for transient reasons
+
+use std::time::Duration;
+
+use serde_json::Value;
+use viewpoint_core::{Page, Response};
+use viewpoint_js::js;
+
+use super::BrowserNavigateInput;
+
+/// Upper bound for the `retries` option
+pub const MAX_RETRIES: u8 = 3;
+
+/// HTTP statuses retried when `retryOn` is not given
+pub const DEFAULT_RETRY_ON: [u16; 2] = [503, 504];
+
+/// Delay between attempts when `retryDelayMs` is not given
+pub const DEFAULT_RETRY_DELAY_MS: u64 = 1000;
+
+/// Chromium network errors that may succeed on a second attempt
+const TRANSIENT_NET_ERRORS: &[&str] = &[
+    "ERR_ADDRESS_UNREACHABLE",
+    "ERR_CONNECTION_CLOSED",
+    "ERR_CONNECTION_REFUSED",
+    "ERR_CONNECTION_RESET",
+    "ERR_CONNECTION_TIMED_OUT",
+    "ERR_EMPTY_RESPONSE",
+    "ERR_INTERNET_DISCONNECTED",
+    "ERR_NAME_NOT_RESOLVED",
+    "ERR_NETWORK_CHANGED",
+    "ERR_TIMED_OUT",
+];
+
+/// Whether a navigation error looks transient: a dropped or refused
+/// connection, a DNS failure, or a timeout.
+///
+/// Errors such as an invalid URL or a certificate failure are permanent and
+/// are returned without retrying.
+#[must_use]
+pub fn is_transient_error(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    TRANSIENT_NET_ERRORS
+        .iter()
+        .any(|code| message.contains(code))
+        || lower.contains("timeout")
+        || lower.contains("timed out")
+}
+
+/// A navigation that succeeded, possibly after retries
+pub(super) struct Navigated {
+    /// Number of attempts made
+    pub attempts: u8,
+
+    /// Expiry of the certificate the final response was served with, in
+    /// seconds since the Unix epoch (`securityDetails.validTo`)
+    pub cert_valid_to: Option<f64>,
+}
+
+impl Navigated {
+    fn new(attempts: u8, response: &Response) -> Self {
+        Self {
+            attempts,
+            cert_valid_to: response.security_details().map(|details| details.valid_to),
+        }
+    }
+}
+
+/// Navigate `page` to the input URL, retrying transient failures as configured.
+///
+/// Returns the successful navigation, or the last error once the retries are
+/// exhausted. Statuses are only checked when retries are enabled, so a plain
+/// navigation to a 503 page still succeeds as before.
+pub(super) async fn goto_with_retries(
+    page: &Page,
+    input: &BrowserNavigateInput,
+) -> Result<Navigated, String> {
+    let retries = input.retries.unwrap_or(0);
+    let retry_on = input.retry_on.as_deref().unwrap_or(&DEFAULT_RETRY_ON);
+    let delay = Duration::from_millis(input.retry_delay_ms.unwrap_or(DEFAULT_RETRY_DELAY_MS));
+
+    let mut attempt = 1;
+    loop {
+        let mut navigation = page
+            .goto(&input.url)
+            .wait_until(input.wait_until.load_state());
+        if let Some(timeout_ms) = input.timeout_ms {
+            navigation = navigation.timeout(Duration::from_millis(timeout_ms));
+        }
+
+        let error = match navigation.goto().await {
+            Err(e) if is_transient_error(&e.to_string()) => e.to_string(),
+            Err(e) => return Err(e.to_string()),
+            Ok(response) if retries == 0 => return Ok(Navigated::new(attempt, &response)),
+            Ok(response) => match response_status(page).await {
+                Some(status) if retry_on.contains(&status) => format!("HTTP {status}"),
+                _ => return Ok(Navigated::new(attempt, &response)),
+            },
+        };
+
+        if attempt > retries {
+            if retries == 0 {
+                return Err(error);
+            }
+            return Err(format!("{error} (gave up after {attempt} attempts)"));
+        }
+        tracing::debug!(url = %input.url, attempt, retries, error = %error, "Retrying navigation");
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// HTTP status of the active document, if the browser reports one
+async fn response_status(page: &Page) -> Option<u16> {
+    let status: Value = page
+        .evaluate(&js! {
+            (() => {
+                const entry = performance.getEntriesByType("navigation")[0];
+                return entry && entry.responseStatus ? entry.responseStatus : null;
+            })()
+        })
+        .await
+        .ok()?;
+    status.as_u64().and_then(|s| u16::try_from(s).ok())
+}
@@ -0,0 +1,66 @@
+//! Connection security details for `includeSecurityInfo`
+//!
+//! The protocol comes from the final URL. For HTTPS pages the certificate
+//! expiry is the `validTo` of the navigation response's security details.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Security details of the page's connection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityInfo {
+    /// URL scheme of the final page (e.g., `"https"`, `"http"`, `"data"`)
+    pub protocol: String,
+    /// Whether the page was served over HTTPS with an unexpired certificate
+    /// that the browser accepted. Omitted when there is no evidence either
+    /// way: an HTTPS response without security details, or an unexpired
+    /// certificate that was not checked because errors were ignored
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_valid: Option<bool>,
+    /// Certificate expiry as an RFC 3339 timestamp, if the response had one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_expiry: Option<String>,
+}
+
+impl SecurityInfo {
+    /// Security details for `url`, the page's final URL.
+    ///
+    /// `valid_to` is the certificate expiry from the final response's
+    /// security details, in seconds since the Unix epoch. `verified` is
+    /// whether the browser checked the certificate chain, i.e. whether
+    /// certificate errors were not being ignored.
+    #[must_use]
+    pub fn new(url: &str, valid_to: Option<f64>, verified: bool) -> Self {
+        let protocol = url
+            .split_once(':')
+            .map_or("unknown", |(scheme, _)| scheme)
+            .to_ascii_lowercase();
+        if protocol != "https" {
+            return Self {
+                protocol,
+                cert_valid: Some(false),
+                cert_expiry: None,
+            };
+        }
+
+        let expiry = valid_to.and_then(expiry_time);
+        let unexpired = expiry.map(|expiry| expiry > Utc::now());
+        Self {
+            protocol,
+            // An accepted chain is only evidence when the browser checked it
+            cert_valid: if verified {
+                unexpired
+            } else {
+                unexpired.filter(|unexpired| !unexpired)
+            },
+            cert_expiry: expiry.map(|expiry| expiry.to_rfc3339()),
+        }
+    }
+}
+
+/// Convert a `validTo` timestamp in seconds since the Unix epoch
+#[allow(clippy::cast_possible_truncation)]
+fn expiry_time(valid_to: f64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(valid_to as i64, 0)
+}
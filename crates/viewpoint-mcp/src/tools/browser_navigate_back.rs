@@ -1,14 +1,73 @@
 //! Browser navigate back tool for navigating back in history
 
 use async_trait::async_trait;
+use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
 
+use super::navigation_wait::NavigationWaitCondition;
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
 
 /// Browser navigate back tool - navigates back in browser history
 pub struct BrowserNavigateBackTool;
 
+/// Input parameters for `browser_navigate_back` and `browser_navigate_forward`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryStepsInput {
+    /// Number of history entries to move (default: 1)
+    #[serde(default = "default_steps")]
+    pub steps: usize,
+
+    /// Load state to wait for on the restored page (default: return as soon
+    /// as the history move completes)
+    pub wait_until: Option<NavigationWaitCondition>,
+}
+
+const fn default_steps() -> usize {
+    1
+}
+
+/// Number of history entries available on either side of the current entry
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct HistoryDepth {
+    /// Entries available behind the current one
+    pub back: usize,
+    /// Entries available ahead of the current one
+    pub forward: usize,
+}
+
+/// Query the page's session history depth.
+///
+/// Uses the Navigation API; returns `None` if the page doesn't support it,
+/// in which case callers skip the up-front depth check.
+pub(super) async fn history_depth(page: &Page) -> Option<HistoryDepth> {
+    let js_code = js! {
+        (() => {
+            if (!window.navigation || !navigation.currentEntry) {
+                return null;
+            }
+            const index = navigation.currentEntry.index;
+            return { back: index, forward: navigation.entries().length - 1 - index };
+        })()
+    };
+
+    let result: Value = page.evaluate(&js_code).await.ok()?;
+    serde_json::from_value(result).ok()
+}
+
+/// Error message for a history move that exceeds the available entries
+pub(super) fn insufficient_history_message(
+    direction: &str,
+    steps: usize,
+    available: usize,
+) -> String {
+    let entries = if available == 1 { "entry" } else { "entries" };
+    format!("Cannot go {direction} {steps} steps, only {available} {entries} in history")
+}
+
 impl BrowserNavigateBackTool {
     /// Create a new browser navigate back tool
     #[must_use]
@@ -30,17 +89,45 @@ impl Tool for BrowserNavigateBackTool {
     }
 
     fn description(&self) -> &'static str {
-        "Navigate back to the previous page in the browser history."
+        "Navigate back in the browser history. Goes back one page by default; \
+         use steps to go back several entries at once, and waitUntil to wait for the \
+         restored page to reach a load state."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["navigation"]
     }
 
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
-            "properties": {}
+            "properties": {
+                "steps": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "default": 1,
+                    "description": "Number of history entries to go back"
+                },
+                "waitUntil": NavigationWaitCondition::schema(
+                    "Load state to wait for on the restored page, same as browser_navigate. \
+                     Omit to return without waiting",
+                    None
+                )
+            }
         })
     }
 
-    async fn execute(&self, _args: &Value, browser: &mut BrowserState) -> ToolResult {
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: HistoryStepsInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if input.steps == 0 {
+            return Err(ToolError::InvalidParams(
+                "steps must be at least 1".to_string(),
+            ));
+        }
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -59,10 +146,27 @@ impl Tool for BrowserNavigateBackTool {
                 .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
                 .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
 
+            // Fail before moving at all if there isn't enough history
+            if let Some(depth) = history_depth(&page).await
+                && depth.back < input.steps
+            {
+                return Err(ToolError::ExecutionFailed(insufficient_history_message(
+                    "back",
+                    input.steps,
+                    depth.back,
+                )));
+            }
+
             // Navigate back
-            page.go_back()
-                .await
-                .map_err(|e| ToolError::ExecutionFailed(format!("Navigation back failed: {e}")))?;
+            for _ in 0..input.steps {
+                page.go_back().await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Navigation back failed: {e}"))
+                })?;
+            }
+
+            if let Some(condition) = input.wait_until {
+                condition.wait(&page).await?;
+            }
 
             // Get URL for response
             page.url().await.ok()
@@ -75,11 +179,17 @@ impl Tool for BrowserNavigateBackTool {
 
         context.invalidate_cache();
 
+        let steps = if input.steps == 1 {
+            String::new()
+        } else {
+            format!(" {} steps", input.steps)
+        };
+
         if let Some(url) = url {
             context.set_current_url(Some(url.clone())).await;
-            Ok(ToolOutput::text(format!("Navigated back to {url}")))
+            Ok(ToolOutput::text(format!("Navigated back{steps} to {url}")))
         } else {
-            Ok(ToolOutput::text("Navigated back"))
+            Ok(ToolOutput::text(format!("Navigated back{steps}")))
         }
     }
 }
@@ -0,0 +1,141 @@
+//! Browser navigate forward tool for navigating forward in history
+
+use async_trait::async_trait;
+use serde_json::{Value, json};
+
+use super::browser_navigate_back::{
+    HistoryStepsInput, history_depth, insufficient_history_message,
+};
+use super::navigation_wait::NavigationWaitCondition;
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+/// Browser navigate forward tool - navigates forward in browser history
+pub struct BrowserNavigateForwardTool;
+
+impl BrowserNavigateForwardTool {
+    /// Create a new browser navigate forward tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserNavigateForwardTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserNavigateForwardTool {
+    fn name(&self) -> &'static str {
+        "browser_navigate_forward"
+    }
+
+    fn description(&self) -> &'static str {
+        "Navigate forward in the browser history after going back. Goes forward one page \
+         by default; use steps to go forward several entries at once."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["navigation"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "steps": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "default": 1,
+                    "description": "Number of history entries to go forward"
+                },
+                "waitUntil": NavigationWaitCondition::schema(
+                    "Load state to wait for on the restored page, same as browser_navigate. \
+                     Omit to return without waiting",
+                    None
+                )
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: HistoryStepsInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if input.steps == 0 {
+            return Err(ToolError::InvalidParams(
+                "steps must be at least 1".to_string(),
+            ));
+        }
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active context (immutable) to get page and navigate
+        let url = {
+            let context = browser
+                .active_context()
+                .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+            let page = context
+                .active_page()
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+                .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+            // Fail before moving at all if there isn't enough history
+            if let Some(depth) = history_depth(&page).await
+                && depth.forward < input.steps
+            {
+                return Err(ToolError::ExecutionFailed(insufficient_history_message(
+                    "forward",
+                    input.steps,
+                    depth.forward,
+                )));
+            }
+
+            // Navigate forward
+            for _ in 0..input.steps {
+                page.go_forward().await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Navigation forward failed: {e}"))
+                })?;
+            }
+
+            if let Some(condition) = input.wait_until {
+                condition.wait(&page).await?;
+            }
+
+            // Get URL for response
+            page.url().await.ok()
+        };
+
+        // Now get mutable context to invalidate cache and update URL
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        context.invalidate_cache();
+
+        let steps = if input.steps == 1 {
+            String::new()
+        } else {
+            format!(" {} steps", input.steps)
+        };
+
+        if let Some(url) = url {
+            context.set_current_url(Some(url.clone())).await;
+            Ok(ToolOutput::text(format!(
+                "Navigated forward{steps} to {url}"
+            )))
+        } else {
+            Ok(ToolOutput::text(format!("Navigated forward{steps}")))
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Request and response bodies for the text output (`captureBody`)
+//!
+//! Bodies come from the responses captured for the page and are matched to
+//! Performance API entries by URL in order.
+
+use serde_json::{Value, json};
+
+use super::har::truncate_body;
+use crate::browser::StoredResponse;
+
+/// Default maximum size of each captured body, in bytes
+pub const DEFAULT_CAPTURE_MAX_BODY_SIZE: usize = 4096;
+
+/// Warning shown when bodies are included in the output
+pub const CAPTURE_BODY_WARNING: &str = "Warning: captureBody significantly increases memory usage \
+     and response size; only enable it when bodies are needed.";
+
+/// Add `requestBody` and `responseBody` fields to each request summary.
+///
+/// Each summary is paired with the first not-yet-used captured response for
+/// the same URL. Binary response bodies are base64-encoded and marked with
+/// `"responseBodyEncoding": "base64"`; bodies longer than `max_body_size`
+/// bytes are truncated and marked with `"bodyTruncated": true`.
+pub fn attach_bodies(requests: &mut [Value], responses: &[StoredResponse], max_body_size: usize) {
+    let mut used = vec![false; responses.len()];
+
+    for request in requests {
+        let Some(url) = request["url"].as_str() else {
+            continue;
+        };
+        let Some((i, response)) = responses
+            .iter()
+            .enumerate()
+            .find(|(i, r)| !used[*i] && r.url == url)
+        else {
+            continue;
+        };
+        used[i] = true;
+
+        let mut truncated = false;
+        if let Some(body) = response.request_body.as_deref() {
+            let (text, cut) = truncate_body(body, max_body_size);
+            request["requestBody"] = json!(text);
+            truncated |= cut;
+        }
+        if let Some((text, cut)) = response_body(response, max_body_size) {
+            request["responseBody"] = json!(text);
+            if response.body_base64 {
+                request["responseBodyEncoding"] = json!("base64");
+            }
+            truncated |= cut;
+        }
+        if truncated {
+            request["bodyTruncated"] = json!(true);
+        }
+    }
+}
+
+/// A response body truncated to at most `max_bytes`.
+///
+/// Base64 bodies are cut on a 4-character boundary so they stay decodable.
+#[must_use]
+pub fn response_body(response: &StoredResponse, max_bytes: usize) -> Option<(&str, bool)> {
+    let body = response.body.as_deref()?;
+    if !response.body_base64 {
+        return Some(truncate_body(body, max_bytes));
+    }
+    if body.len() <= max_bytes {
+        return Some((body, false));
+    }
+    Some((&body[..max_bytes - max_bytes % 4], true))
+}
@@ -0,0 +1,246 @@
+//! HAR 1.2 export of network activity
+//!
+//! Each entry is built from one captured response: headers, bodies, and
+//! timings all come from the same request, so repeated URLs never borrow
+//! each other's timings.
+
+use chrono::{DateTime, SecondsFormat};
+use serde_json::{Value, json};
+
+use super::body::response_body;
+use crate::browser::{RequestTiming, StoredResponse};
+
+/// Default maximum body size included per HAR entry, in bytes
+pub const DEFAULT_HAR_MAX_BODY_SIZE: usize = 64 * 1024;
+
+/// Content type prefixes that count as static resources
+const STATIC_CONTENT_TYPES: &[&str] = &[
+    "image/",
+    "font/",
+    "text/css",
+    "text/javascript",
+    "application/javascript",
+    "application/x-javascript",
+    "application/font-",
+];
+
+/// Whether a response is a successful static resource (image, font,
+/// stylesheet, script), judged by its content type
+#[must_use]
+pub fn is_successful_static(response: &StoredResponse) -> bool {
+    let content_type = response
+        .response_header("content-type")
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let is_static = STATIC_CONTENT_TYPES
+        .iter()
+        .any(|prefix| content_type.starts_with(prefix));
+    is_static && (200..400).contains(&response.status)
+}
+
+/// Build a HAR 1.2 document from captured responses, oldest first.
+///
+/// Response bodies longer than `max_body_size` bytes are truncated and
+/// marked with a comment.
+#[must_use]
+pub fn build_har(responses: &[StoredResponse], max_body_size: usize) -> Value {
+    let entries: Vec<Value> = responses
+        .iter()
+        .map(|response| har_entry(response, max_body_size))
+        .collect();
+
+    json!({
+        "log": {
+            "version": "1.2",
+            "creator": {
+                "name": "viewpoint-mcp",
+                "version": env!("CARGO_PKG_VERSION")
+            },
+            "entries": entries
+        }
+    })
+}
+
+/// HAR phase timings for a request.
+///
+/// Phases that did not happen are `-1`; `send`, `wait`, and `receive` are
+/// required by HAR and fall back to `0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HarTimings {
+    blocked: f64,
+    dns: f64,
+    connect: f64,
+    ssl: f64,
+    send: f64,
+    wait: f64,
+    receive: f64,
+}
+
+impl HarTimings {
+    /// Split a request's timing into HAR phases
+    fn from_timing(timing: Option<&RequestTiming>) -> Self {
+        let Some(t) = timing else {
+            return Self {
+                blocked: -1.0,
+                dns: -1.0,
+                connect: -1.0,
+                ssl: -1.0,
+                send: 0.0,
+                wait: 0.0,
+                receive: 0.0,
+            };
+        };
+        let first_phase = [t.domain_lookup_start, t.connect_start, t.request_start]
+            .into_iter()
+            .find(|offset| *offset >= 0.0);
+
+        Self {
+            blocked: first_phase.map_or(-1.0, |offset| phase(0.0, offset)),
+            dns: phase(t.domain_lookup_start, t.domain_lookup_end),
+            connect: phase(t.connect_start, t.connect_end),
+            ssl: phase(t.secure_connection_start, t.connect_end),
+            send: 0.0,
+            wait: phase(t.request_start, t.response_start).max(0.0),
+            receive: phase(t.response_start, t.response_end).max(0.0),
+        }
+    }
+
+    /// Total time of the request: the sum of all phases that happened
+    /// (`ssl` is already part of `connect`)
+    fn total(&self) -> f64 {
+        [
+            self.blocked,
+            self.dns,
+            self.connect,
+            self.send,
+            self.wait,
+            self.receive,
+        ]
+        .into_iter()
+        .filter(|phase| *phase > 0.0)
+        .sum()
+    }
+}
+
+/// Duration between two timing offsets, `-1` if either is missing
+fn phase(start: f64, end: f64) -> f64 {
+    if start >= 0.0 && end >= start {
+        end - start
+    } else {
+        -1.0
+    }
+}
+
+/// Build a single HAR entry
+fn har_entry(response: &StoredResponse, max_body_size: usize) -> Value {
+    let timings = HarTimings::from_timing(response.timing.as_ref());
+    let started = response
+        .timing
+        .map_or(response.captured_at, |timing| timing.start_time);
+
+    let mut content = json!({
+        "size": response.body_size,
+        "mimeType": response.response_header("content-type").unwrap_or("")
+    });
+    if let Some((text, truncated)) = response_body(response, max_body_size) {
+        content["text"] = json!(text);
+        if response.body_base64 {
+            content["encoding"] = json!("base64");
+        }
+        if truncated {
+            content["comment"] = json!(format!("Body truncated to {max_body_size} bytes"));
+        }
+    }
+
+    let mut entry = json!({
+        "startedDateTime": started_date_time(started),
+        "time": timings.total(),
+        "request": {
+            "method": response.method,
+            "url": response.url,
+            "httpVersion": "unknown",
+            "cookies": [],
+            "headers": har_headers(&response.request_headers),
+            "queryString": query_string(&response.url),
+            "headersSize": -1,
+            "bodySize": -1
+        },
+        "response": {
+            "status": response.status,
+            "statusText": response.status_text,
+            "httpVersion": "unknown",
+            "cookies": [],
+            "headers": har_headers(&response.response_headers),
+            "content": content,
+            "redirectURL": response.response_header("location").unwrap_or(""),
+            "headersSize": -1,
+            "bodySize": if response.body.is_some() { i64::try_from(response.body_size).unwrap_or(i64::MAX) } else { -1 }
+        },
+        "cache": {},
+        "timings": {
+            "blocked": timings.blocked,
+            "dns": timings.dns,
+            "connect": timings.connect,
+            "ssl": timings.ssl,
+            "send": timings.send,
+            "wait": timings.wait,
+            "receive": timings.receive
+        }
+    });
+    if let Some(body) = response.request_body.as_deref() {
+        let (text, _) = truncate_body(body, max_body_size);
+        entry["request"]["postData"] = json!({
+            "mimeType": response.request_header("content-type").unwrap_or(""),
+            "text": text
+        });
+        entry["request"]["bodySize"] = json!(body.len());
+    }
+    entry
+}
+
+/// Format epoch milliseconds as an ISO 8601 timestamp
+#[allow(clippy::cast_possible_truncation)]
+fn started_date_time(epoch_ms: f64) -> String {
+    DateTime::from_timestamp_millis(epoch_ms as i64)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+/// Convert header pairs to HAR `{ name, value }` objects
+fn har_headers(headers: &[(String, String)]) -> Vec<Value> {
+    headers
+        .iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect()
+}
+
+/// Split a URL's query string into HAR `{ name, value }` objects
+fn query_string(url: &str) -> Vec<Value> {
+    let Some((_, query)) = url.split_once('?') else {
+        return Vec::new();
+    };
+    let query = query.split_once('#').map_or(query, |(q, _)| q);
+
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            json!({ "name": name, "value": value })
+        })
+        .collect()
+}
+
+/// Truncate a body to at most `max_bytes`, on a UTF-8 character boundary
+#[must_use]
+pub fn truncate_body(body: &str, max_bytes: usize) -> (&str, bool) {
+    if body.len() <= max_bytes {
+        return (body, false);
+    }
+
+    let mut end = max_bytes;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    (&body[..end], true)
+}
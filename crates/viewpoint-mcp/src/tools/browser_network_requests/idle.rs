@@ -0,0 +1,121 @@
+//! Waiting for page network activity to settle
+
+use std::time::{Duration, Instant};
+
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use crate::browser::SharedRequestActivity;
+use crate::tools::ToolError;
+
+/// Default quiet period before the network is considered idle
+pub const DEFAULT_IDLE_TIMEOUT_MS: u64 = 500;
+
+/// Maximum time to wait for the network to become idle, as a multiple of
+/// the idle timeout
+const NETWORK_IDLE_MAX_WAIT_FACTOR: u32 = 20;
+
+/// Lower bound for the maximum wait, so short idle timeouts still leave the
+/// page time to load
+const NETWORK_IDLE_MIN_WAIT_MS: u64 = 5_000;
+
+/// Interval between network activity polls
+const NETWORK_IDLE_POLL_INTERVAL_MS: u64 = 100;
+
+/// Maximum time [`wait_for_network_idle`] waits for a given idle timeout
+#[must_use]
+pub fn max_idle_wait(idle_timeout: Duration) -> Duration {
+    idle_timeout
+        .saturating_mul(NETWORK_IDLE_MAX_WAIT_FACTOR)
+        .max(Duration::from_millis(NETWORK_IDLE_MIN_WAIT_MS))
+}
+
+/// Tracks network activity and reports when it has been quiet long enough
+#[derive(Debug)]
+pub struct NetworkIdleTracker {
+    idle_timeout: Duration,
+    last_finished: Option<usize>,
+    quiet_since: Instant,
+}
+
+impl NetworkIdleTracker {
+    /// Create a tracker requiring `idle_timeout` without network activity
+    #[must_use]
+    pub fn new(idle_timeout: Duration, now: Instant) -> Self {
+        Self {
+            idle_timeout,
+            last_finished: None,
+            quiet_since: now,
+        }
+    }
+
+    /// Record the number of requests in flight and finished so far,
+    /// returning `true` once the page is loaded, nothing is in flight, and
+    /// no request has finished for the idle timeout
+    pub fn observe(
+        &mut self,
+        loaded: bool,
+        in_flight: usize,
+        finished: usize,
+        now: Instant,
+    ) -> bool {
+        if !loaded || in_flight > 0 || self.last_finished != Some(finished) {
+            self.last_finished = Some(finished);
+            self.quiet_since = now;
+            return false;
+        }
+        now.duration_since(self.quiet_since) >= self.idle_timeout
+    }
+}
+
+/// Wait until the page has finished loading, no requests are in flight, and
+/// none have finished for `idle_timeout`.
+///
+/// Requests are counted from the page's request events. Without them, the
+/// Performance API's resource entries stand in for finished requests and
+/// pending requests go unnoticed.
+///
+/// # Errors
+///
+/// Returns `ToolError::Timeout` if the network does not settle within
+/// [`max_idle_wait`] of `idle_timeout`.
+pub async fn wait_for_network_idle(
+    page: &Page,
+    activity: Option<&SharedRequestActivity>,
+    idle_timeout: Duration,
+) -> Result<(), ToolError> {
+    let start = Instant::now();
+    let max_wait = max_idle_wait(idle_timeout);
+    let deadline = start + max_wait;
+    let mut tracker = NetworkIdleTracker::new(idle_timeout, start);
+
+    loop {
+        let (loaded, entries): (bool, usize) = page
+            .evaluate(&js! {
+                [document.readyState === "complete", performance.getEntriesByType("resource").length]
+            })
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to read network activity: {e}"))
+            })?;
+
+        let (in_flight, finished) = match activity {
+            Some(activity) => (activity.in_flight(), activity.finished()),
+            None => (0, entries),
+        };
+
+        let now = Instant::now();
+        if tracker.observe(loaded, in_flight, finished, now) {
+            return Ok(());
+        }
+
+        if now >= deadline {
+            return Err(ToolError::Timeout {
+                tool_name: "browser_network_requests".to_string(),
+                timeout_ms: u64::try_from(max_wait.as_millis()).unwrap_or(u64::MAX),
+            });
+        }
+
+        tokio::time::sleep(Duration::from_millis(NETWORK_IDLE_POLL_INTERVAL_MS)).await;
+    }
+}
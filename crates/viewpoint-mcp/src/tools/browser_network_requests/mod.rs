@@ -0,0 +1,297 @@
+//! Browser network requests tool for listing network requests
+
+mod body;
+mod har;
+mod idle;
+
+pub use body::{CAPTURE_BODY_WARNING, DEFAULT_CAPTURE_MAX_BODY_SIZE, attach_bodies, response_body};
+pub use har::{DEFAULT_HAR_MAX_BODY_SIZE, build_har, is_successful_static, truncate_body};
+pub use idle::{DEFAULT_IDLE_TIMEOUT_MS, NetworkIdleTracker, max_idle_wait};
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::{BrowserState, SharedNetworkBuffer, StoredResponse};
+
+/// Note shown when a call turns on response capture for the context
+pub const CAPTURE_STARTED_NOTE: &str = "Note: network capture started with this call; \
+     requests made earlier have no headers or bodies. Reload the page to record them.";
+
+/// Browser network requests tool - lists network requests since page load
+pub struct BrowserNetworkRequestsTool;
+
+/// Output format for network requests
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkOutputFormat {
+    /// JSON summary of each request (default)
+    #[default]
+    Text,
+    /// HAR 1.2 document with timings, headers, and bodies
+    Har,
+}
+
+/// Input parameters for `browser_network_requests`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserNetworkRequestsInput {
+    /// Whether to include static resources (images, fonts, scripts)
+    #[serde(default)]
+    pub include_static: bool,
+
+    /// Output format
+    #[serde(default)]
+    pub format: NetworkOutputFormat,
+
+    /// Maximum body size per HAR entry or captured body, in bytes
+    pub max_body_size: Option<usize>,
+
+    /// Include request and response bodies in the text output
+    #[serde(default)]
+    pub capture_body: bool,
+
+    /// Wait for network activity to settle before listing requests
+    #[serde(default)]
+    pub wait_for_idle: bool,
+
+    /// Quiet period without new requests that counts as idle, in milliseconds
+    pub idle_timeout_ms: Option<u64>,
+}
+
+impl BrowserNetworkRequestsTool {
+    /// Create a new browser network requests tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserNetworkRequestsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserNetworkRequestsTool {
+    fn name(&self) -> &'static str {
+        "browser_network_requests"
+    }
+
+    fn description(&self) -> &'static str {
+        "Returns all network requests made since loading the page. By default, excludes \
+         successful static resources (images, fonts, scripts). Set includeStatic: true \
+         to see all requests. Set format: 'har' to export a HAR 1.2 log with timings, \
+         headers, and response bodies. Set captureBody: true to include request and response \
+         bodies in the text output. Responses are only captured from the first call that uses \
+         format: 'har' or captureBody onwards. Set waitForIdle: true to wait for pages that keep \
+         issuing requests after load."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["inspection"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "includeStatic": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Include successful static resources like images, fonts, scripts"
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["text", "har"],
+                    "default": "text",
+                    "description": "Output format: 'text' (request summaries) or 'har' (HAR 1.2 log with timings, request/response headers, and bodies)"
+                },
+                "maxBodySize": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": format!(
+                        "Maximum body size in bytes included per HAR entry (default {DEFAULT_HAR_MAX_BODY_SIZE}) \
+                         or per body with captureBody (default {DEFAULT_CAPTURE_MAX_BODY_SIZE}); longer bodies are truncated"
+                    )
+                },
+                "captureBody": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Include request and response bodies in each request entry. Binary response bodies \
+                                   are base64-encoded. Significantly increases memory usage and response size."
+                },
+                "waitForIdle": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Wait until the page has loaded and no new requests complete for idleTimeoutMs before listing requests"
+                },
+                "idleTimeoutMs": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "default": DEFAULT_IDLE_TIMEOUT_MS,
+                    "description": "Quiet period in milliseconds with no requests in flight that counts as idle (used with waitForIdle). \
+                                   The wait gives up after 20 times this period, and at least 5 seconds"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserNetworkRequestsInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active page
+        let context = browser
+            .active_context()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        // Capturing reads every response body, so only do it once asked for
+        let capture_started = (input.format == NetworkOutputFormat::Har || input.capture_body)
+            && context.enable_network_capture().await;
+        let note = if capture_started {
+            format!("{CAPTURE_STARTED_NOTE}\n\n")
+        } else {
+            String::new()
+        };
+
+        if input.wait_for_idle {
+            let idle_timeout =
+                Duration::from_millis(input.idle_timeout_ms.unwrap_or(DEFAULT_IDLE_TIMEOUT_MS));
+            let activity = context.active_request_activity().await;
+            idle::wait_for_network_idle(&page, activity.as_ref(), idle_timeout).await?;
+        }
+
+        if input.format == NetworkOutputFormat::Har {
+            let mut responses =
+                current_responses(&page, context.active_network_buffer().await).await;
+            if !input.include_static {
+                responses.retain(|response| !is_successful_static(response));
+            }
+
+            let har = build_har(
+                &responses,
+                input.max_body_size.unwrap_or(DEFAULT_HAR_MAX_BODY_SIZE),
+            );
+            let har = serde_json::to_string_pretty(&har)
+                .map_err(|e| ToolError::ExecutionFailed(format!("Failed to serialize HAR: {e}")))?;
+            return Ok(ToolOutput::text(format!("{note}{har}")));
+        }
+
+        // Use JavaScript to retrieve network requests via Performance API
+        // This gives us the resource timing entries
+        let include_static = input.include_static;
+        let js_code = js! {
+            (() => {
+                const entries = performance.getEntriesByType("resource");
+                const staticTypes = ["img", "font", "stylesheet", "script"];
+
+                const requests = entries.map(entry => {
+                    let resourceType = "other";
+                    if (entry.initiatorType) {
+                        resourceType = entry.initiatorType;
+                    }
+
+                    return {
+                        url: entry.name,
+                        type: resourceType,
+                        duration: Math.round(entry.duration),
+                        size: entry.transferSize || 0,
+                        status: entry.responseStatus || null
+                    };
+                });
+
+                const includeStatic = #{include_static};
+                if (includeStatic) {
+                    return requests;
+                }
+
+                // Filter out successful static resources
+                return requests.filter(r => {
+                    const isStatic = staticTypes.includes(r.type);
+                    const isSuccess = r.status === null || (r.status >= 200 && r.status < 400);
+                    return !(isStatic && isSuccess);
+                });
+            })()
+        };
+
+        let mut result: serde_json::Value = page.evaluate(&js_code).await.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to get network requests: {e}"))
+        })?;
+
+        if input.capture_body
+            && let Some(requests) = result.as_array_mut()
+        {
+            let responses = current_responses(&page, context.active_network_buffer().await).await;
+            attach_bodies(
+                requests,
+                &responses,
+                input.max_body_size.unwrap_or(DEFAULT_CAPTURE_MAX_BODY_SIZE),
+            );
+        }
+
+        // Format output
+        let requests = result.as_array().map_or(0, Vec::len);
+
+        if requests == 0 {
+            return Ok(ToolOutput::text(format!(
+                "{note}No network requests recorded."
+            )));
+        }
+
+        Ok(ToolOutput::text(format!(
+            "{note}{}Network requests ({} total{}):\n\n{}",
+            if input.capture_body {
+                format!("{CAPTURE_BODY_WARNING}\n\n")
+            } else {
+                String::new()
+            },
+            requests,
+            if input.include_static {
+                ""
+            } else {
+                ", excluding static resources"
+            },
+            serde_json::to_string_pretty(&result).unwrap_or_else(|_| "[]".to_string())
+        )))
+    }
+}
+
+/// Responses captured since the page's current document started loading.
+///
+/// Responses of earlier documents are dropped from the buffer, so a
+/// navigation starts a fresh log.
+async fn current_responses(
+    page: &Page,
+    buffer: Option<SharedNetworkBuffer>,
+) -> Vec<StoredResponse> {
+    let Some(buffer) = buffer else {
+        return Vec::new();
+    };
+
+    let time_origin: Option<f64> = page.evaluate(&js! { performance.timeOrigin }).await.ok();
+    let mut buffer = buffer.write().await;
+    if let Some(time_origin) = time_origin {
+        buffer.retain_since(time_origin);
+    }
+    buffer.responses().iter().cloned().collect()
+}
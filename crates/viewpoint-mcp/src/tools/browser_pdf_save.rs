@@ -5,7 +5,7 @@ use serde::Deserialize;
 use serde_json::{Value, json};
 
 use super::traits::Capability;
-use super::{Tool, ToolError, ToolOutput, ToolResult};
+use super::{ContentItem, Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
 
 /// Browser PDF save tool
@@ -15,12 +15,20 @@ pub struct BrowserPdfSaveTool;
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BrowserPdfSaveInput {
-    /// Path to save the PDF file (required)
-    pub path: String,
+    /// Path to save the PDF file, or `null` to return the PDF inline.
+    ///
+    /// The key is required so that exactly one mode is chosen explicitly.
+    #[serde(deserialize_with = "Option::deserialize")]
+    pub path: Option<String>,
 
-    /// Paper format
-    #[serde(default)]
-    pub format: PaperFormat,
+    /// Paper format (defaults to Letter unless `width`/`height` are given)
+    pub format: Option<PaperFormat>,
+
+    /// Custom paper width in inches (alternative to `format`)
+    pub width: Option<f64>,
+
+    /// Custom paper height in inches (alternative to `format`)
+    pub height: Option<f64>,
 
     /// Use landscape orientation
     #[serde(default)]
@@ -38,6 +46,126 @@ pub struct BrowserPdfSaveInput {
 
     /// Margin in inches (uniform on all sides)
     pub margin: Option<f64>,
+
+    /// HTML template for the page header
+    pub header_template: Option<String>,
+
+    /// HTML template for the page footer
+    pub footer_template: Option<String>,
+}
+
+/// Template that renders nothing, used for whichever of the header and footer
+/// was not given so Chrome does not fill it with its default date and title
+const EMPTY_TEMPLATE: &str = "<span></span>";
+
+impl BrowserPdfSaveInput {
+    /// Header and footer templates to render, if either was given.
+    ///
+    /// The missing one is replaced by an empty template.
+    #[must_use]
+    pub fn header_footer_templates(&self) -> Option<(&str, &str)> {
+        if self.header_template.is_none() && self.footer_template.is_none() {
+            return None;
+        }
+        Some((
+            self.header_template.as_deref().unwrap_or(EMPTY_TEMPLATE),
+            self.footer_template.as_deref().unwrap_or(EMPTY_TEMPLATE),
+        ))
+    }
+}
+
+/// Smallest and largest custom paper dimension, in inches
+const PAPER_SIZE_RANGE: std::ops::RangeInclusive<f64> = 0.1..=100.0;
+
+impl BrowserPdfSaveInput {
+    /// Paper format to use when no custom size is given
+    #[must_use]
+    pub fn paper_format(&self) -> PaperFormat {
+        self.format.unwrap_or_default()
+    }
+
+    /// Custom paper size as `(width, height)` in inches, or `None` to use a format.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the size is combined with `format`, only one
+    /// dimension is given, or a dimension is outside 0.1–100 inches.
+    pub fn paper_size(&self) -> Result<Option<(f64, f64)>, String> {
+        let (width, height) = match (self.width, self.height) {
+            (None, None) => return Ok(None),
+            (Some(width), Some(height)) => (width, height),
+            _ => return Err("width and height must be provided together".to_string()),
+        };
+
+        if self.format.is_some() {
+            return Err("format cannot be combined with width and height".to_string());
+        }
+
+        for (name, value) in [("width", width), ("height", height)] {
+            if !PAPER_SIZE_RANGE.contains(&value) {
+                return Err(format!(
+                    "Paper {name} must be between {} and {} inches, got {value}",
+                    PAPER_SIZE_RANGE.start(),
+                    PAPER_SIZE_RANGE.end()
+                ));
+            }
+        }
+
+        Ok(Some((width, height)))
+    }
+}
+
+/// Validate page ranges in CSS `@page` syntax (e.g., "1-5, 8, 11-13").
+///
+/// Pages are 1-based; a range may be open-ended ("5-" or "-3") but its start
+/// must not exceed its end.
+///
+/// # Errors
+///
+/// Returns an error message describing the first invalid range.
+pub fn validate_page_ranges(page_ranges: &str) -> Result<(), String> {
+    let parse_page = |page: &str, range: &str| -> Result<Option<u32>, String> {
+        if page.is_empty() {
+            return Ok(None);
+        }
+        match page.parse::<u32>() {
+            Ok(0) | Err(_) => Err(format!(
+                "Invalid page range '{range}': pages must be numbers starting at 1"
+            )),
+            Ok(n) => Ok(Some(n)),
+        }
+    };
+
+    for range in page_ranges.split(',').map(str::trim) {
+        if range.is_empty() {
+            return Err(format!("Invalid page ranges '{page_ranges}': empty range"));
+        }
+
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (
+                parse_page(start.trim(), range)?,
+                parse_page(end.trim(), range)?,
+            ),
+            None => {
+                let page = parse_page(range, range)?;
+                (page, page)
+            }
+        };
+
+        match (start, end) {
+            (None, None) => {
+                return Err(format!("Invalid page range '{range}': missing page number"));
+            }
+            (Some(start), Some(end)) if start > end => {
+                return Err(format!(
+                    "Invalid page range '{range}': start is greater than end"
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
 }
 
 /// Paper format options
@@ -97,8 +225,13 @@ impl Tool for BrowserPdfSaveTool {
     }
 
     fn description(&self) -> &'static str {
-        "Save the current page as a PDF file. Supports various paper formats, \
-         orientation, scaling, and page range selection."
+        "Save the current page as a PDF file, or return it inline as base64 with path: null. \
+         Supports various paper formats or a custom width/height, orientation, scaling, page range selection, and \
+         header/footer templates."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["inspection"]
     }
 
     fn input_schema(&self) -> Value {
@@ -107,14 +240,28 @@ impl Tool for BrowserPdfSaveTool {
             "required": ["path"],
             "properties": {
                 "path": {
-                    "type": "string",
-                    "description": "File path to save the PDF (e.g., '/tmp/page.pdf')"
+                    "type": ["string", "null"],
+                    "description": "File path to save the PDF (e.g., '/tmp/page.pdf'), or null to return the PDF \
+                                   inline as base64-encoded application/pdf content instead of writing it to disk. \
+                                   Use null when the client cannot read files on the server's machine."
                 },
                 "format": {
                     "type": "string",
                     "enum": ["letter", "legal", "tabloid", "ledger", "a0", "a1", "a2", "a3", "a4", "a5", "a6"],
                     "default": "letter",
-                    "description": "Paper format"
+                    "description": "Paper format. Cannot be combined with width/height"
+                },
+                "width": {
+                    "type": "number",
+                    "minimum": 0.1,
+                    "maximum": 100,
+                    "description": "Custom paper width in inches, for non-standard sizes (requires height)"
+                },
+                "height": {
+                    "type": "number",
+                    "minimum": 0.1,
+                    "maximum": 100,
+                    "description": "Custom paper height in inches, for non-standard sizes (requires width)"
                 },
                 "landscape": {
                     "type": "boolean",
@@ -134,11 +281,19 @@ impl Tool for BrowserPdfSaveTool {
                 },
                 "pageRanges": {
                     "type": "string",
-                    "description": "Page ranges to print (e.g., '1-5, 8, 11-13')"
+                    "description": "Page ranges to print, 1-based (e.g., '1-5, 8, 11-13'). Defaults to all pages"
                 },
                 "margin": {
                     "type": "number",
                     "description": "Margin in inches (uniform on all sides)"
+                },
+                "headerTemplate": {
+                    "type": "string",
+                    "description": "HTML template for the header. Elements with classes 'date', 'title', 'url', 'pageNumber', and 'totalPages' are filled in (e.g., '<span class=\"pageNumber\"></span>')"
+                },
+                "footerTemplate": {
+                    "type": "string",
+                    "description": "HTML template for the footer, using the same classes as headerTemplate. When only one of the two templates is given, the other is left blank"
                 }
             }
         })
@@ -154,7 +309,7 @@ impl Tool for BrowserPdfSaveTool {
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
         // Validate path
-        if input.path.is_empty() {
+        if input.path.as_deref().is_some_and(str::is_empty) {
             return Err(ToolError::InvalidParams("Path cannot be empty".to_string()));
         }
 
@@ -167,6 +322,12 @@ impl Tool for BrowserPdfSaveTool {
             ));
         }
 
+        if let Some(ref page_ranges) = input.page_ranges {
+            validate_page_ranges(page_ranges).map_err(ToolError::InvalidParams)?;
+        }
+
+        let paper_size = input.paper_size().map_err(ToolError::InvalidParams)?;
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -187,10 +348,20 @@ impl Tool for BrowserPdfSaveTool {
         // Build PDF with options
         let mut pdf_builder = page
             .pdf()
-            .format(input.format.into())
             .landscape(input.landscape)
-            .print_background(input.print_background)
-            .path(&input.path);
+            .print_background(input.print_background);
+
+        let paper = if let Some((width, height)) = paper_size {
+            pdf_builder = pdf_builder.paper_width(width).paper_height(height);
+            format!("size: {width}x{height} in")
+        } else {
+            pdf_builder = pdf_builder.format(input.paper_format().into());
+            format!("format: {:?}", input.paper_format())
+        };
+
+        if let Some(ref path) = input.path {
+            pdf_builder = pdf_builder.path(path);
+        }
 
         if let Some(scale) = input.scale {
             pdf_builder = pdf_builder.scale(scale);
@@ -204,17 +375,37 @@ impl Tool for BrowserPdfSaveTool {
             pdf_builder = pdf_builder.margin(margin);
         }
 
+        // Headers and footers are only rendered when explicitly enabled
+        if let Some((header_template, footer_template)) = input.header_footer_templates() {
+            pdf_builder = pdf_builder
+                .display_header_footer(true)
+                .header_template(header_template)
+                .footer_template(footer_template);
+        }
+
         // Generate the PDF
         let data = pdf_builder
             .generate()
             .await
             .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
 
+        let Some(path) = input.path else {
+            use base64::engine::{Engine as _, general_purpose::STANDARD};
+
+            return Ok(ToolOutput::new(vec![
+                ContentItem::text(format!(
+                    "PDF generated inline ({} bytes, {paper}, landscape: {})",
+                    data.len(),
+                    input.landscape
+                )),
+                ContentItem::image(STANDARD.encode(&data), "application/pdf"),
+            ]));
+        };
+
         Ok(ToolOutput::text(format!(
-            "PDF saved to '{}' ({} bytes, format: {:?}, landscape: {})",
-            input.path,
+            "PDF saved to '{}' ({} bytes, {paper}, landscape: {})",
+            path,
             data.len(),
-            input.format,
             input.landscape
         )))
     }
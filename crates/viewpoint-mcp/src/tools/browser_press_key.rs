@@ -6,6 +6,7 @@ use serde_json::{Value, json};
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
+use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
 
 /// Browser press key tool - presses a keyboard key
 pub struct BrowserPressKeyTool;
@@ -16,6 +17,13 @@ pub struct BrowserPressKeyTool;
 pub struct BrowserPressKeyInput {
     /// Key name to press (e.g., "Enter", "Tab", "Control+a", "`ArrowLeft`")
     pub key: String,
+
+    /// Element reference from snapshot to focus before pressing the key
+    #[serde(rename = "ref")]
+    pub element_ref: Option<String>,
+
+    /// Human-readable element description (required if ref is provided)
+    pub element: Option<String>,
 }
 
 impl BrowserPressKeyTool {
@@ -40,7 +48,13 @@ impl Tool for BrowserPressKeyTool {
 
     fn description(&self) -> &'static str {
         "Press a keyboard key. Supports key names like 'Enter', 'Tab', 'Escape', 'ArrowLeft', \
-         and key combinations like 'Control+a', 'Shift+Tab', 'Alt+F4'."
+         and key combinations like 'Control+a', 'Shift+Tab', 'Alt+F4'. Presses into the focused \
+         element, or pass a ref to focus that element first (useful for custom widgets that \
+         only react to keyboard events)."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -51,6 +65,14 @@ impl Tool for BrowserPressKeyTool {
                 "key": {
                     "type": "string",
                     "description": "Name of the key to press or a character to generate, such as 'ArrowLeft', 'Enter', 'Tab', or 'a'. Key combinations use '+' (e.g., 'Control+a', 'Shift+Tab')."
+                },
+                "ref": {
+                    "type": "string",
+                    "description": "Element reference from browser_snapshot to focus before pressing the key. Defaults to the currently focused element."
+                },
+                "element": {
+                    "type": "string",
+                    "description": "Human-readable description of the element. Required if ref is provided."
                 }
             }
         })
@@ -65,6 +87,12 @@ impl Tool for BrowserPressKeyTool {
             return Err(ToolError::InvalidParams("Key cannot be empty".to_string()));
         }
 
+        if input.element_ref.is_some() && input.element.is_none() {
+            return Err(ToolError::InvalidParams(
+                "element description is required when ref is provided".to_string(),
+            ));
+        }
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -82,6 +110,27 @@ impl Tool for BrowserPressKeyTool {
             .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
             .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
 
+        // Focus the target element first so the key goes to it
+        if let Some(element_ref) = &input.element_ref {
+            let snapshot = AccessibilitySnapshot::capture(&page, SnapshotOptions::default())
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+
+            snapshot.lookup(element_ref).map_err(|e| {
+                ToolError::ElementNotFound(format!("Element ref '{element_ref}': {e}"))
+            })?;
+
+            page.locator_from_ref(element_ref)
+                .focus()
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to focus element '{}': {e}",
+                        input.element.as_deref().unwrap_or_default()
+                    ))
+                })?;
+        }
+
         // Press the key using the keyboard API
         page.keyboard().press(&input.key).await.map_err(|e| {
             ToolError::ExecutionFailed(format!("Failed to press key '{}': {}", input.key, e))
@@ -90,6 +139,12 @@ impl Tool for BrowserPressKeyTool {
         // Invalidate cache after keyboard interaction
         context.invalidate_cache();
 
-        Ok(ToolOutput::text(format!("Pressed key '{}'", input.key)))
+        match (&input.element, &input.element_ref) {
+            (Some(element), Some(element_ref)) => Ok(ToolOutput::text(format!(
+                "Pressed key '{}' on {element} [ref={element_ref}]",
+                input.key
+            ))),
+            _ => Ok(ToolOutput::text(format!("Pressed key '{}'", input.key))),
+        }
     }
 }
@@ -3,6 +3,7 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_cdp::protocol::emulation::SetDeviceMetricsOverrideParams;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -19,6 +20,12 @@ pub struct BrowserResizeInput {
 
     /// Height of the viewport in pixels
     pub height: i32,
+
+    /// Device pixel ratio to emulate (e.g., 2.0 for Retina displays)
+    pub device_scale_factor: Option<f64>,
+
+    /// Whether to emulate a mobile device (meta viewport, overlay scrollbars)
+    pub mobile: Option<bool>,
 }
 
 impl BrowserResizeTool {
@@ -42,10 +49,15 @@ impl Tool for BrowserResizeTool {
     }
 
     fn description(&self) -> &'static str {
-        "Resize the browser viewport to the specified dimensions. \
+        "Resize the browser viewport to the specified dimensions, optionally emulating \
+         a HiDPI device pixel ratio or a mobile device. \
          This affects how the page is rendered and can trigger responsive layouts."
     }
 
+    fn categories(&self) -> Vec<&str> {
+        vec!["management"]
+    }
+
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
@@ -60,13 +72,26 @@ impl Tool for BrowserResizeTool {
                     "type": "number",
                     "description": "Height of the viewport in pixels",
                     "minimum": 1
+                },
+                "deviceScaleFactor": {
+                    "type": "number",
+                    "description": "Device pixel ratio to emulate (e.g., 2 for Retina displays)",
+                    "exclusiveMinimum": 0,
+                    "maximum": 10
+                },
+                "mobile": {
+                    "type": "boolean",
+                    "description": "Emulate a mobile device (honors meta viewport, uses overlay scrollbars)"
                 }
             }
         })
     }
 
     async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        use std::fmt::Write;
+
         const MAX_DIMENSION: i32 = 16384;
+        const MAX_SCALE_FACTOR: f64 = 10.0;
 
         // Parse input
         let input: BrowserResizeInput = serde_json::from_value(args.clone())
@@ -96,6 +121,14 @@ impl Tool for BrowserResizeTool {
             )));
         }
 
+        if let Some(scale) = input.device_scale_factor
+            && !(scale > 0.0 && scale <= MAX_SCALE_FACTOR)
+        {
+            return Err(ToolError::InvalidParams(format!(
+                "deviceScaleFactor must be greater than 0 and at most {MAX_SCALE_FACTOR}"
+            )));
+        }
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -113,17 +146,48 @@ impl Tool for BrowserResizeTool {
             .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
             .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
 
-        // Set viewport size
-        page.set_viewport_size(input.width, input.height)
-            .await
-            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to resize viewport: {e}")))?;
+        if input.device_scale_factor.is_some() || input.mobile.is_some() {
+            // Device metrics override sets size, pixel ratio, and mobile mode together
+            let params = SetDeviceMetricsOverrideParams {
+                width: input.width,
+                height: input.height,
+                device_scale_factor: input.device_scale_factor.unwrap_or(0.0),
+                mobile: input.mobile.unwrap_or(false),
+                ..Default::default()
+            };
+            page.connection()
+                .send_command::<_, Value>(
+                    "Emulation.setDeviceMetricsOverride",
+                    Some(params),
+                    Some(page.session_id()),
+                )
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Failed to resize viewport: {e}"))
+                })?;
+        } else {
+            // Set viewport size
+            page.set_viewport_size(input.width, input.height)
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Failed to resize viewport: {e}"))
+                })?;
+        }
 
         // Invalidate cache since the layout may have changed
         context.invalidate_cache();
 
-        Ok(ToolOutput::text(format!(
+        let mut result = format!(
             "Resized viewport to {}x{} pixels",
             input.width, input.height
-        )))
+        );
+        if let Some(scale) = input.device_scale_factor {
+            let _ = write!(result, " at {scale}x device scale factor");
+        }
+        if input.mobile == Some(true) {
+            result.push_str(" with mobile emulation");
+        }
+
+        Ok(ToolOutput::text(result))
     }
 }
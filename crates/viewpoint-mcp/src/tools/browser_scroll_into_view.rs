@@ -1,8 +1,12 @@
 //! Browser scroll into view tool for scrolling elements into the visible viewport
 
+use std::fmt::Write;
+
 use async_trait::async_trait;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -21,6 +25,61 @@ pub struct BrowserScrollIntoViewInput {
 
     /// Human-readable element description for verification
     pub element: String,
+
+    /// Whether to include the element's bounding box after scrolling
+    #[serde(default)]
+    pub return_bounds: bool,
+}
+
+/// Window scroll position reported after scrolling
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScrollPosition {
+    /// Horizontal scroll offset in CSS pixels
+    pub scroll_x: f64,
+
+    /// Vertical scroll offset in CSS pixels
+    pub scroll_y: f64,
+
+    /// Largest possible horizontal scroll offset
+    pub max_scroll_x: f64,
+
+    /// Largest possible vertical scroll offset
+    pub max_scroll_y: f64,
+
+    /// Whether the page cannot scroll further down
+    pub at_bottom: bool,
+}
+
+impl ScrollPosition {
+    /// Read the window's scroll position.
+    ///
+    /// `atBottom` allows one pixel of slack, since scroll offsets can be
+    /// fractional on zoomed or high-DPI pages.
+    async fn read(page: &Page) -> Result<Self, ToolError> {
+        let position: Value = page
+            .evaluate(&js! {
+                (() => {
+                    const root = document.documentElement;
+                    const maxScrollX = Math.max(0, root.scrollWidth - window.innerWidth);
+                    const maxScrollY = Math.max(0, root.scrollHeight - window.innerHeight);
+                    return {
+                        scrollX: window.scrollX,
+                        scrollY: window.scrollY,
+                        maxScrollX,
+                        maxScrollY,
+                        atBottom: window.scrollY >= maxScrollY - 1
+                    };
+                })()
+            })
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to read scroll position: {e}"))
+            })?;
+
+        serde_json::from_value(position)
+            .map_err(|e| ToolError::ExecutionFailed(format!("Invalid scroll position: {e}")))
+    }
 }
 
 impl BrowserScrollIntoViewTool {
@@ -45,7 +104,14 @@ impl Tool for BrowserScrollIntoViewTool {
 
     fn description(&self) -> &'static str {
         "Scroll an element into the visible viewport. Useful for bringing elements into view \
-         before taking screenshots or when elements are outside the visible viewport."
+         before taking screenshots or when elements are outside the visible viewport. \
+         Use returnBounds: true to get the element's viewport coordinates for vision tools. \
+         The response includes the new window scroll position and whether the page is \
+         scrolled to the bottom."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -60,6 +126,11 @@ impl Tool for BrowserScrollIntoViewTool {
                 "element": {
                     "type": "string",
                     "description": "Human-readable description of the element"
+                },
+                "returnBounds": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Include the element's bounding box { x, y, width, height } in CSS pixels relative to the viewport after scrolling"
                 }
             }
         })
@@ -112,9 +183,46 @@ impl Tool for BrowserScrollIntoViewTool {
         // Invalidate cache after scroll (DOM may have changed via lazy loading)
         context.invalidate_cache();
 
-        Ok(ToolOutput::text(format!(
+        let mut result = format!(
             "Scrolled {} into view [ref={}]",
             input.element, input.element_ref
-        )))
+        );
+
+        // The scroll already happened, so a failed read only drops the position
+        match ScrollPosition::read(&page).await {
+            Ok(position) => {
+                let position = serde_json::to_value(&position).unwrap_or_default();
+                let _ = write!(result, "\nScroll position: {position}");
+            }
+            Err(e) => tracing::warn!(error = %e, "Failed to read scroll position"),
+        }
+
+        if input.return_bounds {
+            let bbox = locator
+                .bounding_box()
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to get bounding box for '{}': {e}",
+                        input.element
+                    ))
+                })?
+                .ok_or_else(|| {
+                    ToolError::ElementNotFound(format!(
+                        "Element '{}' has no bounding box (may be hidden)",
+                        input.element
+                    ))
+                })?;
+
+            let bounds = json!({
+                "x": bbox.x,
+                "y": bbox.y,
+                "width": bbox.width,
+                "height": bbox.height
+            });
+            let _ = write!(result, "\nBounds: {bounds}");
+        }
+
+        Ok(ToolOutput::text(result))
     }
 }
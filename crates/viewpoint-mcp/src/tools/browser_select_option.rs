@@ -3,6 +3,7 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -23,7 +24,100 @@ pub struct BrowserSelectOptionInput {
     pub element: String,
 
     /// Values to select (can be single or multiple for multi-select)
+    #[serde(default)]
     pub values: Vec<String>,
+
+    /// Visible text of the option to select
+    pub label: Option<String>,
+
+    /// Zero-based index of the option to select
+    pub index: Option<usize>,
+}
+
+/// How the option to select is identified
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OptionSelector {
+    /// By `value` attribute (or visible text)
+    Values(Vec<String>),
+    /// By visible text
+    Label(String),
+    /// By zero-based position in the dropdown
+    Index(usize),
+}
+
+impl OptionSelector {
+    /// JavaScript expression resolving a label to an option value on
+    /// `element`, or `None` for selectors that need no lookup
+    fn resolve_script(&self) -> Option<String> {
+        match self {
+            Self::Values(_) | Self::Index(_) => None,
+            Self::Label(label) => Some(js! {
+                (() => {
+                    const label = #{label}.trim();
+                    const option = Array.from(element.options || [])
+                        .find(o => (o.label || o.text).trim() === label);
+                    return option ? option.value : null;
+                })()
+            }),
+        }
+    }
+
+    /// Human-readable description (e.g., `label 'Canada'`, `index 2`)
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Values(values) => format!("values {values:?}"),
+            Self::Label(label) => format!("label '{label}'"),
+            Self::Index(index) => format!("index {index}"),
+        }
+    }
+}
+
+/// JavaScript selecting the option at `index` on `element` and firing the
+/// `input` and `change` events a user selection would.
+///
+/// Selecting by position rather than by value keeps options that share a
+/// value apart. Returns the selected option's value, or `null` if the index
+/// is out of range.
+fn select_index_script(index: usize) -> String {
+    js! {
+        (() => {
+            const options = element.options || [];
+            if (#{index} >= options.length) {
+                return null;
+            }
+            element.selectedIndex = #{index};
+            element.dispatchEvent(new Event("input", { bubbles: true }));
+            element.dispatchEvent(new Event("change", { bubbles: true }));
+            return options[#{index}].value;
+        })()
+    }
+}
+
+impl BrowserSelectOptionInput {
+    /// Get the option selector, requiring exactly one of `values`, `label`, or `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ToolError::InvalidParams` if none or more than one is provided.
+    pub fn selector(&self) -> Result<OptionSelector, ToolError> {
+        let provided = usize::from(!self.values.is_empty())
+            + usize::from(self.label.is_some())
+            + usize::from(self.index.is_some());
+        if provided != 1 {
+            return Err(ToolError::InvalidParams(
+                "Exactly one of values, label, or index must be provided".to_string(),
+            ));
+        }
+
+        Ok(if let Some(label) = &self.label {
+            OptionSelector::Label(label.clone())
+        } else if let Some(index) = self.index {
+            OptionSelector::Index(index)
+        } else {
+            OptionSelector::Values(self.values.clone())
+        })
+    }
 }
 
 impl BrowserSelectOptionTool {
@@ -47,14 +141,19 @@ impl Tool for BrowserSelectOptionTool {
     }
 
     fn description(&self) -> &'static str {
-        "Select an option in a dropdown element. For multi-select elements, \
-         multiple values can be provided."
+        "Select an option in a dropdown element by value, visible text (label), or \
+         zero-based index. Provide exactly one of values, label, or index. For \
+         multi-select elements, multiple values can be provided."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
         json!({
             "type": "object",
-            "required": ["ref", "element", "values"],
+            "required": ["ref", "element"],
             "properties": {
                 "ref": {
                     "type": "string",
@@ -68,6 +167,15 @@ impl Tool for BrowserSelectOptionTool {
                     "type": "array",
                     "items": { "type": "string" },
                     "description": "Values to select (by value attribute or visible text)"
+                },
+                "label": {
+                    "type": "string",
+                    "description": "Visible text of the option to select (alternative to values)"
+                },
+                "index": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "description": "Zero-based index of the option to select (alternative to values)"
                 }
             }
         })
@@ -78,11 +186,7 @@ impl Tool for BrowserSelectOptionTool {
         let input: BrowserSelectOptionInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
-        if input.values.is_empty() {
-            return Err(ToolError::InvalidParams(
-                "At least one value must be provided".to_string(),
-            ));
-        }
+        let selector = input.selector()?;
 
         // Ensure browser is initialized
         browser
@@ -115,12 +219,60 @@ impl Tool for BrowserSelectOptionTool {
         // Use native ref resolution API from viewpoint 0.2.9
         let locator = page.locator_from_ref(&input.element_ref);
 
+        if let OptionSelector::Index(index) = selector {
+            let value: Value = locator
+                .evaluate(&select_index_script(index))
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to select option(s) in '{}': {e}",
+                        input.element
+                    ))
+                })?;
+            let value = value.as_str().ok_or_else(|| {
+                ToolError::InvalidParams(format!(
+                    "No option with {} in '{}'",
+                    selector.describe(),
+                    input.element
+                ))
+            })?;
+
+            context.invalidate_cache();
+            return Ok(ToolOutput::text(format!(
+                "Selected option with {} (value {value:?}) in {} [ref={}]",
+                selector.describe(),
+                input.element,
+                input.element_ref
+            )));
+        }
+
+        // Resolve a label to the option's value
+        let values = match selector.resolve_script() {
+            None => input.values.clone(),
+            Some(script) => {
+                let value: Value = locator.evaluate(&script).await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to read options of '{}': {e}",
+                        input.element
+                    ))
+                })?;
+                let value = value.as_str().ok_or_else(|| {
+                    ToolError::InvalidParams(format!(
+                        "No option with {} in '{}'",
+                        selector.describe(),
+                        input.element
+                    ))
+                })?;
+                vec![value.to_string()]
+            }
+        };
+
         // Select the options using the new builder API from viewpoint 0.2.10
         // Navigation waiting is automatic by default
-        let select_result = if input.values.len() == 1 {
-            locator.select_option().value(&input.values[0]).await
+        let select_result = if values.len() == 1 {
+            locator.select_option().value(&values[0]).await
         } else {
-            let values_slice: Vec<&str> = input.values.iter().map(String::as_str).collect();
+            let values_slice: Vec<&str> = values.iter().map(String::as_str).collect();
             locator.select_option().values(&values_slice).await
         };
 
@@ -134,9 +286,18 @@ impl Tool for BrowserSelectOptionTool {
         // Invalidate cache after interaction
         context.invalidate_cache();
 
+        let selected = match &selector {
+            OptionSelector::Values(values) => format!("{values:?}"),
+            _ => format!(
+                "option with {} (value {:?})",
+                selector.describe(),
+                values[0]
+            ),
+        };
+
         Ok(ToolOutput::text(format!(
-            "Selected {:?} in {} [ref={}]",
-            input.values, input.element, input.element_ref
+            "Selected {selected} in {} [ref={}]",
+            input.element, input.element_ref
         )))
     }
 }
@@ -0,0 +1,111 @@
+//! Browser set extra headers tool for adding default request headers to a context
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+/// Browser set extra headers tool - adds HTTP headers to every request in the active context
+pub struct BrowserSetExtraHeadersTool;
+
+/// Input parameters for `browser_set_extra_headers`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserSetExtraHeadersInput {
+    /// Headers to merge into the context's extra headers; empty clears all
+    pub headers: HashMap<String, String>,
+}
+
+impl BrowserSetExtraHeadersTool {
+    /// Create a new browser set extra headers tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserSetExtraHeadersTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserSetExtraHeadersTool {
+    fn name(&self) -> &'static str {
+        "browser_set_extra_headers"
+    }
+
+    fn description(&self) -> &'static str {
+        "Set extra HTTP headers sent with every request in the active browser context \
+         (e.g., X-API-Key). Headers are merged with those set previously; pass an empty \
+         object to clear all extra headers."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["context"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["headers"],
+            "properties": {
+                "headers": {
+                    "type": "object",
+                    "additionalProperties": { "type": "string" },
+                    "description": "Header names and values to add. Existing headers with the same name (case-insensitive) are replaced. An empty object clears all extra headers."
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserSetExtraHeadersInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        if let Some(name) = input.headers.keys().find(|name| name.trim().is_empty()) {
+            return Err(ToolError::InvalidParams(format!(
+                "Header name cannot be empty: '{name}'"
+            )));
+        }
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let clearing = input.headers.is_empty();
+        context
+            .merge_extra_headers(input.headers)
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to set extra headers: {e}")))?;
+
+        if clearing {
+            return Ok(ToolOutput::text(format!(
+                "Cleared extra headers for context '{}'",
+                context.name
+            )));
+        }
+
+        let mut names: Vec<&str> = context.extra_headers().keys().map(String::as_str).collect();
+        names.sort_unstable();
+
+        Ok(ToolOutput::text(format!(
+            "Extra headers for context '{}' ({}): {}",
+            context.name,
+            names.len(),
+            names.join(", ")
+        )))
+    }
+}
@@ -0,0 +1,120 @@
+//! Temporarily expanding collapsed widgets for a snapshot
+//!
+//! Collapsed menus, accordions, and tree items keep their children out of the
+//! accessibility tree. Each element with `aria-expanded="false"` is clicked
+//! before capture and clicked again afterwards, so the page is left as it was.
+//!
+//! Links, form submit and reset buttons, and elements inside links are never
+//! clicked. While the clicks run, scripted clicks have their default action
+//! cancelled and navigations the page starts are cancelled, so a widget that
+//! navigates from its click handler cannot take the page away either.
+
+use serde_json::Value;
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use crate::tools::ToolError;
+
+/// Attribute recording the order in which widgets were expanded
+const EXPANDED_ATTR: &str = "data-viewpoint-expanded";
+
+/// Rounds of expansion, so widgets revealed by expanding a parent are
+/// expanded too
+const MAX_PASSES: u32 = 5;
+
+/// Click every collapsed widget on the page, returning how many were clicked.
+///
+/// Call [`restore_collapsed`] afterwards even if this fails, since some
+/// widgets may have been expanded before the error.
+///
+/// # Errors
+///
+/// Returns an error if the script cannot run.
+pub async fn expand_collapsed(page: &Page) -> Result<u64, ToolError> {
+    let expanded = toggle_widgets(page, true).await?;
+    Ok(expanded.as_u64().unwrap_or_default())
+}
+
+/// Collapse the widgets expanded by [`expand_collapsed`], innermost first.
+///
+/// Widgets left expanded by an earlier call that was cancelled are collapsed
+/// too. Failures are logged rather than returned: the snapshot has already
+/// been captured, and the page may have navigated away.
+pub async fn restore_collapsed(page: &Page) {
+    if let Err(e) = toggle_widgets(page, false).await {
+        tracing::warn!(error = %e, "Failed to collapse expanded widgets");
+    }
+}
+
+/// Expand collapsed widgets, or collapse the ones marked as expanded, with
+/// navigation blocked for the duration of the clicks
+async fn toggle_widgets(page: &Page, expand: bool) -> Result<Value, ToolError> {
+    let action = if expand { "expand" } else { "collapse" };
+    page.evaluate(&js! {
+        (async () => {
+            const expand = #{expand};
+            const marker = #{EXPANDED_ATTR};
+
+            // Scripted clicks must not follow links or submit forms, and
+            // click handlers must not navigate the page
+            const cancelDefault = (event) => {
+                if (!event.isTrusted) {
+                    event.preventDefault();
+                }
+            };
+            const cancelNavigation = (event) => {
+                if (event.cancelable) {
+                    event.preventDefault();
+                }
+            };
+            window.addEventListener("click", cancelDefault);
+            if (window.navigation) {
+                window.navigation.addEventListener("navigate", cancelNavigation);
+            }
+
+            const navigates = (widget) =>
+                widget.closest("a[href], area[href]") !== null
+                    || (widget.form instanceof HTMLFormElement
+                        && ["submit", "reset", "image"].includes(widget.type));
+
+            try {
+                if (!expand) {
+                    const widgets = [...document.querySelectorAll("[" + marker + "]")]
+                        .sort((a, b) => b.getAttribute(marker) - a.getAttribute(marker));
+                    for (const widget of widgets) {
+                        widget.removeAttribute(marker);
+                        if (widget.getAttribute("aria-expanded") === "true") {
+                            widget.click();
+                        }
+                    }
+                    return null;
+                }
+
+                let expanded = 0;
+                for (let pass = 0; pass < #{MAX_PASSES}; pass++) {
+                    const collapsed = [...document.querySelectorAll(
+                        "[aria-expanded=\"false\"]:not([" + marker + "])"
+                    )].filter((widget) => !navigates(widget));
+                    if (collapsed.length === 0) {
+                        break;
+                    }
+                    for (const widget of collapsed) {
+                        widget.setAttribute(marker, String(expanded));
+                        widget.click();
+                        expanded++;
+                    }
+                    // Let frameworks render the revealed content
+                    await new Promise((resolve) => requestAnimationFrame(resolve));
+                }
+                return expanded;
+            } finally {
+                window.removeEventListener("click", cancelDefault);
+                if (window.navigation) {
+                    window.navigation.removeEventListener("navigate", cancelNavigation);
+                }
+            }
+        })()
+    })
+    .await
+    .map_err(|e| ToolError::ExecutionFailed(format!("Failed to {action} widgets: {e}")))
+}
@@ -0,0 +1,353 @@
+//! Browser snapshot tool for capturing accessibility tree
+
+mod expand;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tracing::{debug, instrument};
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::{BrowserState, SnapshotCacheKey};
+use crate::snapshot::{AccessibilitySnapshot, OutputFormat, SnapshotError, SnapshotOptions};
+
+/// Browser snapshot tool - captures accessibility tree for LLM consumption
+pub struct BrowserSnapshotTool;
+
+/// Input parameters for `browser_snapshot`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserSnapshotInput {
+    /// Whether to include all refs (bypass compact mode)
+    #[serde(default)]
+    pub all_refs: bool,
+
+    /// Maximum length of element names before truncation (default: 100)
+    #[serde(default)]
+    pub max_text_length: Option<usize>,
+
+    /// CSS selector scoping the snapshot to the subtree of the first match
+    pub selector: Option<String>,
+
+    /// Append a summary line with element counts by role
+    #[serde(default)]
+    pub element_counts: bool,
+
+    /// Append a compact statistics block counting interactive elements by role
+    #[serde(default)]
+    pub include_stats: bool,
+
+    /// Mark the element holding keyboard focus with `[focused]`
+    #[serde(default)]
+    pub focus_element: bool,
+
+    /// Only show elements whose accessible name contains this text (case-insensitive)
+    pub aria_label_contains: Option<String>,
+
+    /// Omit elements with these roles, keeping their children with other roles
+    pub skip_roles: Option<Vec<String>>,
+
+    /// Output syntax: `text` (default) or `markdown`
+    pub format: Option<String>,
+
+    /// Expand collapsed widgets (`aria-expanded="false"`) for the capture,
+    /// collapsing them again afterwards
+    #[serde(default)]
+    pub expand_collapsed: bool,
+}
+
+impl BrowserSnapshotInput {
+    /// Parse the requested output format
+    fn output_format(&self) -> Result<OutputFormat, ToolError> {
+        self.format
+            .as_deref()
+            .map_or(Ok(OutputFormat::Text), str::parse)
+            .map_err(ToolError::InvalidParams)
+    }
+
+    /// Format a snapshot, applying the requested filters, truncation length, and syntax
+    fn format(&self, snapshot: &AccessibilitySnapshot, output_format: OutputFormat) -> String {
+        let skip_roles = self.skip_roles.as_deref().unwrap_or_default();
+        if self.aria_label_contains.is_some() || !skip_roles.is_empty() {
+            return snapshot.format_filtered(
+                self.aria_label_contains.as_deref(),
+                skip_roles,
+                self.max_text_length,
+                output_format,
+            );
+        }
+        snapshot.format_as(output_format, self.max_text_length)
+    }
+
+    /// Render the tool output for a snapshot, including header and hints
+    fn render(&self, snapshot: &AccessibilitySnapshot, output_format: OutputFormat) -> String {
+        use std::fmt::Write;
+
+        // Use single-pass counting
+        let (ref_count, element_count) = snapshot.root().counts();
+        let compact = snapshot.is_compact();
+
+        debug!(element_count, ref_count, "format_snapshot");
+        let output = self.format(snapshot, output_format);
+
+        let mut scope = self
+            .selector
+            .as_ref()
+            .map_or_else(String::new, |selector| format!(" scoped to '{selector}'"));
+        if let Some(needle) = &self.aria_label_contains {
+            let _ = write!(scope, " filtered to names containing '{needle}'");
+        }
+        if let Some(roles) = self.skip_roles.as_ref().filter(|roles| !roles.is_empty()) {
+            let _ = write!(scope, " skipping roles {}", roles.join(", "));
+        }
+        let mut result = format!(
+            "Page snapshot{scope} ({element_count} elements, {ref_count} refs{})\n\n{output}",
+            if compact { ", compact mode" } else { "" },
+        );
+
+        if self.element_counts {
+            result.push_str("\n\n");
+            result.push_str(&snapshot.element_counts_summary());
+        }
+
+        // Add usage hint if in compact mode
+        if compact {
+            result
+                .push_str("\n\n[Hint: Use allRefs: true to see refs for all interactive elements]");
+        }
+
+        if self.include_stats {
+            result.push_str("\n\n");
+            result.push_str(&snapshot.role_stats());
+        }
+
+        result
+    }
+}
+
+impl BrowserSnapshotTool {
+    /// Create a new browser snapshot tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserSnapshotTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserSnapshotTool {
+    #[allow(clippy::unnecessary_literal_bound)]
+    fn name(&self) -> &'static str {
+        "browser_snapshot"
+    }
+
+    #[allow(clippy::unnecessary_literal_bound)]
+    fn description(&self) -> &'static str {
+        "Capture accessibility snapshot of the current page. Returns a structured text \
+         representation of the page's accessibility tree, with element references (refs) \
+         that can be used to interact with elements."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["inspection"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "allRefs": {
+                    "type": "boolean",
+                    "description": "Include refs for all interactive elements, bypassing compact mode. \
+                                   Use when page has many elements and you need to interact with \
+                                   Tier 2 (contextually interactive) elements.",
+                    "default": false
+                },
+                "maxTextLength": {
+                    "type": "integer",
+                    "description": "Maximum length of element names before they are truncated with '...'. \
+                                   Use smaller values (e.g., 50) to save tokens or larger values \
+                                   (e.g., 500) when reading page content.",
+                    "default": 100
+                },
+                "selector": {
+                    "type": "string",
+                    "description": "CSS selector limiting the snapshot to the subtree rooted at the first \
+                                   matching element. Useful on large pages when working within one component."
+                },
+                "elementCounts": {
+                    "type": "boolean",
+                    "description": "Append a summary line counting interactive elements and elements per role \
+                                   (e.g., how many buttons, links, and headings are on the page).",
+                    "default": false
+                },
+                "includeStats": {
+                    "type": "boolean",
+                    "description": "Append a compact statistics block at the end, such as \
+                                   '[Stats: 12 buttons, 8 links, 3 textboxes, 47 other]', to gauge page complexity.",
+                    "default": false
+                },
+                "ariaLabelContains": {
+                    "type": "string",
+                    "description": "Only show elements whose accessible name contains this text (case-insensitive). \
+                                   Header counts and compact mode still reflect the full page."
+                },
+                "skipRoles": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Roles to omit from the output (e.g., ['text', 'img']). Children of an \
+                                   omitted element are kept unless they have a skipped role too. \
+                                   Header counts still reflect the full page."
+                },
+                "focusElement": {
+                    "type": "boolean",
+                    "description": "Mark the element that currently has keyboard focus with [focused]. \
+                                   Useful after pressing Tab to see where focus landed.",
+                    "default": false
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["text", "markdown"],
+                    "description": "Output syntax. 'markdown' renders roles in bold, elements with refs as \
+                                   inline code spans, and the tree as nested Markdown lists, for clients \
+                                   that display tool output as Markdown.",
+                    "default": "text"
+                },
+                "expandCollapsed": {
+                    "type": "boolean",
+                    "description": "Click every element with aria-expanded=\"false\" (menus, accordions, tree items) \
+                                   before capturing, so their hidden children appear, then collapse them again. \
+                                   Links and form submit buttons are skipped, and navigation is blocked \
+                                   while clicking. Refs inside the expanded content are hidden again afterwards.",
+                    "default": false
+                }
+            }
+        })
+    }
+
+    #[instrument(skip(self, args, browser), fields(all_refs))]
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserSnapshotInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        tracing::Span::current().record("all_refs", input.all_refs);
+
+        let output_format = input.output_format()?;
+
+        // Ensure browser is initialized
+        debug!("browser_initialize: start");
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+        debug!("browser_initialize: complete");
+
+        // Get context name for multi-context ref prefixing
+        let context_name = if browser.list_contexts().len() > 1 {
+            Some(
+                browser
+                    .active_context()
+                    .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?
+                    .name
+                    .clone(),
+            )
+        } else {
+            None
+        };
+
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Get active page for the cache key and capture
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active page".to_string()))?;
+
+        // Scoped, focus-marked, and expanded snapshots are never cached: the first would
+        // replace the full-page snapshot, focus changes don't invalidate the cache, and
+        // the expanded content is collapsed again after capture
+        let cache_key =
+            if input.selector.is_none() && !input.focus_element && !input.expand_collapsed {
+                SnapshotCacheKey::capture(&page).await
+            } else {
+                None
+            };
+
+        // Try to get cached snapshot first
+        if let Some(key) = &cache_key
+            && let Some(cached) = context.get_cached_snapshot(key, input.all_refs).await
+        {
+            debug!("snapshot cache hit");
+            return Ok(ToolOutput::text(input.render(cached, output_format)));
+        }
+
+        debug!("snapshot cache miss");
+
+        // Capture new snapshot
+        debug!("capture_snapshot: start");
+
+        let options = SnapshotOptions {
+            all_refs: input.all_refs,
+            context: context_name,
+            selector: input.selector.clone(),
+            focus_element: input.focus_element,
+            ..Default::default()
+        };
+
+        let captured = async {
+            let expanded = if input.expand_collapsed {
+                expand::expand_collapsed(&page).await?
+            } else {
+                0
+            };
+            let snapshot = AccessibilitySnapshot::capture(&page, options)
+                .await
+                .map_err(|e| match e {
+                    SnapshotError::SelectorNotFound(_) => ToolError::ElementNotFound(e.to_string()),
+                    _ => ToolError::ExecutionFailed(e.to_string()),
+                })?;
+            Ok::<_, ToolError>((expanded, snapshot))
+        }
+        .await;
+
+        // Collapse again even if expanding or capturing failed part way
+        if input.expand_collapsed {
+            expand::restore_collapsed(&page).await;
+            context.invalidate_cache();
+        }
+
+        let (expanded, snapshot) = captured?;
+
+        debug!("capture_snapshot: complete");
+
+        let mut result = input.render(&snapshot, output_format);
+        if input.expand_collapsed {
+            use std::fmt::Write;
+            let _ = write!(
+                result,
+                "\n\n[Expanded {expanded} collapsed widget(s) for this snapshot; they have been collapsed again]"
+            );
+        }
+
+        let Some(key) = cache_key else {
+            return Ok(ToolOutput::text(result));
+        };
+
+        // Cache the snapshot for future requests
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+        context.cache_snapshot(snapshot, key, input.all_refs);
+
+        Ok(ToolOutput::text(result))
+    }
+}
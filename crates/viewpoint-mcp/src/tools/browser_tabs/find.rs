@@ -0,0 +1,42 @@
+//! URL pattern matching for the `findByUrl` action
+
+/// Whether `url` matches `pattern`.
+///
+/// Patterns containing `*` or `?` are globs matched against the whole URL
+/// (`*` matches any run of characters, `?` exactly one); any other pattern
+/// matches as a substring.
+#[must_use]
+pub fn url_matches(url: &str, pattern: &str) -> bool {
+    if !pattern.contains(['*', '?']) {
+        return url.contains(pattern);
+    }
+
+    let url: Vec<char> = url.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+
+    // Greedy matching with backtracking to the most recent `*`
+    let (mut u, mut p) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while u < url.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, u));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == url[u] => {
+                u += 1;
+                p += 1;
+            }
+            _ => match star {
+                Some((star_p, star_u)) => {
+                    p = star_p + 1;
+                    u = star_u + 1;
+                    star = Some((star_p, star_u + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
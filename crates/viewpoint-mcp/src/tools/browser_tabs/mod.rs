@@ -0,0 +1,473 @@
+//! Browser tabs tool for tab management
+
+mod find;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::{Tool, ToolError, ToolOutput, ToolResult};
+use crate::browser::BrowserState;
+
+pub use find::url_matches;
+
+/// Browser tabs tool - list, create, duplicate, close, select, or move tabs
+pub struct BrowserTabsTool;
+
+/// Input parameters for `browser_tabs`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrowserTabsInput {
+    /// Action to perform: "list", "new", "duplicate", "close", "select", "move", "title", "url",
+    /// or "findByUrl"
+    pub action: TabAction,
+
+    /// Tab index for close/select operations
+    pub index: Option<usize>,
+
+    /// Current index of the tab to move
+    pub from: Option<usize>,
+
+    /// Target index for the moved tab
+    pub to: Option<usize>,
+
+    /// Create the new tab as a pinned tab (new action only)
+    #[serde(default)]
+    pub pinned: bool,
+
+    /// Context to query instead of the active one (title/url actions only)
+    pub context: Option<String>,
+
+    /// URL substring or glob to search for (findByUrl action only)
+    pub pattern: Option<String>,
+}
+
+/// Tab actions
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TabAction {
+    /// List all open tabs
+    List,
+    /// Create a new tab
+    New,
+    /// Open a new tab at the same URL as the current tab
+    Duplicate,
+    /// Close a tab (by index, or current if not specified)
+    Close,
+    /// Select/switch to a tab by index
+    Select,
+    /// Move a tab to a different position
+    Move,
+    /// Get the document title of the active tab
+    Title,
+    /// Get the URL of the active tab
+    Url,
+    /// Find the first tab whose URL matches a pattern
+    #[serde(rename = "findByUrl")]
+    FindByUrl,
+}
+
+impl BrowserTabsTool {
+    /// Create a new browser tabs tool
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for BrowserTabsTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Tool for BrowserTabsTool {
+    fn name(&self) -> &'static str {
+        "browser_tabs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Manage browser tabs. Actions: 'list' shows all tabs, 'new' creates a tab (optionally pinned), \
+         'duplicate' opens the current tab's URL in a new tab, \
+         'close' closes a tab by index (or current), 'select' switches to a tab by index, \
+         'move' reorders a tab from one index to another, \
+         'title' and 'url' return the active tab's title or URL (optionally in another context), \
+         'findByUrl' returns the index of the first tab whose URL matches a substring or glob pattern."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["management"]
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["action"],
+            "properties": {
+                "action": {
+                    "type": "string",
+                    "enum": ["list", "new", "duplicate", "close", "select", "move", "title", "url", "findByUrl"],
+                    "description": "Operation to perform on tabs"
+                },
+                "index": {
+                    "type": "number",
+                    "description": "Tab index for close/select operations. If omitted for close, closes the current tab."
+                },
+                "from": {
+                    "type": "number",
+                    "description": "Current index of the tab to move (required for move)"
+                },
+                "to": {
+                    "type": "number",
+                    "description": "Index the tab should end up at; tabs in between shift by one. Pinned tabs stay ahead of unpinned ones, so the index is clamped to the tab's own group (required for move)"
+                },
+                "pinned": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "For new: create a pinned tab, placed after any other pinned tabs"
+                },
+                "context": {
+                    "type": "string",
+                    "description": "For title/url: name of the context to query instead of the active one"
+                },
+                "pattern": {
+                    "type": "string",
+                    "description": "For findByUrl: URL substring, or a glob matched against the whole URL when it contains * or ? (e.g., 'https://*.example.com/*')"
+                }
+            }
+        })
+    }
+
+    async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult {
+        // Parse input
+        let input: BrowserTabsInput = serde_json::from_value(args.clone())
+            .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
+
+        // Ensure browser is initialized
+        browser
+            .initialize()
+            .await
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        match input.action {
+            TabAction::List => self.list_tabs(browser).await,
+            TabAction::New => self.new_tab(browser, input.pinned).await,
+            TabAction::Duplicate => self.duplicate_tab(browser).await,
+            TabAction::Close => self.close_tab(browser, input.index).await,
+            TabAction::Select => self.select_tab(browser, input.index).await,
+            TabAction::Move => self.move_tab(browser, input.from, input.to).await,
+            TabAction::Title | TabAction::Url => {
+                self.query_tab(browser, input.action, input.context.as_deref())
+                    .await
+            }
+            TabAction::FindByUrl => self.find_tab(browser, input.pattern.as_deref()).await,
+        }
+    }
+}
+
+impl BrowserTabsTool {
+    async fn list_tabs(&self, browser: &BrowserState) -> ToolResult {
+        use std::fmt::Write;
+
+        let context = browser
+            .active_context()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let pages = context
+            .pages()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get pages: {e}")))?;
+
+        let page_count = pages.len();
+        let active_index = context.active_page_index().await;
+
+        if page_count == 0 {
+            return Ok(ToolOutput::text("No tabs open"));
+        }
+
+        let mut result = format!("Tabs ({page_count} total):\n");
+
+        for (i, page) in pages.iter().enumerate() {
+            let marker = if i == active_index { " [active]" } else { "" };
+            let pinned = if context.is_pinned(page.target_id()) {
+                " (pinned)"
+            } else {
+                ""
+            };
+            let url = page.url().await.unwrap_or_else(|_| "unknown".to_string());
+            let _ = writeln!(result, "  {i}: {url}{marker}{pinned}");
+        }
+
+        Ok(ToolOutput::text(result.trim_end()))
+    }
+
+    async fn query_tab(
+        &self,
+        browser: &BrowserState,
+        action: TabAction,
+        context_name: Option<&str>,
+    ) -> ToolResult {
+        let is_title = matches!(action, TabAction::Title);
+        let label = if is_title { "title" } else { "URL" };
+
+        let context = match context_name {
+            Some(name) => browser.get_context(name).map_err(|e| {
+                ToolError::InvalidParams(format!("Context '{name}' not found: {e}"))
+            })?,
+            None => browser
+                .active_context()
+                .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?,
+        };
+        let page = context
+            .active_page()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get active page: {e}")))?
+            .ok_or_else(|| ToolError::BrowserNotAvailable("No active tab".to_string()))?;
+        let index = context.active_page_index().await;
+
+        let value = if is_title {
+            page.title()
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get title: {e}")))?
+        } else {
+            page.url()
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get URL: {e}")))?
+        };
+
+        let location =
+            context_name.map_or_else(String::new, |name| format!(" in context '{name}'"));
+        Ok(ToolOutput::text(format!(
+            "Tab {index}{location} {label}: {value}"
+        )))
+    }
+
+    async fn find_tab(&self, browser: &BrowserState, pattern: Option<&str>) -> ToolResult {
+        let pattern = pattern.ok_or_else(|| {
+            ToolError::InvalidParams("pattern is required for findByUrl action".to_string())
+        })?;
+
+        let context = browser
+            .active_context()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let pages = context
+            .pages()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get pages: {e}")))?;
+
+        let mut result = json!({ "found": false });
+        for (index, page) in pages.iter().enumerate() {
+            let Ok(url) = page.url().await else {
+                continue;
+            };
+            if url_matches(&url, pattern) {
+                let title = page.title().await.unwrap_or_default();
+                result = json!({ "found": true, "index": index, "url": url, "title": title });
+                break;
+            }
+        }
+
+        Ok(ToolOutput::text(result.to_string()))
+    }
+
+    async fn new_tab(&self, browser: &mut BrowserState, pinned: bool) -> ToolResult {
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        if pinned {
+            context.new_pinned_page().await
+        } else {
+            context.new_page().await
+        }
+        .map_err(|e| ToolError::ExecutionFailed(format!("Failed to create new tab: {e}")))?;
+
+        let new_index = context.active_page_index().await;
+        let page_count = context
+            .page_count()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get page count: {e}")))?;
+
+        // Invalidate cache for new tab
+        context.invalidate_cache();
+
+        let kind = if pinned { "pinned tab" } else { "tab" };
+        Ok(ToolOutput::text(format!(
+            "Created new {kind} at index {new_index} ({page_count} tabs total)"
+        )))
+    }
+
+    async fn duplicate_tab(&self, browser: &mut BrowserState) -> ToolResult {
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        // Read the URL of the tab being duplicated before switching away from it
+        let source_index = context.active_page_index().await;
+        let url = context.get_current_url().await.ok_or_else(|| {
+            ToolError::BrowserNotAvailable("No active tab to duplicate".to_string())
+        })?;
+
+        let page = context.new_page().await.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to create duplicate tab: {e}"))
+        })?;
+
+        // A blank tab is already a faithful copy of a blank tab
+        if url != "about:blank" {
+            page.goto(&url).goto().await.map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to navigate duplicate tab: {e}"))
+            })?;
+        }
+
+        context.set_current_url(Some(url.clone())).await;
+
+        let new_index = context.active_page_index().await;
+
+        // Invalidate cache for the new active tab
+        context.invalidate_cache();
+
+        Ok(ToolOutput::text(format!(
+            "Duplicated tab {source_index} to new tab at index {new_index} ({url})"
+        )))
+    }
+
+    async fn close_tab(&self, browser: &mut BrowserState, index: Option<usize>) -> ToolResult {
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page_count = context
+            .page_count()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get page count: {e}")))?;
+        if page_count == 0 {
+            return Err(ToolError::BrowserNotAvailable(
+                "No tabs to close".to_string(),
+            ));
+        }
+
+        let target_index = match index {
+            Some(i) => i,
+            None => context.active_page_index().await,
+        };
+
+        if target_index >= page_count {
+            return Err(ToolError::InvalidParams(format!(
+                "Tab index {target_index} out of range (0-{})",
+                page_count - 1
+            )));
+        }
+
+        context
+            .close_page(target_index)
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to close tab: {e}")))?;
+
+        // Invalidate cache
+        context.invalidate_cache();
+
+        let remaining = page_count - 1;
+        Ok(ToolOutput::text(format!(
+            "Closed tab at index {target_index} ({remaining} tabs remaining)"
+        )))
+    }
+
+    async fn select_tab(&self, browser: &mut BrowserState, index: Option<usize>) -> ToolResult {
+        let index = index.ok_or_else(|| {
+            ToolError::InvalidParams("index is required for select action".to_string())
+        })?;
+
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let pages = context
+            .pages()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get pages: {e}")))?;
+
+        if index >= pages.len() {
+            return Err(ToolError::InvalidParams(format!(
+                "Tab index {index} out of range (0-{})",
+                pages.len().saturating_sub(1)
+            )));
+        }
+
+        // Get the page at the target index and bring it to front
+        let page = pages
+            .into_iter()
+            .nth(index)
+            .ok_or_else(|| ToolError::ExecutionFailed("Page not found".to_string()))?;
+
+        // Bring the page to front in the browser UI
+        page.bring_to_front().await.map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to bring tab to front: {e}"))
+        })?;
+
+        // Update internal tracking
+        let switched = context.switch_page(index).await;
+
+        if switched {
+            // Invalidate cache when switching tabs
+            context.invalidate_cache();
+            Ok(ToolOutput::text(format!(
+                "Switched to tab at index {index}"
+            )))
+        } else {
+            Err(ToolError::ExecutionFailed(format!(
+                "Failed to switch to tab at index {index}"
+            )))
+        }
+    }
+
+    async fn move_tab(
+        &self,
+        browser: &mut BrowserState,
+        from: Option<usize>,
+        to: Option<usize>,
+    ) -> ToolResult {
+        let (Some(from), Some(to)) = (from, to) else {
+            return Err(ToolError::InvalidParams(
+                "from and to are required for move action".to_string(),
+            ));
+        };
+
+        let context = browser
+            .active_context_mut()
+            .map_err(|e| ToolError::BrowserNotAvailable(e.to_string()))?;
+
+        let page_count = context
+            .page_count()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to get page count: {e}")))?;
+
+        for index in [from, to] {
+            if index >= page_count {
+                return Err(ToolError::InvalidParams(format!(
+                    "Tab index {index} out of range (0-{})",
+                    page_count.saturating_sub(1)
+                )));
+            }
+        }
+
+        let moved_to = context
+            .move_page(from, to)
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Failed to move tab: {e}")))?
+            .ok_or_else(|| {
+                ToolError::ExecutionFailed(format!("Failed to move tab from index {from} to {to}"))
+            })?;
+
+        let active_index = context.active_page_index().await;
+        let clamped = if moved_to == to {
+            String::new()
+        } else {
+            format!(", the nearest index that keeps pinned tabs first (requested {to})")
+        };
+        Ok(ToolOutput::text(format!(
+            "Moved tab from index {from} to {moved_to}{clamped} (active tab is now at index {active_index})"
+        )))
+    }
+}
@@ -0,0 +1,98 @@
+//! Screenshot capture for the viewport, full page, an element, or a region
+
+use serde_json::Value;
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use super::BrowserTakeScreenshotInput;
+use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+use crate::tools::ToolError;
+
+/// Take the screenshot described by `input`.
+///
+/// Returns the image bytes and the viewport position (in CSS pixels) of the
+/// image's top-left corner, used to place redaction zones.
+pub(super) async fn capture(
+    page: &Page,
+    input: &BrowserTakeScreenshotInput,
+) -> Result<(Vec<u8>, (f64, f64)), ToolError> {
+    let screenshot = || {
+        let builder = page.screenshot();
+        if input.omit_background {
+            builder.omit_background(true)
+        } else {
+            builder
+        }
+    };
+
+    if let Some(ref element_ref_str) = input.element_ref {
+        // Validate the ref exists in the snapshot
+        let options = SnapshotOptions::default();
+        let snapshot = AccessibilitySnapshot::capture(page, options)
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+
+        snapshot.lookup(element_ref_str).map_err(|e| {
+            ToolError::ElementNotFound(format!("Element ref '{element_ref_str}': {e}"))
+        })?;
+
+        // Get the locator for the element
+        let locator = page.locator_from_ref(element_ref_str);
+
+        // Workaround: locator.screenshot() doesn't work with ref-based locators
+        // in viewpoint-core 0.2.16, so we use bounding_box + page screenshot with clip
+        let bbox = locator
+            .bounding_box()
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to get bounding box for element: {e}"))
+            })?
+            .ok_or_else(|| {
+                ToolError::ElementNotFound(format!(
+                    "Element ref '{element_ref_str}' has no bounding box (may be hidden)"
+                ))
+            })?;
+
+        let bytes = screenshot()
+            .clip(bbox.x, bbox.y, bbox.width, bbox.height)
+            .capture()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Element screenshot failed: {e}")))?;
+        Ok((bytes, (bbox.x, bbox.y)))
+    } else if let Some(clip) = input.clip {
+        // Region screenshot
+        let bytes = screenshot()
+            .clip(clip.x, clip.y, clip.width, clip.height)
+            .capture()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Region screenshot failed: {e}")))?;
+        Ok((bytes, (clip.x, clip.y)))
+    } else {
+        // Page screenshot
+        let mut builder = screenshot();
+        if input.full_page {
+            builder = builder.full_page(true);
+        }
+        let bytes = builder
+            .capture()
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(format!("Screenshot failed: {e}")))?;
+
+        // Full page images start at the document origin, not the viewport
+        let origin = if input.full_page && !input.redact.is_empty() {
+            let scroll: Value = page
+                .evaluate(&js! { [window.scrollX, window.scrollY] })
+                .await
+                .map_err(|e| {
+                    ToolError::ExecutionFailed(format!("Failed to get scroll position: {e}"))
+                })?;
+            (
+                -scroll[0].as_f64().unwrap_or(0.0),
+                -scroll[1].as_f64().unwrap_or(0.0),
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        Ok((bytes, origin))
+    }
+}
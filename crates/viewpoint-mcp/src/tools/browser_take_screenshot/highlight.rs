@@ -0,0 +1,143 @@
+//! Highlight overlays for marking elements in screenshots
+//!
+//! Each highlighted element gets an outline in its color and an optional
+//! label at the top-left of its bounding item. The overlays are positioned
+//! elements added to the page just before capture and removed right after,
+//! so the browser renders the label text.
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+use crate::tools::ToolError;
+
+/// Attribute marking overlay elements so they can be found and removed
+const OVERLAY_ATTR: &str = "data-viewpoint-highlight";
+
+/// An element to outline in the screenshot
+#[derive(Debug, Clone, Deserialize)]
+pub struct Highlight {
+    /// Element reference from snapshot
+    #[serde(rename = "ref")]
+    pub element_ref: String,
+
+    /// CSS color of the outline and label background (default: red)
+    #[serde(default = "default_color")]
+    pub color: String,
+
+    /// Text drawn at the top-left of the element's bounding box
+    pub label: Option<String>,
+}
+
+fn default_color() -> String {
+    "red".to_string()
+}
+
+/// Outline each highlighted element, resolving refs against a fresh snapshot.
+///
+/// # Errors
+///
+/// Returns an error if a ref is unknown or its element has no bounding item.
+pub async fn add_overlays(page: &Page, highlights: &[Highlight]) -> Result<(), ToolError> {
+    let snapshot = AccessibilitySnapshot::capture(page, SnapshotOptions::default())
+        .await
+        .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+
+    let mut boxes = Vec::with_capacity(highlights.len());
+    for highlight in highlights {
+        let element_ref = &highlight.element_ref;
+        snapshot
+            .lookup(element_ref)
+            .map_err(|e| ToolError::ElementNotFound(format!("Element ref '{element_ref}': {e}")))?;
+
+        let bbox = page
+            .locator_from_ref(element_ref)
+            .bounding_box()
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!(
+                    "Failed to get bounding box for '{element_ref}': {e}"
+                ))
+            })?
+            .ok_or_else(|| {
+                ToolError::ElementNotFound(format!(
+                    "Element ref '{element_ref}' has no bounding box (may be hidden)"
+                ))
+            })?;
+
+        boxes.push(json!({
+            "x": bbox.x,
+            "y": bbox.y,
+            "width": bbox.width,
+            "height": bbox.height,
+            "color": highlight.color,
+            "label": highlight.label,
+        }));
+    }
+
+    // Styles are set as properties, so colors and labels cannot inject markup
+    let boxes = Value::Array(boxes).to_string();
+    page.evaluate::<Value>(&js! {
+        (() => {
+            for (const item of JSON.parse(#{boxes})) {
+                const outline = document.createElement("div");
+                outline.setAttribute(#{OVERLAY_ATTR}, "");
+                Object.assign(outline.style, {
+                    position: "absolute",
+                    left: (item.x + window.scrollX) + "px",
+                    top: (item.y + window.scrollY) + "px",
+                    width: item.width + "px",
+                    height: item.height + "px",
+                    border: "3px solid",
+                    borderColor: item.color,
+                    boxSizing: "border-box",
+                    pointerEvents: "none",
+                    zIndex: "2147483647"
+                });
+                if (item.label) {
+                    const label = document.createElement("span");
+                    label.textContent = item.label;
+                    Object.assign(label.style, {
+                        position: "absolute",
+                        left: "0",
+                        top: "0",
+                        padding: "1px 4px",
+                        background: item.color,
+                        color: "white",
+                        font: "bold 12px sans-serif",
+                        whiteSpace: "nowrap"
+                    });
+                    outline.appendChild(label);
+                }
+                document.documentElement.appendChild(outline);
+            }
+            return null;
+        })()
+    })
+    .await
+    .map_err(|e| ToolError::ExecutionFailed(format!("Failed to draw highlights: {e}")))?;
+
+    Ok(())
+}
+
+/// Remove all highlight overlays from the page.
+///
+/// Failures are logged rather than returned: the screenshot has already been
+/// taken, and the page may have navigated away.
+pub async fn remove_overlays(page: &Page) {
+    let removed = page
+        .evaluate::<Value>(&js! {
+            (() => {
+                document
+                    .querySelectorAll("[" + #{OVERLAY_ATTR} + "]")
+                    .forEach((overlay) => overlay.remove());
+                return null;
+            })()
+        })
+        .await;
+    if let Err(e) = removed {
+        tracing::warn!(error = %e, "Failed to remove screenshot highlights");
+    }
+}
@@ -0,0 +1,86 @@
+//! Downscaling of screenshots returned inline to the client
+
+use std::io::Cursor;
+
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageFormat as ImgFormat};
+
+/// Maximum dimension for inline images (per Claude's vision guidelines)
+pub(crate) const MAX_INLINE_DIMENSION: u32 = 1568;
+
+/// Maximum megapixels for inline images (per Claude's vision guidelines)
+pub(crate) const MAX_INLINE_MEGAPIXELS: f64 = 1.15;
+
+/// Scale an image to fit within Claude's vision limits for inline images.
+///
+/// Constraints:
+/// - Max 1568px on any dimension
+/// - Max 1.15 megapixels total
+/// - Convert to JPEG at quality 80 for smaller size
+///
+/// Returns the scaled image as JPEG bytes.
+pub(crate) fn scale_image_for_inline(image_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let scaled_img = scale_to_inline_limits(image_bytes)?;
+
+    // Encode as JPEG
+    let mut buffer = Cursor::new(Vec::new());
+    scaled_img
+        .write_to(&mut buffer, ImgFormat::Jpeg)
+        .map_err(|e| format!("Failed to encode JPEG: {e}"))?;
+
+    // Note: The image crate doesn't support quality settings in this API
+    // For better quality control, we'd need to use the jpeg encoder directly
+    // For now, the default quality is acceptable
+
+    Ok(buffer.into_inner())
+}
+
+/// Scale an image to fit within the inline limits, keeping its alpha channel.
+///
+/// Used for screenshots with a transparent background, which JPEG would lose.
+/// Returns the scaled image as PNG bytes.
+pub(crate) fn scale_transparent_image_for_inline(image_bytes: &[u8]) -> Result<Vec<u8>, String> {
+    let scaled_img = scale_to_inline_limits(image_bytes)?;
+
+    let mut buffer = Cursor::new(Vec::new());
+    scaled_img
+        .write_to(&mut buffer, ImgFormat::Png)
+        .map_err(|e| format!("Failed to encode PNG: {e}"))?;
+    Ok(buffer.into_inner())
+}
+
+/// Decode an image and downscale it to the inline dimension and megapixel limits
+fn scale_to_inline_limits(image_bytes: &[u8]) -> Result<DynamicImage, String> {
+    // Load the image
+    let img =
+        image::load_from_memory(image_bytes).map_err(|e| format!("Failed to decode image: {e}"))?;
+
+    let (width, height) = (img.width(), img.height());
+    let megapixels = (width as f64 * height as f64) / 1_000_000.0;
+
+    // Calculate scaling factors
+    let mut scale = 1.0_f64;
+
+    // Scale down if any dimension exceeds max
+    if width > MAX_INLINE_DIMENSION {
+        scale = scale.min(MAX_INLINE_DIMENSION as f64 / width as f64);
+    }
+    if height > MAX_INLINE_DIMENSION {
+        scale = scale.min(MAX_INLINE_DIMENSION as f64 / height as f64);
+    }
+
+    // Scale down if total megapixels exceed limit
+    if megapixels * scale * scale > MAX_INLINE_MEGAPIXELS {
+        let target_scale = (MAX_INLINE_MEGAPIXELS / megapixels).sqrt();
+        scale = scale.min(target_scale);
+    }
+
+    // Apply scaling if needed
+    Ok(if scale < 1.0 {
+        let new_width = ((width as f64) * scale).round() as u32;
+        let new_height = ((height as f64) * scale).round() as u32;
+        img.resize(new_width, new_height, FilterType::Lanczos3)
+    } else {
+        img
+    })
+}
@@ -3,25 +3,26 @@
 //! Screenshots are saved to the screenshot directory (default: `.viewpoint-mcp-screenshots/`)
 //! and can optionally return inline image data based on the `--image-responses` configuration.
 
-use std::io::Cursor;
+mod capture;
+mod highlight;
+mod inline;
+mod redact;
 
 use async_trait::async_trait;
 use chrono::Utc;
-use image::ImageFormat as ImgFormat;
-use image::imageops::FilterType;
 use serde::Deserialize;
 use serde_json::{Value, json};
 
 use super::{ContentItem, Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
 use crate::server::ImageResponseMode;
-use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
 
-/// Maximum dimension for inline images (per Claude's vision guidelines)
-pub(crate) const MAX_INLINE_DIMENSION: u32 = 1568;
-
-/// Maximum megapixels for inline images (per Claude's vision guidelines)
-pub(crate) const MAX_INLINE_MEGAPIXELS: f64 = 1.15;
+pub use highlight::Highlight;
+pub(crate) use inline::{
+    MAX_INLINE_DIMENSION, MAX_INLINE_MEGAPIXELS, scale_image_for_inline,
+    scale_transparent_image_for_inline,
+};
+pub use redact::{PixelRect, RedactZone, apply_redactions};
 
 /// Browser take screenshot tool - captures screenshots
 pub struct BrowserTakeScreenshotTool;
@@ -48,6 +49,34 @@ pub struct BrowserTakeScreenshotInput {
     #[serde(default = "default_image_type")]
     #[serde(rename = "type")]
     pub image_type: ImageFormat,
+
+    /// Rectangle to capture, in page coordinates (mutually exclusive with `ref`)
+    pub clip: Option<ClipRegion>,
+
+    /// Zones to black out before the image is saved or returned
+    #[serde(default)]
+    pub redact: Vec<RedactZone>,
+
+    /// Omit the default white background to produce a transparent PNG
+    #[serde(default)]
+    pub omit_background: bool,
+
+    /// Elements to outline, each with its own color and optional label
+    #[serde(default)]
+    pub highlights: Vec<Highlight>,
+}
+
+/// Rectangular screenshot region in CSS pixels
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ClipRegion {
+    /// Left edge
+    pub x: f64,
+    /// Top edge
+    pub y: f64,
+    /// Width
+    pub width: f64,
+    /// Height
+    pub height: f64,
 }
 
 fn default_image_type() -> ImageFormat {
@@ -55,7 +84,7 @@ fn default_image_type() -> ImageFormat {
 }
 
 /// Image format for screenshots
-#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ImageFormat {
     #[default]
@@ -85,61 +114,6 @@ fn generate_timestamp_filename(extension: &str) -> String {
     format!("page-{formatted}.{extension}")
 }
 
-/// Scale an image to fit within Claude's vision limits for inline images.
-///
-/// Constraints:
-/// - Max 1568px on any dimension
-/// - Max 1.15 megapixels total
-/// - Convert to JPEG at quality 80 for smaller size
-///
-/// Returns the scaled image as JPEG bytes.
-pub(crate) fn scale_image_for_inline(image_bytes: &[u8]) -> Result<Vec<u8>, String> {
-    // Load the image
-    let img =
-        image::load_from_memory(image_bytes).map_err(|e| format!("Failed to decode image: {e}"))?;
-
-    let (width, height) = (img.width(), img.height());
-    let megapixels = (width as f64 * height as f64) / 1_000_000.0;
-
-    // Calculate scaling factors
-    let mut scale = 1.0_f64;
-
-    // Scale down if any dimension exceeds max
-    if width > MAX_INLINE_DIMENSION {
-        scale = scale.min(MAX_INLINE_DIMENSION as f64 / width as f64);
-    }
-    if height > MAX_INLINE_DIMENSION {
-        scale = scale.min(MAX_INLINE_DIMENSION as f64 / height as f64);
-    }
-
-    // Scale down if total megapixels exceed limit
-    if megapixels * scale * scale > MAX_INLINE_MEGAPIXELS {
-        let target_scale = (MAX_INLINE_MEGAPIXELS / megapixels).sqrt();
-        scale = scale.min(target_scale);
-    }
-
-    // Apply scaling if needed
-    let scaled_img = if scale < 1.0 {
-        let new_width = ((width as f64) * scale).round() as u32;
-        let new_height = ((height as f64) * scale).round() as u32;
-        img.resize(new_width, new_height, FilterType::Lanczos3)
-    } else {
-        img
-    };
-
-    // Encode as JPEG
-    let mut buffer = Cursor::new(Vec::new());
-    scaled_img
-        .write_to(&mut buffer, ImgFormat::Jpeg)
-        .map_err(|e| format!("Failed to encode JPEG: {e}"))?;
-
-    // Note: The image crate doesn't support quality settings in this API
-    // For better quality control, we'd need to use the jpeg encoder directly
-    // For now, the default quality is acceptable
-
-    Ok(buffer.into_inner())
-}
-
 #[async_trait]
 impl Tool for BrowserTakeScreenshotTool {
     fn name(&self) -> &'static str {
@@ -148,7 +122,12 @@ impl Tool for BrowserTakeScreenshotTool {
 
     fn description(&self) -> &'static str {
         "Take a screenshot of the current page. Can capture the viewport, full page, \
-         or a specific element. Use browser_snapshot for interacting with elements."
+         or a specific element. Pass highlights to outline several elements, each with its \
+         own color and label. Use browser_snapshot for interacting with elements."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["inspection"]
     }
 
     fn input_schema(&self) -> Value {
@@ -177,6 +156,63 @@ impl Tool for BrowserTakeScreenshotTool {
                     "enum": ["png", "jpeg"],
                     "default": "png",
                     "description": "Image format"
+                },
+                "clip": {
+                    "type": "object",
+                    "description": "Capture only this rectangle in page coordinates (CSS pixels). Cannot be combined with ref or fullPage",
+                    "required": ["x", "y", "width", "height"],
+                    "properties": {
+                        "x": { "type": "number" },
+                        "y": { "type": "number" },
+                        "width": { "type": "number", "exclusiveMinimum": 0 },
+                        "height": { "type": "number", "exclusiveMinimum": 0 }
+                    }
+                },
+                "redact": {
+                    "type": "array",
+                    "description": "Zones to paint solid black before the screenshot is saved (e.g., to mask passwords or PII)",
+                    "items": {
+                        "oneOf": [
+                            {
+                                "type": "object",
+                                "description": "Rectangle in CSS pixels relative to the top-left of the screenshot",
+                                "required": ["x", "y", "width", "height"],
+                                "properties": {
+                                    "x": { "type": "number" },
+                                    "y": { "type": "number" },
+                                    "width": { "type": "number", "minimum": 0 },
+                                    "height": { "type": "number", "minimum": 0 }
+                                }
+                            },
+                            {
+                                "type": "object",
+                                "description": "Element whose bounding box is redacted",
+                                "required": ["ref", "element"],
+                                "properties": {
+                                    "ref": { "type": "string", "description": "Element reference from snapshot" },
+                                    "element": { "type": "string", "description": "Human-readable element description" }
+                                }
+                            }
+                        ]
+                    }
+                },
+                "omitBackground": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Omit the page's default white background so areas without a background are transparent. PNG only; inline images are returned as PNG too"
+                },
+                "highlights": {
+                    "type": "array",
+                    "description": "Elements to outline in the screenshot, e.g. to point out several findings in one image",
+                    "items": {
+                        "type": "object",
+                        "required": ["ref"],
+                        "properties": {
+                            "ref": { "type": "string", "description": "Element reference from snapshot" },
+                            "color": { "type": "string", "default": "red", "description": "CSS color of the outline and label" },
+                            "label": { "type": "string", "description": "Text drawn at the top-left of the element" }
+                        }
+                    }
                 }
             }
         })
@@ -203,6 +239,36 @@ impl Tool for BrowserTakeScreenshotTool {
             ));
         }
 
+        if let Some(clip) = input.clip {
+            if input.element_ref.is_some() {
+                return Err(ToolError::InvalidParams(
+                    "clip and ref are mutually exclusive".to_string(),
+                ));
+            }
+            if input.full_page {
+                return Err(ToolError::InvalidParams(
+                    "fullPage cannot be used with clip".to_string(),
+                ));
+            }
+            if clip.width <= 0.0 || clip.height <= 0.0 {
+                return Err(ToolError::InvalidParams(
+                    "clip width and height must be positive".to_string(),
+                ));
+            }
+        }
+
+        if input.omit_background && input.image_type == ImageFormat::Jpeg {
+            return Err(ToolError::InvalidParams(
+                "omitBackground requires type 'png'; JPEG has no transparency".to_string(),
+            ));
+        }
+
+        if input.highlights.iter().any(|h| h.element_ref.is_empty()) {
+            return Err(ToolError::InvalidParams(
+                "each highlight needs a non-empty ref".to_string(),
+            ));
+        }
+
         // Get screenshot configuration from browser state
         let screenshot_dir = browser.screenshot_dir().clone();
         let image_responses = browser.image_responses();
@@ -233,55 +299,23 @@ impl Tool for BrowserTakeScreenshotTool {
             .filename
             .unwrap_or_else(|| generate_timestamp_filename(extension));
 
-        // Take the screenshot
-        let screenshot_bytes = if let Some(ref element_ref_str) = input.element_ref {
-            // Validate the ref exists in the snapshot
-            let options = SnapshotOptions::default();
-            let snapshot = AccessibilitySnapshot::capture(&page, options)
-                .await
-                .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+        // Overlays are part of the page, so they must be removed whether or not capture succeeds
+        if !input.highlights.is_empty() {
+            highlight::add_overlays(&page, &input.highlights).await?;
+        }
+        let captured = capture::capture(&page, &input).await;
+        if !input.highlights.is_empty() {
+            highlight::remove_overlays(&page).await;
+        }
+        let (mut screenshot_bytes, origin) = captured?;
 
-            snapshot.lookup(element_ref_str).map_err(|e| {
-                ToolError::ElementNotFound(format!("Element ref '{element_ref_str}': {e}"))
+        // Redact before anything is written to disk or returned
+        if !input.redact.is_empty() {
+            let rects = redact::resolve_zones(&page, &input.redact, origin).await?;
+            screenshot_bytes = apply_redactions(&screenshot_bytes, &rects).map_err(|e| {
+                ToolError::ExecutionFailed(format!("Failed to redact screenshot: {e}"))
             })?;
-
-            // Get the locator for the element
-            let locator = page.locator_from_ref(element_ref_str);
-
-            // Workaround: locator.screenshot() doesn't work with ref-based locators
-            // in viewpoint-core 0.2.16, so we use bounding_box + page screenshot with clip
-            let bbox = locator
-                .bounding_box()
-                .await
-                .map_err(|e| {
-                    ToolError::ExecutionFailed(format!(
-                        "Failed to get bounding box for element: {e}"
-                    ))
-                })?
-                .ok_or_else(|| {
-                    ToolError::ElementNotFound(format!(
-                        "Element ref '{element_ref_str}' has no bounding box (may be hidden)"
-                    ))
-                })?;
-
-            page.screenshot()
-                .clip(bbox.x, bbox.y, bbox.width, bbox.height)
-                .capture()
-                .await
-                .map_err(|e| {
-                    ToolError::ExecutionFailed(format!("Element screenshot failed: {e}"))
-                })?
-        } else {
-            // Page screenshot
-            let mut builder = page.screenshot();
-            if input.full_page {
-                builder = builder.full_page(true);
-            }
-            builder
-                .capture()
-                .await
-                .map_err(|e| ToolError::ExecutionFailed(format!("Screenshot failed: {e}")))?
-        };
+        }
 
         // Create screenshot directory if it doesn't exist
         if !screenshot_dir.exists() {
@@ -312,11 +346,34 @@ impl Tool for BrowserTakeScreenshotTool {
             } else {
                 "element".to_string()
             }
+        } else if let Some(clip) = input.clip {
+            format!(
+                "region {}x{} at ({}, {})",
+                clip.width, clip.height, clip.x, clip.y
+            )
         } else if input.full_page {
             "full page".to_string()
         } else {
             "viewport".to_string()
         };
+        let description = if input.redact.is_empty() {
+            description
+        } else {
+            format!("{description}, {} zone(s) redacted", input.redact.len())
+        };
+        let description = if input.highlights.is_empty() {
+            description
+        } else {
+            format!(
+                "{description}, {} element(s) highlighted",
+                input.highlights.len()
+            )
+        };
+        let description = if input.omit_background {
+            format!("{description}, transparent background")
+        } else {
+            description
+        };
 
         // Build response based on image response mode
         match image_responses {
@@ -334,8 +391,16 @@ impl Tool for BrowserTakeScreenshotTool {
                 )))
             }
             ImageResponseMode::Inline => {
-                // Include file path AND base64 image
-                let scaled_bytes = scale_image_for_inline(&screenshot_bytes).map_err(|e| {
+                // Include file path AND base64 image, keeping PNG for transparency
+                let (scaled, mime_type) = if input.omit_background {
+                    (
+                        scale_transparent_image_for_inline(&screenshot_bytes),
+                        "image/png",
+                    )
+                } else {
+                    (scale_image_for_inline(&screenshot_bytes), "image/jpeg")
+                };
+                let scaled_bytes = scaled.map_err(|e| {
                     ToolError::ExecutionFailed(format!("Failed to scale image for inline: {e}"))
                 })?;
 
@@ -346,7 +411,7 @@ impl Tool for BrowserTakeScreenshotTool {
                         "Screenshot saved to {} ({description})",
                         relative_path
                     )),
-                    ContentItem::image(base64_data, "image/jpeg"),
+                    ContentItem::image(base64_data, mime_type),
                 ]))
             }
         }
@@ -0,0 +1,188 @@
+//! Redaction zones for masking sensitive screenshot regions
+//!
+//! Zones are resolved to image pixel rectangles and painted solid black
+//! before the screenshot is saved or returned.
+
+use std::io::Cursor;
+
+use image::{DynamicImage, ImageFormat, Rgba};
+use serde::Deserialize;
+use viewpoint_core::Page;
+use viewpoint_js::js;
+
+use crate::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+use crate::tools::ToolError;
+
+/// A region of the screenshot to black out
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RedactZone {
+    /// Rectangle in CSS pixels relative to the top-left of the screenshot
+    Region {
+        /// Left edge
+        x: f64,
+        /// Top edge
+        y: f64,
+        /// Width
+        width: f64,
+        /// Height
+        height: f64,
+    },
+    /// Element whose bounding box should be redacted
+    Element {
+        /// Element reference from snapshot
+        #[serde(rename = "ref")]
+        element_ref: String,
+        /// Human-readable element description
+        element: String,
+    },
+}
+
+/// Rectangle in image pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelRect {
+    /// Left edge
+    pub x: i64,
+    /// Top edge
+    pub y: i64,
+    /// Width
+    pub width: i64,
+    /// Height
+    pub height: i64,
+}
+
+impl PixelRect {
+    /// Convert a CSS-pixel rectangle to image pixels at the given device pixel ratio
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_css(x: f64, y: f64, width: f64, height: f64, scale: f64) -> Self {
+        // Round outward so partially covered pixels are also redacted
+        let left = (x * scale).floor();
+        let top = (y * scale).floor();
+        let right = ((x + width) * scale).ceil();
+        let bottom = ((y + height) * scale).ceil();
+        Self {
+            x: left as i64,
+            y: top as i64,
+            width: (right - left) as i64,
+            height: (bottom - top) as i64,
+        }
+    }
+}
+
+/// Resolve redaction zones to image pixel rectangles.
+///
+/// `origin` is the viewport position (in CSS pixels) of the screenshot's
+/// top-left corner, used to translate element bounding boxes into image space.
+pub async fn resolve_zones(
+    page: &Page,
+    zones: &[RedactZone],
+    origin: (f64, f64),
+) -> Result<Vec<PixelRect>, ToolError> {
+    let scale: f64 = page
+        .evaluate(&js! { window.devicePixelRatio || 1 })
+        .await
+        .map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to get device pixel ratio: {e}"))
+        })?;
+
+    let needs_snapshot = zones
+        .iter()
+        .any(|zone| matches!(zone, RedactZone::Element { .. }));
+    let snapshot = if needs_snapshot {
+        Some(
+            AccessibilitySnapshot::capture(page, SnapshotOptions::default())
+                .await
+                .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?,
+        )
+    } else {
+        None
+    };
+
+    let mut rects = Vec::with_capacity(zones.len());
+    for zone in zones {
+        let rect = match zone {
+            RedactZone::Region {
+                x,
+                y,
+                width,
+                height,
+            } => PixelRect::from_css(*x, *y, *width, *height, scale),
+            RedactZone::Element {
+                element_ref,
+                element,
+            } => {
+                if let Some(snapshot) = &snapshot {
+                    snapshot.lookup(element_ref).map_err(|e| {
+                        ToolError::ElementNotFound(format!("Element ref '{element_ref}': {e}"))
+                    })?;
+                }
+
+                let bbox = page
+                    .locator_from_ref(element_ref)
+                    .bounding_box()
+                    .await
+                    .map_err(|e| {
+                        ToolError::ExecutionFailed(format!(
+                            "Failed to get bounding box for '{element}': {e}"
+                        ))
+                    })?
+                    .ok_or_else(|| {
+                        ToolError::ElementNotFound(format!(
+                            "Element '{element}' has no bounding box (may be hidden)"
+                        ))
+                    })?;
+
+                PixelRect::from_css(
+                    bbox.x - origin.0,
+                    bbox.y - origin.1,
+                    bbox.width,
+                    bbox.height,
+                    scale,
+                )
+            }
+        };
+        rects.push(rect);
+    }
+
+    Ok(rects)
+}
+
+/// Paint each rectangle solid black and re-encode the image in its original format.
+///
+/// Rectangles are clipped to the image bounds; those entirely outside are ignored.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn apply_redactions(image_bytes: &[u8], rects: &[PixelRect]) -> Result<Vec<u8>, String> {
+    let format =
+        image::guess_format(image_bytes).map_err(|e| format!("Unknown image format: {e}"))?;
+    let mut img = image::load_from_memory(image_bytes)
+        .map_err(|e| format!("Failed to decode image: {e}"))?
+        .to_rgba8();
+
+    let (img_width, img_height) = (i64::from(img.width()), i64::from(img.height()));
+    let black = Rgba([0, 0, 0, 255]);
+
+    for rect in rects {
+        let left = rect.x.clamp(0, img_width);
+        let top = rect.y.clamp(0, img_height);
+        let right = (rect.x + rect.width).clamp(0, img_width);
+        let bottom = (rect.y + rect.height).clamp(0, img_height);
+
+        for py in top..bottom {
+            for px in left..right {
+                img.put_pixel(px as u32, py as u32, black);
+            }
+        }
+    }
+
+    let mut buffer = Cursor::new(Vec::new());
+    let img = DynamicImage::ImageRgba8(img);
+    if format == ImageFormat::Jpeg {
+        // JPEG has no alpha channel
+        img.to_rgb8().write_to(&mut buffer, format)
+    } else {
+        img.write_to(&mut buffer, format)
+    }
+    .map_err(|e| format!("Failed to encode image: {e}"))?;
+
+    Ok(buffer.into_inner())
+}
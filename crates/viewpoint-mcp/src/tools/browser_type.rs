@@ -3,6 +3,8 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
+use viewpoint_core::Page;
+use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
@@ -32,6 +34,14 @@ pub struct BrowserTypeInput {
     /// Whether to submit (press Enter) after typing
     #[serde(default)]
     pub submit: bool,
+
+    /// Whether to add the text after the existing value instead of replacing it
+    #[serde(default)]
+    pub append: bool,
+
+    /// Whether to paste the text from the clipboard instead of typing it
+    #[serde(default)]
+    pub paste_mode: bool,
 }
 
 impl BrowserTypeTool {
@@ -42,6 +52,34 @@ impl BrowserTypeTool {
     }
 }
 
+/// Put `text` on the clipboard and paste it into the focused element with Ctrl+V
+async fn paste_text(page: &Page, text: &str) -> Result<(), ToolError> {
+    let available: bool = page
+        .evaluate(&js! { !!(navigator.clipboard && window.isSecureContext) })
+        .await
+        .map_err(|e| {
+            ToolError::ExecutionFailed(format!("Failed to check clipboard support: {e}"))
+        })?;
+    if !available {
+        return Err(ToolError::ExecutionFailed(
+            "pasteMode needs the Clipboard API, which is unavailable on this page (requires a \
+             secure context such as https or localhost)"
+                .to_string(),
+        ));
+    }
+
+    page.evaluate::<Value>(&js! {
+        navigator.clipboard.writeText(#{text}).then(() => null)
+    })
+    .await
+    .map_err(|e| ToolError::ExecutionFailed(format!("Failed to write clipboard: {e}")))?;
+
+    page.keyboard()
+        .press("Control+v")
+        .await
+        .map_err(|e| ToolError::ExecutionFailed(format!("Failed to press Ctrl+V: {e}")))
+}
+
 impl Default for BrowserTypeTool {
     fn default() -> Self {
         Self::new()
@@ -56,7 +94,14 @@ impl Tool for BrowserTypeTool {
 
     fn description(&self) -> &'static str {
         "Type text into an editable element on the page. Use 'slowly: true' for character-by-character \
-         typing that triggers key handlers. Use 'submit: true' to press Enter after typing."
+         typing that triggers key handlers. Use 'submit: true' to press Enter after typing. \
+         Use 'append: true' to keep the existing value and add the text at the end. \
+         Use 'pasteMode: true' to paste large text via the clipboard instead of typing it \
+         (requires a secure context such as https or localhost)."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["interaction"]
     }
 
     fn input_schema(&self) -> Value {
@@ -85,6 +130,16 @@ impl Tool for BrowserTypeTool {
                     "type": "boolean",
                     "default": false,
                     "description": "Press Enter after typing"
+                },
+                "append": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Keep the existing value and type at the end of it instead of clearing the field first"
+                },
+                "pasteMode": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "Write the text to the clipboard and paste it with Ctrl+V; much faster than typing for large inputs"
                 }
             }
         })
@@ -95,6 +150,13 @@ impl Tool for BrowserTypeTool {
         let input: BrowserTypeInput = serde_json::from_value(args.clone())
             .map_err(|e| ToolError::InvalidParams(e.to_string()))?;
 
+        if input.paste_mode && input.slowly {
+            return Err(ToolError::InvalidParams(
+                "pasteMode and slowly cannot be combined: pasting inserts the text at once"
+                    .to_string(),
+            ));
+        }
+
         // Ensure browser is initialized
         browser
             .initialize()
@@ -126,18 +188,43 @@ impl Tool for BrowserTypeTool {
         // Use native ref resolution API from viewpoint 0.2.9
         let locator = page.locator_from_ref(&input.element_ref);
 
-        // Perform the typing
-        let type_result = if input.slowly {
-            locator.type_text(&input.text).await
+        // Appending keeps the existing value: move the cursor past it and
+        // type without clearing, since fill() always replaces the value
+        if input.append {
+            locator.press("End").await.map_err(|e| {
+                ToolError::ExecutionFailed(format!(
+                    "Failed to move cursor to end of '{}': {e}",
+                    input.element
+                ))
+            })?;
+        }
+
+        // Pasting goes to the focused element, so clear and focus it first
+        // unless appending already placed the cursor at the end
+        if input.paste_mode {
+            if !input.append {
+                locator.fill("").await.map_err(|e| {
+                    ToolError::ExecutionFailed(format!(
+                        "Failed to clear element '{}': {e}",
+                        input.element
+                    ))
+                })?;
+            }
+            paste_text(&page, &input.text).await?;
         } else {
-            locator.fill(&input.text).await
-        };
+            // Perform the typing
+            let type_result = if input.slowly || input.append {
+                locator.type_text(&input.text).await
+            } else {
+                locator.fill(&input.text).await
+            };
 
-        if let Err(e) = type_result {
-            return Err(ToolError::ExecutionFailed(format!(
-                "Failed to type into element '{}': {}",
-                input.element, e
-            )));
+            if let Err(e) = type_result {
+                return Err(ToolError::ExecutionFailed(format!(
+                    "Failed to type into element '{}': {}",
+                    input.element, e
+                )));
+            }
         }
 
         // Submit if requested - use page keyboard API for more reliable behavior
@@ -153,10 +240,21 @@ impl Tool for BrowserTypeTool {
         // Invalidate cache after interaction
         context.invalidate_cache();
 
-        let mut result = format!(
-            "Typed \"{}\" into {} [ref={}]",
-            input.text, input.element, input.element_ref
-        );
+        // Pasted text is usually large, so report its length rather than echoing it
+        let mut result = if input.paste_mode {
+            format!(
+                "Pasted {} characters into {} [ref={}]",
+                input.text.chars().count(),
+                input.element,
+                input.element_ref
+            )
+        } else {
+            let verb = if input.append { "Appended" } else { "Typed" };
+            format!(
+                "{verb} \"{}\" into {} [ref={}]",
+                input.text, input.element, input.element_ref
+            )
+        };
         if input.submit {
             result.push_str(" and submitted");
         }
@@ -3,11 +3,19 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{Value, json};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use viewpoint_core::Page;
 use viewpoint_js::js;
 
 use super::{Tool, ToolError, ToolOutput, ToolResult};
 use crate::browser::BrowserState;
+use crate::snapshot::{AccessibilitySnapshot, ElementRef, SnapshotOptions};
+
+/// Default interval between snapshot polls for ref waits
+const DEFAULT_POLL_INTERVAL_MS: u64 = 500;
+
+/// Default time limit for text and ref waits
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
 
 /// Browser wait for tool - waits for text, text to disappear, or a specified time
 pub struct BrowserWaitForTool;
@@ -24,6 +32,83 @@ pub struct BrowserWaitForInput {
 
     /// Time to wait in seconds
     pub time: Option<f64>,
+
+    /// Element ref to wait for to appear in the accessibility snapshot
+    pub ref_present: Option<String>,
+
+    /// Element ref to wait for to disappear from the accessibility snapshot
+    pub ref_gone: Option<String>,
+
+    /// Interval between snapshot polls for ref waits, in milliseconds
+    pub poll_interval_ms: Option<u64>,
+
+    /// Maximum time to wait for text or a ref, in milliseconds
+    pub timeout_ms: Option<u64>,
+}
+
+/// Build the error returned when a wait exceeds `timeout`
+fn timeout_error(timeout: Duration) -> ToolError {
+    ToolError::Timeout {
+        tool_name: "browser_wait_for".to_string(),
+        timeout_ms: u64::try_from(timeout.as_millis()).unwrap_or(u64::MAX),
+    }
+}
+
+/// Wait until `condition` returns a truthy value in the page.
+///
+/// Returns `ToolError::Timeout` if the condition is not met within `timeout`,
+/// whether our deadline or viewpoint-core's own wait limit fires first.
+async fn wait_for_condition(
+    page: &Page,
+    condition: &str,
+    description: &str,
+    timeout: Duration,
+) -> Result<(), ToolError> {
+    match tokio::time::timeout(timeout, page.wait_for_function(condition).wait()).await {
+        Ok(Ok(_)) => Ok(()),
+        Ok(Err(e)) => {
+            let message = e.to_string();
+            let lower = message.to_lowercase();
+            if lower.contains("timeout") || lower.contains("timed out") {
+                Err(timeout_error(timeout))
+            } else {
+                Err(ToolError::ExecutionFailed(format!(
+                    "Failed waiting for {description}: {message}"
+                )))
+            }
+        }
+        Err(_) => Err(timeout_error(timeout)),
+    }
+}
+
+/// Poll the accessibility snapshot until `element_ref` is present (or gone).
+///
+/// Returns `ToolError::Timeout` if the condition is not met within `timeout`.
+async fn wait_for_ref(
+    page: &Page,
+    element_ref: &str,
+    present: bool,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<(), ToolError> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let snapshot = AccessibilitySnapshot::capture(page, SnapshotOptions::default())
+            .await
+            .map_err(|e| ToolError::ExecutionFailed(e.to_string()))?;
+
+        if snapshot.lookup(element_ref).is_ok() == present {
+            return Ok(());
+        }
+
+        let now = Instant::now();
+        if now >= deadline {
+            return Err(timeout_error(timeout));
+        }
+
+        tokio::time::sleep(poll_interval.min(deadline - now)).await;
+    }
 }
 
 impl BrowserWaitForTool {
@@ -47,8 +132,18 @@ impl Tool for BrowserWaitForTool {
     }
 
     fn description(&self) -> &'static str {
-        "Wait for a condition: text to appear, text to disappear, or a specified time to pass. \
-         Only one of text, textGone, or time should be provided."
+        "Wait for a condition: text to appear, text to disappear, an element ref to appear \
+         or disappear, or a specified time to pass. Only one of text, textGone, refPresent, \
+         refGone, or time should be provided."
+    }
+
+    fn categories(&self) -> Vec<&str> {
+        vec!["state"]
+    }
+
+    fn requires_browser(&self) -> bool {
+        // Time-only waits run without a browser; other waits initialize it themselves
+        false
     }
 
     fn input_schema(&self) -> Value {
@@ -66,6 +161,26 @@ impl Tool for BrowserWaitForTool {
                 "time": {
                     "type": "number",
                     "description": "Time to wait in seconds"
+                },
+                "refPresent": {
+                    "type": "string",
+                    "description": "Element ref from browser_snapshot to wait for to appear"
+                },
+                "refGone": {
+                    "type": "string",
+                    "description": "Element ref from browser_snapshot to wait for to disappear"
+                },
+                "pollIntervalMs": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "default": DEFAULT_POLL_INTERVAL_MS,
+                    "description": "Interval between snapshot checks for refPresent/refGone, in milliseconds"
+                },
+                "timeoutMs": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "default": DEFAULT_TIMEOUT_MS,
+                    "description": "Maximum time to wait for text, textGone, refPresent, or refGone, in milliseconds"
                 }
             }
         })
@@ -81,6 +196,8 @@ impl Tool for BrowserWaitForTool {
             input.text.is_some(),
             input.text_gone.is_some(),
             input.time.is_some(),
+            input.ref_present.is_some(),
+            input.ref_gone.is_some(),
         ]
         .iter()
         .filter(|&&b| b)
@@ -88,13 +205,15 @@ impl Tool for BrowserWaitForTool {
 
         if condition_count == 0 {
             return Err(ToolError::InvalidParams(
-                "At least one of text, textGone, or time must be provided".to_string(),
+                "At least one of text, textGone, refPresent, refGone, or time must be provided"
+                    .to_string(),
             ));
         }
 
         if condition_count > 1 {
             return Err(ToolError::InvalidParams(
-                "Only one of text, textGone, or time should be provided".to_string(),
+                "Only one of text, textGone, refPresent, refGone, or time should be provided"
+                    .to_string(),
             ));
         }
 
@@ -115,7 +234,22 @@ impl Tool for BrowserWaitForTool {
             return Ok(ToolOutput::text(format!("Waited for {seconds} seconds")));
         }
 
-        // Ensure browser is initialized for text-based waits
+        let poll_interval_ms = input.poll_interval_ms.unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+        if poll_interval_ms == 0 {
+            return Err(ToolError::InvalidParams(
+                "pollIntervalMs must be greater than 0".to_string(),
+            ));
+        }
+        let poll_interval = Duration::from_millis(poll_interval_ms);
+
+        // Reject malformed refs up front; otherwise refGone would succeed at once
+        for element_ref in [&input.ref_present, &input.ref_gone].into_iter().flatten() {
+            ElementRef::parse(element_ref).map_err(ToolError::InvalidParams)?;
+        }
+
+        let timeout = Duration::from_millis(input.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+
+        // Ensure browser is initialized for text- and ref-based waits
         browser
             .initialize()
             .await
@@ -137,12 +271,7 @@ impl Tool for BrowserWaitForTool {
             // Use js! macro for compile-time JavaScript validation and proper string escaping
             let js_condition = js! { () => document.body.innerText.includes(#{text}) };
 
-            page.wait_for_function(&js_condition)
-                .wait()
-                .await
-                .map_err(|e| {
-                    ToolError::Timeout(format!("Timeout waiting for text '{text}': {e}"))
-                })?;
+            wait_for_condition(&page, &js_condition, &format!("text '{text}'"), timeout).await?;
 
             // Invalidate cache as page content changed
             context.invalidate_cache();
@@ -155,14 +284,13 @@ impl Tool for BrowserWaitForTool {
             // Use js! macro for compile-time JavaScript validation and proper string escaping
             let js_condition = js! { () => !document.body.innerText.includes(#{text}) };
 
-            page.wait_for_function(&js_condition)
-                .wait()
-                .await
-                .map_err(|e| {
-                    ToolError::Timeout(format!(
-                        "Timeout waiting for text '{text}' to disappear: {e}"
-                    ))
-                })?;
+            wait_for_condition(
+                &page,
+                &js_condition,
+                &format!("text '{text}' to disappear"),
+                timeout,
+            )
+            .await?;
 
             // Invalidate cache as page content changed
             context.invalidate_cache();
@@ -172,6 +300,23 @@ impl Tool for BrowserWaitForTool {
             )));
         }
 
+        // Handle ref appearance/disappearance by polling the accessibility snapshot
+        if let Some(ref element_ref) = input.ref_present {
+            wait_for_ref(&page, element_ref, true, poll_interval, timeout).await?;
+            context.invalidate_cache();
+            return Ok(ToolOutput::text(format!(
+                "Element ref '{element_ref}' appeared on page"
+            )));
+        }
+
+        if let Some(ref element_ref) = input.ref_gone {
+            wait_for_ref(&page, element_ref, false, poll_interval, timeout).await?;
+            context.invalidate_cache();
+            return Ok(ToolOutput::text(format!(
+                "Element ref '{element_ref}' disappeared from page"
+            )));
+        }
+
         // This shouldn't be reachable due to earlier validation
         Err(ToolError::InvalidParams(
             "No valid wait condition provided".to_string(),
@@ -21,11 +21,31 @@ pub enum ToolError {
     #[error("Element not found: {0}")]
     ElementNotFound(String),
 
-    /// Timeout during execution
-    #[error("Timeout: {0}")]
-    Timeout(String),
+    /// Tool execution exceeded its time limit
+    #[error("Tool '{tool_name}' timed out after {timeout_ms}ms")]
+    Timeout {
+        /// Name of the tool that timed out
+        tool_name: String,
+        /// Time limit that was exceeded, in milliseconds
+        timeout_ms: u64,
+    },
 
     /// JSON serialization error
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 }
+
+impl ToolError {
+    /// Stable machine-readable category name, used in tool call logs
+    #[must_use]
+    pub const fn category(&self) -> &'static str {
+        match self {
+            Self::InvalidParams(_) => "invalid_params",
+            Self::ExecutionFailed(_) => "execution_failed",
+            Self::BrowserNotAvailable(_) => "browser_not_available",
+            Self::ElementNotFound(_) => "element_not_found",
+            Self::Timeout { .. } => "timeout",
+            Self::Json(_) => "json",
+        }
+    }
+}
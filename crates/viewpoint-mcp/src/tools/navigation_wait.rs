@@ -0,0 +1,95 @@
+//! Wait conditions shared by the navigation tools
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde_json::{Value, json};
+use viewpoint_core::{DocumentLoadState, Page};
+
+use super::ToolError;
+
+/// Navigation wait condition
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NavigationWaitCondition {
+    /// Wait for the `load` event
+    #[default]
+    Load,
+    /// Wait for the `DOMContentLoaded` event
+    DomContentLoaded,
+    /// Wait until there are no network connections for at least 500ms
+    NetworkIdle0,
+    /// Wait until there are no more than 2 network connections for at least 500ms
+    NetworkIdle2,
+    /// Alias for `networkidle0`
+    #[serde(rename = "networkidle")]
+    NetworkIdle,
+}
+
+impl NavigationWaitCondition {
+    /// Every condition, in the order the schema lists them
+    const ALL: [Self; 5] = [
+        Self::Load,
+        Self::DomContentLoaded,
+        Self::NetworkIdle,
+        Self::NetworkIdle0,
+        Self::NetworkIdle2,
+    ];
+
+    /// The name accepted in `waitUntil`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Load => "load",
+            Self::DomContentLoaded => "domcontentloaded",
+            Self::NetworkIdle0 => "networkidle0",
+            Self::NetworkIdle2 => "networkidle2",
+            Self::NetworkIdle => "networkidle",
+        }
+    }
+
+    /// Map to the viewpoint-core load state
+    ///
+    /// viewpoint-core has a single network idle state, so all network idle
+    /// variants map to it.
+    pub(super) const fn load_state(self) -> DocumentLoadState {
+        match self {
+            Self::Load => DocumentLoadState::Load,
+            Self::DomContentLoaded => DocumentLoadState::DomContentLoaded,
+            Self::NetworkIdle0 | Self::NetworkIdle2 | Self::NetworkIdle => {
+                DocumentLoadState::NetworkIdle
+            }
+        }
+    }
+
+    /// JSON schema for a `waitUntil` property
+    ///
+    /// `default` is the condition the tool uses when `waitUntil` is omitted,
+    /// or `None` if it does not wait at all.
+    pub(super) fn schema(description: &str, default: Option<Self>) -> Value {
+        let mut schema = json!({
+            "type": "string",
+            "enum": Self::ALL.map(Self::as_str),
+            "description": description
+        });
+        if let Some(default) = default {
+            schema["default"] = json!(default.as_str());
+        }
+        schema
+    }
+
+    /// Wait for the page to reach this condition after a history navigation
+    pub(super) async fn wait(self, page: &Page) -> Result<(), ToolError> {
+        page.wait_for_load_state(self.load_state())
+            .await
+            .map_err(|e| {
+                ToolError::ExecutionFailed(format!("Waiting for {self} state failed: {e}"))
+            })
+    }
+}
+
+impl fmt::Display for NavigationWaitCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
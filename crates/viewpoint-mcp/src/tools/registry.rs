@@ -4,10 +4,35 @@
 //! providing filtered access based on enabled capabilities.
 
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, Weak};
 
 use super::traits::{Capability, Tool};
 
+/// Category name used by [`ToolRegistry::list_by_category`] for tools without categories
+pub const UNCATEGORIZED: &str = "uncategorized";
+
+/// Registered tools and enabled capabilities, shared with [`ToolLookup`]
+struct RegistryState {
+    tools: HashMap<String, Arc<dyn Tool>>,
+    enabled_capabilities: HashSet<Capability>,
+}
+
+impl RegistryState {
+    fn is_tool_available(&self, tool: &Arc<dyn Tool>) -> bool {
+        match tool.required_capability() {
+            None => true,
+            Some(cap) => self.enabled_capabilities.contains(&cap),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        self.tools
+            .get(name)
+            .filter(|tool| self.is_tool_available(tool))
+            .cloned()
+    }
+}
+
 /// Registry of available MCP tools.
 ///
 /// The registry stores tool implementations and tracks which capabilities
@@ -34,49 +59,71 @@ use super::traits::{Capability, Tool};
 /// assert!(!tools.iter().any(|t| t.name() == "browser_mouse_click_xy"));
 /// ```
 pub struct ToolRegistry {
-    tools: HashMap<String, Arc<dyn Tool>>,
-    enabled_capabilities: HashSet<Capability>,
+    state: Arc<RwLock<RegistryState>>,
 }
 
 impl ToolRegistry {
     /// Create a new empty tool registry
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            tools: HashMap::new(),
-            enabled_capabilities: HashSet::new(),
-        }
+        Self::with_capabilities([])
     }
 
     /// Create a new tool registry with specified capabilities enabled
     #[must_use]
     pub fn with_capabilities(capabilities: impl IntoIterator<Item = Capability>) -> Self {
         Self {
-            tools: HashMap::new(),
-            enabled_capabilities: capabilities.into_iter().collect(),
+            state: Arc::new(RwLock::new(RegistryState {
+                tools: HashMap::new(),
+                enabled_capabilities: capabilities.into_iter().collect(),
+            })),
         }
     }
 
+    fn read(&self) -> RwLockReadGuard<'_, RegistryState> {
+        self.state.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, RegistryState> {
+        self.state.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Enable a capability
     pub fn enable_capability(&mut self, capability: Capability) {
-        self.enabled_capabilities.insert(capability);
+        self.write().enabled_capabilities.insert(capability);
     }
 
     /// Check if a capability is enabled
     #[must_use]
     pub fn is_capability_enabled(&self, capability: Capability) -> bool {
-        self.enabled_capabilities.contains(&capability)
+        self.read().enabled_capabilities.contains(&capability)
     }
 
     /// Get the set of enabled capabilities
     #[must_use]
-    pub fn enabled_capabilities(&self) -> &HashSet<Capability> {
-        &self.enabled_capabilities
+    pub fn enabled_capabilities(&self) -> HashSet<Capability> {
+        self.read().enabled_capabilities.clone()
+    }
+
+    /// Remove all enabled capabilities, hiding every capability-gated tool
+    ///
+    /// Only available in tests and with the `testing` feature.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn reset_capabilities(&mut self) {
+        self.write().enabled_capabilities.clear();
     }
 
     /// Register a tool
     pub fn register(&mut self, tool: Arc<dyn Tool>) {
-        self.tools.insert(tool.name().to_string(), tool);
+        self.write().tools.insert(tool.name().to_string(), tool);
+    }
+
+    /// Remove all registered tools, keeping the enabled capabilities
+    ///
+    /// Only available in tests and with the `testing` feature.
+    #[cfg(any(test, feature = "testing"))]
+    pub fn deregister_all(&mut self) {
+        self.write().tools.clear();
     }
 
     /// Get a tool by name, respecting capability requirements
@@ -84,10 +131,8 @@ impl ToolRegistry {
     /// Returns `None` if the tool doesn't exist or if it requires
     /// a capability that isn't enabled.
     #[must_use]
-    pub fn get(&self, name: &str) -> Option<&Arc<dyn Tool>> {
-        self.tools
-            .get(name)
-            .filter(|tool| self.is_tool_available(tool))
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        self.read().get(name)
     }
 
     /// Get a tool by name, ignoring capability requirements
@@ -95,34 +140,81 @@ impl ToolRegistry {
     /// This is useful for error messages that need to distinguish
     /// between "unknown tool" and "capability not enabled".
     #[must_use]
-    pub fn get_unchecked(&self, name: &str) -> Option<&Arc<dyn Tool>> {
-        self.tools.get(name)
+    pub fn get_unchecked(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        self.read().tools.get(name).cloned()
     }
 
     /// Check if a tool is available based on its capability requirements
     #[must_use]
     pub fn is_tool_available(&self, tool: &Arc<dyn Tool>) -> bool {
-        match tool.required_capability() {
-            None => true,
-            Some(cap) => self.enabled_capabilities.contains(&cap),
-        }
+        self.read().is_tool_available(tool)
     }
 
     /// List all registered tools that are currently available
     ///
     /// Tools requiring capabilities that aren't enabled are excluded.
     #[must_use]
-    pub fn list(&self) -> Vec<&Arc<dyn Tool>> {
-        self.tools
+    pub fn list(&self) -> Vec<Arc<dyn Tool>> {
+        let state = self.read();
+        state
+            .tools
             .values()
-            .filter(|tool| self.is_tool_available(tool))
+            .filter(|tool| state.is_tool_available(tool))
+            .cloned()
             .collect()
     }
 
+    /// List available tools sorted alphabetically by name
+    ///
+    /// Unlike [`ToolRegistry::list`], the order is the same on every call.
+    #[must_use]
+    pub fn list_sorted(&self) -> Vec<Arc<dyn Tool>> {
+        let mut tools = self.list();
+        tools.sort_by_key(|tool| tool.name());
+        tools
+    }
+
+    /// Group available tools by category
+    ///
+    /// A tool appears under each of its categories. Tools without any
+    /// category are grouped under [`UNCATEGORIZED`].
+    #[must_use]
+    pub fn list_by_category(&self) -> HashMap<String, Vec<Arc<dyn Tool>>> {
+        let mut groups: HashMap<String, Vec<Arc<dyn Tool>>> = HashMap::new();
+        for tool in self.list() {
+            let categories = tool.categories();
+            if categories.is_empty() {
+                groups
+                    .entry(UNCATEGORIZED.to_string())
+                    .or_default()
+                    .push(Arc::clone(&tool));
+            }
+            for category in categories {
+                groups
+                    .entry(category.to_string())
+                    .or_default()
+                    .push(Arc::clone(&tool));
+            }
+        }
+        groups
+    }
+
     /// List all registered tools, regardless of capability requirements
     #[must_use]
-    pub fn list_all(&self) -> Vec<&Arc<dyn Tool>> {
-        self.tools.values().collect()
+    pub fn list_all(&self) -> Vec<Arc<dyn Tool>> {
+        self.read().tools.values().cloned().collect()
+    }
+
+    /// A handle for looking up tools in this registry later.
+    ///
+    /// Lookups see tools registered and capabilities enabled after the handle
+    /// was created. The handle does not keep the registry alive, so a tool
+    /// holding one can itself be registered without creating a cycle.
+    #[must_use]
+    pub fn lookup(&self) -> ToolLookup {
+        ToolLookup {
+            state: Arc::downgrade(&self.state),
+        }
     }
 }
 
@@ -132,25 +224,47 @@ impl Default for ToolRegistry {
     }
 }
 
+/// Weak handle to a [`ToolRegistry`], created by [`ToolRegistry::lookup`]
+#[derive(Clone)]
+pub struct ToolLookup {
+    state: Weak<RwLock<RegistryState>>,
+}
+
+impl ToolLookup {
+    /// Get a tool by name, respecting capability requirements
+    ///
+    /// Returns `None` if the tool doesn't exist, requires a capability that
+    /// isn't enabled, or the registry has been dropped.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        let state = self.state.upgrade()?;
+        let guard = state.read().unwrap_or_else(PoisonError::into_inner);
+        guard.get(name)
+    }
+}
+
 /// Register all browser tools with the registry
 ///
-/// This function registers all 31 browser tools:
-/// - 28 core tools (always available)
+/// This function registers all 38 browser tools:
+/// - 34 core tools (always available)
 /// - 3 vision tools (require Vision capability)
 /// - 1 PDF tool (requires Pdf capability)
 pub fn register_all_tools(registry: &mut ToolRegistry) {
     use std::sync::Arc;
 
-    // Navigation tools (2)
+    // Navigation tools (3)
     registry.register(Arc::new(super::BrowserNavigateTool::new()));
     registry.register(Arc::new(super::BrowserNavigateBackTool::new()));
+    registry.register(Arc::new(super::BrowserNavigateForwardTool::new()));
 
-    // Interaction tools (9)
+    // Interaction tools (11)
     registry.register(Arc::new(super::BrowserClickTool::new()));
     registry.register(Arc::new(super::BrowserDragTool::new()));
     registry.register(Arc::new(super::BrowserFileUploadTool::new()));
     registry.register(Arc::new(super::BrowserFillFormTool::new()));
+    registry.register(Arc::new(super::BrowserFocusTool::new()));
     registry.register(Arc::new(super::BrowserHoverTool::new()));
+    registry.register(Arc::new(super::BrowserKeyboardShortcutTool::new()));
     registry.register(Arc::new(super::BrowserPressKeyTool::new()));
     registry.register(Arc::new(super::BrowserScrollIntoViewTool::new()));
     registry.register(Arc::new(super::BrowserSelectOptionTool::new()));
@@ -162,7 +276,8 @@ pub fn register_all_tools(registry: &mut ToolRegistry) {
     registry.register(Arc::new(super::BrowserSnapshotTool::new()));
     registry.register(Arc::new(super::BrowserTakeScreenshotTool::new()));
 
-    // State tools (3)
+    // State tools (4)
+    registry.register(Arc::new(super::BrowserClipboardTool::new()));
     registry.register(Arc::new(super::BrowserEvaluateTool::new()));
     registry.register(Arc::new(super::BrowserHandleDialogTool::new()));
     registry.register(Arc::new(super::BrowserWaitForTool::new()));
@@ -173,12 +288,14 @@ pub fn register_all_tools(registry: &mut ToolRegistry) {
     registry.register(Arc::new(super::BrowserResizeTool::new()));
     registry.register(Arc::new(super::BrowserTabsTool::new()));
 
-    // Context management tools (5)
+    // Context management tools (7)
     registry.register(Arc::new(super::BrowserContextCloseTool::new()));
     registry.register(Arc::new(super::BrowserContextCreateTool::new()));
+    registry.register(Arc::new(super::BrowserContextImportHarTool::new()));
     registry.register(Arc::new(super::BrowserContextListTool::new()));
     registry.register(Arc::new(super::BrowserContextSaveStorageTool::new()));
     registry.register(Arc::new(super::BrowserContextSwitchTool::new()));
+    registry.register(Arc::new(super::BrowserSetExtraHeadersTool::new()));
 
     // Optional vision tools (3) - require Vision capability
     registry.register(Arc::new(super::BrowserMouseClickXyTool::new()));
@@ -187,4 +304,10 @@ pub fn register_all_tools(registry: &mut ToolRegistry) {
 
     // Optional PDF tool (1) - requires Pdf capability
     registry.register(Arc::new(super::BrowserPdfSaveTool::new()));
+
+    // Accessibility tree diff (1) - wraps whichever tools are available when called
+    let lookup = registry.lookup();
+    registry.register(Arc::new(super::BrowserAccessibilityTreeDiffTool::new(
+        lookup,
+    )));
 }
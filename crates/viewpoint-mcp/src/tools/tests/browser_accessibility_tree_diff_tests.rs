@@ -0,0 +1,95 @@
+//! Tests for `browser_accessibility_tree_diff` tool
+
+use std::sync::Arc;
+
+use crate::browser::{BrowserConfig, BrowserState};
+use crate::tools::browser_accessibility_tree_diff::{
+    BrowserAccessibilityTreeDiffInput, BrowserAccessibilityTreeDiffTool,
+};
+use crate::tools::{BrowserClickTool, Tool, ToolError, ToolRegistry, register_all_tools};
+use serde_json::json;
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserAccessibilityTreeDiffTool::new(ToolRegistry::new().lookup());
+
+    assert_eq!(tool.name(), "browser_accessibility_tree_diff");
+    assert!(!tool.description().is_empty());
+    assert_eq!(tool.categories(), vec!["inspection"]);
+    assert!(tool.required_capability().is_none());
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["required"], json!(["tool"]));
+    assert_eq!(schema["properties"]["args"]["type"], "object");
+}
+
+#[test]
+fn test_input_parsing() {
+    let input: BrowserAccessibilityTreeDiffInput = serde_json::from_value(json!({
+        "tool": "browser_click",
+        "args": { "ref": "c0p0f0e1", "element": "Menu toggle" }
+    }))
+    .unwrap();
+    assert_eq!(input.tool, "browser_click");
+    assert_eq!(input.args["ref"], "c0p0f0e1");
+
+    let input: BrowserAccessibilityTreeDiffInput =
+        serde_json::from_value(json!({ "tool": "browser_navigate_back" })).unwrap();
+    assert_eq!(input.args, json!({}));
+
+    let result: Result<BrowserAccessibilityTreeDiffInput, _> =
+        serde_json::from_value(json!({ "args": {} }));
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_unknown_tool_rejected_before_launch() {
+    let mut registry = ToolRegistry::new();
+    registry.register(Arc::new(BrowserClickTool::new()));
+    let tool = BrowserAccessibilityTreeDiffTool::new(registry.lookup());
+    let mut browser = BrowserState::new(BrowserConfig::default());
+
+    for name in ["browser_hover", "browser_accessibility_tree_diff"] {
+        let result = tool.execute(&json!({ "tool": name }), &mut browser).await;
+        assert!(
+            matches!(result, Err(ToolError::InvalidParams(_))),
+            "{name}: {result:?}"
+        );
+    }
+    assert!(!browser.is_initialized());
+}
+
+#[tokio::test]
+async fn test_registered_tool_wraps_only_available_tools() {
+    let mut registry = ToolRegistry::new();
+    register_all_tools(&mut registry);
+    let tool = registry.get("browser_accessibility_tree_diff").unwrap();
+    let mut browser = BrowserState::new(BrowserConfig::default());
+
+    // Vision tools are not enabled, so they cannot be reached through the wrapper
+    let result = tool
+        .execute(
+            &json!({ "tool": "browser_mouse_click_xy", "args": { "x": 1, "y": 1 } }),
+            &mut browser,
+        )
+        .await;
+    assert!(matches!(result, Err(ToolError::InvalidParams(_))));
+    assert!(!browser.is_initialized());
+}
+
+#[tokio::test]
+async fn test_dropped_registry_rejects_every_tool() {
+    let lookup = {
+        let mut registry = ToolRegistry::new();
+        register_all_tools(&mut registry);
+        registry.lookup()
+    };
+    let tool = BrowserAccessibilityTreeDiffTool::new(lookup);
+    let mut browser = BrowserState::new(BrowserConfig::default());
+
+    let result = tool
+        .execute(&json!({ "tool": "browser_click" }), &mut browser)
+        .await;
+    assert!(matches!(result, Err(ToolError::InvalidParams(_))));
+    assert!(!browser.is_initialized());
+}
@@ -33,6 +33,8 @@ fn test_input_parsing() {
     assert_eq!(input.element, Some("Submit button".to_string()));
     assert!(matches!(input.button, ClickButton::Left));
     assert!(!input.double_click);
+    assert!(!input.trial);
+    assert!(!input.force);
 }
 
 #[test]
@@ -51,3 +53,56 @@ fn test_input_with_options() {
     assert!(input.double_click);
     assert_eq!(input.modifiers.len(), 2);
 }
+
+#[test]
+fn test_input_with_trial() {
+    let input: BrowserClickInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Submit button",
+        "trial": true
+    }))
+    .unwrap();
+
+    assert!(input.trial);
+
+    let schema = BrowserClickTool::new().input_schema();
+    assert_eq!(schema["properties"]["trial"]["default"], false);
+}
+
+#[test]
+fn test_input_with_force() {
+    let input: BrowserClickInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Submit button",
+        "force": true
+    }))
+    .unwrap();
+
+    assert!(input.force);
+
+    let schema = BrowserClickTool::new().input_schema();
+    assert_eq!(schema["properties"]["force"]["default"], false);
+    assert!(
+        schema["properties"]["force"]["description"]
+            .as_str()
+            .unwrap()
+            .contains("Last resort")
+    );
+}
+
+#[tokio::test]
+async fn test_trial_with_force_is_rejected() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+    let result = BrowserClickTool::new()
+        .execute(
+            &json!({ "ref": "c0p0f0e1", "element": "Submit button", "trial": true, "force": true }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
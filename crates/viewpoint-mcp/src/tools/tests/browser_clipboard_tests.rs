@@ -0,0 +1,52 @@
+//! Tests for `browser_clipboard` tool
+
+use crate::tools::Tool;
+use crate::tools::browser_clipboard::{
+    BrowserClipboardInput, BrowserClipboardTool, ClipboardAction,
+};
+use serde_json::json;
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserClipboardTool::new();
+
+    assert_eq!(tool.name(), "browser_clipboard");
+    assert!(!tool.description().is_empty());
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["type"], "object");
+    assert!(
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("action"))
+    );
+}
+
+#[test]
+fn test_input_parsing_read() {
+    let input: BrowserClipboardInput = serde_json::from_value(json!({ "action": "read" })).unwrap();
+
+    assert_eq!(input.action, ClipboardAction::Read);
+    assert!(input.text.is_none());
+}
+
+#[test]
+fn test_input_parsing_write() {
+    let input: BrowserClipboardInput = serde_json::from_value(json!({
+        "action": "write",
+        "text": "copied"
+    }))
+    .unwrap();
+
+    assert_eq!(input.action, ClipboardAction::Write);
+    assert_eq!(input.text.as_deref(), Some("copied"));
+}
+
+#[test]
+fn test_input_parsing_invalid_action() {
+    let result: Result<BrowserClipboardInput, _> =
+        serde_json::from_value(json!({ "action": "cut" }));
+
+    assert!(result.is_err());
+}
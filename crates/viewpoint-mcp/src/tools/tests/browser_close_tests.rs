@@ -33,3 +33,50 @@ fn test_input_parsing_with_extra_fields() {
     .unwrap();
     let _ = input;
 }
+
+#[test]
+fn test_input_parsing_context() {
+    let input: BrowserCloseInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.context.is_none());
+
+    let input: BrowserCloseInput = serde_json::from_value(json!({ "context": "session" })).unwrap();
+    assert_eq!(input.context.as_deref(), Some("session"));
+
+    let schema = BrowserCloseTool::new().input_schema();
+    assert_eq!(schema["properties"]["context"]["type"], "string");
+}
+
+#[test]
+fn test_does_not_require_browser() {
+    // The server must not launch a browser before this tool runs
+    assert!(!BrowserCloseTool::new().requires_browser());
+}
+
+#[test]
+fn test_input_parsing_all_tabs() {
+    let input: BrowserCloseInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.all_tabs);
+
+    let input: BrowserCloseInput = serde_json::from_value(json!({ "allTabs": true })).unwrap();
+    assert!(input.all_tabs);
+
+    let schema = BrowserCloseTool::new().input_schema();
+    assert_eq!(schema["properties"]["allTabs"]["default"], false);
+}
+
+#[tokio::test]
+async fn test_all_tabs_with_context_is_rejected() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = BrowserCloseTool::new()
+        .execute(
+            &json!({ "allTabs": true, "context": "default" }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+}
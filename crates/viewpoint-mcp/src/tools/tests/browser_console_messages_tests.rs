@@ -1,8 +1,10 @@
 //! Tests for `browser_console_messages` tool
 
+use crate::browser::{StoredConsoleMessage, StoredConsoleMessageType};
 use crate::tools::Tool;
 use crate::tools::browser_console_messages::{
-    BrowserConsoleMessagesInput, BrowserConsoleMessagesTool, ConsoleLevel,
+    BrowserConsoleMessagesInput, BrowserConsoleMessagesTool, ConsoleLevel, ConsoleOutputFormat,
+    message_to_json, message_to_text,
 };
 use serde_json::json;
 
@@ -22,6 +24,7 @@ fn test_input_defaults() {
     let input: BrowserConsoleMessagesInput = serde_json::from_value(json!({})).unwrap();
 
     assert_eq!(input.level, ConsoleLevel::Info);
+    assert_eq!(input.format, None);
 }
 
 #[test]
@@ -43,3 +46,55 @@ fn test_input_debug_level() {
 
     assert_eq!(input.level, ConsoleLevel::Debug);
 }
+
+#[test]
+fn test_input_json_format() {
+    let input: BrowserConsoleMessagesInput = serde_json::from_value(json!({
+        "format": "json"
+    }))
+    .unwrap();
+
+    assert_eq!(input.format, Some(ConsoleOutputFormat::Json));
+}
+
+fn sample_message() -> StoredConsoleMessage {
+    StoredConsoleMessage {
+        message_type: StoredConsoleMessageType::Error,
+        text: "Boom".to_string(),
+        timestamp: 1_700_000_000_142.4,
+        url: Some("https://example.com/app.js".to_string()),
+        line_number: Some(42),
+    }
+}
+
+#[test]
+fn test_message_to_json_relative_timestamp() {
+    let value = message_to_json(&sample_message(), 1_700_000_000_000.0);
+
+    assert_eq!(
+        value,
+        json!({
+            "timestamp_ms": 142.0,
+            "level": "error",
+            "text": "Boom",
+            "source_url": "https://example.com/app.js",
+            "line_number": 42
+        })
+    );
+}
+
+#[test]
+fn test_message_to_text() {
+    let mut message = sample_message();
+    assert_eq!(
+        message_to_text(&message, 1_700_000_000_000.0),
+        "[+142ms] [error] Boom (https://example.com/app.js:42)"
+    );
+
+    message.url = None;
+    message.line_number = None;
+    assert_eq!(
+        message_to_text(&message, 1_700_000_000_000.0),
+        "[+142ms] [error] Boom"
+    );
+}
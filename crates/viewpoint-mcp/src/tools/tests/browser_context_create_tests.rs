@@ -31,6 +31,10 @@ fn test_input_parsing_minimal() {
     assert_eq!(input.name, "test-context");
     assert!(input.proxy.is_none());
     assert!(input.storage_state.is_none());
+    assert!(input.storage_state_json.is_none());
+    assert!(input.user_agent.is_none());
+    assert!(input.locale.is_none());
+    assert!(input.timezone.is_none());
 }
 
 #[test]
@@ -67,6 +71,89 @@ fn test_input_parsing_with_storage_state() {
     );
 }
 
+#[test]
+fn test_input_parsing_with_inline_storage_state() {
+    let input: BrowserContextCreateInput = serde_json::from_value(json!({
+        "name": "auth-context",
+        "storageStateJson": {
+            "cookies": [{ "name": "session", "value": "abc", "domain": "example.com", "path": "/" }],
+            "origins": []
+        }
+    }))
+    .unwrap();
+
+    assert!(input.storage_state.is_none());
+    let state = input.storage_state_json.unwrap();
+    assert_eq!(state["cookies"][0]["name"], "session");
+
+    let schema = BrowserContextCreateTool::new().input_schema();
+    assert_eq!(schema["properties"]["storageStateJson"]["type"], "object");
+}
+
+#[tokio::test]
+async fn test_both_storage_states_rejected() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = BrowserContextCreateTool::new()
+        .execute(
+            &json!({
+                "name": "auth-context",
+                "storageState": "/tmp/state.json",
+                "storageStateJson": { "cookies": [], "origins": [] }
+            }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
+#[tokio::test]
+async fn test_non_object_inline_storage_state_rejected() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = BrowserContextCreateTool::new()
+        .execute(
+            &json!({ "name": "auth-context", "storageStateJson": "cookies" }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
+#[tokio::test]
+async fn test_inline_storage_state_rejected_until_supported() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = BrowserContextCreateTool::new()
+        .execute(
+            &json!({
+                "name": "auth-context",
+                "storageStateJson": {
+                    "cookies": [{ "name": "session", "value": "secret", "domain": "example.com", "path": "/" }],
+                    "origins": []
+                }
+            }),
+            &mut browser,
+        )
+        .await;
+
+    let Err(crate::tools::ToolError::InvalidParams(message)) = result else {
+        panic!("Inline storage state should be rejected: {result:?}");
+    };
+    assert!(message.contains("not supported"), "Got: {message}");
+    assert!(!browser.is_initialized());
+}
+
 #[test]
 fn test_input_parsing_full() {
     let input: BrowserContextCreateInput = serde_json::from_value(json!({
@@ -82,3 +169,65 @@ fn test_input_parsing_full() {
     assert!(input.proxy.is_some());
     assert!(input.storage_state.is_some());
 }
+
+#[test]
+fn test_input_parsing_with_user_agent() {
+    let input: BrowserContextCreateInput = serde_json::from_value(json!({
+        "name": "mobile-context",
+        "userAgent": "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        input.user_agent,
+        Some("Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)".to_string())
+    );
+}
+
+#[test]
+fn test_input_parsing_with_locale_and_timezone() {
+    let input: BrowserContextCreateInput = serde_json::from_value(json!({
+        "name": "german",
+        "locale": "de-DE",
+        "timezone": "Europe/Berlin"
+    }))
+    .unwrap();
+
+    assert_eq!(input.locale.as_deref(), Some("de-DE"));
+    assert_eq!(input.timezone.as_deref(), Some("Europe/Berlin"));
+
+    let schema = BrowserContextCreateTool::new().input_schema();
+    assert!(schema["properties"]["locale"].is_object());
+    assert!(schema["properties"]["timezone"].is_object());
+}
+
+#[test]
+fn test_input_parsing_with_viewport() {
+    let input: BrowserContextCreateInput = serde_json::from_value(json!({
+        "name": "mobile",
+        "viewport": { "width": 390, "height": 844 }
+    }))
+    .unwrap();
+
+    let viewport = input.viewport.unwrap();
+    assert_eq!((viewport.width, viewport.height), (390, 844));
+
+    // Both dimensions are required and must be non-negative
+    assert!(
+        serde_json::from_value::<BrowserContextCreateInput>(json!({
+            "name": "mobile",
+            "viewport": { "width": 390 }
+        }))
+        .is_err()
+    );
+    assert!(
+        serde_json::from_value::<BrowserContextCreateInput>(json!({
+            "name": "mobile",
+            "viewport": { "width": -1, "height": 844 }
+        }))
+        .is_err()
+    );
+
+    let schema = BrowserContextCreateTool::new().input_schema();
+    assert_eq!(schema["properties"]["viewport"]["type"], "object");
+}
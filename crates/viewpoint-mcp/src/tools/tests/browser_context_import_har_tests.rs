@@ -0,0 +1,113 @@
+//! Tests for `browser_context_import_har` tool
+
+use crate::tools::Tool;
+use crate::tools::browser_context_import_har::{
+    BrowserContextImportHarInput, BrowserContextImportHarTool, parse_har,
+};
+use serde_json::json;
+
+fn har(entries: &serde_json::Value) -> String {
+    json!({ "log": { "version": "1.2", "entries": entries } }).to_string()
+}
+
+fn entry(url: &str, status: u16, text: &str) -> serde_json::Value {
+    json!({
+        "request": { "method": "GET", "url": url, "headers": [] },
+        "response": {
+            "status": status,
+            "statusText": "OK",
+            "headers": [
+                { "name": "Content-Type", "value": "text/plain" },
+                { "name": "Content-Encoding", "value": "gzip" }
+            ],
+            "content": { "size": text.len(), "mimeType": "text/plain", "text": text }
+        }
+    })
+}
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserContextImportHarTool::new();
+
+    assert_eq!(tool.name(), "browser_context_import_har");
+    assert!(!tool.description().is_empty());
+    assert_eq!(tool.categories(), vec!["context"]);
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], json!(["path"]));
+    assert!(schema["properties"]["urlFilter"].is_object());
+}
+
+#[test]
+fn test_input_parsing() {
+    let input: BrowserContextImportHarInput = serde_json::from_value(json!({
+        "path": "/tmp/recording.har",
+        "urlFilter": "/api/"
+    }))
+    .unwrap();
+
+    assert_eq!(input.path, "/tmp/recording.har");
+    assert_eq!(input.url_filter.as_deref(), Some("/api/"));
+
+    let input: BrowserContextImportHarInput =
+        serde_json::from_value(json!({ "path": "a.har", "url_filter": "x" })).unwrap();
+    assert_eq!(input.url_filter.as_deref(), Some("x"));
+}
+
+#[test]
+fn test_parse_har_routes() {
+    let contents = har(&json!([
+        entry("https://example.com/", 200, "<h1>Home</h1>"),
+        entry("https://example.com/api/items", 201, "[1,2]"),
+    ]));
+
+    let routes = parse_har(&contents, None).unwrap();
+
+    assert_eq!(routes.len(), 2);
+    assert_eq!(routes[1].url, "https://example.com/api/items");
+    assert_eq!(routes[1].status, 201);
+    assert_eq!(routes[1].body, b"[1,2]");
+    // Content-Encoding no longer applies to the decoded body
+    assert_eq!(
+        routes[1].headers,
+        vec![("Content-Type".to_string(), "text/plain".to_string())]
+    );
+}
+
+#[test]
+fn test_parse_har_first_entry_wins_and_filters() {
+    let contents = har(&json!([
+        entry("https://example.com/api/items", 200, "first"),
+        entry("https://example.com/api/items", 200, "second"),
+        entry("https://example.com/style.css", 200, "body {}"),
+        entry("https://example.com/api/aborted", 0, ""),
+    ]));
+
+    let routes = parse_har(&contents, Some("/api/")).unwrap();
+
+    assert_eq!(routes.len(), 1);
+    assert_eq!(routes[0].body, b"first");
+}
+
+#[test]
+fn test_parse_har_base64_body() {
+    let contents = har(&json!([{
+        "request": { "url": "https://example.com/logo.bin" },
+        "response": {
+            "status": 200,
+            "content": { "text": "AAEC", "encoding": "base64" }
+        }
+    }]));
+
+    let routes = parse_har(&contents, None).unwrap();
+
+    assert_eq!(routes[0].body, vec![0, 1, 2]);
+    assert!(routes[0].headers.is_empty());
+}
+
+#[test]
+fn test_parse_har_invalid() {
+    assert!(parse_har("not json", None).is_err());
+    assert!(parse_har(r#"{"entries": []}"#, None).is_err());
+}
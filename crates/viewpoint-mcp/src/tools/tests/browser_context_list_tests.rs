@@ -32,3 +32,17 @@ fn test_input_parsing_with_extra_fields() {
     }));
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_input_parsing_verbose() {
+    let input: BrowserContextListInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.verbose);
+
+    let input: BrowserContextListInput =
+        serde_json::from_value(json!({ "verbose": true })).unwrap();
+    assert!(input.verbose);
+
+    let schema = BrowserContextListTool::new().input_schema();
+    assert_eq!(schema["properties"]["verbose"]["type"], "boolean");
+    assert_eq!(schema["properties"]["verbose"]["default"], false);
+}
@@ -2,7 +2,7 @@
 
 use crate::tools::Tool;
 use crate::tools::browser_context_save_storage::{
-    BrowserContextSaveStorageInput, BrowserContextSaveStorageTool,
+    BrowserContextSaveStorageInput, BrowserContextSaveStorageTool, filter_storage_state,
 };
 use serde_json::json;
 
@@ -39,6 +39,9 @@ fn test_input_parsing_minimal() {
 
     assert!(input.name.is_none());
     assert_eq!(input.path, "/tmp/storage.json");
+    assert!(input.domains.is_none());
+    assert!(input.include_cookies);
+    assert!(input.include_local_storage);
 }
 
 #[test]
@@ -64,3 +67,88 @@ fn test_input_parsing_default_context() {
     assert_eq!(input.name, Some("default".to_string()));
     assert_eq!(input.path, "./storage.json");
 }
+
+fn sample_state() -> serde_json::Value {
+    json!({
+        "cookies": [
+            { "name": "session", "value": "1", "domain": ".example.com", "path": "/" },
+            { "name": "pref", "value": "2", "domain": "app.example.com", "path": "/" },
+            { "name": "track", "value": "3", "domain": "tracker.net", "path": "/" },
+            { "name": "fake", "value": "4", "domain": "notexample.com", "path": "/" }
+        ],
+        "origins": [
+            { "origin": "https://example.com", "localStorage": [{ "name": "k", "value": "v" }] }
+        ]
+    })
+}
+
+fn parse_input(args: serde_json::Value) -> BrowserContextSaveStorageInput {
+    serde_json::from_value(args).unwrap()
+}
+
+#[test]
+fn test_input_parsing_with_filters() {
+    let input = parse_input(json!({
+        "path": "/tmp/storage.json",
+        "domains": ["example.com"],
+        "includeCookies": true,
+        "includeLocalStorage": false
+    }));
+
+    assert_eq!(input.domains, Some(vec!["example.com".to_string()]));
+    assert!(input.include_cookies);
+    assert!(!input.include_local_storage);
+
+    let schema = BrowserContextSaveStorageTool::new().input_schema();
+    assert_eq!(schema["properties"]["includeCookies"]["default"], true);
+    assert_eq!(schema["properties"]["includeLocalStorage"]["default"], true);
+    assert_eq!(schema["properties"]["domains"]["type"], "array");
+}
+
+#[test]
+fn test_filter_storage_state_keeps_everything_by_default() {
+    let state = filter_storage_state(sample_state(), &parse_input(json!({ "path": "s.json" })));
+
+    assert_eq!(state["cookies"].as_array().unwrap().len(), 4);
+    assert_eq!(state["origins"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        state["exported"],
+        json!({ "cookies": true, "localStorage": true, "domains": null })
+    );
+}
+
+#[test]
+fn test_filter_storage_state_by_domain() {
+    let state = filter_storage_state(
+        sample_state(),
+        &parse_input(json!({ "path": "s.json", "domains": ["example.com"] })),
+    );
+
+    let names: Vec<_> = state["cookies"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["session", "pref"]);
+    assert_eq!(state["origins"].as_array().unwrap().len(), 1);
+    assert_eq!(state["exported"]["domains"], json!(["example.com"]));
+}
+
+#[test]
+fn test_filter_storage_state_excludes_sections() {
+    let state = filter_storage_state(
+        sample_state(),
+        &parse_input(json!({ "path": "s.json", "includeCookies": false })),
+    );
+    assert_eq!(state["cookies"], json!([]));
+    assert_eq!(state["origins"].as_array().unwrap().len(), 1);
+
+    let state = filter_storage_state(
+        sample_state(),
+        &parse_input(json!({ "path": "s.json", "includeLocalStorage": false })),
+    );
+    assert_eq!(state["cookies"].as_array().unwrap().len(), 4);
+    assert_eq!(state["origins"], json!([]));
+    assert_eq!(state["exported"]["localStorage"], false);
+}
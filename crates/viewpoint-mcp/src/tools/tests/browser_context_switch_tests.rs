@@ -13,12 +13,10 @@ fn test_tool_metadata() {
 
     let schema = tool.input_schema();
     assert_eq!(schema["type"], "object");
-    assert!(
-        schema["required"]
-            .as_array()
-            .unwrap()
-            .contains(&json!("name"))
-    );
+    // Either name or index may be given, so neither is required
+    assert!(schema.get("required").is_none());
+    assert_eq!(schema["properties"]["name"]["type"], "string");
+    assert_eq!(schema["properties"]["index"]["type"], "integer");
 }
 
 #[test]
@@ -28,7 +26,8 @@ fn test_input_parsing() {
     }))
     .unwrap();
 
-    assert_eq!(input.name, "my-context");
+    assert_eq!(input.name.as_deref(), Some("my-context"));
+    assert!(input.index.is_none());
 }
 
 #[test]
@@ -38,5 +37,16 @@ fn test_input_parsing_default_context() {
     }))
     .unwrap();
 
-    assert_eq!(input.name, "default");
+    assert_eq!(input.name.as_deref(), Some("default"));
+}
+
+#[test]
+fn test_input_parsing_index() {
+    let input: BrowserContextSwitchInput = serde_json::from_value(json!({
+        "index": 2
+    }))
+    .unwrap();
+
+    assert!(input.name.is_none());
+    assert_eq!(input.index, Some(2));
 }
@@ -30,4 +30,22 @@ fn test_input_parsing() {
 
     assert_eq!(input.start_ref, "e1a2b3c");
     assert_eq!(input.end_ref, "e4d5e6f");
+    assert!(!input.use_drag_drop);
+}
+
+#[test]
+fn test_input_parsing_use_drag_drop() {
+    let input: BrowserDragInput = serde_json::from_value(json!({
+        "startRef": "e1",
+        "startElement": "Item A",
+        "endRef": "e2",
+        "endElement": "Item B",
+        "useDragDrop": true
+    }))
+    .unwrap();
+
+    assert!(input.use_drag_drop);
+
+    let schema = BrowserDragTool::new().input_schema();
+    assert_eq!(schema["properties"]["useDragDrop"]["type"], "boolean");
 }
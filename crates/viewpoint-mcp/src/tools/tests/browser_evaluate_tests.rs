@@ -1,7 +1,11 @@
 //! Tests for `browser_evaluate` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_evaluate::{BrowserEvaluateInput, BrowserEvaluateTool};
+use crate::tools::browser_evaluate::{
+    BrowserEvaluateInput, BrowserEvaluateTool, JQUERY_INTEGRITY, JQUERY_SOURCE, JQUERY_URL,
+    chunk_result, truncate_result,
+};
+use base64::engine::{Engine as _, general_purpose::STANDARD};
 use serde_json::json;
 
 #[test]
@@ -56,3 +60,197 @@ fn test_input_parsing_complex_function() {
 
     assert!(input.function.contains("querySelectorAll"));
 }
+
+#[test]
+fn test_input_parsing_max_result_length() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => JSON.stringify(window)",
+        "maxResultLength": 100
+    }))
+    .unwrap();
+
+    assert_eq!(input.max_result_length, Some(100));
+    assert!(!input.isolated_world);
+}
+
+#[test]
+fn test_input_parsing_isolated_world() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => document.title",
+        "isolatedWorld": true
+    }))
+    .unwrap();
+
+    assert!(input.isolated_world);
+}
+
+#[test]
+fn test_input_parsing_frame() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => window.location.href",
+        "frame": "iframe#payment"
+    }))
+    .unwrap();
+
+    assert_eq!(input.frame.as_deref(), Some("iframe#payment"));
+    assert!(input.element_ref.is_none());
+}
+
+#[test]
+fn test_schema_has_frame() {
+    let schema = BrowserEvaluateTool::new().input_schema();
+    assert_eq!(schema["properties"]["frame"]["type"], "string");
+}
+
+#[test]
+fn test_input_parsing_all_frames() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => document.title",
+        "allFrames": true
+    }))
+    .unwrap();
+
+    assert!(input.all_frames);
+
+    let input: BrowserEvaluateInput =
+        serde_json::from_value(json!({ "function": "() => 1" })).unwrap();
+    assert!(!input.all_frames);
+
+    let schema = BrowserEvaluateTool::new().input_schema();
+    assert_eq!(schema["properties"]["allFrames"]["default"], false);
+}
+
+#[tokio::test]
+async fn test_all_frames_with_frame_is_rejected() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = BrowserEvaluateTool::new()
+        .execute(
+            &json!({ "function": "() => 1", "allFrames": true, "frame": "#myframe" }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
+#[test]
+fn test_truncate_result_within_limit() {
+    assert_eq!(truncate_result("short".to_string(), 5), "short");
+}
+
+#[test]
+fn test_truncate_result_over_limit() {
+    assert_eq!(
+        truncate_result("abcdefghij".to_string(), 4),
+        "abcd...[truncated, 10 chars total]"
+    );
+}
+
+#[test]
+fn test_truncate_result_counts_chars_not_bytes() {
+    assert_eq!(
+        truncate_result("héllo wörld".to_string(), 2),
+        "hé...[truncated, 11 chars total]"
+    );
+}
+
+#[test]
+fn test_chunk_result_within_limit() {
+    assert_eq!(chunk_result("short", 5), vec!["short"]);
+    assert_eq!(chunk_result("no limit", 0), vec!["no limit"]);
+}
+
+#[test]
+fn test_chunk_result_over_limit() {
+    assert_eq!(chunk_result("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+}
+
+#[test]
+fn test_chunk_result_counts_chars_not_bytes() {
+    let chunks = chunk_result("héllo wörld", 5);
+    assert_eq!(chunks, vec!["héllo", " wörl", "d"]);
+    assert_eq!(chunks.concat(), "héllo wörld");
+}
+
+#[test]
+fn test_input_await_promise_defaults_to_true() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => 1"
+    }))
+    .unwrap();
+
+    assert!(input.await_promise);
+}
+
+#[test]
+fn test_input_parsing_await_promise_false() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "async () => { await new Promise(() => {}); }",
+        "awaitPromise": false
+    }))
+    .unwrap();
+
+    assert!(!input.await_promise);
+}
+
+#[test]
+fn test_schema_has_await_promise() {
+    let schema = BrowserEvaluateTool::new().input_schema();
+
+    assert_eq!(schema["properties"]["awaitPromise"]["type"], "boolean");
+    assert_eq!(schema["properties"]["awaitPromise"]["default"], true);
+}
+
+#[test]
+fn test_input_parsing_inject_jquery() {
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => $('h1').text()"
+    }))
+    .unwrap();
+    assert!(!input.inject_jquery);
+
+    let input: BrowserEvaluateInput = serde_json::from_value(json!({
+        "function": "() => $('h1').text()",
+        "injectJQuery": true
+    }))
+    .unwrap();
+    assert!(input.inject_jquery);
+
+    let schema = BrowserEvaluateTool::new().input_schema();
+    assert_eq!(schema["properties"]["injectJQuery"]["default"], false);
+}
+
+#[test]
+#[ignore = "the bundled jquery-3.6.1.min.js is a distribution rebuild; replace it with the file from JQUERY_URL"]
+fn test_bundled_jquery_matches_upstream_release() {
+    let digest = ring::digest::digest(&ring::digest::SHA256, JQUERY_SOURCE.as_bytes());
+    let integrity = format!("sha256-{}", STANDARD.encode(digest.as_ref()));
+
+    assert_eq!(
+        integrity, JQUERY_INTEGRITY,
+        "bundled copy differs from {JQUERY_URL}"
+    );
+}
+
+#[tokio::test]
+async fn test_inject_jquery_with_isolated_world_is_rejected() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = BrowserEvaluateTool::new()
+        .execute(
+            &json!({ "function": "() => $('h1').text()", "injectJQuery": true, "isolatedWorld": true }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
@@ -1,7 +1,9 @@
 //! Tests for `browser_file_upload` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_file_upload::{BrowserFileUploadInput, BrowserFileUploadTool};
+use crate::tools::browser_file_upload::{
+    BrowserFileUploadInput, BrowserFileUploadTool, file_matches_accept,
+};
 use serde_json::json;
 
 #[test]
@@ -56,3 +58,48 @@ fn test_single_file() {
     assert_eq!(input.paths.len(), 1);
     assert_eq!(input.paths[0], "/home/user/documents/report.pdf");
 }
+
+#[test]
+fn test_input_parsing_files_alias() {
+    let input: BrowserFileUploadInput = serde_json::from_value(json!({
+        "files": ["/tmp/a.png", "/tmp/b.png"]
+    }))
+    .unwrap();
+
+    assert_eq!(input.paths, vec!["/tmp/a.png", "/tmp/b.png"]);
+    assert!(input.accept_check);
+}
+
+#[test]
+fn test_input_parsing_accept_check_disabled() {
+    let input: BrowserFileUploadInput = serde_json::from_value(json!({
+        "paths": ["/tmp/a.exe"],
+        "acceptCheck": false
+    }))
+    .unwrap();
+
+    assert!(!input.accept_check);
+}
+
+#[test]
+fn test_accept_extension_tokens() {
+    assert!(file_matches_accept(".pdf,.docx", "/tmp/report.PDF"));
+    assert!(file_matches_accept(".pdf, .docx", "/tmp/letter.docx"));
+    assert!(!file_matches_accept(".pdf,.docx", "/tmp/photo.png"));
+}
+
+#[test]
+fn test_accept_mime_tokens() {
+    assert!(file_matches_accept("image/png", "/tmp/photo.png"));
+    assert!(!file_matches_accept("image/png", "/tmp/photo.jpg"));
+    assert!(file_matches_accept("image/*", "/tmp/photo.jpg"));
+    assert!(!file_matches_accept("image/*", "/tmp/notes.txt"));
+    // Unknown extensions never match MIME tokens
+    assert!(!file_matches_accept("image/*", "/tmp/blob.xyz"));
+}
+
+#[test]
+fn test_accept_empty_allows_all() {
+    assert!(file_matches_accept("", "/tmp/anything.bin"));
+    assert!(file_matches_accept(" , ", "/tmp/anything.bin"));
+}
@@ -40,6 +40,41 @@ fn test_input_parsing() {
     assert_eq!(input.fields[0].field_type, FieldType::Textbox);
     assert_eq!(input.fields[0].element_ref, "c0p0f0e1");
     assert_eq!(input.fields[0].value, "user@example.com");
+    assert!(!input.rollback_on_failure);
+    assert!(input.clear_first);
+}
+
+#[test]
+fn test_input_parsing_clear_first() {
+    let input: BrowserFillFormInput = serde_json::from_value(json!({
+        "fields": [
+            { "name": "Notes", "type": "textbox", "ref": "c0p0f0e1", "value": " more" }
+        ],
+        "clearFirst": false
+    }))
+    .unwrap();
+
+    assert!(!input.clear_first);
+
+    let schema = BrowserFillFormTool::new().input_schema();
+    assert_eq!(schema["properties"]["clearFirst"]["type"], "boolean");
+    assert_eq!(schema["properties"]["clearFirst"]["default"], true);
+}
+
+#[test]
+fn test_input_parsing_rollback_on_failure() {
+    let input: BrowserFillFormInput = serde_json::from_value(json!({
+        "fields": [
+            { "name": "Email", "type": "textbox", "ref": "c0p0f0e1", "value": "a@b.c" }
+        ],
+        "rollbackOnFailure": true
+    }))
+    .unwrap();
+
+    assert!(input.rollback_on_failure);
+
+    let schema = BrowserFillFormTool::new().input_schema();
+    assert_eq!(schema["properties"]["rollbackOnFailure"]["type"], "boolean");
 }
 
 #[test]
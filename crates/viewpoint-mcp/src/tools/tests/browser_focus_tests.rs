@@ -0,0 +1,65 @@
+//! Tests for `browser_focus` tool
+
+use crate::tools::Tool;
+use crate::tools::browser_focus::{BrowserFocusInput, BrowserFocusTool, FocusDirection};
+use serde_json::json;
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserFocusTool::new();
+
+    assert_eq!(tool.name(), "browser_focus");
+    assert!(!tool.description().is_empty());
+    assert_eq!(tool.categories(), vec!["interaction"]);
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], json!(["ref", "element"]));
+    assert_eq!(
+        schema["properties"]["direction"]["enum"],
+        json!(["forward", "backward"])
+    );
+}
+
+#[test]
+fn test_input_parsing() {
+    let input: BrowserFocusInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Search box"
+    }))
+    .unwrap();
+
+    assert_eq!(input.element_ref, "c0p0f0e1");
+    assert_eq!(input.element, "Search box");
+    assert!(input.direction.is_none());
+}
+
+#[test]
+fn test_input_parsing_direction() {
+    let input: BrowserFocusInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Search box",
+        "direction": "backward"
+    }))
+    .unwrap();
+    assert_eq!(input.direction, Some(FocusDirection::Backward));
+
+    let result = serde_json::from_value::<BrowserFocusInput>(json!({
+        "ref": "c0p0f0e1",
+        "element": "Search box",
+        "direction": "sideways"
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_direction_keys() {
+    assert_eq!(FocusDirection::Forward.key(), "Tab");
+    assert_eq!(FocusDirection::Backward.key(), "Shift+Tab");
+}
+
+#[test]
+fn test_missing_ref() {
+    let result = serde_json::from_value::<BrowserFocusInput>(json!({ "element": "Search box" }));
+    assert!(result.is_err());
+}
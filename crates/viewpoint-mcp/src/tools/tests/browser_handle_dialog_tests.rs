@@ -30,6 +30,7 @@ fn test_input_parsing_accept() {
 
     assert!(input.accept);
     assert!(input.prompt_text.is_none());
+    assert!(!input.preregister);
 }
 
 #[test]
@@ -67,3 +68,24 @@ fn test_input_parsing_dismiss_with_prompt_text() {
     assert!(!input.accept);
     assert_eq!(input.prompt_text, Some("Ignored text".to_string()));
 }
+
+#[test]
+fn test_input_parsing_preregister() {
+    let input: BrowserHandleDialogInput = serde_json::from_value(json!({
+        "accept": true,
+        "preregister": true
+    }))
+    .unwrap();
+
+    assert!(input.accept);
+    assert!(input.preregister);
+}
+
+#[test]
+fn test_schema_has_preregister() {
+    let tool = BrowserHandleDialogTool::new();
+    let schema = tool.input_schema();
+
+    assert_eq!(schema["properties"]["preregister"]["type"], "boolean");
+    assert_eq!(schema["properties"]["preregister"]["default"], false);
+}
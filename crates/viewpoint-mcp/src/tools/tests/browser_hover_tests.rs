@@ -1,7 +1,10 @@
 //! Tests for `browser_hover` tool
 
+use crate::snapshot::SnapshotElement;
 use crate::tools::Tool;
-use crate::tools::browser_hover::{BrowserHoverInput, BrowserHoverTool};
+use crate::tools::browser_hover::{
+    BrowserHoverInput, BrowserHoverTool, HoverPosition, tooltip_texts,
+};
 use serde_json::json;
 
 #[test]
@@ -31,4 +34,89 @@ fn test_input_parsing() {
 
     assert_eq!(input.element_ref, "e1a2b3c");
     assert_eq!(input.element, "Menu item");
+    assert!(input.position.is_none());
+    assert!(input.duration_ms.is_none());
+    assert!(!input.capture_tooltip);
+}
+
+#[test]
+fn test_input_parsing_capture_tooltip() {
+    let input: BrowserHoverInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Help icon",
+        "captureTooltip": true,
+        "durationMs": 800
+    }))
+    .unwrap();
+
+    assert!(input.capture_tooltip);
+    assert_eq!(input.duration_ms, Some(800));
+
+    let schema = BrowserHoverTool::new().input_schema();
+    assert_eq!(schema["properties"]["captureTooltip"]["default"], false);
+    assert_eq!(schema["properties"]["durationMs"]["type"], "integer");
+}
+
+#[test]
+fn test_tooltip_texts() {
+    let root = SnapshotElement::new("document")
+        .with_child(SnapshotElement::new("button").with_name("Help"))
+        .with_child(SnapshotElement::new("tooltip").with_name("Opens the help center"))
+        .with_child(
+            SnapshotElement::new("group").with_child(
+                SnapshotElement::new("tooltip")
+                    .with_child(SnapshotElement::new("text").with_name("Ctrl"))
+                    .with_child(SnapshotElement::new("text").with_name("+ K")),
+            ),
+        );
+
+    assert_eq!(
+        tooltip_texts(&root),
+        vec!["Opens the help center", "Ctrl + K"]
+    );
+    assert!(tooltip_texts(&SnapshotElement::new("document")).is_empty());
+}
+
+#[test]
+fn test_input_parsing_with_position() {
+    let input: BrowserHoverInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Map",
+        "position": { "xOffset": 12.5, "yOffset": 40 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        input.position,
+        Some(HoverPosition {
+            x_offset: 12.5,
+            y_offset: 40.0
+        })
+    );
+
+    // snake_case keys are accepted too
+    let input: BrowserHoverInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Map",
+        "position": { "x_offset": 1, "y_offset": 2 }
+    }))
+    .unwrap();
+    assert_eq!(input.position.map(|p| p.y_offset), Some(2.0));
+}
+
+#[test]
+fn test_position_validate() {
+    let position = HoverPosition {
+        x_offset: 10.0,
+        y_offset: 20.0,
+    };
+    assert!(position.validate(100.0, 50.0).is_ok());
+    assert!(position.validate(5.0, 50.0).is_err());
+    assert!(position.validate(100.0, 10.0).is_err());
+
+    let negative = HoverPosition {
+        x_offset: -1.0,
+        y_offset: 0.0,
+    };
+    assert!(negative.validate(100.0, 100.0).is_err());
 }
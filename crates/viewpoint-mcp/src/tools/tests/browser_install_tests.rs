@@ -33,3 +33,9 @@ fn test_input_parsing_with_extra_fields() {
     .unwrap();
     let _ = input;
 }
+
+#[test]
+fn test_does_not_require_browser() {
+    // The server must not launch a browser before this tool runs
+    assert!(!BrowserInstallTool::new().requires_browser());
+}
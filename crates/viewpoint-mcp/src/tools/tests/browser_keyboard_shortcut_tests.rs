@@ -0,0 +1,94 @@
+//! Tests for `browser_keyboard_shortcut` tool
+
+use crate::tools::Tool;
+use crate::tools::browser_keyboard_shortcut::{
+    BrowserKeyboardShortcutInput, BrowserKeyboardShortcutTool, KeyShortcut, ShortcutModifier,
+};
+use serde_json::json;
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserKeyboardShortcutTool::new();
+
+    assert_eq!(tool.name(), "browser_keyboard_shortcut");
+    assert!(!tool.description().is_empty());
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["type"], "object");
+    assert!(
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("shortcut"))
+    );
+}
+
+#[test]
+fn test_input_parsing() {
+    let input: BrowserKeyboardShortcutInput = serde_json::from_value(json!({
+        "shortcut": "Ctrl+Z"
+    }))
+    .unwrap();
+
+    assert_eq!(input.shortcut, "Ctrl+Z");
+}
+
+#[test]
+fn test_parse_ctrl_z() {
+    let shortcut = KeyShortcut::parse("Ctrl+Z").unwrap();
+
+    assert_eq!(shortcut.modifiers, vec![ShortcutModifier::Control]);
+    assert_eq!(shortcut.key, "z");
+    assert_eq!(shortcut.to_key_combination(), "Control+z");
+}
+
+#[test]
+fn test_parse_cmd_maps_to_meta() {
+    let shortcut = KeyShortcut::parse("Cmd+Shift+4").unwrap();
+
+    assert_eq!(
+        shortcut.modifiers,
+        vec![ShortcutModifier::Shift, ShortcutModifier::Meta]
+    );
+    assert_eq!(shortcut.to_key_combination(), "Shift+Meta+4");
+}
+
+#[test]
+fn test_parse_modifiers_canonical_order_and_case() {
+    let shortcut = KeyShortcut::parse("shift+CTRL+i").unwrap();
+
+    assert_eq!(shortcut.to_key_combination(), "Control+Shift+i");
+}
+
+#[test]
+fn test_parse_function_key() {
+    assert_eq!(KeyShortcut::parse("F5").unwrap().to_key_combination(), "F5");
+    assert_eq!(
+        KeyShortcut::parse("Alt+f4").unwrap().to_key_combination(),
+        "Alt+F4"
+    );
+    assert!(KeyShortcut::parse("F25").is_err());
+}
+
+#[test]
+fn test_parse_named_key_aliases() {
+    assert_eq!(KeyShortcut::parse("Esc").unwrap().key, "Escape");
+    assert_eq!(KeyShortcut::parse("Ctrl+PgDn").unwrap().key, "PageDown");
+    assert_eq!(KeyShortcut::parse("Alt+Left").unwrap().key, "ArrowLeft");
+}
+
+#[test]
+fn test_parse_plus_key() {
+    let shortcut = KeyShortcut::parse("Ctrl++").unwrap();
+
+    assert_eq!(shortcut.modifiers, vec![ShortcutModifier::Control]);
+    assert_eq!(shortcut.key, "+");
+}
+
+#[test]
+fn test_parse_invalid_shortcuts() {
+    assert!(KeyShortcut::parse("").is_err());
+    assert!(KeyShortcut::parse("Ctrl+Shift").is_err());
+    assert!(KeyShortcut::parse("Ctrl+A+B").is_err());
+    assert!(KeyShortcut::parse("Ctrl+Bogus").is_err());
+}
@@ -1,7 +1,12 @@
 //! Tests for `browser_navigate_back` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_navigate_back::BrowserNavigateBackTool;
+use crate::tools::browser_navigate::BrowserNavigateTool;
+use crate::tools::browser_navigate_back::{
+    BrowserNavigateBackTool, HistoryStepsInput, insufficient_history_message,
+};
+use crate::tools::navigation_wait::NavigationWaitCondition;
+use serde_json::json;
 
 #[test]
 fn test_tool_metadata() {
@@ -13,3 +18,69 @@ fn test_tool_metadata() {
     let schema = tool.input_schema();
     assert_eq!(schema["type"], "object");
 }
+
+#[test]
+fn test_input_steps_default() {
+    let input: HistoryStepsInput = serde_json::from_value(json!({})).unwrap();
+    assert_eq!(input.steps, 1);
+}
+
+#[test]
+fn test_input_steps() {
+    let input: HistoryStepsInput = serde_json::from_value(json!({ "steps": 3 })).unwrap();
+    assert_eq!(input.steps, 3);
+}
+
+#[test]
+fn test_insufficient_history_message() {
+    assert_eq!(
+        insufficient_history_message("back", 3, 1),
+        "Cannot go back 3 steps, only 1 entry in history"
+    );
+    assert_eq!(
+        insufficient_history_message("forward", 4, 2),
+        "Cannot go forward 4 steps, only 2 entries in history"
+    );
+}
+
+#[test]
+fn test_input_wait_until() {
+    let input: HistoryStepsInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.wait_until.is_none());
+
+    let input: HistoryStepsInput =
+        serde_json::from_value(json!({ "waitUntil": "networkidle" })).unwrap();
+    assert_eq!(input.wait_until, Some(NavigationWaitCondition::NetworkIdle));
+
+    assert!(serde_json::from_value::<HistoryStepsInput>(json!({ "waitUntil": "never" })).is_err());
+}
+
+#[test]
+fn test_schema_wait_until_matches_navigate() {
+    let back = BrowserNavigateBackTool::new().input_schema();
+    let navigate = BrowserNavigateTool::new().input_schema();
+
+    assert_eq!(
+        back["properties"]["waitUntil"]["enum"],
+        navigate["properties"]["waitUntil"]["enum"]
+    );
+    assert_eq!(navigate["properties"]["waitUntil"]["default"], "load");
+    assert!(back["properties"]["waitUntil"].get("default").is_none());
+}
+
+#[test]
+fn test_wait_condition_wire_names() {
+    let input: HistoryStepsInput =
+        serde_json::from_value(json!({ "waitUntil": "networkidle0" })).unwrap();
+    let condition = input.wait_until.unwrap();
+
+    assert_eq!(condition.as_str(), "networkidle0");
+    assert_eq!(
+        format!("Waiting for {condition} state failed"),
+        "Waiting for networkidle0 state failed"
+    );
+    assert_eq!(
+        NavigationWaitCondition::DomContentLoaded.to_string(),
+        "domcontentloaded"
+    );
+}
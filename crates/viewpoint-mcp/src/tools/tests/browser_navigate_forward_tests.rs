@@ -0,0 +1,17 @@
+//! Tests for `browser_navigate_forward` tool
+
+use crate::tools::Tool;
+use crate::tools::browser_navigate_forward::BrowserNavigateForwardTool;
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserNavigateForwardTool::new();
+
+    assert_eq!(tool.name(), "browser_navigate_forward");
+    assert!(!tool.description().is_empty());
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["steps"]["default"], 1);
+    assert!(schema["properties"]["waitUntil"].get("default").is_none());
+}
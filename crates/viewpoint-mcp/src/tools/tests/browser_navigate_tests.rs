@@ -1,7 +1,11 @@
 //! Tests for `browser_navigate` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_navigate::{BrowserNavigateInput, BrowserNavigateTool};
+use crate::tools::browser_navigate::{
+    BrowserNavigateInput, BrowserNavigateTool, DEFAULT_RETRY_ON, NavigationResult, SecurityInfo,
+    is_transient_error,
+};
+use crate::tools::navigation_wait::NavigationWaitCondition;
 use serde_json::json;
 
 #[test]
@@ -29,4 +33,290 @@ fn test_input_parsing() {
     .unwrap();
 
     assert_eq!(input.url, "https://example.com");
+    assert_eq!(input.wait_until, NavigationWaitCondition::Load);
+    assert!(input.timeout_ms.is_none());
+}
+
+#[test]
+fn test_input_parsing_wait_until() {
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://example.com",
+        "waitUntil": "networkidle2",
+        "timeoutMs": 5000
+    }))
+    .unwrap();
+
+    assert_eq!(input.wait_until, NavigationWaitCondition::NetworkIdle2);
+    assert_eq!(input.timeout_ms, Some(5000));
+
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://example.com",
+        "waitUntil": "domcontentloaded"
+    }))
+    .unwrap();
+    assert_eq!(input.wait_until, NavigationWaitCondition::DomContentLoaded);
+}
+
+#[test]
+fn test_input_parsing_invalid_wait_until() {
+    let result: Result<BrowserNavigateInput, _> = serde_json::from_value(json!({
+        "url": "https://example.com",
+        "waitUntil": "never"
+    }));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_input_parsing_basic_auth() {
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://staging.example.com",
+        "basicAuth": { "username": "admin", "password": "s3cret" }
+    }))
+    .unwrap();
+
+    let auth = input.basic_auth.unwrap();
+    assert_eq!(auth.username, "admin");
+    assert_eq!(auth.password, "s3cret");
+    assert!(!auth.persist);
+
+    // The password must not leak through debug output
+    let debug = format!("{auth:?}");
+    assert!(!debug.contains("s3cret"), "Got: {debug}");
+
+    let result: Result<BrowserNavigateInput, _> = serde_json::from_value(json!({
+        "url": "https://staging.example.com",
+        "basicAuth": { "username": "admin" }
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_input_parsing_ignore_https_errors() {
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://staging.example.com"
+    }))
+    .unwrap();
+    assert!(!input.ignore_https_errors);
+
+    // The key uses the conventional `HTTPS` capitalization
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://staging.example.com",
+        "ignoreHTTPSErrors": true
+    }))
+    .unwrap();
+    assert!(input.ignore_https_errors);
+
+    let schema = BrowserNavigateTool::new().input_schema();
+    assert_eq!(schema["properties"]["ignoreHTTPSErrors"]["type"], "boolean");
+}
+
+#[test]
+fn test_navigation_result_without_redirect() {
+    let result = NavigationResult::new(
+        "https://example.com",
+        "https://example.com/".to_string(),
+        "Example Domain".to_string(),
+    );
+
+    assert!(result.redirected_from.is_none());
+
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "status": "navigated",
+            "url": "https://example.com/",
+            "title": "Example Domain"
+        })
+    );
+}
+
+#[test]
+fn test_navigation_result_ignores_url_normalization() {
+    let result = NavigationResult::new(
+        "HTTPS://Example.com:443",
+        "https://example.com/".to_string(),
+        String::new(),
+    );
+
+    assert!(result.redirected_from.is_none());
+}
+
+#[test]
+fn test_navigation_result_trailing_slash_path_is_redirect() {
+    let result = NavigationResult::new(
+        "https://example.com/docs",
+        "https://example.com/docs/".to_string(),
+        String::new(),
+    );
+
+    assert_eq!(
+        result.redirected_from.as_deref(),
+        Some("https://example.com/docs")
+    );
+}
+
+#[test]
+fn test_navigation_result_with_redirect() {
+    let result = NavigationResult::new(
+        "http://example.com/login",
+        "https://example.com/dashboard".to_string(),
+        String::new(),
+    );
+
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(value["url"], "https://example.com/dashboard");
+    assert_eq!(value["redirectedFrom"], "http://example.com/login");
+    assert_eq!(value["title"], "");
+}
+
+#[test]
+fn test_input_parsing_include_security_info() {
+    let input: BrowserNavigateInput =
+        serde_json::from_value(json!({ "url": "https://example.com" })).unwrap();
+    assert!(!input.include_security_info);
+
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://example.com",
+        "includeSecurityInfo": true
+    }))
+    .unwrap();
+    assert!(input.include_security_info);
+
+    let schema = BrowserNavigateTool::new().input_schema();
+    assert_eq!(
+        schema["properties"]["includeSecurityInfo"]["default"],
+        false
+    );
+}
+
+#[test]
+fn test_navigation_result_with_security_info() {
+    let mut result = NavigationResult::new(
+        "https://example.com/",
+        "https://example.com/".to_string(),
+        "Example Domain".to_string(),
+    );
+    result.security = Some(SecurityInfo {
+        protocol: "https".to_string(),
+        cert_valid: Some(true),
+        cert_expiry: Some("2030-01-15T12:00:00+00:00".to_string()),
+    });
+
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        value["security"],
+        json!({
+            "protocol": "https",
+            "certValid": true,
+            "certExpiry": "2030-01-15T12:00:00+00:00"
+        })
+    );
+}
+
+#[test]
+fn test_security_info_requires_evidence() {
+    let future = 4_102_444_800.0; // 2100-01-01
+    let past = 946_684_800.0; // 2000-01-01
+
+    let plain = SecurityInfo::new("http://example.com/", None, true);
+    assert_eq!(plain.cert_valid, Some(false));
+    assert_eq!(plain.cert_expiry, None);
+
+    let checked = SecurityInfo::new("https://example.com/", Some(future), true);
+    assert_eq!(checked.cert_valid, Some(true));
+    assert_eq!(
+        checked.cert_expiry.as_deref(),
+        Some("2100-01-01T00:00:00+00:00")
+    );
+
+    // Without security details nothing is known about the certificate
+    let unknown = SecurityInfo::new("https://example.com/", None, true);
+    assert_eq!(unknown.cert_valid, None);
+    let value = serde_json::to_value(&unknown).unwrap();
+    assert_eq!(value, json!({ "protocol": "https" }));
+
+    // With errors ignored, an unexpired certificate proves nothing, an expired one does
+    let ignored = SecurityInfo::new("https://example.com/", Some(future), false);
+    assert_eq!(ignored.cert_valid, None);
+    assert!(ignored.cert_expiry.is_some());
+    let expired = SecurityInfo::new("https://example.com/", Some(past), false);
+    assert_eq!(expired.cert_valid, Some(false));
+}
+
+#[test]
+fn test_input_parsing_retries() {
+    let input: BrowserNavigateInput =
+        serde_json::from_value(json!({ "url": "https://example.com" })).unwrap();
+    assert_eq!(input.retries, None);
+    assert_eq!(input.retry_on, None);
+    assert_eq!(input.retry_delay_ms, None);
+
+    let input: BrowserNavigateInput = serde_json::from_value(json!({
+        "url": "https://example.com",
+        "retries": 2,
+        "retryOn": [502, 503],
+        "retryDelayMs": 250
+    }))
+    .unwrap();
+    assert_eq!(input.retries, Some(2));
+    assert_eq!(input.retry_on, Some(vec![502, 503]));
+    assert_eq!(input.retry_delay_ms, Some(250));
+
+    let schema = BrowserNavigateTool::new().input_schema();
+    assert_eq!(schema["properties"]["retries"]["maximum"], 3);
+    assert_eq!(
+        schema["properties"]["retryOn"]["default"],
+        json!(DEFAULT_RETRY_ON)
+    );
+    assert_eq!(schema["properties"]["retryDelayMs"]["default"], 1000);
+}
+
+#[tokio::test]
+async fn test_too_many_retries_rejected_before_launch() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+    let result = BrowserNavigateTool::new()
+        .execute(
+            &json!({ "url": "https://example.com", "retries": 4 }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
+#[test]
+fn test_is_transient_error() {
+    assert!(is_transient_error(
+        "net::ERR_CONNECTION_REFUSED at http://localhost:1/"
+    ));
+    assert!(is_transient_error("net::ERR_NAME_NOT_RESOLVED"));
+    assert!(is_transient_error("Navigation timeout of 30000ms exceeded"));
+    assert!(is_transient_error("Operation timed out"));
+
+    assert!(!is_transient_error("net::ERR_CERT_AUTHORITY_INVALID"));
+    assert!(!is_transient_error("Cannot navigate to invalid URL"));
+}
+
+#[test]
+fn test_navigation_result_attempts() {
+    let mut result = NavigationResult::new(
+        "https://example.com/",
+        "https://example.com/".to_string(),
+        String::new(),
+    );
+    assert!(
+        serde_json::to_value(&result)
+            .unwrap()
+            .get("attempts")
+            .is_none()
+    );
+
+    result.attempts = Some(2);
+    assert_eq!(serde_json::to_value(&result).unwrap()["attempts"], 2);
 }
@@ -1,11 +1,55 @@
 //! Tests for `browser_network_requests` tool
 
+use std::time::{Duration, Instant};
+
+use crate::browser::{NetworkBuffer, RequestActivity, RequestTiming, StoredResponse};
 use crate::tools::Tool;
 use crate::tools::browser_network_requests::{
-    BrowserNetworkRequestsInput, BrowserNetworkRequestsTool,
+    BrowserNetworkRequestsInput, BrowserNetworkRequestsTool, NetworkIdleTracker,
+    NetworkOutputFormat, attach_bodies, build_har, is_successful_static, max_idle_wait,
+    response_body, truncate_body,
 };
 use serde_json::json;
 
+fn timing() -> RequestTiming {
+    RequestTiming {
+        start_time: 1_700_000_000_000.0,
+        domain_lookup_start: -1.0,
+        domain_lookup_end: -1.0,
+        connect_start: -1.0,
+        secure_connection_start: -1.0,
+        connect_end: -1.0,
+        request_start: 0.0,
+        response_start: 30.0,
+        response_end: 42.5,
+    }
+}
+
+fn response(url: &str, body: &str) -> StoredResponse {
+    StoredResponse {
+        url: url.to_string(),
+        method: "POST".to_string(),
+        status: 201,
+        status_text: "Created".to_string(),
+        request_headers: vec![("accept".to_string(), "application/json".to_string())],
+        response_headers: vec![("content-type".to_string(), "application/json".to_string())],
+        request_body: None,
+        body: Some(body.to_string()),
+        body_base64: false,
+        body_size: body.len(),
+        timing: Some(timing()),
+        captured_at: 1_700_000_000_050.0,
+    }
+}
+
+fn binary_response(url: &str, base64_body: &str) -> StoredResponse {
+    StoredResponse {
+        body_base64: true,
+        response_headers: vec![("content-type".to_string(), "image/png".to_string())],
+        ..response(url, base64_body)
+    }
+}
+
 #[test]
 fn test_tool_metadata() {
     let tool = BrowserNetworkRequestsTool::new();
@@ -22,6 +66,230 @@ fn test_input_defaults() {
     let input: BrowserNetworkRequestsInput = serde_json::from_value(json!({})).unwrap();
 
     assert!(!input.include_static);
+    assert_eq!(input.format, NetworkOutputFormat::Text);
+    assert!(input.max_body_size.is_none());
+    assert!(!input.capture_body);
+    assert!(!input.wait_for_idle);
+    assert!(input.idle_timeout_ms.is_none());
+}
+
+#[test]
+fn test_input_wait_for_idle() {
+    let input: BrowserNetworkRequestsInput = serde_json::from_value(json!({
+        "waitForIdle": true,
+        "idleTimeoutMs": 250,
+        "includeStatic": true
+    }))
+    .unwrap();
+
+    assert!(input.wait_for_idle);
+    assert_eq!(input.idle_timeout_ms, Some(250));
+    assert!(input.include_static);
+}
+
+#[test]
+fn test_idle_tracker_requires_quiet_period() {
+    let start = Instant::now();
+    let mut tracker = NetworkIdleTracker::new(Duration::from_millis(500), start);
+
+    assert!(!tracker.observe(true, 0, 3, start));
+    assert!(!tracker.observe(true, 0, 3, start + Duration::from_millis(300)));
+    assert!(tracker.observe(true, 0, 3, start + Duration::from_millis(500)));
+}
+
+#[test]
+fn test_idle_tracker_resets_on_new_requests() {
+    let start = Instant::now();
+    let mut tracker = NetworkIdleTracker::new(Duration::from_millis(500), start);
+
+    assert!(!tracker.observe(true, 0, 1, start));
+    assert!(!tracker.observe(true, 0, 2, start + Duration::from_millis(400)));
+    assert!(!tracker.observe(true, 0, 2, start + Duration::from_millis(800)));
+    assert!(tracker.observe(true, 0, 2, start + Duration::from_millis(900)));
+}
+
+#[test]
+fn test_idle_tracker_waits_for_pending_requests() {
+    let start = Instant::now();
+    let mut tracker = NetworkIdleTracker::new(Duration::from_millis(500), start);
+
+    // A slow request keeps the network busy however long it takes
+    assert!(!tracker.observe(true, 1, 0, start));
+    assert!(!tracker.observe(true, 1, 0, start + Duration::from_secs(2)));
+    assert!(!tracker.observe(true, 0, 1, start + Duration::from_secs(3)));
+    assert!(tracker.observe(true, 0, 1, start + Duration::from_millis(3500)));
+}
+
+#[test]
+fn test_idle_tracker_waits_for_page_load() {
+    let start = Instant::now();
+    let mut tracker = NetworkIdleTracker::new(Duration::ZERO, start);
+
+    assert!(!tracker.observe(false, 0, 0, start));
+    assert!(!tracker.observe(false, 0, 0, start + Duration::from_secs(1)));
+    assert!(!tracker.observe(true, 0, 0, start + Duration::from_secs(1)));
+    assert!(tracker.observe(true, 0, 0, start + Duration::from_secs(1)));
+}
+
+#[test]
+fn test_max_idle_wait_scales_with_idle_timeout() {
+    assert_eq!(
+        max_idle_wait(Duration::from_millis(100)),
+        Duration::from_secs(5)
+    );
+    assert_eq!(
+        max_idle_wait(Duration::from_secs(1)),
+        Duration::from_secs(20)
+    );
+}
+
+#[test]
+fn test_request_activity_counts() {
+    let activity = RequestActivity::default();
+    activity.request_started();
+    activity.request_started();
+    activity.request_finished();
+    assert_eq!(activity.in_flight(), 1);
+    assert_eq!(activity.finished(), 1);
+
+    // Requests started before tracking never go below zero
+    activity.request_finished();
+    activity.request_finished();
+    assert_eq!(activity.in_flight(), 0);
+    assert_eq!(activity.finished(), 3);
+}
+
+#[test]
+fn test_input_har_format() {
+    let input: BrowserNetworkRequestsInput = serde_json::from_value(json!({
+        "format": "har",
+        "maxBodySize": 1024
+    }))
+    .unwrap();
+
+    assert_eq!(input.format, NetworkOutputFormat::Har);
+    assert_eq!(input.max_body_size, Some(1024));
+}
+
+#[test]
+fn test_input_invalid_format() {
+    let result: Result<BrowserNetworkRequestsInput, _> =
+        serde_json::from_value(json!({ "format": "xml" }));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_build_har_shape() {
+    let url = "https://example.com/api?q=rust&page=2";
+    let har = build_har(&[response(url, "{\"ok\":true}")], 1024);
+
+    assert_eq!(har["log"]["version"], "1.2");
+    assert_eq!(har["log"]["creator"]["name"], "viewpoint-mcp");
+
+    let entry = &har["log"]["entries"][0];
+    assert_eq!(entry["startedDateTime"], "2023-11-14T22:13:20.000Z");
+    assert_eq!(entry["time"], 42.5);
+    assert_eq!(entry["request"]["method"], "POST");
+    assert_eq!(entry["request"]["url"], url);
+    assert_eq!(entry["request"]["headers"][0]["name"], "accept");
+    assert_eq!(entry["request"]["queryString"][0]["name"], "q");
+    assert_eq!(entry["request"]["queryString"][1]["value"], "2");
+    assert_eq!(entry["response"]["status"], 201);
+    assert_eq!(entry["response"]["content"]["mimeType"], "application/json");
+    assert_eq!(entry["response"]["content"]["text"], "{\"ok\":true}");
+    assert_eq!(entry["timings"]["wait"], 30.0);
+    assert_eq!(entry["timings"]["receive"], 12.5);
+    assert_eq!(entry["timings"]["dns"], -1.0);
+}
+
+#[test]
+fn test_build_har_without_timing() {
+    let mut untimed = response("https://example.com/", "hello");
+    untimed.timing = None;
+
+    let har = build_har(&[untimed], 1024);
+
+    let entry = &har["log"]["entries"][0];
+    assert_eq!(entry["startedDateTime"], "2023-11-14T22:13:20.050Z");
+    assert_eq!(entry["time"], 0.0);
+    assert_eq!(entry["timings"]["blocked"], -1.0);
+    assert_eq!(entry["timings"]["wait"], 0.0);
+}
+
+#[test]
+fn test_build_har_truncates_body() {
+    let url = "https://example.com/big";
+    let har = build_har(&[response(url, "abcdefghij")], 4);
+
+    let content = &har["log"]["entries"][0]["response"]["content"];
+    assert_eq!(content["text"], "abcd");
+    assert_eq!(content["size"], 10);
+    assert!(content["comment"].as_str().unwrap().contains("truncated"));
+}
+
+#[test]
+fn test_build_har_keeps_timing_with_its_response() {
+    let url = "https://example.com/poll";
+    let first = response(url, "first");
+    let mut second = response(url, "second");
+    second.timing = Some(RequestTiming {
+        start_time: 1_700_000_001_000.0,
+        response_start: 5.0,
+        response_end: 6.0,
+        ..timing()
+    });
+
+    let har = build_har(&[first, second], 1024);
+
+    let entries = &har["log"]["entries"];
+    assert_eq!(entries[0]["response"]["content"]["text"], "first");
+    assert_eq!(entries[0]["timings"]["wait"], 30.0);
+    assert_eq!(entries[1]["response"]["content"]["text"], "second");
+    assert_eq!(entries[1]["timings"]["wait"], 5.0);
+    assert_eq!(entries[1]["startedDateTime"], "2023-11-14T22:13:21.000Z");
+}
+
+#[test]
+fn test_is_successful_static() {
+    let image = binary_response("https://example.com/logo.png", "AAEC");
+    assert!(is_successful_static(&image));
+
+    let missing = StoredResponse {
+        status: 404,
+        ..binary_response("https://example.com/gone.png", "")
+    };
+    assert!(!is_successful_static(&missing));
+
+    assert!(!is_successful_static(&response(
+        "https://example.com/api",
+        "{}"
+    )));
+}
+
+#[test]
+fn test_network_buffer_retain_since_drops_earlier_documents() {
+    let mut buffer = NetworkBuffer::new();
+    buffer.push(StoredResponse {
+        captured_at: 1_000.0,
+        ..response("https://example.com/old", "old")
+    });
+    buffer.push(StoredResponse {
+        captured_at: 2_000.0,
+        ..response("https://example.com/new", "new")
+    });
+
+    buffer.retain_since(1_500.0);
+
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.responses()[0].url, "https://example.com/new");
+}
+
+#[test]
+fn test_truncate_body_char_boundary() {
+    assert_eq!(truncate_body("héllo", 2), ("h", true));
+    assert_eq!(truncate_body("héllo", 3), ("hé", true));
+    assert_eq!(truncate_body("hi", 10), ("hi", false));
 }
 
 #[test]
@@ -33,3 +301,79 @@ fn test_input_include_static() {
 
     assert!(input.include_static);
 }
+
+#[test]
+fn test_input_capture_body() {
+    let input: BrowserNetworkRequestsInput = serde_json::from_value(json!({
+        "captureBody": true,
+        "maxBodySize": 16
+    }))
+    .unwrap();
+
+    assert!(input.capture_body);
+    assert_eq!(input.max_body_size, Some(16));
+
+    let schema = BrowserNetworkRequestsTool::new().input_schema();
+    assert_eq!(schema["properties"]["captureBody"]["type"], "boolean");
+}
+
+#[test]
+fn test_attach_bodies() {
+    let url = "https://example.com/api";
+    let mut posted = response(url, "{\"ok\":true}");
+    posted.request_body = Some("name=ada".to_string());
+    let mut requests = vec![
+        json!({ "url": url }),
+        json!({ "url": "https://example.com/other" }),
+    ];
+
+    attach_bodies(&mut requests, &[posted], 1024);
+
+    assert_eq!(requests[0]["requestBody"], "name=ada");
+    assert_eq!(requests[0]["responseBody"], "{\"ok\":true}");
+    assert!(requests[0].get("responseBodyEncoding").is_none());
+    assert!(requests[0].get("bodyTruncated").is_none());
+    assert!(requests[1].get("responseBody").is_none());
+}
+
+#[test]
+fn test_attach_bodies_truncates_and_encodes_binary() {
+    let url = "https://example.com/logo.png";
+    let mut requests = vec![json!({ "url": url })];
+
+    attach_bodies(
+        &mut requests,
+        &[binary_response(url, "iVBORw0KGgoAAAANSUhEUg==")],
+        10,
+    );
+
+    assert_eq!(requests[0]["responseBody"], "iVBORw0K");
+    assert_eq!(requests[0]["responseBodyEncoding"], "base64");
+    assert_eq!(requests[0]["bodyTruncated"], true);
+}
+
+#[test]
+fn test_response_body_keeps_base64_decodable() {
+    let binary = binary_response("https://example.com/a.bin", "AAECAwQF");
+    assert_eq!(response_body(&binary, 7), Some(("AAEC", true)));
+    assert_eq!(response_body(&binary, 8), Some(("AAECAwQF", false)));
+
+    let text = response("https://example.com/a.txt", "abcdefgh");
+    assert_eq!(response_body(&text, 7), Some(("abcdefg", true)));
+}
+
+#[test]
+fn test_build_har_binary_body_and_post_data() {
+    let url = "https://example.com/upload";
+    let mut upload = binary_response(url, "AAEC");
+    upload.request_body = Some("{\"file\":1}".to_string());
+    upload.request_headers = vec![("content-type".to_string(), "application/json".to_string())];
+
+    let har = build_har(&[upload], 1024);
+
+    let entry = &har["log"]["entries"][0];
+    assert_eq!(entry["response"]["content"]["text"], "AAEC");
+    assert_eq!(entry["response"]["content"]["encoding"], "base64");
+    assert_eq!(entry["request"]["postData"]["mimeType"], "application/json");
+    assert_eq!(entry["request"]["postData"]["text"], "{\"file\":1}");
+}
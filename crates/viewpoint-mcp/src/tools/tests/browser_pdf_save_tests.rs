@@ -1,7 +1,9 @@
 //! Tests for `browser_pdf_save` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_pdf_save::{BrowserPdfSaveInput, BrowserPdfSaveTool, PaperFormat};
+use crate::tools::browser_pdf_save::{
+    BrowserPdfSaveInput, BrowserPdfSaveTool, PaperFormat, validate_page_ranges,
+};
 use serde_json::json;
 
 #[test]
@@ -28,13 +30,17 @@ fn test_input_parsing_minimal() {
     }))
     .unwrap();
 
-    assert_eq!(input.path, "/tmp/test.pdf");
-    assert_eq!(input.format, PaperFormat::Letter);
+    assert_eq!(input.path.as_deref(), Some("/tmp/test.pdf"));
+    assert_eq!(input.format, None);
+    assert_eq!(input.paper_format(), PaperFormat::Letter);
+    assert_eq!(input.paper_size(), Ok(None));
     assert!(!input.landscape);
     assert!(!input.print_background);
     assert!(input.scale.is_none());
     assert!(input.page_ranges.is_none());
     assert!(input.margin.is_none());
+    assert!(input.header_template.is_none());
+    assert!(input.footer_template.is_none());
 }
 
 #[test]
@@ -50,8 +56,8 @@ fn test_input_parsing_with_options() {
     }))
     .unwrap();
 
-    assert_eq!(input.path, "/tmp/report.pdf");
-    assert_eq!(input.format, PaperFormat::A4);
+    assert_eq!(input.path.as_deref(), Some("/tmp/report.pdf"));
+    assert_eq!(input.format, Some(PaperFormat::A4));
     assert!(input.landscape);
     assert!(input.print_background);
     assert_eq!(input.scale, Some(0.8));
@@ -59,6 +65,79 @@ fn test_input_parsing_with_options() {
     assert_eq!(input.margin, Some(0.5));
 }
 
+#[test]
+fn test_input_parsing_inline() {
+    let input: BrowserPdfSaveInput = serde_json::from_value(json!({ "path": null })).unwrap();
+    assert!(input.path.is_none());
+
+    // The path key must be present, so inline mode is always an explicit choice
+    assert!(serde_json::from_value::<BrowserPdfSaveInput>(json!({})).is_err());
+
+    let schema = BrowserPdfSaveTool::new().input_schema();
+    assert_eq!(
+        schema["properties"]["path"]["type"],
+        json!(["string", "null"])
+    );
+}
+
+#[test]
+fn test_input_parsing_custom_paper_size() {
+    let input: BrowserPdfSaveInput = serde_json::from_value(json!({
+        "path": "/tmp/receipt.pdf",
+        "width": 3.15,
+        "height": 11.0
+    }))
+    .unwrap();
+
+    assert_eq!(input.paper_size(), Ok(Some((3.15, 11.0))));
+
+    let schema = BrowserPdfSaveTool::new().input_schema();
+    assert_eq!(schema["properties"]["width"]["type"], "number");
+    assert_eq!(schema["properties"]["height"]["maximum"], 100);
+}
+
+#[test]
+fn test_invalid_custom_paper_sizes() {
+    let parse = |args| serde_json::from_value::<BrowserPdfSaveInput>(args).unwrap();
+
+    for args in [
+        json!({ "path": "/tmp/a.pdf", "format": "a4", "width": 5.0, "height": 5.0 }),
+        json!({ "path": "/tmp/a.pdf", "width": 5.0 }),
+        json!({ "path": "/tmp/a.pdf", "height": 5.0 }),
+        json!({ "path": "/tmp/a.pdf", "width": 0.0, "height": 5.0 }),
+        json!({ "path": "/tmp/a.pdf", "width": -1.0, "height": 5.0 }),
+        json!({ "path": "/tmp/a.pdf", "width": 5.0, "height": 100.5 }),
+    ] {
+        assert!(
+            parse(args.clone()).paper_size().is_err(),
+            "{args} should be rejected"
+        );
+    }
+
+    // The range bounds themselves are allowed
+    let input = parse(json!({ "path": "/tmp/a.pdf", "width": 0.1, "height": 100.0 }));
+    assert_eq!(input.paper_size(), Ok(Some((0.1, 100.0))));
+}
+
+#[tokio::test]
+async fn test_format_with_custom_size_is_rejected() {
+    let tool = BrowserPdfSaveTool::new();
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = tool
+        .execute(
+            &json!({ "path": "/tmp/report.pdf", "format": "letter", "width": 4.0, "height": 6.0 }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
 #[test]
 fn test_paper_format_conversion() {
     let letter: viewpoint_core::PaperFormat = PaperFormat::Letter.into();
@@ -67,3 +146,86 @@ fn test_paper_format_conversion() {
     let a4: viewpoint_core::PaperFormat = PaperFormat::A4.into();
     assert!(matches!(a4, viewpoint_core::PaperFormat::A4));
 }
+
+#[test]
+fn test_input_parsing_header_footer() {
+    let input: BrowserPdfSaveInput = serde_json::from_value(json!({
+        "path": "/tmp/report.pdf",
+        "headerTemplate": "<span class=\"date\"></span>",
+        "footerTemplate": "<span class=\"pageNumber\"></span>"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        input.header_template.as_deref(),
+        Some("<span class=\"date\"></span>")
+    );
+    assert_eq!(
+        input.footer_template.as_deref(),
+        Some("<span class=\"pageNumber\"></span>")
+    );
+}
+
+#[test]
+fn test_header_footer_templates() {
+    let none: BrowserPdfSaveInput = serde_json::from_value(json!({})).unwrap();
+    assert_eq!(none.header_footer_templates(), None);
+
+    // A footer alone gets an empty header instead of Chrome's default one
+    let footer_only: BrowserPdfSaveInput = serde_json::from_value(json!({
+        "footerTemplate": "<span class=\"pageNumber\"></span>"
+    }))
+    .unwrap();
+    assert_eq!(
+        footer_only.header_footer_templates(),
+        Some(("<span></span>", "<span class=\"pageNumber\"></span>"))
+    );
+
+    let header_only: BrowserPdfSaveInput = serde_json::from_value(json!({
+        "headerTemplate": "<span class=\"title\"></span>"
+    }))
+    .unwrap();
+    assert_eq!(
+        header_only.header_footer_templates(),
+        Some(("<span class=\"title\"></span>", "<span></span>"))
+    );
+}
+
+#[test]
+fn test_validate_page_ranges_valid() {
+    for ranges in ["1", "1-5, 8, 11-13", "3-3", "5-", "-3", " 2 - 4 ,6"] {
+        assert!(
+            validate_page_ranges(ranges).is_ok(),
+            "'{ranges}' should be valid"
+        );
+    }
+}
+
+#[test]
+fn test_validate_page_ranges_invalid() {
+    for ranges in ["", "0", "1,,2", "5-2", "a-b", "-", "1-2-3", "1.5"] {
+        assert!(
+            validate_page_ranges(ranges).is_err(),
+            "'{ranges}' should be invalid"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_page_ranges_rejected_before_browser() {
+    let tool = BrowserPdfSaveTool::new();
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = tool
+        .execute(
+            &json!({ "path": "/tmp/report.pdf", "pageRanges": "5-2" }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
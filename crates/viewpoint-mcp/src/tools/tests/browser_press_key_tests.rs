@@ -50,3 +50,41 @@ fn test_input_arrow_key() {
 
     assert_eq!(input.key, "ArrowLeft");
 }
+
+#[test]
+fn test_input_ref_defaults_to_none() {
+    let input: BrowserPressKeyInput = serde_json::from_value(json!({
+        "key": "Enter"
+    }))
+    .unwrap();
+
+    assert!(input.element_ref.is_none());
+    assert!(input.element.is_none());
+}
+
+#[test]
+fn test_input_parsing_with_ref() {
+    let input: BrowserPressKeyInput = serde_json::from_value(json!({
+        "key": "Control+b",
+        "ref": "c0p0f0e3",
+        "element": "Rich text editor"
+    }))
+    .unwrap();
+
+    assert_eq!(input.element_ref.as_deref(), Some("c0p0f0e3"));
+    assert_eq!(input.element.as_deref(), Some("Rich text editor"));
+}
+
+#[test]
+fn test_schema_has_ref() {
+    let schema = BrowserPressKeyTool::new().input_schema();
+
+    assert_eq!(schema["properties"]["ref"]["type"], "string");
+    assert_eq!(schema["properties"]["element"]["type"], "string");
+    assert!(
+        !schema["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("ref"))
+    );
+}
@@ -37,6 +37,8 @@ fn test_input_parsing() {
 
     assert_eq!(input.width, 1920);
     assert_eq!(input.height, 1080);
+    assert!(input.device_scale_factor.is_none());
+    assert!(input.mobile.is_none());
 }
 
 #[test]
@@ -62,3 +64,17 @@ fn test_input_parsing_large_dimensions() {
     assert_eq!(input.width, 3840);
     assert_eq!(input.height, 2160);
 }
+
+#[test]
+fn test_input_parsing_hidpi_mobile() {
+    let input: BrowserResizeInput = serde_json::from_value(json!({
+        "width": 390,
+        "height": 844,
+        "deviceScaleFactor": 3.0,
+        "mobile": true
+    }))
+    .unwrap();
+
+    assert_eq!(input.device_scale_factor, Some(3.0));
+    assert_eq!(input.mobile, Some(true));
+}
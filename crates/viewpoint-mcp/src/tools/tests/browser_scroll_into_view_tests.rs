@@ -2,7 +2,7 @@
 
 use crate::tools::Tool;
 use crate::tools::browser_scroll_into_view::{
-    BrowserScrollIntoViewInput, BrowserScrollIntoViewTool,
+    BrowserScrollIntoViewInput, BrowserScrollIntoViewTool, ScrollPosition,
 };
 use serde_json::json;
 
@@ -40,6 +40,19 @@ fn test_input_parsing() {
 
     assert_eq!(input.element_ref, "c0p0f0e1");
     assert_eq!(input.element, "Submit button");
+    assert!(!input.return_bounds);
+}
+
+#[test]
+fn test_input_with_return_bounds() {
+    let input: BrowserScrollIntoViewInput = serde_json::from_value(json!({
+        "ref": "c0p0f0e1",
+        "element": "Footer link",
+        "returnBounds": true
+    }))
+    .unwrap();
+
+    assert!(input.return_bounds);
 }
 
 #[test]
@@ -65,3 +78,22 @@ fn test_default_constructor() {
     let tool = BrowserScrollIntoViewTool::default();
     assert_eq!(tool.name(), "browser_scroll_into_view");
 }
+
+#[test]
+fn test_scroll_position_serialization() {
+    let position: ScrollPosition = serde_json::from_value(json!({
+        "scrollX": 0,
+        "scrollY": 1250.5,
+        "maxScrollX": 0,
+        "maxScrollY": 2400,
+        "atBottom": false
+    }))
+    .unwrap();
+    assert!((position.scroll_y - 1250.5).abs() < f64::EPSILON);
+    assert!(!position.at_bottom);
+
+    assert_eq!(
+        serde_json::to_string(&position).unwrap(),
+        r#"{"scrollX":0.0,"scrollY":1250.5,"maxScrollX":0.0,"maxScrollY":2400.0,"atBottom":false}"#
+    );
+}
@@ -1,7 +1,10 @@
 //! Tests for `browser_select_option` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_select_option::{BrowserSelectOptionInput, BrowserSelectOptionTool};
+use crate::tools::ToolError;
+use crate::tools::browser_select_option::{
+    BrowserSelectOptionInput, BrowserSelectOptionTool, OptionSelector,
+};
 use serde_json::json;
 
 #[test]
@@ -14,11 +17,14 @@ fn test_tool_metadata() {
     let schema = tool.input_schema();
     assert_eq!(schema["type"], "object");
     assert!(
-        schema["required"]
+        !schema["required"]
             .as_array()
             .unwrap()
             .contains(&json!("values"))
     );
+    assert!(schema["properties"]["values"].is_object());
+    assert!(schema["properties"]["label"].is_object());
+    assert!(schema["properties"]["index"].is_object());
 }
 
 #[test]
@@ -45,3 +51,66 @@ fn test_multi_select() {
 
     assert_eq!(input.values.len(), 3);
 }
+
+fn parse(args: serde_json::Value) -> BrowserSelectOptionInput {
+    serde_json::from_value(args).unwrap()
+}
+
+#[test]
+fn test_selector_values() {
+    let input = parse(json!({ "ref": "e1", "element": "Country", "values": ["US"] }));
+
+    assert_eq!(
+        input.selector().unwrap(),
+        OptionSelector::Values(vec!["US".to_string()])
+    );
+}
+
+#[test]
+fn test_selector_label() {
+    let input = parse(json!({ "ref": "e1", "element": "Country", "label": "Canada" }));
+
+    assert_eq!(
+        input.selector().unwrap(),
+        OptionSelector::Label("Canada".to_string())
+    );
+}
+
+#[test]
+fn test_selector_index() {
+    let input = parse(json!({ "ref": "e1", "element": "Country", "index": 0 }));
+
+    assert_eq!(input.selector().unwrap(), OptionSelector::Index(0));
+}
+
+#[test]
+fn test_selector_requires_exactly_one() {
+    let none = parse(json!({ "ref": "e1", "element": "Country" }));
+    assert!(matches!(none.selector(), Err(ToolError::InvalidParams(_))));
+
+    let empty_values = parse(json!({ "ref": "e1", "element": "Country", "values": [] }));
+    assert!(matches!(
+        empty_values.selector(),
+        Err(ToolError::InvalidParams(_))
+    ));
+
+    let multiple = parse(json!({
+        "ref": "e1",
+        "element": "Country",
+        "values": ["US"],
+        "label": "Canada"
+    }));
+    assert!(matches!(
+        multiple.selector(),
+        Err(ToolError::InvalidParams(_))
+    ));
+}
+
+#[test]
+fn test_selector_describe() {
+    assert_eq!(
+        OptionSelector::Label("Canada".to_string()).describe(),
+        "label 'Canada'"
+    );
+    assert_eq!(OptionSelector::Index(2).describe(), "index 2");
+}
@@ -0,0 +1,59 @@
+//! Tests for `browser_set_extra_headers` tool
+
+use crate::tools::Tool;
+use crate::tools::browser_set_extra_headers::{
+    BrowserSetExtraHeadersInput, BrowserSetExtraHeadersTool,
+};
+use serde_json::json;
+
+#[test]
+fn test_tool_metadata() {
+    let tool = BrowserSetExtraHeadersTool::new();
+
+    assert_eq!(tool.name(), "browser_set_extra_headers");
+    assert!(!tool.description().is_empty());
+    assert_eq!(tool.categories(), vec!["context"]);
+
+    let schema = tool.input_schema();
+    assert_eq!(schema["type"], "object");
+    assert!(
+        schema["required"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("headers"))
+    );
+}
+
+#[test]
+fn test_input_parsing() {
+    let input: BrowserSetExtraHeadersInput = serde_json::from_value(json!({
+        "headers": { "X-API-Key": "secret", "Accept-Language": "de" }
+    }))
+    .unwrap();
+
+    assert_eq!(input.headers.len(), 2);
+    assert_eq!(input.headers["X-API-Key"], "secret");
+}
+
+#[test]
+fn test_input_empty_headers() {
+    let input: BrowserSetExtraHeadersInput =
+        serde_json::from_value(json!({ "headers": {} })).unwrap();
+
+    assert!(input.headers.is_empty());
+}
+
+#[test]
+fn test_input_rejects_non_string_values() {
+    let result: Result<BrowserSetExtraHeadersInput, _> =
+        serde_json::from_value(json!({ "headers": { "X-Count": 5 } }));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_input_missing_headers() {
+    let result: Result<BrowserSetExtraHeadersInput, _> = serde_json::from_value(json!({}));
+
+    assert!(result.is_err());
+}
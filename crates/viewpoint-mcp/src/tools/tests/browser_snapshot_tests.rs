@@ -14,12 +14,15 @@ fn test_tool_metadata() {
     let schema = tool.input_schema();
     assert_eq!(schema["type"], "object");
     assert!(schema["properties"]["allRefs"].is_object());
+    assert_eq!(schema["properties"]["maxTextLength"]["type"], "integer");
 }
 
 #[test]
 fn test_input_parsing() {
     let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
     assert!(!input.all_refs);
+    assert!(input.max_text_length.is_none());
+    assert!(input.selector.is_none());
 
     let input: BrowserSnapshotInput = serde_json::from_value(json!({
         "allRefs": true
@@ -27,3 +30,142 @@ fn test_input_parsing() {
     .unwrap();
     assert!(input.all_refs);
 }
+
+#[test]
+fn test_input_parsing_max_text_length() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({
+        "maxTextLength": 50
+    }))
+    .unwrap();
+    assert_eq!(input.max_text_length, Some(50));
+
+    assert!(
+        serde_json::from_value::<BrowserSnapshotInput>(json!({ "maxTextLength": -1 })).is_err()
+    );
+}
+
+#[test]
+fn test_input_parsing_selector() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({
+        "selector": "#checkout form"
+    }))
+    .unwrap();
+    assert_eq!(input.selector.as_deref(), Some("#checkout form"));
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["selector"]["type"], "string");
+}
+
+#[test]
+fn test_input_parsing_element_counts() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.element_counts);
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "elementCounts": true })).unwrap();
+    assert!(input.element_counts);
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["elementCounts"]["type"], "boolean");
+}
+
+#[test]
+fn test_input_parsing_include_stats() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.include_stats);
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "includeStats": true })).unwrap();
+    assert!(input.include_stats);
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["includeStats"]["type"], "boolean");
+}
+
+#[test]
+fn test_input_parsing_focus_element() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.focus_element);
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "focusElement": true })).unwrap();
+    assert!(input.focus_element);
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["focusElement"]["type"], "boolean");
+}
+
+#[test]
+fn test_input_parsing_aria_label_contains() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.aria_label_contains.is_none());
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "ariaLabelContains": "checkout" })).unwrap();
+    assert_eq!(input.aria_label_contains.as_deref(), Some("checkout"));
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["ariaLabelContains"]["type"], "string");
+}
+
+#[test]
+fn test_input_parsing_skip_roles() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.skip_roles.is_none());
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "skipRoles": ["text", "img"] })).unwrap();
+    assert_eq!(
+        input.skip_roles,
+        Some(vec!["text".to_string(), "img".to_string()])
+    );
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["skipRoles"]["type"], "array");
+}
+
+#[test]
+fn test_input_parsing_format() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.format.is_none());
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "format": "markdown" })).unwrap();
+    assert_eq!(input.format.as_deref(), Some("markdown"));
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["format"]["default"], "text");
+    assert_eq!(
+        schema["properties"]["format"]["enum"],
+        json!(["text", "markdown"])
+    );
+}
+
+#[tokio::test]
+async fn test_unknown_format_rejected_before_launch() {
+    let tool = BrowserSnapshotTool::new();
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+
+    let result = tool
+        .execute(&json!({ "format": "html" }), &mut browser)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
+#[test]
+fn test_input_parsing_expand_collapsed() {
+    let input: BrowserSnapshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.expand_collapsed);
+
+    let input: BrowserSnapshotInput =
+        serde_json::from_value(json!({ "expandCollapsed": true })).unwrap();
+    assert!(input.expand_collapsed);
+
+    let schema = BrowserSnapshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["expandCollapsed"]["default"], false);
+}
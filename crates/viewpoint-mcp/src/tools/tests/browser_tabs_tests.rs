@@ -1,7 +1,7 @@
 //! Tests for `browser_tabs` tool
 
 use crate::tools::Tool;
-use crate::tools::browser_tabs::{BrowserTabsInput, BrowserTabsTool, TabAction};
+use crate::tools::browser_tabs::{BrowserTabsInput, BrowserTabsTool, TabAction, url_matches};
 use serde_json::json;
 
 #[test]
@@ -76,3 +76,123 @@ fn test_input_parsing_select() {
     assert!(matches!(input.action, TabAction::Select));
     assert_eq!(input.index, Some(1));
 }
+
+#[test]
+fn test_input_parsing_duplicate() {
+    let input: BrowserTabsInput = serde_json::from_value(json!({
+        "action": "duplicate"
+    }))
+    .unwrap();
+
+    assert!(matches!(input.action, TabAction::Duplicate));
+    assert!(input.index.is_none());
+}
+
+#[test]
+fn test_input_parsing_move() {
+    let input: BrowserTabsInput = serde_json::from_value(json!({
+        "action": "move",
+        "from": 0,
+        "to": 2
+    }))
+    .unwrap();
+
+    assert!(matches!(input.action, TabAction::Move));
+    assert_eq!(input.from, Some(0));
+    assert_eq!(input.to, Some(2));
+}
+
+#[test]
+fn test_input_parsing_new_pinned() {
+    let input: BrowserTabsInput = serde_json::from_value(json!({
+        "action": "new",
+        "pinned": true
+    }))
+    .unwrap();
+
+    assert!(matches!(input.action, TabAction::New));
+    assert!(input.pinned);
+}
+
+#[test]
+fn test_input_pinned_defaults_to_false() {
+    let input: BrowserTabsInput = serde_json::from_value(json!({
+        "action": "new"
+    }))
+    .unwrap();
+
+    assert!(!input.pinned);
+}
+
+#[test]
+fn test_schema_has_pinned() {
+    let schema = BrowserTabsTool::new().input_schema();
+
+    assert_eq!(schema["properties"]["pinned"]["type"], "boolean");
+}
+
+#[test]
+fn test_input_parsing_title_and_url() {
+    let input: BrowserTabsInput = serde_json::from_value(json!({
+        "action": "title",
+        "context": "work"
+    }))
+    .unwrap();
+    assert!(matches!(input.action, TabAction::Title));
+    assert_eq!(input.context.as_deref(), Some("work"));
+
+    let input: BrowserTabsInput = serde_json::from_value(json!({ "action": "url" })).unwrap();
+    assert!(matches!(input.action, TabAction::Url));
+    assert!(input.context.is_none());
+}
+
+#[test]
+fn test_schema_has_title_url_and_context() {
+    let schema = BrowserTabsTool::new().input_schema();
+
+    let actions = schema["properties"]["action"]["enum"].as_array().unwrap();
+    assert!(actions.contains(&json!("title")));
+    assert!(actions.contains(&json!("url")));
+    assert_eq!(schema["properties"]["context"]["type"], "string");
+}
+
+#[test]
+fn test_input_parsing_find_by_url() {
+    let input: BrowserTabsInput = serde_json::from_value(json!({
+        "action": "findByUrl",
+        "pattern": "example.com"
+    }))
+    .unwrap();
+    assert!(matches!(input.action, TabAction::FindByUrl));
+    assert_eq!(input.pattern.as_deref(), Some("example.com"));
+
+    let schema = BrowserTabsTool::new().input_schema();
+    let actions = schema["properties"]["action"]["enum"].as_array().unwrap();
+    assert!(actions.contains(&json!("findByUrl")));
+    assert_eq!(schema["properties"]["pattern"]["type"], "string");
+}
+
+#[test]
+fn test_url_matches_substring() {
+    assert!(url_matches("https://example.com/cart", "example.com"));
+    assert!(url_matches("https://example.com/cart", "/cart"));
+    assert!(!url_matches("https://example.com/cart", "checkout"));
+}
+
+#[test]
+fn test_url_matches_glob() {
+    assert!(url_matches(
+        "https://shop.example.com/cart?id=1",
+        "https://*.example.com/*"
+    ));
+    assert!(url_matches("https://example.com/a1", "*/a?"));
+    assert!(url_matches("https://example.com/", "*"));
+
+    // Globs match the whole URL, not a substring
+    assert!(!url_matches("https://example.com/cart", "example.com/*"));
+    assert!(!url_matches("https://example.com/a12", "*/a?"));
+    assert!(!url_matches(
+        "https://other.org/",
+        "https://*.example.com/*"
+    ));
+}
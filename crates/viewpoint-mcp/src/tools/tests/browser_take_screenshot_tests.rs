@@ -2,8 +2,9 @@
 
 use crate::tools::Tool;
 use crate::tools::browser_take_screenshot::{
-    BrowserTakeScreenshotInput, BrowserTakeScreenshotTool, ImageFormat, MAX_INLINE_DIMENSION,
-    MAX_INLINE_MEGAPIXELS, scale_image_for_inline,
+    BrowserTakeScreenshotInput, BrowserTakeScreenshotTool, ClipRegion, ImageFormat,
+    MAX_INLINE_DIMENSION, MAX_INLINE_MEGAPIXELS, PixelRect, RedactZone, apply_redactions,
+    scale_image_for_inline, scale_transparent_image_for_inline,
 };
 use serde_json::json;
 
@@ -51,6 +52,186 @@ fn test_input_element_screenshot() {
     assert_eq!(input.element, Some("Login form".to_string()));
 }
 
+#[test]
+fn test_input_redact_zones() {
+    let input: BrowserTakeScreenshotInput = serde_json::from_value(json!({
+        "redact": [
+            { "x": 10, "y": 20, "width": 100, "height": 30 },
+            { "ref": "c0p0f0e3", "element": "Password field" }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(input.redact.len(), 2);
+    assert!(matches!(
+        input.redact[0],
+        RedactZone::Region { x, width, .. } if (x - 10.0).abs() < f64::EPSILON && (width - 100.0).abs() < f64::EPSILON
+    ));
+    assert!(matches!(
+        &input.redact[1],
+        RedactZone::Element { element_ref, .. } if element_ref == "c0p0f0e3"
+    ));
+}
+
+#[test]
+fn test_input_clip_region() {
+    let input: BrowserTakeScreenshotInput = serde_json::from_value(json!({
+        "clip": { "x": 10, "y": 20.5, "width": 300, "height": 150 }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        input.clip,
+        Some(ClipRegion {
+            x: 10.0,
+            y: 20.5,
+            width: 300.0,
+            height: 150.0
+        })
+    );
+}
+
+#[test]
+fn test_input_clip_requires_all_fields() {
+    let result: Result<BrowserTakeScreenshotInput, _> = serde_json::from_value(json!({
+        "clip": { "x": 10, "y": 20 }
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_input_redact_defaults_empty() {
+    let input: BrowserTakeScreenshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.redact.is_empty());
+}
+
+#[test]
+fn test_input_omit_background() {
+    let input: BrowserTakeScreenshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(!input.omit_background);
+
+    let input: BrowserTakeScreenshotInput =
+        serde_json::from_value(json!({ "omitBackground": true })).unwrap();
+    assert!(input.omit_background);
+    assert_eq!(input.image_type, ImageFormat::Png);
+
+    let schema = BrowserTakeScreenshotTool::new().input_schema();
+    assert_eq!(schema["properties"]["omitBackground"]["type"], "boolean");
+}
+
+#[test]
+fn test_input_highlights() {
+    let input: BrowserTakeScreenshotInput = serde_json::from_value(json!({})).unwrap();
+    assert!(input.highlights.is_empty());
+
+    let input: BrowserTakeScreenshotInput = serde_json::from_value(json!({
+        "highlights": [
+            { "ref": "c0p0f0e1", "color": "#00aa00", "label": "1" },
+            { "ref": "c0p0f0e2" }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(input.highlights.len(), 2);
+    assert_eq!(input.highlights[0].element_ref, "c0p0f0e1");
+    assert_eq!(input.highlights[0].color, "#00aa00");
+    assert_eq!(input.highlights[0].label.as_deref(), Some("1"));
+    assert_eq!(input.highlights[1].color, "red");
+    assert_eq!(input.highlights[1].label, None);
+
+    let schema = BrowserTakeScreenshotTool::new().input_schema();
+    assert_eq!(
+        schema["properties"]["highlights"]["items"]["required"],
+        json!(["ref"])
+    );
+}
+
+#[tokio::test]
+async fn test_highlight_without_ref_rejected_before_launch() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+    let result = BrowserTakeScreenshotTool::new()
+        .execute(
+            &json!({ "highlights": [{ "ref": "", "label": "?" }] }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
+
+// =============================================================================
+// Redaction tests
+// =============================================================================
+
+#[test]
+fn test_pixel_rect_from_css_scales_and_rounds_outward() {
+    let rect = PixelRect::from_css(10.5, 20.0, 5.0, 5.0, 2.0);
+
+    assert_eq!(
+        rect,
+        PixelRect {
+            x: 21,
+            y: 40,
+            width: 10,
+            height: 10
+        }
+    );
+}
+
+#[test]
+fn test_apply_redactions_paints_black() {
+    use image::{ImageFormat, Rgb, RgbImage};
+    use std::io::Cursor;
+
+    let img = RgbImage::from_pixel(20, 20, Rgb([255, 255, 255]));
+    let mut buffer = Cursor::new(Vec::new());
+    img.write_to(&mut buffer, ImageFormat::Png).unwrap();
+
+    let rect = PixelRect {
+        x: 5,
+        y: 5,
+        width: 5,
+        height: 5,
+    };
+    let result = apply_redactions(&buffer.into_inner(), &[rect]).unwrap();
+
+    // Output keeps the input format
+    assert_eq!(image::guess_format(&result).unwrap(), ImageFormat::Png);
+
+    let decoded = image::load_from_memory(&result).unwrap().to_rgb8();
+    assert_eq!(decoded.get_pixel(5, 5), &Rgb([0, 0, 0]));
+    assert_eq!(decoded.get_pixel(9, 9), &Rgb([0, 0, 0]));
+    assert_eq!(decoded.get_pixel(10, 10), &Rgb([255, 255, 255]));
+    assert_eq!(decoded.get_pixel(0, 0), &Rgb([255, 255, 255]));
+}
+
+#[test]
+fn test_apply_redactions_clips_to_bounds() {
+    let png_bytes = create_test_png(10, 10);
+
+    let rects = [
+        PixelRect {
+            x: -5,
+            y: -5,
+            width: 8,
+            height: 8,
+        },
+        PixelRect {
+            x: 50,
+            y: 50,
+            width: 10,
+            height: 10,
+        },
+    ];
+
+    let result = apply_redactions(&png_bytes, &rects).unwrap();
+    let decoded = image::load_from_memory(&result).unwrap();
+    assert_eq!((decoded.width(), decoded.height()), (10, 10));
+}
+
 // =============================================================================
 // Image scaling tests
 // =============================================================================
@@ -177,3 +358,21 @@ fn test_scale_preserves_aspect_ratio() {
         scaled_ratio
     );
 }
+
+#[test]
+fn test_scale_transparent_keeps_png_alpha() {
+    use image::{ImageFormat, Rgba, RgbaImage};
+    use std::io::Cursor;
+
+    let img = RgbaImage::from_pixel(3000, 600, Rgba([0, 0, 0, 0]));
+    let mut buffer = Cursor::new(Vec::new());
+    img.write_to(&mut buffer, ImageFormat::Png).unwrap();
+
+    let result = scale_transparent_image_for_inline(&buffer.into_inner()).unwrap();
+
+    // PNG magic bytes, scaled to the inline limits, still transparent
+    assert_eq!(&result[..4], b"\x89PNG");
+    let decoded = image::load_from_memory(&result).unwrap();
+    assert!(decoded.width() <= MAX_INLINE_DIMENSION);
+    assert_eq!(decoded.to_rgba8().get_pixel(0, 0)[3], 0);
+}
@@ -41,6 +41,7 @@ fn test_input_parsing() {
     assert_eq!(input.text, "user@example.com");
     assert!(!input.slowly);
     assert!(!input.submit);
+    assert!(!input.append);
 }
 
 #[test]
@@ -57,3 +58,67 @@ fn test_input_with_options() {
     assert!(input.slowly);
     assert!(input.submit);
 }
+
+#[test]
+fn test_input_with_append() {
+    let input: BrowserTypeInput = serde_json::from_value(json!({
+        "ref": "e1a2b3c",
+        "element": "Search box",
+        "text": " shoes",
+        "append": true
+    }))
+    .unwrap();
+
+    assert!(input.append);
+    assert!(!input.slowly);
+}
+
+#[test]
+fn test_schema_has_append() {
+    let tool = BrowserTypeTool::new();
+    let schema = tool.input_schema();
+
+    assert_eq!(schema["properties"]["append"]["type"], "boolean");
+    assert_eq!(schema["properties"]["append"]["default"], false);
+}
+
+#[test]
+fn test_input_with_paste_mode() {
+    let input: BrowserTypeInput = serde_json::from_value(json!({
+        "ref": "e1a2b3c",
+        "element": "Editor",
+        "text": "a long article",
+        "pasteMode": true
+    }))
+    .unwrap();
+
+    assert!(input.paste_mode);
+    assert!(!input.append);
+
+    let schema = BrowserTypeTool::new().input_schema();
+    assert_eq!(schema["properties"]["pasteMode"]["type"], "boolean");
+    assert_eq!(schema["properties"]["pasteMode"]["default"], false);
+}
+
+#[tokio::test]
+async fn test_paste_mode_rejects_slowly() {
+    let mut browser = crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+    let result = BrowserTypeTool::new()
+        .execute(
+            &json!({
+                "ref": "e1a2b3c",
+                "element": "Editor",
+                "text": "hello",
+                "pasteMode": true,
+                "slowly": true
+            }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::tools::ToolError::InvalidParams(_))
+    ));
+    assert!(!browser.is_initialized());
+}
@@ -62,3 +62,57 @@ fn test_input_parsing_empty() {
     assert!(input.text_gone.is_none());
     assert!(input.time.is_none());
 }
+
+#[test]
+fn test_input_parsing_ref_present() {
+    let input: BrowserWaitForInput = serde_json::from_value(json!({
+        "refPresent": "c0p0f0e5",
+        "pollIntervalMs": 100,
+        "timeoutMs": 2000
+    }))
+    .unwrap();
+
+    assert_eq!(input.ref_present, Some("c0p0f0e5".to_string()));
+    assert!(input.ref_gone.is_none());
+    assert_eq!(input.poll_interval_ms, Some(100));
+    assert_eq!(input.timeout_ms, Some(2000));
+}
+
+#[test]
+fn test_input_parsing_ref_gone_defaults() {
+    let input: BrowserWaitForInput = serde_json::from_value(json!({
+        "refGone": "c0p0f0e5"
+    }))
+    .unwrap();
+
+    assert_eq!(input.ref_gone, Some("c0p0f0e5".to_string()));
+    assert!(input.poll_interval_ms.is_none());
+    assert!(input.timeout_ms.is_none());
+}
+
+#[test]
+fn test_schema_includes_ref_conditions() {
+    let schema = BrowserWaitForTool::new().input_schema();
+
+    assert!(schema["properties"]["refPresent"].is_object());
+    assert!(schema["properties"]["refGone"].is_object());
+    assert_eq!(schema["properties"]["pollIntervalMs"]["default"], 500);
+}
+
+#[tokio::test]
+async fn test_malformed_ref_is_rejected() {
+    for args in [
+        json!({ "refGone": "not-a-ref" }),
+        json!({ "refPresent": "e12" }),
+    ] {
+        let mut browser =
+            crate::browser::BrowserState::new(crate::browser::BrowserConfig::default());
+        let result = BrowserWaitForTool::new().execute(&args, &mut browser).await;
+
+        assert!(matches!(
+            result,
+            Err(crate::tools::ToolError::InvalidParams(_))
+        ));
+        assert!(!browser.is_initialized());
+    }
+}
@@ -3,11 +3,14 @@
 //! Tests for tool metadata, input parsing, and capability requirements.
 //! These tests don't require a browser - they test the tool definitions themselves.
 
+mod browser_accessibility_tree_diff_tests;
 mod browser_click_tests;
+mod browser_clipboard_tests;
 mod browser_close_tests;
 mod browser_console_messages_tests;
 mod browser_context_close_tests;
 mod browser_context_create_tests;
+mod browser_context_import_har_tests;
 mod browser_context_list_tests;
 mod browser_context_save_storage_tests;
 mod browser_context_switch_tests;
@@ -15,13 +18,16 @@ mod browser_drag_tests;
 mod browser_evaluate_tests;
 mod browser_file_upload_tests;
 mod browser_fill_form_tests;
+mod browser_focus_tests;
 mod browser_handle_dialog_tests;
 mod browser_hover_tests;
 mod browser_install_tests;
+mod browser_keyboard_shortcut_tests;
 mod browser_mouse_click_xy_tests;
 mod browser_mouse_drag_xy_tests;
 mod browser_mouse_move_xy_tests;
 mod browser_navigate_back_tests;
+mod browser_navigate_forward_tests;
 mod browser_navigate_tests;
 mod browser_network_requests_tests;
 mod browser_pdf_save_tests;
@@ -29,6 +35,7 @@ mod browser_press_key_tests;
 mod browser_resize_tests;
 mod browser_scroll_into_view_tests;
 mod browser_select_option_tests;
+mod browser_set_extra_headers_tests;
 mod browser_snapshot_tests;
 mod browser_tabs_tests;
 mod browser_take_screenshot_tests;
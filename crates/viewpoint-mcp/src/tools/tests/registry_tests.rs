@@ -1,7 +1,7 @@
 //! Tests for tool registry
 
 use crate::browser::BrowserState;
-use crate::tools::registry::ToolRegistry;
+use crate::tools::registry::{ToolRegistry, UNCATEGORIZED, register_all_tools};
 use crate::tools::traits::Capability;
 use crate::tools::{Tool, ToolError, ToolResult};
 use async_trait::async_trait;
@@ -171,3 +171,111 @@ fn test_capability_as_str() {
     assert_eq!(Capability::Vision.as_str(), "vision");
     assert_eq!(Capability::Pdf.as_str(), "pdf");
 }
+
+#[test]
+fn test_list_by_category_all_tools() {
+    let mut registry = ToolRegistry::new();
+    register_all_tools(&mut registry);
+
+    let groups = registry.list_by_category();
+
+    assert_eq!(groups["navigation"].len(), 3);
+    assert_eq!(groups["interaction"].len(), 11);
+    assert_eq!(groups["inspection"].len(), 5);
+    assert_eq!(groups["state"].len(), 4);
+    assert_eq!(groups["management"].len(), 4);
+    assert_eq!(groups["context"].len(), 7);
+    assert!(!groups.contains_key(UNCATEGORIZED));
+}
+
+#[test]
+fn test_list_by_category_uncategorized_and_capabilities() {
+    let mut registry = ToolRegistry::new();
+    registry.register(Arc::new(MockTool {
+        name: "basic_tool",
+        capability: None,
+    }));
+    registry.register(Arc::new(MockTool {
+        name: "vision_tool",
+        capability: Some(Capability::Vision),
+    }));
+
+    let groups = registry.list_by_category();
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[UNCATEGORIZED].len(), 1);
+    assert_eq!(groups[UNCATEGORIZED][0].name(), "basic_tool");
+}
+
+#[test]
+fn test_list_sorted_is_alphabetical_and_deterministic() {
+    let mut registry = ToolRegistry::new();
+    register_all_tools(&mut registry);
+
+    let names = |registry: &ToolRegistry| -> Vec<&'static str> {
+        registry.list_sorted().iter().map(|t| t.name()).collect()
+    };
+
+    let first = names(&registry);
+    assert_eq!(first.len(), registry.list().len());
+    assert!(first.windows(2).all(|pair| pair[0] < pair[1]), "{first:?}");
+    for _ in 0..5 {
+        assert_eq!(names(&registry), first);
+    }
+
+    // A separately built registry lists tools in the same order
+    let mut other = ToolRegistry::new();
+    register_all_tools(&mut other);
+    assert_eq!(names(&other), first);
+}
+
+#[test]
+fn test_deregister_all_keeps_capabilities() {
+    let mut registry = ToolRegistry::with_capabilities([Capability::Vision]);
+    register_all_tools(&mut registry);
+    assert!(!registry.list_all().is_empty());
+
+    registry.deregister_all();
+
+    assert!(registry.list_all().is_empty());
+    assert!(registry.get("browser_navigate").is_none());
+    assert!(registry.is_capability_enabled(Capability::Vision));
+}
+
+#[test]
+fn test_reset_capabilities_hides_gated_tools() {
+    let mut registry = ToolRegistry::with_capabilities([Capability::Vision, Capability::Pdf]);
+    register_all_tools(&mut registry);
+    assert!(registry.get("browser_mouse_click_xy").is_some());
+
+    registry.reset_capabilities();
+
+    assert!(registry.enabled_capabilities().is_empty());
+    assert!(registry.get("browser_mouse_click_xy").is_none());
+    assert!(registry.get("browser_pdf_save").is_none());
+    assert!(registry.get("browser_navigate").is_some());
+}
+
+#[test]
+fn test_lookup_sees_later_changes() {
+    let mut registry = ToolRegistry::new();
+    let lookup = registry.lookup();
+    assert!(lookup.get("basic_tool").is_none());
+
+    registry.register(Arc::new(MockTool {
+        name: "basic_tool",
+        capability: None,
+    }));
+    registry.register(Arc::new(MockTool {
+        name: "vision_tool",
+        capability: Some(Capability::Vision),
+    }));
+    assert!(lookup.get("basic_tool").is_some());
+    assert!(lookup.get("vision_tool").is_none());
+
+    registry.enable_capability(Capability::Vision);
+    assert!(lookup.get("vision_tool").is_some());
+
+    drop(registry);
+    assert!(lookup.get("basic_tool").is_none());
+}
@@ -70,8 +70,10 @@ impl std::str::FromStr for Capability {
 ///
 /// - Tools should validate input using [`serde_json::from_value`]
 /// - Browser initialization is lazy - call [`BrowserState::initialize`] first
+/// - Override [`Tool::requires_browser`] for tools that must run without a browser
 /// - Return [`ToolError`] variants for different failure modes
 /// - Override [`Tool::required_capability`] for optional tools
+/// - Override [`Tool::categories`] to group the tool in listings
 #[async_trait]
 pub trait Tool: Send + Sync {
     /// Get the tool name (e.g., `browser_navigate`)
@@ -80,6 +82,14 @@ pub trait Tool: Send + Sync {
     /// Get the tool description for LLM context
     fn description(&self) -> &'static str;
 
+    /// Get the categories this tool belongs to (e.g., `navigation`, `interaction`).
+    ///
+    /// The first category is reported as the tool's `category` in the tools list.
+    /// Tools returning an empty list are uncategorized.
+    fn categories(&self) -> Vec<&str> {
+        vec![]
+    }
+
     /// Get the JSON schema for tool input
     fn input_schema(&self) -> Value;
 
@@ -90,6 +100,15 @@ pub trait Tool: Send + Sync {
         None
     }
 
+    /// Whether the server should start the browser before executing this tool.
+    ///
+    /// Tools that manage the browser itself (e.g., installing or closing it)
+    /// or that only sometimes need it return `false` so they can run when the
+    /// browser is missing or stopped.
+    fn requires_browser(&self) -> bool {
+        true
+    }
+
     /// Execute the tool with given arguments
     async fn execute(&self, args: &Value, browser: &mut BrowserState) -> ToolResult;
 }
@@ -3,6 +3,7 @@
 //! This module provides stdio and SSE transport implementations.
 
 mod error;
+mod rate_limit;
 mod sse;
 mod stdio;
 
@@ -10,7 +11,7 @@ mod stdio;
 mod tests;
 
 pub use error::TransportError;
-pub use sse::{SseConfig, SseTransport};
+pub use sse::{HealthStatus, SseConfig, SseTransport};
 pub use stdio::StdioTransport;
 
 /// Result type for transport operations
@@ -0,0 +1,67 @@
+//! Token bucket rate limiting for the SSE transport
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// Number of tracked clients above which refilled buckets are dropped
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// Token buckets limiting each client to a steady rate.
+///
+/// Every client gets a bucket holding up to `max_per_second` tokens that
+/// refills continuously at `max_per_second` tokens per second, so short
+/// bursts up to the limit are allowed while the sustained rate is capped.
+/// Clients are keyed by IP address; requests without a known address share
+/// one bucket.
+#[derive(Debug)]
+pub(super) struct RateLimiter {
+    /// Bucket capacity and refill rate, in tokens per second
+    rate: f64,
+
+    /// Tokens currently available per client and when they were last refilled
+    buckets: Mutex<HashMap<Option<IpAddr>, (f64, Instant)>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `max_per_second` requests per second per client
+    pub(super) fn new(max_per_second: u32) -> Self {
+        Self {
+            rate: f64::from(max_per_second.max(1)),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a token from `client`'s bucket, returning `false` if it is empty
+    pub(super) fn try_acquire(&self, client: Option<IpAddr>) -> bool {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let now = Instant::now();
+
+        // A bucket that has refilled completely is the same as a new one
+        if buckets.len() >= PRUNE_THRESHOLD {
+            buckets.retain(|_, bucket| self.refill(bucket, now) < self.rate);
+        }
+
+        let bucket = buckets.entry(client).or_insert((self.rate, now));
+        let tokens = self.refill(bucket, now);
+        if tokens >= 1.0 {
+            bucket.0 = tokens - 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Refill `bucket` up to `now`, returning the tokens it holds
+    fn refill(&self, bucket: &mut (f64, Instant), now: Instant) -> f64 {
+        let (tokens, last_refill) = bucket;
+        let elapsed = now.duration_since(*last_refill).as_secs_f64();
+        *tokens = elapsed.mul_add(self.rate, *tokens).min(self.rate);
+        *last_refill = now;
+        *tokens
+    }
+}
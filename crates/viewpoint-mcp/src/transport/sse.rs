@@ -4,19 +4,24 @@
 //! Suitable for web-based MCP clients that can't use stdio.
 
 use std::convert::Infallible;
-use std::sync::Arc;
+use std::net::SocketAddr;
+use std::sync::{Arc, PoisonError};
 
-use axum::extract::State;
+use axum::extract::{ConnectInfo, Request, State};
 use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::{self, Next};
 use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use axum::{Json, Router};
 use rand::RngCore;
-use tokio::sync::Mutex;
+use serde::Serialize;
+use tokio::sync::{Mutex, RwLock};
 use tokio_stream::wrappers::ReceiverStream;
 
 use super::error::TransportError;
+use super::rate_limit::RateLimiter;
+use crate::browser::BrowserState;
 use crate::server::protocol::{JsonRpcRequest, JsonRpcResponse, McpServer};
 
 /// SSE transport configuration.
@@ -32,6 +37,10 @@ use crate::server::protocol::{JsonRpcRequest, JsonRpcResponse, McpServer};
 ///
 /// // Custom API key
 /// let config = SseConfig::with_api_key(8080, "my-secret-key");
+///
+/// // Reject clients sending more than 20 requests per second
+/// let mut config = SseConfig::new(8080);
+/// config.max_requests_per_second = Some(20);
 /// ```
 #[derive(Debug, Clone)]
 pub struct SseConfig {
@@ -40,6 +49,11 @@ pub struct SseConfig {
 
     /// API key for authentication
     pub api_key: String,
+
+    /// Maximum sustained rate of authenticated `/mcp` requests per client
+    /// IP address; excess requests get `429 Too Many Requests`. `None`
+    /// disables rate limiting.
+    pub max_requests_per_second: Option<u32>,
 }
 
 impl SseConfig {
@@ -49,6 +63,7 @@ impl SseConfig {
         Self {
             port,
             api_key: generate_api_key(),
+            max_requests_per_second: None,
         }
     }
 
@@ -58,6 +73,7 @@ impl SseConfig {
         Self {
             port,
             api_key: api_key.into(),
+            max_requests_per_second: None,
         }
     }
 }
@@ -72,7 +88,71 @@ fn generate_api_key() -> String {
 /// Shared state for the SSE server
 struct AppState {
     server: Arc<Mutex<McpServer>>,
+    browser: Arc<RwLock<BrowserState>>,
     api_key: String,
+    rate_limiter: Option<RateLimiter>,
+    /// Last health status observed while the server and browser were free
+    last_health: std::sync::Mutex<HealthStatus>,
+}
+
+impl AppState {
+    /// Current health status, without waiting on in-flight requests.
+    ///
+    /// While the server or browser is busy with a request, the last observed
+    /// status is reported instead.
+    fn health(&self) -> HealthStatus {
+        let mut last = self
+            .last_health
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Ok(server) = self.server.try_lock()
+            && let Ok(browser) = self.browser.try_read()
+        {
+            *last = HealthStatus::new(server.is_initialized(), &browser);
+        }
+        last.clone()
+    }
+}
+
+/// Health check response body for `GET /health`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthStatus {
+    /// `"ok"`, or `"error"` if the browser failed to initialize
+    pub status: &'static str,
+
+    /// Whether the MCP handshake has completed
+    pub initialized: bool,
+
+    /// Whether the browser is launched and ready for tool calls
+    pub browser_ready: bool,
+
+    /// Initialization error, if the browser is in a failed state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HealthStatus {
+    /// Build a health status from server and browser state
+    #[must_use]
+    pub fn new(initialized: bool, browser: &BrowserState) -> Self {
+        let error = browser.init_error().map(ToString::to_string);
+        Self {
+            status: if error.is_some() { "error" } else { "ok" },
+            initialized,
+            browser_ready: browser.is_initialized(),
+            error,
+        }
+    }
+
+    /// HTTP status code for this health status: 200, or 503 if the browser failed
+    #[must_use]
+    pub const fn http_status(&self) -> StatusCode {
+        if self.error.is_some() {
+            StatusCode::SERVICE_UNAVAILABLE
+        } else {
+            StatusCode::OK
+        }
+    }
 }
 
 /// SSE transport for MCP communication.
@@ -80,8 +160,11 @@ struct AppState {
 /// Runs an HTTP server with endpoints for MCP communication:
 /// - `GET /mcp` - SSE connection for server-initiated messages
 /// - `POST /mcp` - JSON-RPC requests
+/// - `GET /health` - Health check for load balancers and orchestrators
 ///
 /// Authentication is via Bearer token in the Authorization header.
+/// Authenticated requests are rate limited per client IP address. The health
+/// endpoint does not require authentication and is not rate limited.
 ///
 /// # Examples
 ///
@@ -105,15 +188,18 @@ struct AppState {
 pub struct SseTransport {
     config: SseConfig,
     server: Arc<Mutex<McpServer>>,
+    browser: Arc<RwLock<BrowserState>>,
 }
 
 impl SseTransport {
     /// Create a new SSE transport
     #[must_use]
     pub fn new(server: McpServer, config: SseConfig) -> Self {
+        let browser = Arc::clone(server.browser_state());
         Self {
             config,
             server: Arc::new(Mutex::new(server)),
+            browser,
         }
     }
 
@@ -129,15 +215,7 @@ impl SseTransport {
     ///
     /// Returns an error if the server fails to bind or start.
     pub async fn run(&self) -> super::Result<()> {
-        let state = Arc::new(AppState {
-            server: Arc::clone(&self.server),
-            api_key: self.config.api_key.clone(),
-        });
-
-        let app = Router::new()
-            .route("/mcp", get(handle_sse))
-            .route("/mcp", post(handle_post))
-            .with_state(state);
+        let app = self.router();
 
         let addr = format!("0.0.0.0:{}", self.config.port);
         let listener = tokio::net::TcpListener::bind(&addr)
@@ -147,12 +225,78 @@ impl SseTransport {
         tracing::info!(port = self.config.port, "SSE server listening");
         tracing::info!(api_key = %self.config.api_key, "API key for authentication");
 
-        axum::serve(listener, app)
-            .await
-            .map_err(|e| TransportError::Io(std::io::Error::other(e.to_string())))?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .map_err(|e| TransportError::Io(std::io::Error::other(e.to_string())))?;
 
         Ok(())
     }
+
+    /// Build the HTTP router with all endpoints and middleware
+    pub(super) fn router(&self) -> Router {
+        let state = Arc::new(AppState {
+            server: Arc::clone(&self.server),
+            browser: Arc::clone(&self.browser),
+            api_key: self.config.api_key.clone(),
+            rate_limiter: self.config.max_requests_per_second.map(RateLimiter::new),
+            last_health: std::sync::Mutex::new(HealthStatus {
+                status: "ok",
+                initialized: false,
+                browser_ready: false,
+                error: None,
+            }),
+        });
+
+        // Layers run outside in, so requests are authenticated before they
+        // count against their client's rate limit
+        Router::new()
+            .route("/mcp", get(handle_sse))
+            .route("/mcp", post(handle_post))
+            .route_layer(middleware::from_fn_with_state(
+                Arc::clone(&state),
+                rate_limit,
+            ))
+            .route_layer(middleware::from_fn_with_state(
+                Arc::clone(&state),
+                authenticate,
+            ))
+            .route("/health", get(handle_health))
+            .with_state(state)
+    }
+}
+
+/// Reject requests that fail [`validate_auth`]
+async fn authenticate(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Err(rejection) = validate_auth(request.headers(), &state.api_key) {
+        return rejection.into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Reject requests with `429 Too Many Requests` once their client exceeds
+/// the rate limit
+async fn rate_limit(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    if let Some(limiter) = &state.rate_limiter {
+        let client = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0.ip());
+        if !limiter.try_acquire(client) {
+            let client = client.map_or_else(|| "unknown".to_string(), |ip| ip.to_string());
+            tracing::warn!(client = %client, "Rate limit exceeded, rejecting request");
+            return (StatusCode::TOO_MANY_REQUESTS, "Rate limit exceeded").into_response();
+        }
+    }
+
+    next.run(request).await
 }
 
 /// Validate the Authorization header
@@ -177,13 +321,7 @@ fn validate_auth(
 }
 
 /// Handle SSE connection
-async fn handle_sse(
-    State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
-) -> Result<impl IntoResponse, Response> {
-    validate_auth(&headers, &state.api_key)
-        .map_err(|(status, msg)| (status, msg).into_response())?;
-
+async fn handle_sse() -> impl IntoResponse {
     let (tx, rx) = tokio::sync::mpsc::channel::<Result<Event, Infallible>>(100);
 
     // Send initial connection event
@@ -191,25 +329,41 @@ async fn handle_sse(
         .send(Ok(Event::default().event("connected").data("ok")))
         .await;
 
-    Ok(Sse::new(ReceiverStream::new(rx)).keep_alive(KeepAlive::default()))
+    Sse::new(ReceiverStream::new(rx)).keep_alive(KeepAlive::default())
 }
 
 /// Handle POST requests (JSON-RPC over HTTP)
+///
+/// Notifications are acknowledged with `202 Accepted` and no body.
 async fn handle_post(
     State(state): State<Arc<AppState>>,
-    headers: HeaderMap,
     Json(request): Json<JsonRpcRequest>,
-) -> Result<Json<JsonRpcResponse>, Response> {
-    validate_auth(&headers, &state.api_key)
-        .map_err(|(status, msg)| (status, msg).into_response())?;
+) -> Response {
+    let mut server = state.server.lock().await;
+
+    if request.is_notification() {
+        server.handle_notification(&request.method, &request.params);
+        return StatusCode::ACCEPTED.into_response();
+    }
 
     let request_id = request.id.clone().unwrap_or(serde_json::Value::Null);
-    let mut server = state.server.lock().await;
 
     let response = match server.handle_request(&request).await {
         Ok(result) => JsonRpcResponse::success(request_id, result),
         Err(e) => JsonRpcResponse::from_error(request_id, &e),
     };
 
-    Ok(Json(response))
+    Json(response).into_response()
+}
+
+/// Handle health checks
+///
+/// Returns `200 OK` with a [`HealthStatus`] body, or `503 Service Unavailable`
+/// if the browser failed to initialize. No authentication is required so
+/// that orchestrator probes can reach it, and the probe never waits for a
+/// running tool call.
+async fn handle_health(State(state): State<Arc<AppState>>) -> Response {
+    let health = state.health();
+
+    (health.http_status(), Json(health)).into_response()
 }
@@ -88,6 +88,13 @@ impl StdioTransport {
                 }
             };
 
+            // Notifications are processed without sending a response
+            if request.is_notification() {
+                let mut server = self.server.lock().await;
+                server.handle_notification(&request.method, &request.params);
+                continue;
+            }
+
             // Handle the request
             let request_id = request.id.clone().unwrap_or(serde_json::Value::Null);
             let mut server = self.server.lock().await;
@@ -97,13 +104,10 @@ impl StdioTransport {
                 Err(e) => JsonRpcResponse::from_error(request_id, &e),
             };
 
-            // Skip response for notifications (no id)
-            if request.id.is_some() {
-                let response_json = serde_json::to_string(&response)?;
-                stdout.write_all(response_json.as_bytes()).await?;
-                stdout.write_all(b"\n").await?;
-                stdout.flush().await?;
-            }
+            let response_json = serde_json::to_string(&response)?;
+            stdout.write_all(response_json.as_bytes()).await?;
+            stdout.write_all(b"\n").await?;
+            stdout.flush().await?;
         }
 
         Ok(())
@@ -1,8 +1,17 @@
 //! Tests for SSE transport and authentication
 
-use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use std::net::SocketAddr;
 
-use crate::transport::SseConfig;
+use axum::Router;
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, HeaderValue, Request, StatusCode};
+use serde_json::json;
+use tower::ServiceExt;
+
+use crate::browser::{BrowserConfig, BrowserState};
+use crate::server::{McpServer, ServerConfig};
+use crate::transport::{HealthStatus, SseConfig, SseTransport};
 
 #[test]
 fn test_sse_config_auto_generated_key() {
@@ -21,6 +30,16 @@ fn test_sse_config_custom_key() {
     assert_eq!(config.api_key, "my-secret-key");
 }
 
+#[test]
+fn test_sse_config_rate_limit_disabled_by_default() {
+    assert!(SseConfig::new(8080).max_requests_per_second.is_none());
+    assert!(
+        SseConfig::with_api_key(8080, "key")
+            .max_requests_per_second
+            .is_none()
+    );
+}
+
 #[test]
 fn test_sse_config_unique_keys() {
     let config1 = SseConfig::new(8080);
@@ -100,3 +119,155 @@ fn test_auth_invalid_key() {
     let (status, _) = result.unwrap_err();
     assert_eq!(status, StatusCode::FORBIDDEN);
 }
+
+#[test]
+fn test_health_status_before_browser_launch() {
+    let browser = BrowserState::new(BrowserConfig::default());
+
+    let health = HealthStatus::new(true, &browser);
+
+    assert_eq!(health.http_status(), StatusCode::OK);
+    assert_eq!(
+        serde_json::to_value(&health).unwrap(),
+        json!({ "status": "ok", "initialized": true, "browser_ready": false })
+    );
+}
+
+#[tokio::test]
+async fn test_health_status_failed_browser() {
+    let config = BrowserConfig {
+        cdp_endpoint: Some("ws://127.0.0.1:1".to_string()),
+        ..Default::default()
+    };
+    let mut browser = BrowserState::new(config);
+    assert!(browser.initialize().await.is_err());
+
+    let health = HealthStatus::new(false, &browser);
+
+    assert_eq!(health.http_status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(health.status, "error");
+    assert!(!health.browser_ready);
+    assert!(health.error.is_some());
+}
+
+#[tokio::test]
+async fn test_health_does_not_wait_for_busy_browser() {
+    let server = McpServer::new(ServerConfig::default());
+    let browser = std::sync::Arc::clone(server.browser_state());
+    let router = SseTransport::new(server, SseConfig::with_api_key(8080, "key")).router();
+
+    // Simulate a long-running tool call holding the browser
+    let _busy = browser.write().await;
+
+    let request = Request::get("/health").body(Body::empty()).unwrap();
+    let response = tokio::time::timeout(std::time::Duration::from_secs(1), router.oneshot(request))
+        .await
+        .expect("health should answer while the browser is busy")
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Build a `tools/list` request with the given API key, optionally from a client address
+fn tools_list_request(api_key: &str, client: Option<[u8; 4]>) -> Request<Body> {
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/list" }).to_string();
+    let mut request = Request::post("/mcp")
+        .header("authorization", format!("Bearer {api_key}"))
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap();
+    if let Some(ip) = client {
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from((ip, 40000))));
+    }
+    request
+}
+
+/// Send `count` requests through a router, returning their statuses
+async fn send(
+    router: &Router,
+    api_key: &str,
+    client: Option<[u8; 4]>,
+    count: usize,
+) -> Vec<StatusCode> {
+    let mut statuses = Vec::with_capacity(count);
+    for _ in 0..count {
+        let request = tools_list_request(api_key, client);
+        let response = router.clone().oneshot(request).await.unwrap();
+        statuses.push(response.status());
+    }
+    statuses
+}
+
+/// Send `count` authenticated `tools/list` requests through a router, returning their statuses
+async fn send_requests(config: SseConfig, count: usize) -> Vec<StatusCode> {
+    let api_key = config.api_key.clone();
+    let transport = SseTransport::new(McpServer::new(ServerConfig::default()), config);
+    send(&transport.router(), &api_key, None, count).await
+}
+
+/// Router limiting each client to 5 requests per second
+fn rate_limited_router() -> Router {
+    let mut config = SseConfig::with_api_key(8080, "key");
+    config.max_requests_per_second = Some(5);
+    SseTransport::new(McpServer::new(ServerConfig::default()), config).router()
+}
+
+#[tokio::test]
+async fn test_rate_limit_rejects_flood() {
+    let mut config = SseConfig::with_api_key(8080, "key");
+    config.max_requests_per_second = Some(20);
+
+    let statuses = send_requests(config, 200).await;
+
+    let limited = statuses
+        .iter()
+        .filter(|status| **status == StatusCode::TOO_MANY_REQUESTS)
+        .count();
+    assert!(limited > 0, "Some requests should be rate limited");
+    assert!(limited < 200, "The initial burst should be allowed");
+    assert_eq!(statuses[0], StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_rate_limit_is_per_client() {
+    let router = rate_limited_router();
+
+    let flooding = send(&router, "key", Some([10, 0, 0, 1]), 50).await;
+    assert!(flooding.contains(&StatusCode::TOO_MANY_REQUESTS));
+
+    let other = send(&router, "key", Some([10, 0, 0, 2]), 1).await;
+    assert_eq!(
+        other,
+        vec![StatusCode::OK],
+        "Other clients keep their own budget"
+    );
+}
+
+#[tokio::test]
+async fn test_rate_limit_counts_only_authenticated_requests() {
+    let router = rate_limited_router();
+
+    let rejected = send(&router, "wrong-key", Some([10, 0, 0, 1]), 50).await;
+    assert!(
+        rejected
+            .iter()
+            .all(|status| *status == StatusCode::FORBIDDEN),
+        "Unauthenticated requests are rejected before rate limiting: {rejected:?}"
+    );
+
+    let statuses = send(&router, "key", Some([10, 0, 0, 1]), 1).await;
+    assert_eq!(statuses, vec![StatusCode::OK]);
+}
+
+#[tokio::test]
+async fn test_no_rate_limit_by_default() {
+    let statuses = send_requests(SseConfig::with_api_key(8080, "key"), 200).await;
+
+    assert!(
+        statuses
+            .iter()
+            .all(|status| *status != StatusCode::TOO_MANY_REQUESTS)
+    );
+}
@@ -9,6 +9,7 @@
 #![cfg(feature = "integration")]
 
 mod browser_integration {
+    pub mod export_tests;
     pub mod snapshot_tests;
     pub mod state_tests;
 
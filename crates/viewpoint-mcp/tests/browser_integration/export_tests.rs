@@ -0,0 +1,89 @@
+//! Browser state JSON export and import tests
+
+use super::headless_config;
+use viewpoint_mcp::browser::{BrowserState, StateSnapshot};
+
+#[tokio::test]
+async fn test_browser_state_snapshot_round_trip() {
+    let first_url = "data:text/html,<h1>First</h1>";
+    let second_url = "data:text/html,<h1>Second</h1>";
+    let other_url = "data:text/html,<h1>Other</h1>";
+
+    let mut state = BrowserState::new(headless_config());
+    state.initialize().await.unwrap();
+
+    // default: two tabs, first one active
+    {
+        let ctx = state.active_context_mut().unwrap();
+        let page = ctx.active_page().await.unwrap().unwrap();
+        page.goto(first_url).goto().await.unwrap();
+        let page = ctx.new_page().await.unwrap();
+        page.goto(second_url).goto().await.unwrap();
+        assert!(ctx.switch_page(0).await);
+    }
+
+    // other: one tab, left as the active context
+    state.create_context("other").await.unwrap();
+    {
+        let ctx = state.active_context_mut().unwrap();
+        let page = ctx.active_page().await.unwrap().unwrap();
+        page.goto(other_url).goto().await.unwrap();
+    }
+
+    let json = state.to_snapshot_json().await;
+    state.shutdown().await;
+
+    let snapshot: StateSnapshot = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(snapshot.active_context, "other");
+    assert_eq!(snapshot.contexts.len(), 2);
+    assert_eq!(snapshot.contexts[0].name, "default");
+    assert_eq!(snapshot.contexts[0].tabs.len(), 2);
+    assert_eq!(snapshot.contexts[0].active_tab, 0);
+
+    // Restore into a fresh browser
+    let mut restored = BrowserState::new(headless_config());
+    restored.restore_from_snapshot(&json).await.unwrap();
+
+    assert_eq!(restored.active_context_name(), "other");
+    assert_eq!(restored.to_snapshot_json().await, json);
+
+    let default = restored.get_context("default").unwrap();
+    let pages = default.pages().await.unwrap();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1].url().await.unwrap(), second_url);
+
+    restored.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_restore_snapshot_keeps_blank_tabs_in_place() {
+    let loaded_url = "data:text/html,<h1>Loaded</h1>";
+    let second_url = "data:text/html,<h1>Second</h1>";
+
+    let mut state = BrowserState::new(headless_config());
+    state.initialize().await.unwrap();
+    {
+        let ctx = state.active_context_mut().unwrap();
+        let page = ctx.active_page().await.unwrap().unwrap();
+        page.goto(loaded_url).goto().await.unwrap();
+    }
+
+    let json = serde_json::json!({
+        "activeContext": "default",
+        "contexts": [{
+            "name": "default",
+            "activeTab": 1,
+            "tabs": [{ "url": "about:blank" }, { "url": second_url }]
+        }]
+    });
+    state.restore_from_snapshot(&json).await.unwrap();
+
+    let ctx = state.get_context("default").unwrap();
+    let pages = ctx.pages().await.unwrap();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].url().await.unwrap(), "about:blank");
+    assert_eq!(pages[1].url().await.unwrap(), second_url);
+    assert_eq!(ctx.active_page_index().await, 1);
+
+    state.shutdown().await;
+}
@@ -2,7 +2,7 @@
 
 use super::headless_config;
 use viewpoint_mcp::browser::BrowserState;
-use viewpoint_mcp::snapshot::{AccessibilitySnapshot, SnapshotOptions};
+use viewpoint_mcp::snapshot::{AccessibilitySnapshot, SnapshotElement, SnapshotOptions};
 
 #[tokio::test]
 async fn test_accessibility_snapshot_basic() {
@@ -239,3 +239,144 @@ async fn test_accessibility_snapshot_compact_mode() {
 
     state.shutdown().await;
 }
+
+/// Collect the `aria-expanded` attribute values in document order
+fn collect_expanded(element: &SnapshotElement, values: &mut Vec<String>) {
+    if let Some(value) = element.attribute("aria-expanded") {
+        values.push(value.to_string());
+    }
+    for child in &element.children {
+        collect_expanded(child, values);
+    }
+}
+
+#[tokio::test]
+async fn test_accessibility_snapshot_aria_expanded_attribute() {
+    let config = headless_config();
+    let mut state = BrowserState::new(config);
+
+    state
+        .initialize()
+        .await
+        .expect("Failed to initialize browser");
+
+    let ctx = state.active_context().expect("Should have context");
+    let page = ctx
+        .active_page()
+        .await
+        .expect("Failed to get active page")
+        .expect("Should have page");
+
+    page.set_content(
+        r#"
+        <html><body>
+            <details><summary>Closed section</summary><p>Hidden</p></details>
+            <details open><summary>Open section</summary><p>Visible</p></details>
+        </body></html>
+    "#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let snapshot = AccessibilitySnapshot::capture(&page, SnapshotOptions::default())
+        .await
+        .expect("Failed to capture snapshot");
+
+    let mut values = Vec::new();
+    collect_expanded(snapshot.root(), &mut values);
+
+    assert_eq!(
+        values,
+        vec!["false".to_string(), "true".to_string()],
+        "Collapsed <details> should be aria-expanded=false and open one true"
+    );
+
+    let formatted = snapshot.format();
+    assert!(formatted.contains(r#"aria-expanded="false""#));
+    assert!(formatted.contains(r#"aria-expanded="true""#));
+
+    state.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_accessibility_snapshot_deterministic_refs_stable_across_reloads() {
+    let config = headless_config();
+    let mut state = BrowserState::new(config);
+
+    state
+        .initialize()
+        .await
+        .expect("Failed to initialize browser");
+
+    let ctx = state.active_context().expect("Should have context");
+    let page = ctx
+        .active_page()
+        .await
+        .expect("Failed to get active page")
+        .expect("Should have page");
+
+    let url = "data:text/html,<html><body><button>Save</button><a href='/x'>Docs</a></body></html>";
+    let options = SnapshotOptions {
+        deterministic: true,
+        ..Default::default()
+    };
+
+    page.goto(url).goto().await.expect("Failed to navigate");
+    let first = AccessibilitySnapshot::capture(&page, options.clone())
+        .await
+        .expect("Failed to capture snapshot");
+
+    page.goto(url).goto().await.expect("Failed to navigate");
+    let second = AccessibilitySnapshot::capture(&page, options)
+        .await
+        .expect("Failed to capture snapshot");
+
+    assert!(first.format().contains("[ref="));
+    assert_eq!(first.format(), second.format());
+
+    state.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_accessibility_snapshot_dom_aria_attributes() {
+    let config = headless_config();
+    let mut state = BrowserState::new(config);
+
+    state
+        .initialize()
+        .await
+        .expect("Failed to initialize browser");
+
+    let ctx = state.active_context().expect("Should have context");
+    let page = ctx
+        .active_page()
+        .await
+        .expect("Failed to get active page")
+        .expect("Should have page");
+
+    page.set_content(
+        r#"
+        <html><body>
+            <button aria-haspopup="menu">Options</button>
+            <a href="/home" aria-current="page">Home</a>
+            <input aria-label="Email" aria-invalid="true" required>
+        </body></html>
+    "#,
+    )
+    .set()
+    .await
+    .expect("Failed to set content");
+
+    let snapshot = AccessibilitySnapshot::capture(&page, SnapshotOptions::default())
+        .await
+        .expect("Failed to capture snapshot");
+
+    let formatted = snapshot.format();
+    assert!(formatted.contains(r#"aria-haspopup="menu""#), "{formatted}");
+    assert!(formatted.contains(r#"aria-current="page""#), "{formatted}");
+    assert!(formatted.contains(r#"aria-invalid="true""#), "{formatted}");
+    assert!(formatted.contains(r#"aria-required="true""#), "{formatted}");
+
+    state.shutdown().await;
+}
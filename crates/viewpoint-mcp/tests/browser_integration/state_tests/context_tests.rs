@@ -1,41 +1,8 @@
-//! Browser state and context management tests
+//! Browser context creation, isolation, and switching tests
 
-use super::headless_config;
-use tempfile::TempDir;
+use crate::browser_integration::headless_config;
 use viewpoint_mcp::browser::{BrowserConfig, BrowserState};
 
-#[tokio::test]
-async fn test_browser_initialize_and_shutdown() {
-    let config = headless_config();
-    let mut state = BrowserState::new(config);
-
-    // Should not be initialized yet
-    assert!(!state.is_initialized());
-    assert!(state.browser().is_none());
-
-    // Initialize browser
-    state
-        .initialize()
-        .await
-        .expect("Failed to initialize browser");
-
-    // Should be initialized now
-    assert!(state.is_initialized());
-    assert!(state.browser().is_some());
-
-    // Default context should exist
-    let ctx = state.active_context().expect("Should have active context");
-    assert_eq!(ctx.name, "default");
-    assert!(ctx.page_count().await.unwrap() > 0);
-
-    // Shutdown
-    state.shutdown().await;
-
-    // Should not be initialized after shutdown
-    assert!(!state.is_initialized());
-    assert!(state.browser().is_none());
-}
-
 #[tokio::test]
 async fn test_browser_multi_context_creation() {
     let config = headless_config();
@@ -147,67 +114,6 @@ async fn test_browser_close_active_context_fallback() {
     state.shutdown().await;
 }
 
-#[tokio::test]
-async fn test_browser_user_data_dir_persistence() {
-    // Create a temporary directory for user data
-    let temp_dir = TempDir::new().expect("Failed to create temp dir");
-    let user_data_path = temp_dir.path().to_path_buf();
-
-    // First session: initialize browser with user data dir
-    {
-        let config = BrowserConfig {
-            headless: true,
-            user_data_dir: Some(user_data_path.clone()),
-            ..Default::default()
-        };
-        let mut state = BrowserState::new(config);
-
-        state
-            .initialize()
-            .await
-            .expect("Failed to initialize browser");
-
-        // Verify browser started with the user data dir
-        assert!(state.is_initialized());
-
-        // The user data directory should now contain browser profile data
-        // (Chrome creates various subdirectories)
-        state.shutdown().await;
-    }
-
-    // Verify some profile data was created
-    assert!(user_data_path.exists());
-    // Chrome typically creates a Default directory or similar
-    let has_profile_data = std::fs::read_dir(&user_data_path)
-        .map(|entries| entries.count() > 0)
-        .unwrap_or(false);
-    assert!(
-        has_profile_data,
-        "User data directory should contain profile data after browser session"
-    );
-
-    // Second session: re-use the same user data dir
-    {
-        let config = BrowserConfig {
-            headless: true,
-            user_data_dir: Some(user_data_path.clone()),
-            ..Default::default()
-        };
-        let mut state = BrowserState::new(config);
-
-        // Should be able to initialize with existing profile
-        state
-            .initialize()
-            .await
-            .expect("Failed to initialize browser with existing profile");
-
-        assert!(state.is_initialized());
-        state.shutdown().await;
-    }
-
-    // temp_dir will be cleaned up when it goes out of scope
-}
-
 #[tokio::test]
 async fn test_browser_switch_nonexistent_context_error() {
     let config = headless_config();
@@ -251,83 +157,6 @@ async fn test_browser_create_duplicate_context_error() {
     state.shutdown().await;
 }
 
-#[tokio::test]
-async fn test_browser_headless_mode() {
-    // Explicitly test headless mode
-    let config = BrowserConfig {
-        headless: true,
-        ..Default::default()
-    };
-    let mut state = BrowserState::new(config);
-
-    state
-        .initialize()
-        .await
-        .expect("Failed to initialize headless browser");
-    assert!(state.is_initialized());
-
-    // Browser should be running in headless mode
-    // (No visible window, but still functional)
-    let ctx = state.active_context().expect("Should have context");
-    assert!(ctx.page_count().await.unwrap() > 0);
-
-    state.shutdown().await;
-}
-
-#[tokio::test]
-async fn test_browser_cdp_endpoint_connection() {
-    use std::process::{Command, Stdio};
-    use std::time::Duration;
-
-    // Find an available port for CDP
-    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("Failed to bind");
-    let cdp_port = listener.local_addr().unwrap().port();
-    drop(listener); // Release the port
-
-    // Launch Chrome with remote debugging enabled
-    let mut chrome_process = Command::new("chromium")
-        .args([
-            "--headless",
-            "--disable-gpu",
-            "--no-sandbox",
-            "--disable-dev-shm-usage",
-            &format!("--remote-debugging-port={cdp_port}"),
-            "about:blank",
-        ])
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()
-        .expect("Failed to launch Chrome with CDP");
-
-    // Give Chrome time to start and open the debugging port
-    tokio::time::sleep(Duration::from_secs(2)).await;
-
-    // Now connect to Chrome via CDP endpoint
-    let config = BrowserConfig {
-        headless: true,
-        cdp_endpoint: Some(format!("http://127.0.0.1:{cdp_port}")),
-        ..Default::default()
-    };
-    let mut state = BrowserState::new(config);
-
-    // Should connect successfully
-    let result = state.initialize().await;
-
-    // Clean up Chrome process regardless of result
-    let _ = chrome_process.kill();
-    let _ = chrome_process.wait();
-
-    // Now check the result
-    result.expect("Failed to connect to Chrome via CDP endpoint");
-    assert!(state.is_initialized());
-
-    // Should have a working browser
-    let ctx = state.active_context().expect("Should have context");
-    assert!(ctx.page_count().await.unwrap() > 0);
-
-    state.shutdown().await;
-}
-
 #[tokio::test]
 async fn test_browser_context_with_proxy_config() {
     use std::sync::Arc;
@@ -0,0 +1,102 @@
+//! Script execution and injection across pages tests
+
+use crate::browser_integration::headless_config;
+use viewpoint_mcp::browser::BrowserState;
+
+#[tokio::test]
+async fn test_browser_execute_script_on_all_pages() {
+    let config = headless_config();
+    let mut state = BrowserState::new(config);
+
+    state
+        .initialize()
+        .await
+        .expect("Failed to initialize browser");
+
+    // Second tab in default context plus a second context
+    state
+        .active_context_mut()
+        .expect("Should have context")
+        .new_page()
+        .await
+        .expect("Failed to create page");
+    state
+        .create_context("other")
+        .await
+        .expect("Failed to create context");
+
+    let results = state
+        .execute_script_on_all_pages("1 + 1".to_string())
+        .await
+        .expect("Script should run on all pages");
+
+    let keys: Vec<&str> = results.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec!["default::0", "default::1", "other::0"]);
+    assert!(
+        results
+            .iter()
+            .all(|(_, value)| *value == serde_json::json!(2))
+    );
+
+    state.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_browser_inject_script_on_new_page() {
+    let config = headless_config();
+    let mut state = BrowserState::new(config);
+
+    state
+        .initialize()
+        .await
+        .expect("Failed to initialize browser");
+
+    state
+        .inject_script_on_new_page("window.__injected = 'yes';".to_string())
+        .await
+        .expect("Failed to inject script");
+    assert_eq!(state.init_scripts().len(), 1);
+
+    // Pages created after injection, in existing and new contexts, get the script
+    state
+        .active_context_mut()
+        .expect("Should have context")
+        .new_page()
+        .await
+        .expect("Failed to create page");
+    state
+        .create_context("later")
+        .await
+        .expect("Failed to create context");
+
+    // Init scripts run when a new document loads, so load one in each new page
+    for name in ["default", "later"] {
+        state.switch_context(name).expect("Context should exist");
+        let page = state
+            .active_context()
+            .expect("Should have context")
+            .active_page()
+            .await
+            .expect("Failed to get active page")
+            .expect("Should have page");
+        page.goto("data:text/html,<p>loaded</p>")
+            .goto()
+            .await
+            .expect("Failed to navigate");
+    }
+
+    let results = state
+        .execute_script_on_all_pages("window.__injected ?? null".to_string())
+        .await
+        .expect("Script should run on all pages");
+
+    let injected: Vec<&str> = results
+        .iter()
+        .filter(|(_, value)| *value == serde_json::json!("yes"))
+        .map(|(key, _)| key.as_str())
+        .collect();
+    assert!(injected.contains(&"default::1"));
+    assert!(injected.contains(&"later::0"));
+
+    state.shutdown().await;
+}
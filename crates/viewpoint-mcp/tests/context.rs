@@ -9,6 +9,8 @@
 mod context {
     pub mod close_tests;
     pub mod create_tests;
+    pub mod har_tests;
+    pub mod headers_tests;
     pub mod integration_tests;
     pub mod list_tests;
     pub mod storage_tests;
@@ -2,7 +2,7 @@
 
 use serde_json::json;
 use tempfile::TempDir;
-use viewpoint_mcp::tools::{BrowserContextCreateTool, Tool};
+use viewpoint_mcp::tools::{BrowserContextCreateTool, BrowserEvaluateTool, Tool};
 
 use super::create_browser;
 
@@ -117,3 +117,124 @@ async fn test_context_create_switches_to_new() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_context_create_with_user_agent() {
+    let mut browser = create_browser().await;
+    let tool = BrowserContextCreateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let user_agent = "ViewpointTestAgent/1.0";
+    let output = tool
+        .execute(
+            &json!({ "name": "ua_context", "userAgent": user_agent }),
+            &mut browser,
+        )
+        .await
+        .expect("Create context with user agent should succeed");
+    assert!(output.contains(user_agent));
+
+    let result = eval_tool
+        .execute(
+            &json!({ "function": "() => navigator.userAgent" }),
+            &mut browser,
+        )
+        .await
+        .expect("Evaluate should succeed");
+    assert!(
+        result.contains(user_agent),
+        "navigator.userAgent should be overridden: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_context_create_with_locale_and_timezone() {
+    let mut browser = create_browser().await;
+    let tool = BrowserContextCreateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let output = tool
+        .execute(
+            &json!({ "name": "german", "locale": "de-DE", "timezone": "Europe/Berlin" }),
+            &mut browser,
+        )
+        .await
+        .expect("Create context with locale should succeed");
+    assert!(output.contains("de-DE"));
+    assert!(output.contains("Europe/Berlin"));
+
+    let result = eval_tool
+        .execute(
+            &json!({ "function": "() => new Intl.DateTimeFormat().resolvedOptions().locale" }),
+            &mut browser,
+        )
+        .await
+        .expect("Evaluate should succeed");
+    assert!(
+        result.contains("de-DE"),
+        "Intl locale should be de-DE: {result:?}"
+    );
+
+    let result = eval_tool
+        .execute(
+            &json!({ "function": "() => Intl.DateTimeFormat().resolvedOptions().timeZone" }),
+            &mut browser,
+        )
+        .await
+        .expect("Evaluate should succeed");
+    assert!(
+        result.contains("Europe/Berlin"),
+        "Intl timezone should be Europe/Berlin: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_context_create_with_viewport() {
+    let mut browser = create_browser().await;
+    let tool = BrowserContextCreateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let output = tool
+        .execute(
+            &json!({ "name": "mobile", "viewport": { "width": 390, "height": 844 } }),
+            &mut browser,
+        )
+        .await
+        .expect("Create context with viewport should succeed");
+    assert!(output.contains("390x844"), "Got: {output:?}");
+
+    let result = eval_tool
+        .execute(
+            &json!({ "function": "() => `${window.innerWidth}x${window.innerHeight}`" }),
+            &mut browser,
+        )
+        .await
+        .expect("Evaluate should succeed");
+    assert!(
+        result.contains("390x844"),
+        "Viewport should be 390x844: {result:?}"
+    );
+
+    // A second context gets its own viewport
+    tool.execute(
+        &json!({ "name": "desktop", "viewport": { "width": 1440, "height": 900 } }),
+        &mut browser,
+    )
+    .await
+    .expect("Create second context should succeed");
+
+    let result = eval_tool
+        .execute(
+            &json!({ "function": "() => window.innerWidth" }),
+            &mut browser,
+        )
+        .await
+        .expect("Evaluate should succeed");
+    assert!(result.contains("1440"), "Width should be 1440: {result:?}");
+
+    browser.shutdown().await;
+}
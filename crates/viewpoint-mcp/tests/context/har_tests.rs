@@ -0,0 +1,84 @@
+//! Tests for browser_context_import_har tool
+
+use serde_json::json;
+use viewpoint_mcp::tools::{
+    BrowserContextImportHarTool, BrowserEvaluateTool, BrowserNavigateTool, Tool,
+};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_import_har_replays_recorded_responses() {
+    let mut browser = create_browser().await;
+    let har_tool = BrowserContextImportHarTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    // The host does not exist, so the page can only load from the HAR
+    let har = json!({
+        "log": {
+            "version": "1.2",
+            "entries": [
+                {
+                    "request": { "method": "GET", "url": "http://har-replay.invalid/" },
+                    "response": {
+                        "status": 200,
+                        "headers": [{ "name": "Content-Type", "value": "text/html" }],
+                        "content": { "mimeType": "text/html", "text": "<h1 id='title'>Recorded</h1>" }
+                    }
+                },
+                {
+                    "request": { "method": "GET", "url": "http://other.invalid/" },
+                    "response": { "status": 200, "content": { "text": "skipped" } }
+                }
+            ]
+        }
+    });
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("recording.har");
+    std::fs::write(&path, har.to_string()).unwrap();
+
+    let output = har_tool
+        .execute(
+            &json!({ "path": path.to_str().unwrap(), "urlFilter": "har-replay" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("\"routes\":1"), "Got: {output:?}");
+
+    nav_tool
+        .execute(
+            &json!({ "url": "http://har-replay.invalid/" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let title = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('title').textContent" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(title.contains("Recorded"), "Got: {title:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_import_har_missing_file() {
+    let mut browser = create_browser().await;
+    let har_tool = BrowserContextImportHarTool::new();
+
+    let result = har_tool
+        .execute(
+            &json!({ "path": "/nonexistent/recording.har" }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err());
+
+    browser.shutdown().await;
+}
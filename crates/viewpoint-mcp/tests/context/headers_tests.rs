@@ -0,0 +1,59 @@
+//! Tests for browser_set_extra_headers tool
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserSetExtraHeadersTool, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_set_extra_headers_merges() {
+    let mut browser = create_browser().await;
+    let headers_tool = BrowserSetExtraHeadersTool::new();
+
+    headers_tool
+        .execute(
+            &json!({ "headers": { "X-API-Key": "first" } }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    headers_tool
+        .execute(
+            &json!({ "headers": { "x-api-key": "second", "X-Trace": "abc" } }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let headers = browser.active_context().unwrap().extra_headers().clone();
+    assert_eq!(headers.len(), 2, "Got: {headers:?}");
+    assert_eq!(headers["x-api-key"], "second");
+    assert_eq!(headers["X-Trace"], "abc");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_set_extra_headers_empty_clears() {
+    let mut browser = create_browser().await;
+    let headers_tool = BrowserSetExtraHeadersTool::new();
+
+    headers_tool
+        .execute(
+            &json!({ "headers": { "X-API-Key": "secret" } }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = headers_tool
+        .execute(&json!({ "headers": {} }), &mut browser)
+        .await
+        .unwrap();
+
+    assert!(output.contains("Cleared"), "Got: {output:?}");
+    assert!(browser.active_context().unwrap().extra_headers().is_empty());
+
+    browser.shutdown().await;
+}
@@ -1,7 +1,9 @@
 //! Tests for browser_context_list tool
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserContextCreateTool, BrowserContextListTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserContextCreateTool, BrowserContextListTool, BrowserNavigateTool, BrowserTabsTool, Tool,
+};
 
 use super::create_browser;
 
@@ -65,3 +67,103 @@ async fn test_context_list_shows_active() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_context_list_sorted_by_creation_with_age() {
+    let mut browser = create_browser().await;
+    let create_tool = BrowserContextCreateTool::new();
+    let list_tool = BrowserContextListTool::new();
+
+    // Names deliberately not in alphabetical order
+    for name in ["zulu", "alpha", "mike"] {
+        create_tool
+            .execute(&json!({ "name": name }), &mut browser)
+            .await
+            .unwrap();
+    }
+
+    let names: Vec<&str> = browser
+        .list_contexts()
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["default", "zulu", "alpha", "mike"]);
+
+    let output = list_tool.execute(&json!({}), &mut browser).await.unwrap();
+    assert!(output.contains("createdAt"));
+    assert!(output.contains("s ago"));
+
+    let zulu = output.find("zulu").unwrap();
+    let alpha = output.find("alpha").unwrap();
+    let mike = output.find("mike").unwrap();
+    assert!(zulu < alpha && alpha < mike);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_context_list_shows_global_proxy_on_default_context() {
+    use viewpoint_mcp::browser::{BrowserConfig, BrowserState, ProxyConfig};
+
+    // The proxy is never contacted because no page navigates
+    let config = BrowserConfig {
+        headless: true,
+        proxy: Some(ProxyConfig::new("http://127.0.0.1:9").with_bypass("localhost")),
+        ..Default::default()
+    };
+    let mut browser = BrowserState::new(config);
+    browser
+        .initialize()
+        .await
+        .expect("Failed to initialize browser");
+    let list_tool = BrowserContextListTool::new();
+
+    let output = list_tool.execute(&json!({}), &mut browser).await.unwrap();
+    assert!(
+        output.contains("http://127.0.0.1:9"),
+        "Default context should report the global proxy. Got: {output:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_context_list_verbose_includes_tabs() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let list_tool = BrowserContextListTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<title>First</title>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    BrowserTabsTool::new()
+        .execute(&json!({ "action": "new" }), &mut browser)
+        .await
+        .unwrap();
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<title>Second</title>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = list_tool
+        .execute(&json!({ "verbose": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(output.contains("\"tabs\""), "Got: {output:?}");
+    assert!(output.contains("\"title\": \"First\""), "Got: {output:?}");
+    assert!(output.contains("\"title\": \"Second\""), "Got: {output:?}");
+    assert!(output.contains("\"tabIndex\": 1"), "Got: {output:?}");
+    assert!(output.contains("\"isActiveTab\": true"), "Got: {output:?}");
+
+    let output = list_tool.execute(&json!({}), &mut browser).await.unwrap();
+    assert!(!output.contains("\"tabs\""), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
@@ -82,6 +82,38 @@ async fn test_context_save_storage_named_context() {
     browser.shutdown().await;
 }
 
+#[tokio::test]
+async fn test_context_save_storage_selective() {
+    let temp_dir = TempDir::new().unwrap();
+    let storage_path = temp_dir.path().join("selective_storage.json");
+
+    let mut browser = create_browser().await;
+    let save_tool = BrowserContextSaveStorageTool::new();
+
+    let output = save_tool
+        .execute(
+            &json!({
+                "path": storage_path.to_str().unwrap(),
+                "domains": ["example.com"],
+                "includeLocalStorage": false
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("\"cookies\":0"), "Got: {output:?}");
+
+    let content = std::fs::read_to_string(&storage_path).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert_eq!(parsed["origins"], json!([]));
+    assert_eq!(
+        parsed["exported"],
+        json!({ "cookies": true, "localStorage": false, "domains": ["example.com"] })
+    );
+
+    browser.shutdown().await;
+}
+
 #[tokio::test]
 async fn test_context_save_storage_nonexistent_context() {
     let temp_dir = TempDir::new().unwrap();
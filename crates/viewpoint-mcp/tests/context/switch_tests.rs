@@ -95,3 +95,63 @@ async fn test_context_switch_preserves_state() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_context_switch_by_index() {
+    let mut browser = create_browser().await;
+    let create_tool = BrowserContextCreateTool::new();
+    let switch_tool = BrowserContextSwitchTool::new();
+
+    for name in ["first", "second"] {
+        create_tool
+            .execute(&json!({ "name": name }), &mut browser)
+            .await
+            .unwrap();
+    }
+
+    // Contexts are indexed in creation order, starting with the default context
+    for (index, expected) in ["default", "first", "second"].iter().enumerate() {
+        switch_tool
+            .execute(&json!({ "index": index }), &mut browser)
+            .await
+            .unwrap();
+        assert_eq!(browser.active_context_name(), *expected);
+    }
+
+    // Name takes precedence over index
+    switch_tool
+        .execute(&json!({ "name": "first", "index": 2 }), &mut browser)
+        .await
+        .unwrap();
+    assert_eq!(browser.active_context_name(), "first");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_context_switch_index_errors() {
+    let mut browser = create_browser().await;
+    let switch_tool = BrowserContextSwitchTool::new();
+
+    let out_of_range = switch_tool
+        .execute(&json!({ "index": 5 }), &mut browser)
+        .await;
+    assert!(
+        matches!(
+            out_of_range,
+            Err(viewpoint_mcp::tools::ToolError::InvalidParams(_))
+        ),
+        "Out of range index should be InvalidParams: {out_of_range:?}"
+    );
+
+    let missing = switch_tool.execute(&json!({}), &mut browser).await;
+    assert!(
+        matches!(
+            missing,
+            Err(viewpoint_mcp::tools::ToolError::InvalidParams(_))
+        ),
+        "Missing name and index should be InvalidParams: {missing:?}"
+    );
+
+    browser.shutdown().await;
+}
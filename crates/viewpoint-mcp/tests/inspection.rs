@@ -7,11 +7,19 @@
 #![cfg(feature = "integration")]
 
 mod inspection {
+    pub mod clipboard_tests;
     pub mod console_network_tests;
+    pub mod evaluate_async_tests;
+    pub mod evaluate_chunk_tests;
+    pub mod evaluate_jquery_tests;
     pub mod evaluate_tests;
+    pub mod network_body_tests;
     pub mod screenshot_tests;
     pub mod snapshot_basic_tests;
     pub mod snapshot_cache_tests;
+    pub mod snapshot_expand_tests;
+    pub mod snapshot_focus_tests;
+    pub mod tree_diff_tests;
     pub mod wait_tests;
 
     use viewpoint_mcp::browser::{BrowserConfig, BrowserState};
@@ -0,0 +1,75 @@
+//! Clipboard integration tests
+//!
+//! The Clipboard API only works in secure contexts, so these tests serve the
+//! page from a local HTTP server on localhost instead of a `data:` URL.
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use viewpoint_mcp::tools::{BrowserClipboardTool, BrowserNavigateTool, ContentItem, Tool};
+
+use super::create_browser;
+
+/// Serve a blank HTML page on localhost and return its URL
+async fn serve_blank_page() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            let body = "<html><body>clipboard</body></html>";
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    format!("http://localhost:{port}/")
+}
+
+#[tokio::test]
+async fn test_clipboard_write_then_read() {
+    let mut browser = create_browser().await;
+    let url = serve_blank_page().await;
+    let clipboard_tool = BrowserClipboardTool::new();
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let text = "viewpoint clipboard round trip";
+    clipboard_tool
+        .execute(&json!({ "action": "write", "text": text }), &mut browser)
+        .await
+        .unwrap();
+
+    let output = clipboard_tool
+        .execute(&json!({ "action": "read" }), &mut browser)
+        .await
+        .unwrap();
+
+    let [ContentItem::Text { text: read_back }] = output.content.as_slice() else {
+        panic!("Expected a single text item, got: {output:?}");
+    };
+    assert_eq!(read_back, text);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_clipboard_write_requires_text() {
+    let mut browser = create_browser().await;
+
+    let result = BrowserClipboardTool::new()
+        .execute(&json!({ "action": "write" }), &mut browser)
+        .await;
+
+    assert!(result.is_err());
+
+    browser.shutdown().await;
+}
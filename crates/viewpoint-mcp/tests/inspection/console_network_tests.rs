@@ -1,6 +1,8 @@
 //! Console messages and network requests integration tests
 
 use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
 use viewpoint_mcp::tools::{
     BrowserConsoleMessagesTool, BrowserNavigateTool, BrowserNetworkRequestsTool, Tool,
 };
@@ -218,6 +220,44 @@ async fn test_console_messages_empty_page() {
     browser.shutdown().await;
 }
 
+#[tokio::test]
+async fn test_console_messages_json_format() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let console_tool = BrowserConsoleMessagesTool::new();
+
+    let html = r#"<script>console.warn('structured warning');</script>"#;
+
+    nav_tool
+        .execute(
+            &json!({ "url": format!("data:text/html,{}", html) }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+    let output = console_tool
+        .execute(&json!({ "format": "json" }), &mut browser)
+        .await
+        .unwrap();
+
+    assert!(output.contains("\"timestamp_ms\""), "Got: {output}");
+    assert!(output.contains("\"level\": \"warning\""), "Got: {output}");
+    assert!(output.contains("structured warning"), "Got: {output}");
+
+    // Omitting the format keeps the original JSON array output
+    let output = console_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(output.contains("\"type\": \"warning\""), "Got: {output}");
+    assert!(output.contains("\"lineNumber\""), "Got: {output}");
+
+    browser.shutdown().await;
+}
+
 #[tokio::test]
 async fn test_network_requests_basic() {
     let mut browser = create_browser().await;
@@ -262,3 +302,143 @@ async fn test_network_requests_include_static() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_network_requests_har_format() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let network_tool = BrowserNetworkRequestsTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>HAR</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = network_tool
+        .execute(
+            &json!({ "format": "har", "includeStatic": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(output.contains("\"version\": \"1.2\""), "Got: {output}");
+    assert!(output.contains("\"entries\""), "Got: {output}");
+    assert!(output.contains("\"viewpoint-mcp\""), "Got: {output}");
+
+    browser.shutdown().await;
+}
+
+/// Serve a page on localhost that keeps fetching `/late-N` after load
+async fn serve_late_fetch_page() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            let body = r"<html><body><script>
+                let remaining = 3;
+                const next = () => {
+                    if (remaining-- > 0) {
+                        fetch('/late-' + remaining).finally(() => setTimeout(next, 100));
+                    }
+                };
+                window.addEventListener('load', () => setTimeout(next, 100));
+            </script></body></html>";
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    format!("http://localhost:{port}/")
+}
+
+#[tokio::test]
+async fn test_network_requests_wait_for_idle() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let network_tool = BrowserNetworkRequestsTool::new();
+
+    let url = serve_late_fetch_page().await;
+    nav_tool
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let result = network_tool
+        .execute(
+            &json!({ "waitForIdle": true, "idleTimeoutMs": 300 }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "waitForIdle should succeed: {result:?}");
+    let output = result.unwrap();
+    assert!(
+        output.contains("late-0"),
+        "Should include requests issued after load. Got: {output:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+/// Serve a page whose `/slow` fetch takes a second to answer
+async fn serve_slow_fetch_page() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let body = if buf[..n].starts_with(b"GET /slow") {
+                    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                    "done"
+                } else {
+                    "<html><body><script>fetch('/slow');</script></body></html>"
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+
+    format!("http://localhost:{port}/")
+}
+
+#[tokio::test]
+async fn test_network_requests_wait_for_idle_waits_for_pending_request() {
+    let mut browser = create_browser().await;
+
+    let url = serve_slow_fetch_page().await;
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    // The quiet period is shorter than the pending request
+    let output = BrowserNetworkRequestsTool::new()
+        .execute(
+            &json!({ "waitForIdle": true, "idleTimeoutMs": 200 }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        output.contains("/slow"),
+        "Should wait for the in-flight request. Got: {output:?}"
+    );
+
+    browser.shutdown().await;
+}
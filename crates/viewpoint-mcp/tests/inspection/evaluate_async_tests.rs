@@ -0,0 +1,78 @@
+//! Evaluate tool integration tests for `awaitPromise`
+
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_evaluate_awaits_promise_by_default() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Test</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "async () => { await new Promise(r => setTimeout(r, 100)); return 'done'; }" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("done"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_evaluate_await_promise_false_returns_immediately() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Test</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let started = Instant::now();
+    let output = eval_tool
+        .execute(
+            &json!({
+                "function": "async () => { await new Promise(r => setTimeout(r, 3000)); window.__finished = true; return 'done'; }",
+                "awaitPromise": false
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        started.elapsed() < Duration::from_secs(2),
+        "Evaluate should not wait for the promise"
+    );
+    assert!(output.contains("<async, not awaited>"), "Got: {output:?}");
+
+    // The script was started but has not finished yet
+    let finished = eval_tool
+        .execute(
+            &json!({ "function": "() => String(window.__finished === true)" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(finished.contains("false"), "Got: {finished:?}");
+
+    browser.shutdown().await;
+}
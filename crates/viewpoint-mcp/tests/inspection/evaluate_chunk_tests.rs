@@ -0,0 +1,51 @@
+//! Evaluate result chunking integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, ContentItem, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_evaluate_splits_long_result_into_chunks() {
+    let mut browser = create_browser()
+        .await
+        .with_max_evaluate_result_length(Some(10));
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "() => 'abcdefghij'.repeat(2) + 'xyz'" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let texts: Vec<&str> = output
+        .content
+        .iter()
+        .map(|item| match item {
+            ContentItem::Text { text } => text.as_str(),
+            other => panic!("Expected text content, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(texts.len(), 4, "Got: {texts:?}");
+    assert!(
+        texts[0].contains("23 chars, split into 3 chunks"),
+        "Got: {texts:?}"
+    );
+    assert_eq!(texts[1], "Result (chunk 1/3):\nabcdefghij");
+    assert_eq!(texts[3], "Result (chunk 3/3):\nxyz");
+
+    // An explicit maxResultLength still truncates into a single item
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "() => 'abcdefghij'.repeat(2)", "maxResultLength": 5 }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert_eq!(output.content.len(), 1);
+    assert!(output.contains("abcde...[truncated"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
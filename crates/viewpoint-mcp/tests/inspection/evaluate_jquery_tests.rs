@@ -0,0 +1,71 @@
+//! Evaluate tool jQuery injection integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_evaluate_inject_jquery() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<ul><li>Apple</li><li>Pear</li></ul>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = eval_tool
+        .execute(
+            &json!({
+                "function": "() => $('li').map((i, el) => $(el).text()).get().join(',')",
+                "injectJQuery": true
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("Apple,Pear"), "Got: {output:?}");
+
+    // jQuery stays loaded for later calls on the same page
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "() => typeof $.fn.jquery" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("string"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_evaluate_inject_jquery_keeps_page_jquery() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<script>window.jQuery = window.$ = function () { return 'page jQuery'; };</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "() => $()", "injectJQuery": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("page jQuery"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
@@ -431,3 +431,43 @@ async fn test_evaluate_element_requires_element_description() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_evaluate_isolated_world_does_not_leak_globals() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<div id='shared'>DOM</div><script>window.pageGlobal = 'page';</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // The isolated world sees the DOM but not the page's globals
+    let isolated = eval_tool
+        .execute(
+            &json!({
+                "function": "() => { window.isolatedGlobal = 'leaked'; return [typeof window.pageGlobal, document.getElementById('shared').textContent].join(','); }",
+                "isolatedWorld": true
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(isolated.contains("undefined,DOM"), "Got: {isolated:?}");
+
+    // The global set in the isolated world is not visible from the main world
+    let main = eval_tool
+        .execute(
+            &json!({ "function": "() => typeof window.isolatedGlobal" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(main.contains("undefined"), "Got: {main:?}");
+
+    browser.shutdown().await;
+}
@@ -0,0 +1,187 @@
+//! Network request body capture integration tests
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserNetworkRequestsTool, Tool};
+
+use super::create_browser;
+
+/// Serve a page on localhost that posts `name=ada` to `/api` after load
+async fn serve_posting_page() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 2048];
+            let n = stream.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let (content_type, body) = if request.starts_with("POST /api") {
+                ("application/json", r#"{"saved":true}"#)
+            } else {
+                (
+                    "text/html",
+                    r"<html><body><script>
+                        fetch('/api', { method: 'POST', body: 'name=ada' });
+                    </script></body></html>",
+                )
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    format!("http://localhost:{port}/")
+}
+
+#[tokio::test]
+async fn test_network_requests_capture_body() {
+    let mut browser = create_browser().await;
+    let network_tool = BrowserNetworkRequestsTool::new();
+
+    // The first captureBody call turns capture on for later requests
+    let first = network_tool
+        .execute(&json!({ "captureBody": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        first.starts_with("Note: network capture started"),
+        "Should say capture just started. Got: {first:?}"
+    );
+
+    let url = serve_posting_page().await;
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let output = network_tool
+        .execute(
+            &json!({ "captureBody": true, "waitForIdle": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        output.starts_with("Warning: captureBody"),
+        "Should warn about memory usage. Got: {output:?}"
+    );
+    assert!(
+        output.contains("\"requestBody\": \"name=ada\""),
+        "Should include the request body. Got: {output:?}"
+    );
+    assert!(
+        output.contains(r#""responseBody": "{\"saved\":true}""#),
+        "Should include the response body. Got: {output:?}"
+    );
+
+    let plain = network_tool
+        .execute(&json!({ "waitForIdle": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        !plain.contains("responseBody"),
+        "Bodies are opt-in. Got: {plain:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_network_har_captures_after_enabling_and_resets_on_navigation() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let network_tool = BrowserNetworkRequestsTool::new();
+
+    let url = serve_posting_page().await;
+    nav_tool
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let before = network_tool
+        .execute(&json!({ "format": "har" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        before.starts_with("Note: network capture started"),
+        "Requests before the first HAR call are not captured. Got: {before:?}"
+    );
+
+    nav_tool
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+    let har = network_tool
+        .execute(
+            &json!({ "format": "har", "waitForIdle": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(har.starts_with('{'), "No note once capturing. Got: {har:?}");
+    assert!(har.contains("/api"), "Should record the fetch. Got: {har}");
+    assert!(
+        har.contains("\"text\": \"name=ada\""),
+        "Should record the POST body. Got: {har}"
+    );
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<p>elsewhere</p>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let after = network_tool
+        .execute(&json!({ "format": "har" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        !after.contains("/api"),
+        "Navigation should reset the log. Got: {after}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_network_nothing_stored_before_capture_is_enabled() {
+    let mut browser = create_browser().await;
+    let network_tool = BrowserNetworkRequestsTool::new();
+
+    let url = serve_posting_page().await;
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    // Listing requests without bodies or HAR output does not start capture
+    network_tool
+        .execute(&json!({ "waitForIdle": true }), &mut browser)
+        .await
+        .unwrap();
+
+    let context = browser.active_context().unwrap();
+    assert!(!context.network_capture_enabled());
+    assert!(
+        context.active_network_buffer().await.is_none(),
+        "No responses should be buffered while capture is off"
+    );
+
+    assert!(context.enable_network_capture().await);
+    let buffer = context
+        .active_network_buffer()
+        .await
+        .expect("Capture should add a buffer to the active page");
+    assert!(
+        buffer.read().await.is_empty(),
+        "Responses received before capture started are not stored"
+    );
+
+    browser.shutdown().await;
+}
@@ -2,7 +2,7 @@
 
 use serde_json::json;
 use viewpoint_mcp::tools::{
-    BrowserNavigateTool, BrowserSnapshotTool, BrowserTakeScreenshotTool, Tool,
+    BrowserEvaluateTool, BrowserNavigateTool, BrowserSnapshotTool, BrowserTakeScreenshotTool, Tool,
 };
 
 use super::create_browser;
@@ -205,3 +205,202 @@ async fn test_element_screenshot_bounding_box_workaround() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_screenshot_with_redaction_zones() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let screenshot_tool = BrowserTakeScreenshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='password' value='secret'><button>Login</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let ref_pattern = regex::Regex::new(r"\[ref=(c\d+p\d+f\d+e\d+)\]").unwrap();
+    let element_ref = ref_pattern
+        .captures(&snapshot)
+        .expect("Should find a ref in snapshot")
+        .get(1)
+        .unwrap()
+        .as_str()
+        .to_string();
+
+    let result = screenshot_tool
+        .execute(
+            &json!({
+                "redact": [
+                    { "x": 0, "y": 0, "width": 50, "height": 50 },
+                    { "ref": element_ref, "element": "Password field" }
+                ]
+            }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        result.is_ok(),
+        "Redacted screenshot failed: {:?}",
+        result.err()
+    );
+
+    // Unknown refs are rejected
+    let result = screenshot_tool
+        .execute(
+            &json!({ "redact": [{ "ref": "c0p0f0e999", "element": "Missing" }] }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err());
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_screenshot_clip_region() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let screenshot_tool = BrowserTakeScreenshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Clip Test</h1><p>Some text</p>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let result = screenshot_tool
+        .execute(
+            &json!({ "clip": { "x": 0, "y": 0, "width": 200, "height": 100 } }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Clip screenshot failed: {:?}", result.err());
+
+    // clip and ref are mutually exclusive
+    let result = screenshot_tool
+        .execute(
+            &json!({
+                "clip": { "x": 0, "y": 0, "width": 200, "height": 100 },
+                "ref": "c0p0f0e1",
+                "element": "Heading"
+            }),
+            &mut browser,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(viewpoint_mcp::tools::ToolError::InvalidParams(_))
+    ));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_screenshot_omit_background() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let screenshot_tool = BrowserTakeScreenshotTool::new();
+
+    // Only the box has a background; the rest of the page should be transparent
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<div style='width:50px;height:50px;background:red'></div>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let filename = "omit-background-test.png";
+    let output = screenshot_tool
+        .execute(
+            &json!({ "omitBackground": true, "filename": filename }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("transparent background"), "Got: {output:?}");
+
+    let path = browser.screenshot_dir().join(filename);
+    let image = image::open(&path).unwrap().to_rgba8();
+    let corner = image.get_pixel(image.width() - 1, image.height() - 1);
+    assert_eq!(corner[3], 0, "Empty area should be transparent: {corner:?}");
+    let _ = std::fs::remove_file(&path);
+
+    // JPEG cannot carry transparency
+    let result = screenshot_tool
+        .execute(
+            &json!({ "omitBackground": true, "type": "jpeg" }),
+            &mut browser,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(viewpoint_mcp::tools::ToolError::InvalidParams(_))
+    ));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_screenshot_highlights_multiple_elements() {
+    let mut browser = create_browser().await;
+
+    BrowserNavigateTool::new()
+        .execute(
+            &json!({ "url": "data:text/html,<button>Save</button><button>Cancel</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = BrowserSnapshotTool::new()
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let ref_pattern = regex::Regex::new(r"\[ref=(c\d+p\d+f\d+e\d+)\]").unwrap();
+    let refs: Vec<_> = ref_pattern
+        .captures_iter(&snapshot)
+        .map(|c| c.get(1).unwrap().as_str().to_string())
+        .collect();
+    assert!(refs.len() >= 2, "Expected two button refs: {snapshot}");
+
+    let output = BrowserTakeScreenshotTool::new()
+        .execute(
+            &json!({
+                "highlights": [
+                    { "ref": refs[0], "color": "red", "label": "1" },
+                    { "ref": refs[1], "color": "blue", "label": "2" }
+                ]
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        output.contains("2 element(s) highlighted"),
+        "Got: {output:?}"
+    );
+
+    // The overlays are removed once the screenshot is taken
+    let remaining = BrowserEvaluateTool::new()
+        .execute(
+            &json!({
+                "function": "() => document.querySelectorAll('[data-viewpoint-highlight]').length"
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(remaining.contains('0'), "Got: {remaining:?}");
+
+    browser.shutdown().await;
+}
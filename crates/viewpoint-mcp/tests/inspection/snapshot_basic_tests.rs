@@ -1,7 +1,7 @@
 //! Basic snapshot tool integration tests
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserSnapshotTool, Tool};
+use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserSnapshotTool, Tool, ToolError};
 
 use super::create_browser;
 
@@ -169,3 +169,210 @@ async fn test_snapshot_after_navigation() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_snapshot_scoped_to_selector() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<nav><button>Outside</button></nav><form id='login'><button>Sign in</button></form>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "selector": "form" }), &mut browser)
+        .await
+        .expect("Scoped snapshot should succeed");
+    assert!(
+        snapshot.contains("Sign in"),
+        "Should contain scoped content: {snapshot:?}"
+    );
+    assert!(
+        !snapshot.contains("Outside"),
+        "Should exclude content outside the selector: {snapshot:?}"
+    );
+
+    // A full snapshot afterwards still covers the whole page
+    let full = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(full.contains("Outside"), "Full snapshot: {full:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_selector_not_found() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button>Only</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let result = snapshot_tool
+        .execute(&json!({ "selector": ".missing" }), &mut browser)
+        .await;
+    assert!(
+        matches!(result, Err(ToolError::ElementNotFound(_))),
+        "Unmatched selector should fail with ElementNotFound: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_include_stats() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Title</h1><button>A</button><button>B</button><a href='#x'>Link</a>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "includeStats": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        snapshot.contains("[Stats: 2 buttons, 1 link"),
+        "Should end with a stats block. Got: {snapshot:?}"
+    );
+
+    let plain = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        !plain.contains("[Stats:"),
+        "Stats are opt-in. Got: {plain:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_aria_label_contains() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Store</h1><button>Add to Cart</button><button>View cart</button><a href='#x'>Help</a>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "ariaLabelContains": "CART" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        snapshot.contains("\"Add to Cart\"") && snapshot.contains("\"View cart\""),
+        "Should keep case-insensitive matches. Got: {snapshot:?}"
+    );
+    assert!(
+        !snapshot.contains("\"Store\"") && !snapshot.contains("\"Help\""),
+        "Should drop non-matching elements. Got: {snapshot:?}"
+    );
+    assert!(
+        snapshot.contains("filtered to names containing 'CART'"),
+        "Got: {snapshot:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_skip_roles() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Store</h1><button>Checkout</button><a href='#x'>Help</a>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "skipRoles": ["heading", "link"] }), &mut browser)
+        .await
+        .unwrap();
+    assert!(snapshot.contains("\"Checkout\""), "Got: {snapshot:?}");
+    assert!(
+        !snapshot.contains("\"Store\"") && !snapshot.contains("\"Help\""),
+        "Should drop skipped roles. Got: {snapshot:?}"
+    );
+    assert!(
+        snapshot.contains("skipping roles heading, link"),
+        "Got: {snapshot:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_markdown_format() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Store</h1><button>Checkout</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "format": "markdown" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        snapshot.contains("- **heading** \"Store\""),
+        "Got: {snapshot:?}"
+    );
+    assert!(
+        snapshot.contains("`button \"Checkout\" [ref="),
+        "Interactive elements should be code spans. Got: {snapshot:?}"
+    );
+
+    // The cached snapshot is re-rendered in the requested format
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        snapshot.contains("- heading \"Store\""),
+        "Got: {snapshot:?}"
+    );
+
+    let result = snapshot_tool
+        .execute(&json!({ "format": "html" }), &mut browser)
+        .await;
+    assert!(matches!(result, Err(ToolError::InvalidParams(_))));
+
+    browser.shutdown().await;
+}
@@ -1,7 +1,7 @@
 //! Snapshot caching integration tests
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserSnapshotTool, Tool};
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserNavigateTool, BrowserSnapshotTool, Tool};
 
 use super::create_browser;
 
@@ -93,3 +93,155 @@ async fn test_snapshot_cache_invalidation_on_allrefs() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_snapshot_cache_miss_after_dom_mutation() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    // The page adds a button on its own, without any tool interaction
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Mutations</h1><script>setTimeout(() => { const b = document.createElement('button'); b.textContent = 'Late'; document.body.appendChild(b); }, 300)</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snap1 = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(!snap1.contains("Late"), "Got: {snap1:?}");
+
+    tokio::time::sleep(std::time::Duration::from_millis(600)).await;
+
+    // The mutation count changed, so the cached snapshot must not be reused
+    let snap2 = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(snap2.contains("Late"), "Got: {snap2:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_cache_miss_after_iframe_mutation() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    // Only the iframe's document changes; the main document stays the same
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Outer</h1><iframe srcdoc=\"<p>Inner</p><script>setTimeout(() => { const b = document.createElement('button'); b.textContent = 'Framed'; document.body.appendChild(b); }, 800)</script>\"></iframe>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+
+    let snap1 = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(!snap1.contains("Framed"), "Got: {snap1:?}");
+
+    tokio::time::sleep(std::time::Duration::from_millis(900)).await;
+
+    let snap2 = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(snap2.contains("Framed"), "Got: {snap2:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_cache_leaves_page_globals_alone() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Globals</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let before = eval_tool
+        .execute(
+            &json!({ "function": "() => Object.getOwnPropertyNames(window).length" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+
+    let after = eval_tool
+        .execute(
+            &json!({ "function": "() => Object.getOwnPropertyNames(window).length" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert_eq!(before, after, "Snapshot caching added a global");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_cache_across_navigations() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>First page</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let first = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(first.contains("First page"), "Got: {first:?}");
+
+    // A different URL is a cache miss
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Second page</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let second = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(second.contains("Second page"), "Got: {second:?}");
+    assert!(!second.contains("First page"), "Got: {second:?}");
+
+    // An unchanged page is a cache hit
+    let again = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert_eq!(
+        second, again,
+        "Unchanged page should reuse the cached snapshot"
+    );
+
+    browser.shutdown().await;
+}
@@ -0,0 +1,99 @@
+//! Snapshot tests for temporarily expanding collapsed widgets
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserNavigateTool, BrowserSnapshotTool, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_snapshot_expand_collapsed() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    // A disclosure button toggling a hidden panel
+    let html = "<button id='toggle' aria-expanded='false' onclick=\"\
+        const open = this.getAttribute('aria-expanded') === 'true'; \
+        this.setAttribute('aria-expanded', String(!open)); \
+        document.getElementById('panel').hidden = open;\">Shipping</button>\
+        <div id='panel' hidden><a href='/rates'>Rates</a></div>";
+    nav_tool
+        .execute(
+            &json!({ "url": format!("data:text/html,{html}") }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let collapsed = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(!collapsed.contains("\"Rates\""), "Got: {collapsed:?}");
+
+    let expanded = snapshot_tool
+        .execute(&json!({ "expandCollapsed": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(expanded.contains("link \"Rates\""), "Got: {expanded:?}");
+    assert!(
+        expanded.contains("Expanded 1 collapsed widget(s)"),
+        "Got: {expanded:?}"
+    );
+
+    // The accordion is collapsed again afterwards
+    let state = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('toggle').getAttribute('aria-expanded') + ',' + document.getElementById('panel').hidden" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(state.contains("false,true"), "Got: {state:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_expand_collapsed_never_navigates() {
+    let mut browser = create_browser().await;
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    // A link, a submit button, and a button whose handler navigates, all
+    // claiming to be collapsed, next to a plain disclosure button
+    let html = "<a href='https://example.com/' aria-expanded='false'>Docs</a>\
+        <form action='https://example.com/'><button aria-expanded='false'>Send</button></form>\
+        <button id='away' aria-expanded='false' onclick=\"\
+        this.setAttribute('aria-expanded', String(this.getAttribute('aria-expanded') !== 'true')); \
+        location.href = 'https://example.com/';\">Away</button>\
+        <button id='toggle' aria-expanded='false' \
+        onclick=\"this.setAttribute('aria-expanded', String(this.getAttribute('aria-expanded') !== 'true'))\">\
+        Filters</button>";
+    let url = format!("data:text/html,{html}");
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let expanded = snapshot_tool
+        .execute(&json!({ "expandCollapsed": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        expanded.contains("Expanded 2 collapsed widget(s)"),
+        "Got: {expanded:?}"
+    );
+
+    let state = eval_tool
+        .execute(
+            &json!({ "function": "() => [location.protocol, document.getElementById('away').getAttribute('aria-expanded'), document.getElementById('toggle').getAttribute('aria-expanded')].join(',')" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(state.contains("data:,false,false"), "Got: {state:?}");
+
+    browser.shutdown().await;
+}
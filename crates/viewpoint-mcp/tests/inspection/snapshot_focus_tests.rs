@@ -0,0 +1,132 @@
+//! Snapshot focus marker integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserPressKeyTool, BrowserSnapshotTool, Tool};
+
+use super::create_browser;
+
+/// Return the snapshot line carrying the `[focused]` marker, if any
+fn focused_line(snapshot: &str) -> Option<&str> {
+    snapshot.lines().find(|line| line.contains("[focused]"))
+}
+
+#[tokio::test]
+async fn test_snapshot_focus_follows_tab() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let press_tool = BrowserPressKeyTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<form><input aria-label='First'><input aria-label='Second'><button>Send</button></form>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "focusElement": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        focused_line(&snapshot).is_none(),
+        "Nothing is focused before tabbing. Got: {snapshot:?}"
+    );
+
+    for expected in ["\"First\"", "\"Second\"", "\"Send\""] {
+        press_tool
+            .execute(&json!({ "key": "Tab" }), &mut browser)
+            .await
+            .unwrap();
+        let snapshot = snapshot_tool
+            .execute(&json!({ "focusElement": true }), &mut browser)
+            .await
+            .unwrap();
+
+        let line = focused_line(&snapshot);
+        assert!(
+            line.is_some_and(|line| line.contains(expected)),
+            "Focus should be on {expected}. Got: {snapshot:?}"
+        );
+        assert_eq!(
+            snapshot.matches("[focused]").count(),
+            1,
+            "Exactly one element is focused. Got: {snapshot:?}"
+        );
+    }
+
+    let plain = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        !plain.contains("[focused]"),
+        "Focus marker is opt-in. Got: {plain:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_focus_inside_shadow_root() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    // The document's activeElement is the shadow host, not the input
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button>Outside</button><div id='host'></div><script>const root=document.getElementById('host').attachShadow({mode:'open'});root.innerHTML='<input aria-label=\"Inner\">';root.querySelector('input').focus()</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "focusElement": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        focused_line(&snapshot).is_some_and(|line| line.contains("\"Inner\"")),
+        "Focus should be on the input inside the shadow root. Got: {snapshot:?}"
+    );
+    assert_eq!(
+        snapshot.matches("[focused]").count(),
+        1,
+        "Got: {snapshot:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_snapshot_focus_inside_same_origin_frame() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button>Outside</button><iframe srcdoc='<input aria-label=\"Framed\">' onload='this.contentWindow.focus();this.contentDocument.querySelector(\"input\").focus()'></iframe>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "focusElement": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        focused_line(&snapshot).is_some_and(|line| line.contains("\"Framed\"")),
+        "Focus should be on the input inside the frame. Got: {snapshot:?}"
+    );
+    assert_eq!(
+        snapshot.matches("[focused]").count(),
+        1,
+        "Got: {snapshot:?}"
+    );
+
+    browser.shutdown().await;
+}
@@ -0,0 +1,86 @@
+//! Accessibility tree diff integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{
+    BrowserNavigateTool, BrowserSnapshotTool, Capability, Tool, ToolRegistry, register_all_tools,
+};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_tree_diff_reports_expanded_toggle() {
+    let mut browser = create_browser().await;
+    let mut registry = ToolRegistry::new();
+    register_all_tools(&mut registry);
+    let diff_tool = registry.get("browser_accessibility_tree_diff").unwrap();
+
+    let html = "data:text/html,<button aria-expanded='false' \
+                onclick=\"this.setAttribute('aria-expanded', 'true')\">Details</button>";
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": html }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = BrowserSnapshotTool::new()
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let re = regex::Regex::new(r"\[ref=(c\d+p\d+f\d+e\d+)\]").unwrap();
+    let button_ref = re
+        .captures(&snapshot)
+        .map(|c| c.get(1).unwrap().as_str().to_string())
+        .expect("Should have a button ref");
+
+    let output = diff_tool
+        .execute(
+            &json!({
+                "tool": "browser_click",
+                "args": { "ref": button_ref, "element": "Details" }
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(output.contains("Clicked"), "Got: {output:?}");
+    assert!(
+        output.contains("aria-expanded: \"false\" -> \"true\""),
+        "Got: {output:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tree_diff_wraps_tools_enabled_after_registration() {
+    let mut browser = create_browser().await;
+    let mut registry = ToolRegistry::new();
+    register_all_tools(&mut registry);
+    let diff_tool = registry.get("browser_accessibility_tree_diff").unwrap();
+
+    // Enabled after the diff tool was registered, as the server may do
+    registry.enable_capability(Capability::Vision);
+
+    let html = "data:text/html,<button style='position:fixed;left:0;top:0;width:100px;height:40px' \
+                aria-pressed='false' onclick=\"this.setAttribute('aria-pressed', 'true')\">Bold</button>";
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": html }), &mut browser)
+        .await
+        .unwrap();
+
+    let output = diff_tool
+        .execute(
+            &json!({
+                "tool": "browser_mouse_click_xy",
+                "args": { "x": 50, "y": 20, "element": "Bold" }
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(output.contains("Clicked"), "Got: {output:?}");
+    assert!(output.contains("pressed"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
@@ -1,7 +1,9 @@
 //! Wait tool integration tests
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserWaitForTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserNavigateTool, BrowserSnapshotTool, BrowserWaitForTool, Tool, ToolError,
+};
 
 use super::create_browser;
 
@@ -73,3 +75,103 @@ async fn test_wait_for_text_gone() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_wait_for_ref_gone_and_timeout() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let wait_tool = BrowserWaitForTool::new();
+
+    // Button removes itself shortly after load
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button id='b'>Temporary</button><script>setTimeout(() => document.getElementById('b').remove(), 300)</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let ref_pattern = regex::Regex::new(r"\[ref=(c\d+p\d+f\d+e\d+)\]").unwrap();
+    let element_ref = ref_pattern
+        .captures(&snapshot)
+        .expect("Should find a ref in snapshot")
+        .get(1)
+        .unwrap()
+        .as_str()
+        .to_string();
+
+    let result = wait_tool
+        .execute(
+            &json!({ "refGone": element_ref, "pollIntervalMs": 50, "timeoutMs": 5000 }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "refGone wait failed: {:?}", result.err());
+
+    // The ref never comes back, so waiting for it times out
+    let result = wait_tool
+        .execute(
+            &json!({ "refPresent": element_ref, "pollIntervalMs": 50, "timeoutMs": 200 }),
+            &mut browser,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ToolError::Timeout {
+            timeout_ms: 200,
+            ..
+        })
+    ));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_wait_for_text_timeout() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let wait_tool = BrowserWaitForTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Present</h1>" }),
+            &mut browser,
+        )
+        .await
+        .expect("Navigate should succeed");
+
+    let result = wait_tool
+        .execute(
+            &json!({ "text": "Never shown", "timeoutMs": 200 }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(ToolError::Timeout {
+                timeout_ms: 200,
+                ..
+            })
+        ),
+        "Expected timeout, got: {result:?}"
+    );
+
+    let result = wait_tool
+        .execute(
+            &json!({ "textGone": "Present", "timeoutMs": 200 }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        matches!(result, Err(ToolError::Timeout { .. })),
+        "Expected timeout, got: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
@@ -12,13 +12,19 @@
 #![cfg(feature = "integration")]
 
 mod interaction {
+    pub mod all_frames_tests;
     pub mod click_tests;
+    pub mod click_trial_tests;
     pub mod drag_tests;
+    pub mod focus_tests;
     pub mod form_tests;
+    pub mod hover_tests;
     pub mod iframe_tests;
     pub mod key_tests;
     pub mod scroll_tests;
+    pub mod select_tests;
     pub mod type_tests;
+    pub mod upload_tests;
 
     use viewpoint_mcp::browser::{BrowserConfig, BrowserState};
 
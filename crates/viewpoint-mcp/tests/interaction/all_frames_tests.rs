@@ -0,0 +1,80 @@
+//! Evaluation in every frame integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_evaluate_in_all_frames() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let html = r#"<html><body><h1>Main</h1><iframe id="a" srcdoc="<p>First</p>"></iframe><iframe id="b" srcdoc="<p>Second</p><script>window.broken=true</script>"></iframe></body></html>"#;
+
+    nav_tool
+        .execute(
+            &json!({ "url": format!("data:text/html,{}", html.replace(' ', "%20")) }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Wait for iframes to load
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let output = eval_tool
+        .execute(
+            &json!({
+                "function": "() => { if (window.broken) throw new Error('frame failed'); return document.querySelector('h1, p').textContent; }",
+                "allFrames": true
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Every frame is reported, and a throwing frame does not abort the call
+    assert!(output.contains("\"Main\""), "Got: {output:?}");
+    assert!(output.contains("\"First\""), "Got: {output:?}");
+    assert!(output.contains("frame failed"), "Got: {output:?}");
+    assert!(output.contains("about:srcdoc"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_evaluate_in_all_frames_uses_each_frames_globals() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    // The frame removes its own eval, which must not be needed to run the function
+    let html = r#"<html><body><script>var label="main"</script><iframe srcdoc="<script>var label='inner';window.eval=undefined</script>"></iframe></body></html>"#;
+
+    nav_tool
+        .execute(
+            &json!({ "url": format!("data:text/html,{}", html.replace(' ', "%20")) }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Wait for the iframe to load
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "() => window.label", "allFrames": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(output.contains("\"main\""), "Got: {output:?}");
+    assert!(output.contains("\"inner\""), "Got: {output:?}");
+    assert!(!output.contains("error"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
@@ -436,3 +436,45 @@ async fn test_double_click() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_force_click() {
+    use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserSnapshotTool};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let click_tool = BrowserClickTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button onclick='window.clicks=(window.clicks||0)+1'>Forced</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let button_ref = extract_first_ref(&snapshot).expect("Should have a button ref");
+
+    let output = click_tool
+        .execute(
+            &json!({ "ref": button_ref, "element": "Forced button", "force": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("forced"), "Got: {output:?}");
+
+    let value = eval_tool
+        .execute(&json!({ "function": "() => window.clicks" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(value.contains('1'), "Got: {value:?}");
+
+    browser.shutdown().await;
+}
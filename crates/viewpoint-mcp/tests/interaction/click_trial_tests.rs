@@ -0,0 +1,155 @@
+//! Trial click integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{
+    BrowserClickTool, BrowserEvaluateTool, BrowserNavigateTool, BrowserSnapshotTool, Tool,
+};
+
+use super::{create_browser, extract_first_ref};
+
+#[tokio::test]
+async fn test_trial_click_does_not_click() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let click_tool = BrowserClickTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<div style='height:3000px'></div><button onclick='window.clicks=(window.clicks||0)+1'>Far away</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let button_ref = extract_first_ref(&snapshot).expect("Should have a button ref");
+
+    let output = click_tool
+        .execute(
+            &json!({ "ref": button_ref, "element": "Far away button", "trial": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("clickable"), "Got: {output:?}");
+
+    // The button was scrolled into view but never clicked
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => [window.clicks === undefined, window.scrollY > 0]" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(!value.contains("false"), "Got: {value:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_trial_click_reports_obscured_element() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let click_tool = BrowserClickTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button>Covered</button><div id='overlay' style='position:fixed;inset:0;background:white'></div>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let button_ref = extract_first_ref(&snapshot).expect("Should have a button ref");
+
+    let error = click_tool
+        .execute(
+            &json!({ "ref": button_ref, "element": "Covered button", "trial": true }),
+            &mut browser,
+        )
+        .await
+        .expect_err("Covered button should not be clickable");
+    assert!(
+        error.to_string().contains("obscured by <div#overlay>"),
+        "Got: {error}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_trial_click_reports_disabled_element() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let click_tool = BrowserClickTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button disabled>Disabled</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({ "allRefs": true }), &mut browser)
+        .await
+        .unwrap();
+    let button_ref = extract_first_ref(&snapshot).expect("Should have a button ref");
+
+    let error = click_tool
+        .execute(
+            &json!({ "ref": button_ref, "element": "Disabled button", "trial": true }),
+            &mut browser,
+        )
+        .await
+        .expect_err("Disabled button should not be clickable");
+    assert!(error.to_string().contains("is disabled"), "Got: {error}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_trial_click_inside_shadow_root() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let click_tool = BrowserClickTool::new();
+
+    // The button lives in an open shadow root, so the document hit test only sees its host
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<div id='host'></div><script>document.getElementById('host').attachShadow({mode:'open'}).innerHTML='<button>Shadow</button>'</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let button_ref = extract_first_ref(&snapshot).expect("Should have a button ref");
+
+    let output = click_tool
+        .execute(
+            &json!({ "ref": button_ref, "element": "Shadow button", "trial": true }),
+            &mut browser,
+        )
+        .await
+        .expect("Button inside a shadow root should be clickable");
+    assert!(output.contains("clickable"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
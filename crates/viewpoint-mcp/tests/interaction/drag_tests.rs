@@ -1,10 +1,32 @@
 //! Drag tool integration tests
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserDragTool, BrowserNavigateTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserDragTool, BrowserEvaluateTool, BrowserNavigateTool, BrowserSnapshotTool, Tool,
+};
 
 use super::create_browser;
 
+/// Sortable list that only reorders on HTML5 drag-and-drop events
+const SORTABLE_LIST_HTML: &str = r#"data:text/html,<html><body>
+<ul id="list">
+  <li><button draggable="true" id="a">Item A</button></li>
+  <li><button draggable="true" id="b">Item B</button></li>
+  <li><button draggable="true" id="c">Item C</button></li>
+</ul>
+<script>
+  document.querySelectorAll('[draggable]').forEach(item => {
+    item.addEventListener('dragstart', e => e.dataTransfer.setData('text/plain', item.id));
+    item.addEventListener('dragover', e => e.preventDefault());
+    item.addEventListener('drop', e => {
+      e.preventDefault();
+      const dragged = document.getElementById(e.dataTransfer.getData('text/plain'));
+      item.parentElement.after(dragged.parentElement);
+    });
+  });
+</script>
+</body></html>"#;
+
 #[tokio::test]
 async fn test_drag_with_invalid_start_ref() {
     use viewpoint_mcp::tools::BrowserSnapshotTool;
@@ -52,3 +74,138 @@ async fn test_drag_with_invalid_start_ref() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_drag_with_html5_drag_drop_reorders_list() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let drag_tool = BrowserDragTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(&json!({ "url": SORTABLE_LIST_HTML }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let find_ref = |name: &str| {
+        let re =
+            regex::Regex::new(&format!(r#"button "{name}".*\[ref=(c\d+p\d+f\d+e\d+)\]"#)).unwrap();
+        re.captures(&snapshot)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_else(|| panic!("No ref for {name}: {snapshot:?}"))
+    };
+    let (item_a, item_c) = (find_ref("Item A"), find_ref("Item C"));
+
+    let output = drag_tool
+        .execute(
+            &json!({
+                "startRef": item_a,
+                "startElement": "Item A",
+                "endRef": item_c,
+                "endElement": "Item C",
+                "useDragDrop": true
+            }),
+            &mut browser,
+        )
+        .await
+        .expect("HTML5 drag should succeed");
+    assert!(
+        output.contains("HTML5 drag and drop"),
+        "Output should mention HTML5 drag and drop: {output:?}"
+    );
+    assert!(
+        !output.contains("did not accept"),
+        "Drop should be accepted: {output:?}"
+    );
+
+    let order = eval_tool
+        .execute(
+            &json!({ "function": "() => Array.from(document.querySelectorAll('#list button')).map(b => b.id).join(',')" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        order.contains("b,c,a"),
+        "Item A should be moved after Item C: {order:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+/// Draggable button on the page and a drop zone inside an iframe
+const CROSS_FRAME_HTML: &str = r#"data:text/html,<html><body>
+<button draggable="true" id="src">Drag Me</button>
+<iframe srcdoc="<button id='zone'>Drop Zone</button><script>
+  const zone = document.getElementById('zone');
+  zone.addEventListener('dragover', e => e.preventDefault());
+  zone.addEventListener('drop', e => { parent.document.title = 'dropped ' + e.dataTransfer.getData('text/plain'); });
+</script>"></iframe>
+<script>
+  document.getElementById('src').addEventListener('dragstart', e => e.dataTransfer.setData('text/plain', 'payload'));
+  document.getElementById('src').addEventListener('dragend', e => { document.body.dataset.ended = e.dataTransfer.dropEffect; });
+</script>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_drag_with_html5_drag_drop_into_iframe() {
+    let mut browser = create_browser().await;
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": CROSS_FRAME_HTML }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = BrowserSnapshotTool::new()
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let find_ref = |name: &str| {
+        let re = regex::Regex::new(&format!(
+            r#"button "{name}".*\[ref=(c\d+p\d+(?:f\d+)+e\d+)\]"#
+        ))
+        .unwrap();
+        re.captures(&snapshot)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().to_string())
+            .unwrap_or_else(|| panic!("No ref for {name}: {snapshot:?}"))
+    };
+
+    let output = BrowserDragTool::new()
+        .execute(
+            &json!({
+                "startRef": find_ref("Drag Me"),
+                "startElement": "Drag Me",
+                "endRef": find_ref("Drop Zone"),
+                "endElement": "Drop Zone",
+                "useDragDrop": true
+            }),
+            &mut browser,
+        )
+        .await
+        .expect("Cross-frame HTML5 drag should succeed");
+    assert!(
+        !output.contains("did not accept"),
+        "Drop should be accepted: {output:?}"
+    );
+
+    let result = BrowserEvaluateTool::new()
+        .execute(
+            &json!({ "function": "() => document.title + ' ' + document.body.dataset.ended" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        result.contains("dropped payload move"),
+        "Drop data and dragend should reach both frames: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
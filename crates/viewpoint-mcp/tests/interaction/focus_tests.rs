@@ -0,0 +1,114 @@
+//! Tests for browser_focus tool
+
+use serde_json::json;
+use viewpoint_mcp::tools::{
+    BrowserEvaluateTool, BrowserFocusTool, BrowserNavigateTool, BrowserSnapshotTool, Tool,
+};
+
+use super::{create_browser, extract_first_ref};
+
+const PAGE: &str = "data:text/html,<input id='first' aria-label='First' onfocus=\"document.title='menu open'\"><input id='second' aria-label='Second'>";
+
+/// Read the id of the focused element and the document title
+async fn focus_state(browser: &mut viewpoint_mcp::browser::BrowserState) -> String {
+    BrowserEvaluateTool::new()
+        .execute(
+            &json!({ "function": "() => document.activeElement.id + '|' + document.title" }),
+            browser,
+        )
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_focus_element() {
+    let mut browser = create_browser().await;
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let focus_tool = BrowserFocusTool::new();
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": PAGE }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let first_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+
+    let output = focus_tool
+        .execute(
+            &json!({ "ref": first_ref, "element": "First input" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("Focused First input"), "Got: {output:?}");
+
+    let state = focus_state(&mut browser).await;
+    assert!(
+        state.contains("first|menu open"),
+        "Focus handlers should run without a click. Got: {state:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_focus_direction_forward() {
+    let mut browser = create_browser().await;
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let focus_tool = BrowserFocusTool::new();
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": PAGE }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let first_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+
+    let output = focus_tool
+        .execute(
+            &json!({ "ref": first_ref, "element": "First input", "direction": "forward" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        output.contains("then moved focus forward"),
+        "Got: {output:?}"
+    );
+
+    let state = focus_state(&mut browser).await;
+    assert!(
+        state.contains("second|"),
+        "Focus should move to the next input. Got: {state:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_focus_invalid_ref() {
+    let mut browser = create_browser().await;
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": PAGE }), &mut browser)
+        .await
+        .unwrap();
+
+    let result = BrowserFocusTool::new()
+        .execute(
+            &json!({ "ref": "c0p0f0e999", "element": "Missing" }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err(), "Unknown ref should fail");
+
+    browser.shutdown().await;
+}
@@ -3,7 +3,7 @@
 use serde_json::json;
 use viewpoint_mcp::tools::{BrowserFillFormTool, BrowserNavigateTool, Tool};
 
-use super::create_browser;
+use super::{create_browser, extract_first_ref};
 
 #[tokio::test]
 async fn test_fill_form_with_one_invalid_ref() {
@@ -110,3 +110,240 @@ async fn test_evaluate_with_removed_element_ref() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_fill_form_rollback_on_failure_resets_filled_fields() {
+    use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserSnapshotTool};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let fill_tool = BrowserFillFormTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='text' id='name'><select id='plan'><option value='free'>Free</option></select>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let re = regex::Regex::new(r"combobox.*\[ref=(c\d+p\d+f\d+e\d+)\]").unwrap();
+    let textbox_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+    let combobox_ref = re
+        .captures(&snapshot)
+        .map(|c| c.get(1).unwrap().as_str().to_string())
+        .expect("Should have a combobox ref");
+
+    // The second field fails because the option does not exist
+    let result = fill_tool
+        .execute(
+            &json!({
+                "fields": [
+                    { "name": "Name", "type": "textbox", "ref": textbox_ref, "value": "Ada" },
+                    { "name": "Plan", "type": "combobox", "ref": combobox_ref, "value": "enterprise" }
+                ],
+                "rollbackOnFailure": true
+            }),
+            &mut browser,
+        )
+        .await;
+    let error = result.expect_err("Fill with a missing option should fail");
+    assert!(
+        error.to_string().contains("rolled back"),
+        "Error should report the rollback: {error}"
+    );
+
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => JSON.stringify(document.getElementById('name').value)" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        !value.contains("Ada"),
+        "Filled textbox should be reset by the rollback: {value:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_fill_form_reports_all_invalid_refs() {
+    use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserSnapshotTool};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let fill_tool = BrowserFillFormTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='text' id='name'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let textbox_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+
+    let result = fill_tool
+        .execute(
+            &json!({
+                "fields": [
+                    { "name": "Name", "type": "textbox", "ref": textbox_ref, "value": "Ada" },
+                    { "name": "Email", "type": "textbox", "ref": "c0p0f0e99998", "value": "a@b.c" },
+                    { "name": "Phone", "type": "textbox", "ref": "not-a-ref", "value": "123" }
+                ]
+            }),
+            &mut browser,
+        )
+        .await;
+    let error = result
+        .expect_err("Fill with invalid refs should fail")
+        .to_string();
+    assert!(
+        error.contains("2 of 3 field ref(s) are invalid"),
+        "Error should count the invalid refs: {error}"
+    );
+    assert!(
+        error.contains("'Email'") && error.contains("'Phone'"),
+        "Error should name every invalid field: {error}"
+    );
+
+    // Validation happens before any field is filled
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => JSON.stringify(document.getElementById('name').value)" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        !value.contains("Ada"),
+        "No field should be filled when refs are invalid: {value:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_fill_form_clear_first_false_appends() {
+    use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserSnapshotTool};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let fill_tool = BrowserFillFormTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='text' id='tags' value='alpha'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let textbox_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+    let read_value =
+        json!({ "function": "() => JSON.stringify(document.getElementById('tags').value)" });
+
+    fill_tool
+        .execute(
+            &json!({
+                "fields": [{ "name": "Tags", "type": "textbox", "ref": textbox_ref, "value": ",beta" }],
+                "clearFirst": false
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let value = eval_tool.execute(&read_value, &mut browser).await.unwrap();
+    assert!(
+        value.contains("alpha,beta"),
+        "Value should be appended to the existing one: {value:?}"
+    );
+
+    // The default replaces the existing value
+    fill_tool
+        .execute(
+            &json!({
+                "fields": [{ "name": "Tags", "type": "textbox", "ref": textbox_ref, "value": "gamma" }]
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let value = eval_tool.execute(&read_value, &mut browser).await.unwrap();
+    assert!(
+        value.contains("gamma") && !value.contains("alpha"),
+        "Value should be replaced by default: {value:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_fill_form_clear_first_false_appends_to_contenteditable() {
+    use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserSnapshotTool};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let fill_tool = BrowserFillFormTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<div id='notes' role='textbox' aria-label='Notes' contenteditable='true'>alpha</div>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let textbox_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+
+    fill_tool
+        .execute(
+            &json!({
+                "fields": [{ "name": "Notes", "type": "textbox", "ref": textbox_ref, "value": ",beta" }],
+                "clearFirst": false
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => JSON.stringify(document.getElementById('notes').textContent)" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        value.contains("alpha,beta"),
+        "Text should be appended to the existing content: {value:?}"
+    );
+
+    browser.shutdown().await;
+}
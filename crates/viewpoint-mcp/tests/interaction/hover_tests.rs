@@ -0,0 +1,145 @@
+//! Hover tool integration tests
+
+use serde_json::json;
+use viewpoint_mcp::tools::{
+    BrowserEvaluateTool, BrowserHoverTool, BrowserNavigateTool, BrowserSnapshotTool, Tool,
+};
+
+use super::{create_browser, extract_first_ref};
+
+/// Button that records the last mouse position relative to its top-left corner
+const TRACKING_BUTTON_HTML: &str = "data:text/html,<html><body style='margin:0'>\
+    <button id='pad' style='position:absolute;left:50px;top:30px;width:200px;height:100px'>Pad</button>\
+    <script>\
+      const pad = document.getElementById('pad');\
+      pad.addEventListener('mousemove', e => {\
+        const rect = pad.getBoundingClientRect();\
+        window.lastOffset = [Math.round(e.clientX - rect.left), Math.round(e.clientY - rect.top)].join(',');\
+      });\
+    </script></body></html>";
+
+#[tokio::test]
+async fn test_hover_at_position_offset() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let hover_tool = BrowserHoverTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(&json!({ "url": TRACKING_BUTTON_HTML }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let pad_ref = extract_first_ref(&snapshot).expect("Should have a ref for the pad");
+
+    let output = hover_tool
+        .execute(
+            &json!({
+                "ref": pad_ref,
+                "element": "Pad",
+                "position": { "xOffset": 10, "yOffset": 80 }
+            }),
+            &mut browser,
+        )
+        .await
+        .expect("Hover at offset should succeed");
+    assert!(output.contains("offset"), "Output: {output:?}");
+
+    let offset = eval_tool
+        .execute(
+            &json!({ "function": "() => window.lastOffset" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        offset.contains("10,80"),
+        "Mouse should be at the requested offset: {offset:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_hover_position_outside_element_fails() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let hover_tool = BrowserHoverTool::new();
+
+    nav_tool
+        .execute(&json!({ "url": TRACKING_BUTTON_HTML }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let pad_ref = extract_first_ref(&snapshot).expect("Should have a ref for the pad");
+
+    let result = hover_tool
+        .execute(
+            &json!({
+                "ref": pad_ref,
+                "element": "Pad",
+                "position": { "xOffset": 500, "yOffset": 10 }
+            }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err(), "Offset beyond the element should fail");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_hover_captures_tooltip() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let hover_tool = BrowserHoverTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<button id='help' onmouseenter=\"const t=document.createElement('div');t.setAttribute('role','tooltip');t.textContent='Opens the help center';document.body.append(t)\">Help</button><button title='Native hint'>Info</button>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let help_ref = extract_first_ref(&snapshot).expect("Should have a ref for the button");
+
+    let output = hover_tool
+        .execute(
+            &json!({ "ref": help_ref, "element": "Help button", "captureTooltip": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        output.contains("Tooltip: \"Opens the help center\""),
+        "Got: {output:?}"
+    );
+
+    // Without captureTooltip the output is unchanged
+    let output = hover_tool
+        .execute(
+            &json!({ "ref": help_ref, "element": "Help button" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(!output.contains("Tooltip"), "Got: {output:?}");
+
+    browser.shutdown().await;
+}
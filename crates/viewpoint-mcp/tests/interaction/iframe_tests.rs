@@ -331,3 +331,102 @@ async fn test_iframe_elements_have_frame_id_in_ref() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_evaluate_in_iframe_context() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let html = r#"<html><body><h1>Main Page</h1><iframe id="myframe" srcdoc="<p>Inner</p>"></iframe></body></html>"#;
+
+    nav_tool
+        .execute(
+            &json!({ "url": format!("data:text/html,{}", html.replace(' ', "%20")) }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Wait for iframe to load
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    // Set a global in the iframe's context
+    eval_tool
+        .execute(
+            &json!({
+                "function": "() => { window.frameValue = 'from-frame'; return true; }",
+                "frame": "#myframe"
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // It can be read back from the same frame...
+    let in_frame = eval_tool
+        .execute(
+            &json!({
+                "function": "() => window.frameValue + ':' + document.querySelector('p').textContent",
+                "frame": "#myframe"
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(in_frame.contains("from-frame:Inner"), "Got: {in_frame:?}");
+
+    // ...but is not visible in the main frame
+    let in_main = eval_tool
+        .execute(
+            &json!({ "function": "() => typeof window.frameValue" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(in_main.contains("undefined"), "Got: {in_main:?}");
+
+    // A selector that matches no frame is reported as not found
+    let missing = eval_tool
+        .execute(
+            &json!({ "function": "() => 1", "frame": "#nope" }),
+            &mut browser,
+        )
+        .await;
+    assert!(missing.is_err());
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_evaluate_in_iframe_without_its_eval() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    // The frame removes its own eval, which evaluation must not depend on
+    let html = r#"<html><body><iframe id="myframe" srcdoc="<p>Inner</p><script>window.eval = undefined;</script>"></iframe></body></html>"#;
+
+    nav_tool
+        .execute(
+            &json!({ "url": format!("data:text/html,{}", html.replace(' ', "%20")) }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let result = eval_tool
+        .execute(
+            &json!({
+                "function": "() => typeof window.eval + ':' + document.querySelector('p').textContent",
+                "frame": "#myframe"
+            }),
+            &mut browser,
+        )
+        .await
+        .expect("Evaluation should not need the frame's eval");
+    assert!(result.contains("undefined:Inner"), "Got: {result:?}");
+
+    browser.shutdown().await;
+}
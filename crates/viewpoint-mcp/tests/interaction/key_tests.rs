@@ -1,7 +1,9 @@
 //! Keyboard press key integration tests
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserPressKeyTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserKeyboardShortcutTool, BrowserNavigateTool, BrowserPressKeyTool, Tool,
+};
 
 use super::create_browser;
 
@@ -288,3 +290,117 @@ async fn test_tool_schemas_are_valid() {
         );
     }
 }
+
+#[tokio::test]
+async fn test_keyboard_shortcut() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let shortcut_tool = BrowserKeyboardShortcutTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='text'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    for shortcut in ["Ctrl+A", "Ctrl+Z", "Cmd+Shift+4", "Esc"] {
+        let result = shortcut_tool
+            .execute(&json!({ "shortcut": shortcut }), &mut browser)
+            .await;
+        assert!(result.is_ok(), "Failed to press {shortcut}: {result:?}");
+    }
+
+    // Unparseable shortcuts are rejected before touching the page
+    let result = shortcut_tool
+        .execute(&json!({ "shortcut": "Ctrl+Shift" }), &mut browser)
+        .await;
+    assert!(result.is_err());
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_press_key_on_ref_focuses_element() {
+    use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserSnapshotTool};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let press_tool = BrowserPressKeyTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    // A custom editor that only reacts to key events, next to an autofocused input
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input autofocus><div id='editor' role='textbox' aria-label='Editor' tabindex='0' onkeydown='this.dataset.key = event.key'></div>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let re = regex::Regex::new(r#"textbox "Editor".*\[ref=(c\d+p\d+f\d+e\d+)\]"#).unwrap();
+    let editor_ref = re
+        .captures(&snapshot)
+        .map(|c| c.get(1).unwrap().as_str().to_string())
+        .expect("Should have an editor ref");
+
+    let output = press_tool
+        .execute(
+            &json!({ "key": "Enter", "ref": editor_ref, "element": "Editor" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("on Editor"), "Got: {output:?}");
+
+    let key = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('editor').dataset.key || 'none'" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        key.contains("Enter"),
+        "Editor should receive the key: {key:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_press_key_on_invalid_ref() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let press_tool = BrowserPressKeyTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='text'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let result = press_tool
+        .execute(
+            &json!({ "key": "Enter", "ref": "c0p0f0e99999", "element": "Missing" }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(viewpoint_mcp::tools::ToolError::ElementNotFound(_))
+        ),
+        "Invalid ref should be ElementNotFound: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
@@ -199,3 +199,105 @@ async fn test_scroll_into_view_already_visible() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_scroll_into_view_returns_bounds() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let scroll_tool = BrowserScrollIntoViewTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<html><body style='margin:0'><div style='height:3000px'></div><button style='width:120px;height:40px'>Bottom</button></body></html>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let ref_str = extract_first_ref(&snapshot).expect("Should find button ref");
+
+    let output = scroll_tool
+        .execute(
+            &json!({ "ref": ref_str, "element": "Bottom button", "returnBounds": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let bounds_json = output
+        .lines()
+        .find_map(|line| line.strip_prefix("Bounds: "))
+        .unwrap_or_else(|| panic!("Output should include bounds. Got: {output:?}"));
+    let bounds: serde_json::Value = serde_json::from_str(bounds_json).unwrap();
+
+    // The box is in viewport coordinates, so the scrolled-to button is on screen
+    assert_eq!(bounds["width"], 120.0);
+    assert_eq!(bounds["height"], 40.0);
+    let y = bounds["y"].as_f64().unwrap();
+    assert!((0.0..3000.0).contains(&y), "Got: {bounds}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_scroll_into_view_reports_scroll_position() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let scroll_tool = BrowserScrollIntoViewTool::new();
+
+    // Scrolls to the button, with more content below it, then to the last button
+    for (page, at_bottom) in [
+        (
+            "<div style='height:3000px'></div><button>Middle</button><div style='height:3000px'></div>",
+            false,
+        ),
+        (
+            "<div style='height:3000px'></div><button>Last</button>",
+            true,
+        ),
+    ] {
+        nav_tool
+            .execute(
+                &json!({ "url": format!("data:text/html,<html><body style='margin:0'>{page}</body></html>") }),
+                &mut browser,
+            )
+            .await
+            .unwrap();
+
+        let snapshot = snapshot_tool
+            .execute(&json!({}), &mut browser)
+            .await
+            .unwrap();
+        let ref_str = extract_first_ref(&snapshot).expect("Should find button ref");
+
+        let output = scroll_tool
+            .execute(
+                &json!({ "ref": ref_str, "element": "Button" }),
+                &mut browser,
+            )
+            .await
+            .unwrap();
+
+        let position_json = output
+            .lines()
+            .find_map(|line| line.strip_prefix("Scroll position: "))
+            .unwrap_or_else(|| panic!("Output should include scroll position. Got: {output:?}"));
+        let position: serde_json::Value = serde_json::from_str(position_json).unwrap();
+
+        let scroll_y = position["scrollY"].as_f64().unwrap();
+        let max_scroll_y = position["maxScrollY"].as_f64().unwrap();
+        assert!(scroll_y > 1000.0, "Got: {position}");
+        assert!(scroll_y <= max_scroll_y, "Got: {position}");
+        assert_eq!(position["scrollX"], 0.0, "Got: {position}");
+        assert_eq!(position["maxScrollX"], 0.0, "Got: {position}");
+        assert_eq!(position["atBottom"], at_bottom, "Got: {position}");
+    }
+
+    browser.shutdown().await;
+}
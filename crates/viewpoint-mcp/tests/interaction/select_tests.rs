@@ -0,0 +1,187 @@
+//! Select option integration tests
+
+use serde_json::json;
+use viewpoint_mcp::browser::BrowserState;
+use viewpoint_mcp::tools::{
+    BrowserEvaluateTool, BrowserNavigateTool, BrowserSelectOptionTool, BrowserSnapshotTool, Tool,
+    ToolError,
+};
+
+use super::{create_browser, extract_first_ref};
+
+/// Navigate to a page with a country dropdown and return the dropdown's ref
+async fn open_country_select(browser: &mut BrowserState) -> String {
+    let html = "<select id='country'>\
+        <option value='us'>United States</option>\
+        <option value='ca'>Canada</option>\
+        <option value='mx'>Mexico</option>\
+        </select>";
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": format!("data:text/html,{html}") }), browser)
+        .await
+        .unwrap();
+
+    let snapshot = BrowserSnapshotTool::new()
+        .execute(&json!({}), browser)
+        .await
+        .unwrap();
+    extract_first_ref(&snapshot).expect("Dropdown should have a ref")
+}
+
+/// Read the dropdown's current value
+async fn selected_value(browser: &mut BrowserState) -> String {
+    let output = BrowserEvaluateTool::new()
+        .execute(
+            &json!({ "function": "() => document.getElementById('country').value" }),
+            browser,
+        )
+        .await
+        .unwrap();
+    format!("{output:?}")
+}
+
+#[tokio::test]
+async fn test_select_option_by_value() {
+    let mut browser = create_browser().await;
+    let select_ref = open_country_select(&mut browser).await;
+
+    let result = BrowserSelectOptionTool::new()
+        .execute(
+            &json!({ "ref": select_ref, "element": "Country", "values": ["mx"] }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Select by value failed: {result:?}");
+    assert!(selected_value(&mut browser).await.contains("mx"));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_select_option_by_label() {
+    let mut browser = create_browser().await;
+    let select_ref = open_country_select(&mut browser).await;
+
+    let result = BrowserSelectOptionTool::new()
+        .execute(
+            &json!({ "ref": select_ref, "element": "Country", "label": "Canada" }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Select by label failed: {result:?}");
+    assert!(selected_value(&mut browser).await.contains("ca"));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_select_option_by_index() {
+    let mut browser = create_browser().await;
+    let select_ref = open_country_select(&mut browser).await;
+
+    let result = BrowserSelectOptionTool::new()
+        .execute(
+            &json!({ "ref": select_ref, "element": "Country", "index": 2 }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Select by index failed: {result:?}");
+    assert!(selected_value(&mut browser).await.contains("mx"));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_select_option_unknown_label_or_index() {
+    let mut browser = create_browser().await;
+    let select_ref = open_country_select(&mut browser).await;
+    let select_tool = BrowserSelectOptionTool::new();
+
+    let result = select_tool
+        .execute(
+            &json!({ "ref": select_ref, "element": "Country", "label": "Atlantis" }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        matches!(result, Err(ToolError::InvalidParams(_))),
+        "Got: {result:?}"
+    );
+
+    let result = select_tool
+        .execute(
+            &json!({ "ref": select_ref, "element": "Country", "index": 10 }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        matches!(result, Err(ToolError::InvalidParams(_))),
+        "Got: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_select_option_multiple_selectors_rejected() {
+    let mut browser = create_browser().await;
+    let select_ref = open_country_select(&mut browser).await;
+
+    let result = BrowserSelectOptionTool::new()
+        .execute(
+            &json!({ "ref": select_ref, "element": "Country", "label": "Canada", "index": 1 }),
+            &mut browser,
+        )
+        .await;
+    assert!(
+        matches!(result, Err(ToolError::InvalidParams(_))),
+        "Got: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_select_option_by_index_with_duplicate_values() {
+    let mut browser = create_browser().await;
+    let html = "<select id='size' onchange=\"document.title = 'changed ' + this.selectedIndex\">\
+        <option value=''>Choose</option>\
+        <option value='m'>Medium (regular)</option>\
+        <option value='m'>Medium (slim)</option>\
+        </select>";
+    BrowserNavigateTool::new()
+        .execute(
+            &json!({ "url": format!("data:text/html,{html}") }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let snapshot = BrowserSnapshotTool::new()
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let select_ref = extract_first_ref(&snapshot).expect("Dropdown should have a ref");
+
+    BrowserSelectOptionTool::new()
+        .execute(
+            &json!({ "ref": select_ref, "element": "Size", "index": 2 }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = BrowserEvaluateTool::new()
+        .execute(
+            &json!({ "function": "() => document.getElementById('size').selectedIndex + ' ' + document.title" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        format!("{output:?}").contains("2 changed 2"),
+        "The second 'm' option should be selected and change fired. Got: {output:?}"
+    );
+
+    browser.shutdown().await;
+}
@@ -1,9 +1,34 @@
 //! Type tool integration tests
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserTypeTool, Tool};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use viewpoint_mcp::tools::{
+    BrowserEvaluateTool, BrowserNavigateTool, BrowserSnapshotTool, BrowserTypeTool, Tool,
+};
 
-use super::create_browser;
+use super::{create_browser, extract_first_ref};
+
+/// Serve a page with a textarea on localhost, where the Clipboard API is available
+async fn serve_textarea_page() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+            let body = "<html><body><textarea id='doc'>draft</textarea></body></html>";
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    format!("http://localhost:{port}/")
+}
 
 #[tokio::test]
 async fn test_type_nonexistent_ref() {
@@ -65,3 +90,86 @@ async fn test_type_invalid_ref_format() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_type_append_keeps_existing_value() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let type_tool = BrowserTypeTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='text' id='q' value='red'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let input_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+
+    let output = type_tool
+        .execute(
+            &json!({ "ref": input_ref, "element": "Search", "text": " shoes", "append": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("Appended"), "Got: {output:?}");
+
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('q').value" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(value.contains("red shoes"), "Got: {value:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_type_paste_mode_replaces_value() {
+    let mut browser = create_browser().await;
+    let url = serve_textarea_page().await;
+    let snapshot_tool = BrowserSnapshotTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let snapshot = snapshot_tool
+        .execute(&json!({}), &mut browser)
+        .await
+        .unwrap();
+    let textarea_ref = extract_first_ref(&snapshot).expect("Should have a textbox ref");
+
+    let text = "pasted paragraph ".repeat(500);
+    let output = BrowserTypeTool::new()
+        .execute(
+            &json!({ "ref": textarea_ref, "element": "Document", "text": text, "pasteMode": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("Pasted 8500 characters"), "Got: {output:?}");
+
+    let length = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('doc').value.length" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(length.contains(&text.len().to_string()), "Got: {length:?}");
+
+    browser.shutdown().await;
+}
@@ -0,0 +1,91 @@
+//! File upload integration tests
+
+use serde_json::json;
+use tempfile::TempDir;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserFileUploadTool, BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+/// Create files with the given names in a temp dir, returning their paths
+fn create_files(dir: &TempDir, names: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .map(|name| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, b"test content").unwrap();
+            path.to_string_lossy().to_string()
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_upload_multiple_files() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let upload_tool = BrowserFileUploadTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='file' multiple accept='.txt,image/*'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let dir = TempDir::new().unwrap();
+    let files = create_files(&dir, &["notes.txt", "photo.png", "scan.jpg"]);
+
+    let result = upload_tool
+        .execute(&json!({ "files": files }), &mut browser)
+        .await;
+    assert!(result.is_ok(), "Upload failed: {:?}", result.err());
+    assert!(result.unwrap().contains("Uploaded 3 file(s)"));
+
+    let count = eval_tool
+        .execute(
+            &json!({ "function": "() => document.querySelector('input').files.length" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(count.contains('3'), "Expected 3 files on input: {count}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_upload_rejects_files_not_accepted() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let upload_tool = BrowserFileUploadTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<input type='file' multiple accept='.pdf'>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let dir = TempDir::new().unwrap();
+    let files = create_files(&dir, &["report.pdf", "photo.png"]);
+
+    let result = upload_tool
+        .execute(&json!({ "paths": files }), &mut browser)
+        .await;
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("photo.png"), "Unexpected error: {err}");
+    assert!(!err.contains("report.pdf"), "Unexpected error: {err}");
+
+    // Disabling the check lets the upload through
+    let result = upload_tool
+        .execute(
+            &json!({ "paths": files, "acceptCheck": false }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Upload failed: {:?}", result.err());
+
+    browser.shutdown().await;
+}
@@ -0,0 +1,30 @@
+//! Integration tests for navigation options that need a local HTTP server
+//!
+//! Run with:
+//! ```sh
+//! cargo test --features integration -p viewpoint-mcp --test navigation
+//! ```
+#![cfg(feature = "integration")]
+
+mod navigation {
+    pub mod auth_tests;
+    pub mod history_wait_tests;
+    pub mod https_tests;
+    pub mod retry_tests;
+
+    use viewpoint_mcp::browser::{BrowserConfig, BrowserState};
+
+    /// Helper to create a headless browser state
+    pub async fn create_browser() -> BrowserState {
+        let config = BrowserConfig {
+            headless: true,
+            ..Default::default()
+        };
+        let mut state = BrowserState::new(config);
+        state
+            .initialize()
+            .await
+            .expect("Failed to initialize browser");
+        state
+    }
+}
@@ -0,0 +1,159 @@
+//! Tests for browser_navigate HTTP Basic Authentication
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+/// `Authorization` header value for `admin:s3cret`
+const EXPECTED_AUTH: &str = "Basic YWRtaW46czNjcmV0";
+
+/// Serve a page on localhost that requires Basic Auth as `admin:s3cret`
+async fn serve_protected_page() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 4096];
+            let n = stream.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let authorized = request
+                .lines()
+                .any(|line| line.eq_ignore_ascii_case(&format!("authorization: {EXPECTED_AUTH}")));
+
+            let response = if authorized {
+                let body = "<html><body><h1 id='secret'>Welcome</h1></body></html>";
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            } else {
+                let body = "Unauthorized";
+                format!(
+                    "HTTP/1.1 401 Unauthorized\r\nWWW-Authenticate: Basic realm=\"staging\"\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            };
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    format!("http://127.0.0.1:{port}/")
+}
+
+#[tokio::test]
+async fn test_navigate_with_basic_auth() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+    let url = serve_protected_page().await;
+
+    nav_tool
+        .execute(
+            &json!({
+                "url": url,
+                "basicAuth": { "username": "admin", "password": "s3cret" }
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let heading = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('secret')?.textContent ?? 'missing'" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(heading.contains("Welcome"), "Got: {heading:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_basic_auth_cleared_after_navigation() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+    let url = serve_protected_page().await;
+
+    nav_tool
+        .execute(
+            &json!({
+                "url": url,
+                "basicAuth": { "username": "admin", "password": "s3cret" }
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Without persist, a later navigation is challenged again
+    nav_tool
+        .execute(&json!({ "url": format!("{url}?again") }), &mut browser)
+        .await
+        .unwrap();
+
+    let heading = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('secret')?.textContent ?? 'missing'" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(heading.contains("missing"), "Got: {heading:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_temporary_basic_auth_restores_persisted_credentials() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+    let url = serve_protected_page().await;
+
+    nav_tool
+        .execute(
+            &json!({
+                "url": url,
+                "basicAuth": { "username": "admin", "password": "s3cret", "persist": true }
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // One navigation with other, temporary credentials...
+    nav_tool
+        .execute(
+            &json!({
+                "url": format!("{url}?other"),
+                "basicAuth": { "username": "guest", "password": "guest" }
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // ...leaves the persisted credentials in place for the next one
+    nav_tool
+        .execute(&json!({ "url": format!("{url}?again") }), &mut browser)
+        .await
+        .unwrap();
+
+    let heading = eval_tool
+        .execute(
+            &json!({ "function": "() => document.getElementById('secret')?.textContent ?? 'missing'" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(heading.contains("Welcome"), "Got: {heading:?}");
+
+    browser.shutdown().await;
+}
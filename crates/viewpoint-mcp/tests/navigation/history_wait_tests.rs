@@ -0,0 +1,109 @@
+//! Tests for browser_navigate_back `waitUntil`
+
+use std::time::Duration;
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use viewpoint_mcp::tools::{
+    BrowserEvaluateTool, BrowserNavigateBackTool, BrowserNavigateTool, Tool,
+};
+
+use super::create_browser;
+
+/// Serve `/a`, which fetches the slow `/slow` endpoint after load, and `/b`.
+///
+/// Pages are sent with `Cache-Control: no-store` so going back reloads `/a`
+/// (and refetches `/slow`) instead of restoring it from the back/forward cache.
+async fn serve_pages() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut buf = [0u8; 4096];
+                let n = stream.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+
+                let (content_type, body) = match path.as_str() {
+                    "/a" => (
+                        "text/html",
+                        "<html><body><h1>A</h1><script>\
+                         window.addEventListener('load', () => \
+                         fetch('/slow').then(() => { window.__slowDone = true; }));\
+                         </script></body></html>",
+                    ),
+                    "/slow" => {
+                        tokio::time::sleep(Duration::from_millis(1500)).await;
+                        ("text/plain", "done")
+                    }
+                    _ => ("text/html", "<html><body><h1>B</h1></body></html>"),
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nCache-Control: no-store\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            });
+        }
+    });
+
+    format!("http://127.0.0.1:{port}")
+}
+
+#[tokio::test]
+async fn test_navigate_back_wait_until_networkidle() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let back_tool = BrowserNavigateBackTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+    let base = serve_pages().await;
+
+    nav_tool
+        .execute(&json!({ "url": format!("{base}/a") }), &mut browser)
+        .await
+        .unwrap();
+    nav_tool
+        .execute(&json!({ "url": format!("{base}/b") }), &mut browser)
+        .await
+        .unwrap();
+
+    let output = back_tool
+        .execute(&json!({ "waitUntil": "networkidle" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(output.contains("/a"), "Got: {output:?}");
+
+    // The restored page's slow request has settled by the time back returns
+    let done = eval_tool
+        .execute(
+            &json!({ "function": "() => String(window.__slowDone === true)" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(done.contains("true"), "Got: {done:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_navigate_back_invalid_wait_until() {
+    let mut browser = create_browser().await;
+    let back_tool = BrowserNavigateBackTool::new();
+
+    let result = back_tool
+        .execute(&json!({ "waitUntil": "never" }), &mut browser)
+        .await;
+    assert!(
+        matches!(
+            result,
+            Err(viewpoint_mcp::tools::ToolError::InvalidParams(_))
+        ),
+        "Unknown waitUntil should be rejected: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
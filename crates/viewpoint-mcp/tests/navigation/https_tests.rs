@@ -0,0 +1,106 @@
+//! Tests for browser_navigate's ignoreHTTPSErrors option
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_rustls::rustls::ServerConfig;
+use tokio_rustls::rustls::crypto::ring::default_provider;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use viewpoint_mcp::tools::{BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+/// Self-signed certificate for 127.0.0.1, valid until 2125
+const CERT_PEM: &[u8] = include_bytes!("fixtures/self_signed_cert.pem");
+
+/// Private key of [`CERT_PEM`]
+const KEY_PEM: &[u8] = include_bytes!("fixtures/self_signed_key.pem");
+
+/// Serve a page over HTTPS on localhost with a self-signed certificate
+async fn serve_self_signed_page() -> String {
+    let cert = CertificateDer::from_pem_slice(CERT_PEM).unwrap();
+    let key = PrivateKeyDer::from_pem_slice(KEY_PEM).unwrap();
+    let config = ServerConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_no_client_auth()
+        .with_single_cert(vec![cert], key)
+        .unwrap();
+    let acceptor = TlsAcceptor::from(Arc::new(config));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let acceptor = acceptor.clone();
+            tokio::spawn(async move {
+                // The browser aborts the handshake while the certificate is rejected
+                let Ok(mut stream) = acceptor.accept(stream).await else {
+                    return;
+                };
+                let mut buf = [0u8; 4096];
+                let _ = stream.read(&mut buf).await;
+                let body =
+                    "<html><head><title>Self-signed</title></head><body>Secure</body></html>";
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            });
+        }
+    });
+
+    format!("https://127.0.0.1:{port}/")
+}
+
+#[tokio::test]
+async fn test_ignore_https_errors_applies_to_one_navigation() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let url = serve_self_signed_page().await;
+
+    let result = nav_tool
+        .execute(
+            &json!({ "url": url, "ignoreHTTPSErrors": true }),
+            &mut browser,
+        )
+        .await
+        .expect("Self-signed page should load with ignoreHTTPSErrors");
+    assert!(result.contains("Self-signed"), "Got: {result:?}");
+
+    // Certificate checks are back for the next navigation
+    let rejected = nav_tool
+        .execute(&json!({ "url": format!("{url}?again") }), &mut browser)
+        .await;
+    assert!(rejected.is_err(), "Got: {rejected:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_ignore_https_errors_restored_after_failed_navigation() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let url = serve_self_signed_page().await;
+
+    // Nothing listens on port 1, so this navigation fails
+    let failed = nav_tool
+        .execute(
+            &json!({ "url": "https://127.0.0.1:1/", "ignoreHTTPSErrors": true }),
+            &mut browser,
+        )
+        .await;
+    assert!(failed.is_err(), "Got: {failed:?}");
+
+    let rejected = nav_tool.execute(&json!({ "url": url }), &mut browser).await;
+    assert!(rejected.is_err(), "Got: {rejected:?}");
+
+    browser.shutdown().await;
+}
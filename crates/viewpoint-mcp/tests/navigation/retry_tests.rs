@@ -0,0 +1,105 @@
+//! Tests for browser_navigate retries
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json::json;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use viewpoint_mcp::tools::{BrowserNavigateTool, Tool};
+
+use super::create_browser;
+
+/// Serve a page on localhost that answers 503 to the first `failures`
+/// document requests, returning the URL and the request counter
+async fn serve_flaky_page(failures: usize) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&requests);
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let mut buf = [0u8; 4096];
+            let n = stream.read(&mut buf).await.unwrap_or(0);
+            let request = String::from_utf8_lossy(&buf[..n]);
+
+            let response = if request.starts_with("GET / ")
+                && counter.fetch_add(1, Ordering::SeqCst) < failures
+            {
+                let body = "Service Unavailable";
+                format!(
+                    "HTTP/1.1 503 Service Unavailable\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            } else {
+                let body = "<html><head><title>Ready</title></head><body>ok</body></html>";
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+            };
+            let _ = stream.write_all(response.as_bytes()).await;
+        }
+    });
+
+    (format!("http://127.0.0.1:{port}/"), requests)
+}
+
+#[tokio::test]
+async fn test_navigate_retries_unavailable_status() {
+    let mut browser = create_browser().await;
+    let (url, requests) = serve_flaky_page(2).await;
+
+    let output = BrowserNavigateTool::new()
+        .execute(
+            &json!({ "url": url, "retries": 3, "retryDelayMs": 10 }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(output.contains("\"title\": \"Ready\""), "Got: {output:?}");
+    assert!(output.contains("\"attempts\": 3"), "Got: {output:?}");
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_navigate_returns_last_error_after_retries() {
+    let mut browser = create_browser().await;
+    let (url, requests) = serve_flaky_page(usize::MAX).await;
+
+    let result = BrowserNavigateTool::new()
+        .execute(
+            &json!({ "url": url, "retries": 1, "retryDelayMs": 10 }),
+            &mut browser,
+        )
+        .await;
+
+    let error = result.unwrap_err().to_string();
+    assert!(
+        error.contains("HTTP 503 (gave up after 2 attempts)"),
+        "Got: {error}"
+    );
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_navigate_without_retries_accepts_error_status() {
+    let mut browser = create_browser().await;
+    let (url, requests) = serve_flaky_page(1).await;
+
+    let output = BrowserNavigateTool::new()
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    assert!(!output.contains("attempts"), "Got: {output:?}");
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    browser.shutdown().await;
+}
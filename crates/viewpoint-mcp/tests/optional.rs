@@ -7,6 +7,7 @@
 #![cfg(feature = "integration")]
 
 mod optional {
+    pub mod capability_tests;
     pub mod pdf_tests;
     pub mod vision_tests;
 
@@ -2,7 +2,7 @@
 
 use serde_json::json;
 use tempfile::TempDir;
-use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserPdfSaveTool, Tool};
+use viewpoint_mcp::tools::{BrowserNavigateTool, BrowserPdfSaveTool, ContentItem, Tool};
 
 use super::create_browser;
 
@@ -74,6 +74,41 @@ async fn test_pdf_save_a4_format() {
     browser.shutdown().await;
 }
 
+#[tokio::test]
+async fn test_pdf_save_custom_size() {
+    let temp_dir = TempDir::new().unwrap();
+    let pdf_path = temp_dir.path().join("receipt.pdf");
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let pdf_tool = BrowserPdfSaveTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Receipt</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = pdf_tool
+        .execute(
+            &json!({
+                "path": pdf_path.to_str().unwrap(),
+                "width": 3.15,
+                "height": 8
+            }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(output.contains("size: 3.15x8 in"), "Got: {output:?}");
+    assert!(pdf_path.exists());
+
+    browser.shutdown().await;
+}
+
 #[tokio::test]
 async fn test_pdf_save_landscape() {
     let temp_dir = TempDir::new().unwrap();
@@ -292,3 +327,88 @@ async fn test_pdf_save_all_formats() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_pdf_save_page_ranges_with_header_footer() {
+    let temp_dir = TempDir::new().unwrap();
+    let pdf_path = temp_dir.path().join("ranges.pdf");
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let pdf_tool = BrowserPdfSaveTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1 style='page-break-after:always'>One</h1><h1 style='page-break-after:always'>Two</h1><h1>Three</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let result = pdf_tool
+        .execute(
+            &json!({
+                "path": pdf_path.to_str().unwrap(),
+                "pageRanges": "1, 3",
+                "headerTemplate": "<span class='date'></span>",
+                "footerTemplate": "<span class='pageNumber'></span> / <span class='totalPages'></span>",
+                "margin": 0.5
+            }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(result.is_ok(), "Got: {result:?}");
+    assert!(pdf_path.exists(), "PDF file should be created");
+
+    // Ranges past the end of the document are rejected by the browser
+    let result = pdf_tool
+        .execute(
+            &json!({
+                "path": temp_dir.path().join("missing.pdf").to_str().unwrap(),
+                "pageRanges": "10-12"
+            }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err(), "Out-of-range pages should fail");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_pdf_save_inline() {
+    use base64::engine::{Engine as _, general_purpose::STANDARD};
+
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let pdf_tool = BrowserPdfSaveTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Inline PDF</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let output = pdf_tool
+        .execute(&json!({ "path": null }), &mut browser)
+        .await
+        .unwrap();
+
+    let [
+        ContentItem::Text { text },
+        ContentItem::Image { data, mime_type },
+    ] = output.content.as_slice()
+    else {
+        panic!("Expected text and PDF content items, got {output:?}");
+    };
+    assert!(text.contains("PDF generated inline"), "Got: {text:?}");
+    assert_eq!(mime_type, "application/pdf");
+
+    let bytes = STANDARD.decode(data).expect("PDF should be base64-encoded");
+    assert!(bytes.starts_with(b"%PDF"), "Should be valid PDF");
+
+    browser.shutdown().await;
+}
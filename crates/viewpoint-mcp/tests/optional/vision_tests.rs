@@ -1,134 +1,13 @@
 //! Vision capability tool integration tests (mouse_click_xy, mouse_move_xy, mouse_drag_xy)
 
 use serde_json::json;
-use std::sync::Arc;
 use viewpoint_mcp::tools::{
     BrowserMouseClickXyTool, BrowserMouseDragXyTool, BrowserMouseMoveXyTool, BrowserNavigateTool,
-    BrowserPdfSaveTool, Capability, Tool, ToolRegistry,
+    Tool,
 };
 
 use super::create_browser;
 
-// =============================================================================
-// Capability-Based Tool Filtering Tests
-// =============================================================================
-
-#[test]
-fn test_registry_without_capabilities() {
-    let mut registry = ToolRegistry::new();
-
-    // Register tools
-    registry.register(Arc::new(BrowserNavigateTool::new()));
-    registry.register(Arc::new(BrowserMouseClickXyTool::new()));
-    registry.register(Arc::new(BrowserMouseMoveXyTool::new()));
-    registry.register(Arc::new(BrowserMouseDragXyTool::new()));
-    registry.register(Arc::new(BrowserPdfSaveTool::new()));
-
-    // Without capabilities, vision and PDF tools should be hidden
-    let available = registry.list();
-    let names: Vec<_> = available.iter().map(|t| t.name()).collect();
-
-    assert!(names.contains(&"browser_navigate"));
-    assert!(!names.contains(&"browser_mouse_click_xy"));
-    assert!(!names.contains(&"browser_mouse_move_xy"));
-    assert!(!names.contains(&"browser_mouse_drag_xy"));
-    assert!(!names.contains(&"browser_pdf_save"));
-}
-
-#[test]
-fn test_registry_with_vision_capability() {
-    let mut registry = ToolRegistry::with_capabilities([Capability::Vision]);
-
-    registry.register(Arc::new(BrowserNavigateTool::new()));
-    registry.register(Arc::new(BrowserMouseClickXyTool::new()));
-    registry.register(Arc::new(BrowserMouseMoveXyTool::new()));
-    registry.register(Arc::new(BrowserMouseDragXyTool::new()));
-    registry.register(Arc::new(BrowserPdfSaveTool::new()));
-
-    let available = registry.list();
-    let names: Vec<_> = available.iter().map(|t| t.name()).collect();
-
-    // Vision tools should be available
-    assert!(names.contains(&"browser_navigate"));
-    assert!(names.contains(&"browser_mouse_click_xy"));
-    assert!(names.contains(&"browser_mouse_move_xy"));
-    assert!(names.contains(&"browser_mouse_drag_xy"));
-    // PDF should still be hidden
-    assert!(!names.contains(&"browser_pdf_save"));
-}
-
-#[test]
-fn test_registry_with_pdf_capability() {
-    let mut registry = ToolRegistry::with_capabilities([Capability::Pdf]);
-
-    registry.register(Arc::new(BrowserNavigateTool::new()));
-    registry.register(Arc::new(BrowserMouseClickXyTool::new()));
-    registry.register(Arc::new(BrowserPdfSaveTool::new()));
-
-    let available = registry.list();
-    let names: Vec<_> = available.iter().map(|t| t.name()).collect();
-
-    assert!(names.contains(&"browser_navigate"));
-    assert!(names.contains(&"browser_pdf_save"));
-    // Vision should be hidden
-    assert!(!names.contains(&"browser_mouse_click_xy"));
-}
-
-#[test]
-fn test_registry_with_all_capabilities() {
-    let mut registry = ToolRegistry::with_capabilities([Capability::Vision, Capability::Pdf]);
-
-    registry.register(Arc::new(BrowserNavigateTool::new()));
-    registry.register(Arc::new(BrowserMouseClickXyTool::new()));
-    registry.register(Arc::new(BrowserMouseMoveXyTool::new()));
-    registry.register(Arc::new(BrowserMouseDragXyTool::new()));
-    registry.register(Arc::new(BrowserPdfSaveTool::new()));
-
-    let available = registry.list();
-    assert_eq!(available.len(), 5); // All tools available
-}
-
-#[test]
-fn test_get_unavailable_tool() {
-    let mut registry = ToolRegistry::new();
-    registry.register(Arc::new(BrowserMouseClickXyTool::new()));
-
-    // get() should return None for capability-gated tools
-    assert!(registry.get("browser_mouse_click_xy").is_none());
-
-    // get_unchecked() should still return the tool
-    assert!(registry.get_unchecked("browser_mouse_click_xy").is_some());
-}
-
-#[test]
-fn test_required_capability_vision_tools() {
-    assert_eq!(
-        BrowserMouseClickXyTool::new().required_capability(),
-        Some(Capability::Vision)
-    );
-    assert_eq!(
-        BrowserMouseMoveXyTool::new().required_capability(),
-        Some(Capability::Vision)
-    );
-    assert_eq!(
-        BrowserMouseDragXyTool::new().required_capability(),
-        Some(Capability::Vision)
-    );
-}
-
-#[test]
-fn test_required_capability_pdf_tool() {
-    assert_eq!(
-        BrowserPdfSaveTool::new().required_capability(),
-        Some(Capability::Pdf)
-    );
-}
-
-#[test]
-fn test_required_capability_standard_tool() {
-    assert_eq!(BrowserNavigateTool::new().required_capability(), None);
-}
-
 // =============================================================================
 // browser_mouse_click_xy Tests
 // =============================================================================
@@ -12,6 +12,7 @@ mod tools_management {
     pub mod install_tests;
     pub mod integration_tests;
     pub mod resize_tests;
+    pub mod tabs_query_tests;
     pub mod tabs_tests;
 
     use viewpoint_mcp::browser::{BrowserConfig, BrowserState};
@@ -1,7 +1,9 @@
 //! Tests for browser_close tool
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserCloseTool, BrowserTabsTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserCloseTool, BrowserNavigateTool, BrowserTabsTool, Tool, ToolError,
+};
 
 use super::create_browser;
 
@@ -32,10 +34,134 @@ async fn test_close_last_page() {
 
     // Try to close the only page
     let result = close_tool.execute(&json!({}), &mut browser).await;
+    assert!(
+        matches!(&result, Err(ToolError::ExecutionFailed(msg)) if msg == "Cannot close last tab"),
+        "Got: {result:?}"
+    );
 
-    // May succeed (closing browser) or fail (can't close last page)
-    // Just ensure no panic
-    let _ = result;
+    // The tab is still open
+    let context = browser.active_context().unwrap();
+    assert_eq!(context.page_count().await.unwrap(), 1);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_close_all_tabs_opens_blank_tab() {
+    let mut browser = create_browser().await;
+    let close_tool = BrowserCloseTool::new();
+    let tabs_tool = BrowserTabsTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>First</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    for _ in 0..2 {
+        tabs_tool
+            .execute(&json!({ "action": "new" }), &mut browser)
+            .await
+            .unwrap();
+    }
+
+    let output = close_tool
+        .execute(&json!({ "allTabs": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        output.contains("Closed 3 tab(s) and opened a new blank tab"),
+        "Got: {output:?}"
+    );
+
+    // A single blank tab remains and is usable
+    let context = browser.active_context().unwrap();
+    assert_eq!(context.page_count().await.unwrap(), 1);
+    let url = context
+        .active_page()
+        .await
+        .unwrap()
+        .unwrap()
+        .url()
+        .await
+        .unwrap();
+    assert_eq!(url, "about:blank");
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Again</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_close_all_tabs_with_single_tab() {
+    let mut browser = create_browser().await;
+    let close_tool = BrowserCloseTool::new();
+
+    let output = close_tool
+        .execute(&json!({ "allTabs": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(output.contains("Closed 1 tab(s)"), "Got: {output:?}");
+
+    let context = browser.active_context().unwrap();
+    assert_eq!(context.page_count().await.unwrap(), 1);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_close_context_pages_keeps_context() {
+    let mut browser = create_browser().await;
+    let close_tool = BrowserCloseTool::new();
+    let tabs_tool = BrowserTabsTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+
+    tabs_tool
+        .execute(&json!({ "action": "new" }), &mut browser)
+        .await
+        .unwrap();
+
+    let output = close_tool
+        .execute(&json!({ "context": "default" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(output.contains("Closed 2 page(s)"), "Got: {output:?}");
+
+    // The context survives with no pages
+    let context = browser.get_context("default").unwrap();
+    assert_eq!(context.page_count().await.unwrap(), 0);
+
+    // Navigating opens a fresh page in the same context
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Fresh</h1>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let context = browser.get_context("default").unwrap();
+    assert_eq!(context.page_count().await.unwrap(), 1);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_close_unknown_context() {
+    let mut browser = create_browser().await;
+    let close_tool = BrowserCloseTool::new();
+
+    let result = close_tool
+        .execute(&json!({ "context": "missing" }), &mut browser)
+        .await;
+    assert!(result.is_err());
 
     browser.shutdown().await;
 }
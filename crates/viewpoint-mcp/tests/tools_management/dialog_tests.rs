@@ -1,7 +1,9 @@
 //! Tests for browser_handle_dialog tool
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserHandleDialogTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserEvaluateTool, BrowserHandleDialogTool, BrowserNavigateTool, Tool,
+};
 
 use super::create_browser;
 
@@ -63,3 +65,97 @@ async fn test_dialog_missing_accept() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_dialog_preregister_accepts_alert_on_load() {
+    let mut browser = create_browser().await;
+    let dialog_tool = BrowserHandleDialogTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let output = dialog_tool
+        .execute(
+            &json!({ "accept": true, "preregister": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(output.contains("registered"), "Got: {output:?}");
+
+    // The alert opens during page load, before any further tool call
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<script>window.alert('Hello'); window.afterAlert = true;</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => window.afterAlert === true" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(value.contains("true"), "Got: {value:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_dialog_preregister_uses_latest_response() {
+    let mut browser = create_browser().await;
+    let dialog_tool = BrowserHandleDialogTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    dialog_tool
+        .execute(
+            &json!({ "accept": true, "promptText": "Ada", "preregister": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<script>window.answer = window.prompt('Name?');</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let value = eval_tool
+        .execute(&json!({ "function": "() => window.answer" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(value.contains("Ada"), "Got: {value:?}");
+
+    // A later call switches the registered handler to dismissing
+    let output = dialog_tool
+        .execute(&json!({ "accept": false }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        output.contains("pre-registered handler updated"),
+        "Got: {output:?}"
+    );
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<script>window.confirmed = window.confirm('Sure?');</script>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    let value = eval_tool
+        .execute(
+            &json!({ "function": "() => window.confirmed === false" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(value.contains("true"), "Got: {value:?}");
+
+    browser.shutdown().await;
+}
@@ -1,7 +1,7 @@
 //! Tests for browser_resize tool
 
 use serde_json::json;
-use viewpoint_mcp::tools::{BrowserResizeTool, Tool};
+use viewpoint_mcp::tools::{BrowserEvaluateTool, BrowserResizeTool, Tool};
 
 use super::create_browser;
 
@@ -99,3 +99,41 @@ async fn test_resize_zero_dimensions() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_resize_with_device_scale_factor() {
+    let mut browser = create_browser().await;
+    let resize_tool = BrowserResizeTool::new();
+    let eval_tool = BrowserEvaluateTool::new();
+
+    let result = resize_tool
+        .execute(
+            &json!({ "width": 390, "height": 844, "deviceScaleFactor": 2, "mobile": true }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Resize should succeed: {:?}", result.err());
+
+    let output = eval_tool
+        .execute(
+            &json!({ "function": "() => String(window.devicePixelRatio)" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        output.contains('2'),
+        "devicePixelRatio should be 2: {output:?}"
+    );
+
+    // Non-positive scale factors are rejected
+    let result = resize_tool
+        .execute(
+            &json!({ "width": 390, "height": 844, "deviceScaleFactor": 0 }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err());
+
+    browser.shutdown().await;
+}
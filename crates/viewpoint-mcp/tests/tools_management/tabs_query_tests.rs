@@ -0,0 +1,147 @@
+//! Tests for the browser_tabs query actions (title, url, findByUrl)
+
+use serde_json::json;
+use viewpoint_mcp::tools::{BrowserContextCreateTool, BrowserNavigateTool, BrowserTabsTool, Tool};
+
+use super::create_browser;
+
+#[tokio::test]
+async fn test_tabs_title_and_url() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let tabs_tool = BrowserTabsTool::new();
+
+    let url = "data:text/html,<title>Home Page</title><h1>Home</h1>";
+    nav_tool
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let title = tabs_tool
+        .execute(&json!({ "action": "title" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        title.contains("title: Home Page"),
+        "Should report the page title. Got: {title:?}"
+    );
+
+    let current = tabs_tool
+        .execute(&json!({ "action": "url" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        current.contains("URL: data:text/html"),
+        "Should report the page URL. Got: {current:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tabs_title_in_other_context() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let tabs_tool = BrowserTabsTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<title>Default Tab</title>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Creating a context makes it the active one
+    BrowserContextCreateTool::new()
+        .execute(&json!({ "name": "other" }), &mut browser)
+        .await
+        .unwrap();
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<title>Other Tab</title>" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let title = tabs_tool
+        .execute(
+            &json!({ "action": "title", "context": "default" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        title.contains("in context 'default' title: Default Tab"),
+        "Should query the named context. Got: {title:?}"
+    );
+
+    let result = tabs_tool
+        .execute(
+            &json!({ "action": "url", "context": "missing" }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_err(), "Unknown context should fail");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tabs_find_by_url() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let tabs_tool = BrowserTabsTool::new();
+
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<title>First</title>first" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    tabs_tool
+        .execute(&json!({ "action": "new" }), &mut browser)
+        .await
+        .unwrap();
+    nav_tool
+        .execute(
+            &json!({ "url": "data:text/html,<title>Second</title>second" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    let found = tabs_tool
+        .execute(
+            &json!({ "action": "findByUrl", "pattern": "second" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(found.contains("\"found\":true"), "Got: {found:?}");
+    assert!(found.contains("\"index\":1"), "Got: {found:?}");
+    assert!(found.contains("\"title\":\"Second\""), "Got: {found:?}");
+
+    // Globs match the whole URL, and the first match wins
+    let found = tabs_tool
+        .execute(
+            &json!({ "action": "findByUrl", "pattern": "data:text/html,*" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(found.contains("\"index\":0"), "Got: {found:?}");
+
+    let missing = tabs_tool
+        .execute(
+            &json!({ "action": "findByUrl", "pattern": "https://*.example.com/*" }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(missing.contains("\"found\":false"), "Got: {missing:?}");
+
+    browser.shutdown().await;
+}
@@ -188,3 +188,248 @@ async fn test_tabs_invalid_action() {
 
     browser.shutdown().await;
 }
+
+#[tokio::test]
+async fn test_tabs_duplicate() {
+    let mut browser = create_browser().await;
+    let tabs_tool = BrowserTabsTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+
+    let url = "data:text/html,<h1>Duplicate me</h1>";
+    nav_tool
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    let result = tabs_tool
+        .execute(&json!({ "action": "duplicate" }), &mut browser)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "Duplicate tab should succeed: {:?}",
+        result.err()
+    );
+
+    let context = browser.active_context().unwrap();
+    let pages = context.pages().await.unwrap();
+    assert_eq!(pages.len(), 2, "Duplicate should open a second tab");
+
+    // The duplicate becomes the active tab
+    assert_eq!(context.active_page_index().await, 1);
+
+    let original_url = pages[0].url().await.unwrap();
+    let duplicate_url = pages[1].url().await.unwrap();
+    assert_eq!(original_url, duplicate_url);
+
+    browser.shutdown().await;
+}
+
+/// Open three tabs with distinct URLs and return their URLs in tab order
+async fn open_three_tabs(browser: &mut viewpoint_mcp::browser::BrowserState) -> Vec<String> {
+    let tabs_tool = BrowserTabsTool::new();
+    let nav_tool = BrowserNavigateTool::new();
+
+    let urls: Vec<String> = ["A", "B", "C"]
+        .iter()
+        .map(|name| format!("data:text/html,<h1>{name}</h1>"))
+        .collect();
+
+    for (i, url) in urls.iter().enumerate() {
+        if i > 0 {
+            tabs_tool
+                .execute(&json!({ "action": "new" }), browser)
+                .await
+                .unwrap();
+        }
+        nav_tool
+            .execute(&json!({ "url": url }), browser)
+            .await
+            .unwrap();
+    }
+
+    urls
+}
+
+async fn tab_urls(browser: &viewpoint_mcp::browser::BrowserState) -> Vec<String> {
+    let pages = browser.active_context().unwrap().pages().await.unwrap();
+    let mut urls = Vec::new();
+    for page in &pages {
+        urls.push(page.url().await.unwrap());
+    }
+    urls
+}
+
+#[tokio::test]
+async fn test_tabs_move() {
+    let mut browser = create_browser().await;
+    let tabs_tool = BrowserTabsTool::new();
+    let urls = open_three_tabs(&mut browser).await;
+
+    // Tab C (index 2) is active; move tab A to the end
+    let result = tabs_tool
+        .execute(
+            &json!({ "action": "move", "from": 0, "to": 2 }),
+            &mut browser,
+        )
+        .await;
+    assert!(result.is_ok(), "Move should succeed: {:?}", result.err());
+
+    assert_eq!(
+        tab_urls(&browser).await,
+        vec![urls[1].clone(), urls[2].clone(), urls[0].clone()]
+    );
+
+    // The active tab (C) keeps focus at its new index
+    let context = browser.active_context().unwrap();
+    assert_eq!(context.active_page_index().await, 1);
+    assert_eq!(context.get_current_url().await.unwrap(), urls[2]);
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tabs_move_then_select_and_new() {
+    let mut browser = create_browser().await;
+    let tabs_tool = BrowserTabsTool::new();
+    let urls = open_three_tabs(&mut browser).await;
+
+    tabs_tool
+        .execute(
+            &json!({ "action": "move", "from": 2, "to": 0 }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    // Selecting uses the new order
+    tabs_tool
+        .execute(&json!({ "action": "select", "index": 1 }), &mut browser)
+        .await
+        .unwrap();
+    let context = browser.active_context().unwrap();
+    assert_eq!(context.get_current_url().await.unwrap(), urls[0]);
+
+    // New tabs are appended after the reordered ones
+    tabs_tool
+        .execute(&json!({ "action": "new" }), &mut browser)
+        .await
+        .unwrap();
+    let order = tab_urls(&browser).await;
+    assert_eq!(order.len(), 4);
+    assert_eq!(
+        order[..3],
+        [urls[2].clone(), urls[0].clone(), urls[1].clone()]
+    );
+    assert_eq!(
+        browser.active_context().unwrap().active_page_index().await,
+        3
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tabs_move_out_of_range() {
+    let mut browser = create_browser().await;
+    let tabs_tool = BrowserTabsTool::new();
+
+    let result = tabs_tool
+        .execute(
+            &json!({ "action": "move", "from": 0, "to": 5 }),
+            &mut browser,
+        )
+        .await;
+
+    assert!(
+        matches!(
+            result,
+            Err(viewpoint_mcp::tools::ToolError::InvalidParams(_))
+        ),
+        "Out of range move should be InvalidParams: {result:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tabs_pinned_survives_unpinned_close() {
+    let mut browser = create_browser().await;
+    let tabs_tool = BrowserTabsTool::new();
+
+    let result = tabs_tool
+        .execute(&json!({ "action": "new", "pinned": true }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        result.contains("Created new pinned tab at index 0"),
+        "Pinned tab should be placed first. Got: {result:?}"
+    );
+
+    tabs_tool
+        .execute(&json!({ "action": "new" }), &mut browser)
+        .await
+        .unwrap();
+
+    // Close the unpinned tab that was just opened
+    tabs_tool
+        .execute(&json!({ "action": "close", "index": 2 }), &mut browser)
+        .await
+        .unwrap();
+
+    let list = tabs_tool
+        .execute(&json!({ "action": "list" }), &mut browser)
+        .await
+        .unwrap();
+    assert!(
+        list.contains("Tabs (2 total)"),
+        "Two tabs should remain. Got: {list:?}"
+    );
+    assert!(
+        list.contains("0: about:blank (pinned)"),
+        "Pinned tab should survive the close. Got: {list:?}"
+    );
+    assert_eq!(
+        list.matches("(pinned)").count(),
+        1,
+        "Original tab should not be marked as pinned. Got: {list:?}"
+    );
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_tabs_move_keeps_pinned_tabs_first() {
+    let mut browser = create_browser().await;
+    let tabs_tool = BrowserTabsTool::new();
+
+    // Tab 0 is pinned, tab 1 is the original unpinned tab
+    tabs_tool
+        .execute(&json!({ "action": "new", "pinned": true }), &mut browser)
+        .await
+        .unwrap();
+
+    let result = tabs_tool
+        .execute(
+            &json!({ "action": "move", "from": 1, "to": 0 }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+    assert!(
+        result.contains("Moved tab from index 1 to 1"),
+        "Unpinned tab should stay after the pinned one. Got: {result:?}"
+    );
+
+    let list = tabs_tool
+        .execute(&json!({ "action": "list" }), &mut browser)
+        .await
+        .unwrap();
+    // The new pinned tab is also the active one
+    assert!(
+        list.contains("0: about:blank [active] (pinned)"),
+        "Pinned tab should still be first. Got: {list:?}"
+    );
+
+    browser.shutdown().await;
+}
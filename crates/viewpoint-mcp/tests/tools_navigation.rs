@@ -8,7 +8,10 @@
 
 use serde_json::json;
 use viewpoint_mcp::browser::{BrowserConfig, BrowserState};
-use viewpoint_mcp::tools::{BrowserCloseTool, BrowserNavigateBackTool, BrowserNavigateTool, Tool};
+use viewpoint_mcp::tools::{
+    BrowserCloseTool, BrowserNavigateBackTool, BrowserNavigateForwardTool, BrowserNavigateTool,
+    Tool,
+};
 
 /// Helper to create a headless browser state
 async fn create_browser() -> BrowserState {
@@ -42,7 +45,45 @@ async fn test_navigate_to_url() {
 
     assert!(result.is_ok());
     let msg = result.unwrap();
-    assert!(msg.contains("Navigated to"));
+    assert!(msg.contains("\"status\": \"navigated\""));
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_navigate_reports_title_and_final_url() {
+    let mut browser = create_browser().await;
+    let tool = BrowserNavigateTool::new();
+
+    let url = "data:text/html,<title>Landing</title><h1>Hello</h1>";
+    let msg = tool
+        .execute(&json!({ "url": url }), &mut browser)
+        .await
+        .unwrap();
+
+    assert!(msg.contains("\"title\": \"Landing\""), "Got: {msg:?}");
+    assert!(msg.contains("\"url\": \"data:text/html"), "Got: {msg:?}");
+    assert!(!msg.contains("redirectedFrom"), "Got: {msg:?}");
+
+    browser.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_navigate_include_security_info() {
+    let mut browser = create_browser().await;
+    let tool = BrowserNavigateTool::new();
+
+    let msg = tool
+        .execute(
+            &json!({ "url": "data:text/html,<h1>Plain</h1>", "includeSecurityInfo": true }),
+            &mut browser,
+        )
+        .await
+        .unwrap();
+
+    assert!(msg.contains("\"protocol\": \"data\""), "Got: {msg:?}");
+    assert!(msg.contains("\"certValid\": false"), "Got: {msg:?}");
+    assert!(!msg.contains("certExpiry"), "Got: {msg:?}");
 
     browser.shutdown().await;
 }
@@ -240,6 +281,52 @@ async fn test_navigate_back_multiple_times() {
     browser.shutdown().await;
 }
 
+#[tokio::test]
+async fn test_navigate_back_and_forward_steps() {
+    let mut browser = create_browser().await;
+    let nav_tool = BrowserNavigateTool::new();
+    let back_tool = BrowserNavigateBackTool::new();
+    let forward_tool = BrowserNavigateForwardTool::new();
+
+    for i in 1..=4 {
+        nav_tool
+            .execute(
+                &json!({ "url": format!("data:text/html,<h1>Page {}</h1>", i) }),
+                &mut browser,
+            )
+            .await
+            .unwrap();
+    }
+
+    // Page 4 -> Page 2
+    let result = back_tool
+        .execute(&json!({ "steps": 2 }), &mut browser)
+        .await;
+    assert!(result.is_ok(), "Back 2 steps failed: {:?}", result.err());
+    let msg = result.unwrap();
+    assert!(msg.contains("Page%202") || msg.contains("Page 2"));
+
+    // Page 2 -> Page 4
+    let result = forward_tool
+        .execute(&json!({ "steps": 2 }), &mut browser)
+        .await;
+    assert!(result.is_ok(), "Forward 2 steps failed: {:?}", result.err());
+
+    // Nothing further ahead
+    let result = forward_tool.execute(&json!({}), &mut browser).await;
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("Cannot go forward 1 steps, only 0 entries in history"));
+
+    // Too far back
+    let result = back_tool
+        .execute(&json!({ "steps": 10 }), &mut browser)
+        .await;
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("Cannot go back 10 steps"));
+
+    browser.shutdown().await;
+}
+
 #[tokio::test]
 async fn test_navigate_back_invalidates_cache() {
     let mut browser = create_browser().await;
@@ -376,10 +463,12 @@ async fn test_navigate_after_all_pages_closed() {
         .await;
     assert!(result.is_ok());
 
-    // Close the only page
-    let result = close_tool.execute(&json!({}), &mut browser).await;
+    // Close every page in the context
+    let result = close_tool
+        .execute(&json!({ "context": "default" }), &mut browser)
+        .await;
     assert!(result.is_ok());
-    assert!(result.unwrap().contains("no pages remaining"));
+    assert!(result.unwrap().contains("Closed 1 page(s)"));
 
     // Now navigate again - should auto-create a new page
     let result = navigate_tool
@@ -393,7 +482,7 @@ async fn test_navigate_after_all_pages_closed() {
         "Navigate after close should succeed, got: {:?}",
         result
     );
-    assert!(result.unwrap().contains("Navigated to"));
+    assert!(result.unwrap().contains("\"status\": \"navigated\""));
 
     // Verify the context now has a page
     let context = browser.active_context().unwrap();